@@ -97,9 +97,11 @@ impl PathApproxParams {
         let [min, max] = if a < b { [a, b] } else { [b, a] };
 
         // We can't generate a point exactly at the boundaries of the range as
-        // part of the approximation. Make sure we stay inside the range.
-        let min = min.floor() + 1.;
-        let max = max.ceil() - 1.;
+        // part of the approximation. Make sure we stay inside the range, even
+        // if a boundary is a rounding error away from a multiple of the
+        // increment.
+        let min = (min + EPSILON).floor() + 1.;
+        let max = (max - EPSILON).ceil() - 1.;
 
         let [start, end] = match direction {
             Sign::Negative => [max, min],
@@ -125,6 +127,10 @@ impl PathApproxParams {
     }
 }
 
+/// The fraction of an increment, within which a point is considered to be on a
+/// boundary
+const EPSILON: f64 = 1e-9;
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::TAU;
@@ -180,6 +186,11 @@ mod tests {
         test_path([[TAU], [2.]], [3., 2.]);
         test_path([[TAU - 2.], [0.]], [2., 1.]);
 
+        // Boundaries that are a rounding error away from a generated point
+        // don't end up with that point.
+        test_path([[0.], [TAU * 0.75 + 1e-12]], [1., 2.]);
+        test_path([[TAU * 0.25 - 1e-12], [TAU]], [2., 3.]);
+
         fn test_path(
             boundary: impl Into<CurveBoundary<Point<1>>>,
             expected_coords: impl IntoIterator<Item = impl Into<Scalar>>,
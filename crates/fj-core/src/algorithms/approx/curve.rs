@@ -1,14 +1,24 @@
+//! Curve approximation
+//!
+//! See [`CurveApprox`].
+
 use std::collections::BTreeMap;
 
-use fj_math::{Circle, Line, Point};
+use fj_math::{Circle, Line, NurbsCurve, Point, Scalar};
 
 use crate::{
-    geometry::{CurveBoundary, Geometry, GlobalPath, SurfaceGeom, SurfacePath},
+    geometry::{
+        CurveBoundary, Geometry, GlobalPath, SurfaceGeom, SurfacePath,
+        SweptCurve,
+    },
     storage::Handle,
     topology::{Curve, Surface},
 };
 
-use super::{circle::approx_circle, line::approx_line, ApproxPoint, Tolerance};
+use super::{
+    circle::approx_circle, line::approx_line, nurbs::approx_nurbs, ApproxPoint,
+    Tolerance,
+};
 
 /// Approximate the provided curve
 ///
@@ -48,18 +58,56 @@ fn approx_curve(
     boundary: CurveBoundary<Point<1>>,
     tolerance: impl Into<Tolerance>,
 ) -> CurveApprox {
-    let points = match (path, surface.u) {
-        (SurfacePath::Circle(_), GlobalPath::Circle(_)) => {
-            approx_circle_on_curved_surface()
-        }
-        (SurfacePath::Circle(circle), GlobalPath::Line(_)) => {
-            approx_circle_on_straight_surface(
-                circle, boundary, surface, tolerance,
+    let points = match surface {
+        SurfaceGeom::SweptCurve(swept) => match (path, &swept.u) {
+            (SurfacePath::Circle(_), GlobalPath::Circle(_)) => {
+                approx_circle_on_curved_surface()
+            }
+            (SurfacePath::Circle(circle), GlobalPath::Line(_)) => {
+                approx_circle_on_straight_surface(
+                    circle, boundary, swept, tolerance,
+                )
+            }
+            (SurfacePath::Line(line), _) => {
+                approx_line_on_any_surface(line, boundary, swept, tolerance)
+            }
+            (SurfacePath::Nurbs(curve), GlobalPath::Line(_)) => {
+                approx_nurbs_on_straight_surface(
+                    curve, boundary, swept, tolerance,
+                )
+            }
+            (SurfacePath::Nurbs(curve), _) => approx_path_adaptively(
+                path,
+                boundary,
+                surface,
+                curve.control_points().len() - 1,
+                tolerance,
+            ),
+            (SurfacePath::Circle(_), GlobalPath::Nurbs(curve)) => {
+                approx_path_adaptively(
+                    path,
+                    boundary,
+                    surface,
+                    curve.control_points().len() - 1,
+                    tolerance,
+                )
+            }
+        },
+        SurfaceGeom::Nurbs(nurbs) => {
+            let [num_u, num_v] = [
+                nurbs.control_points().len(),
+                nurbs.control_points()[0].len(),
+            ];
+
+            approx_path_adaptively(
+                path,
+                boundary,
+                surface,
+                num_u.max(num_v) - 1,
+                tolerance,
             )
         }
-        (SurfacePath::Line(line), _) => {
-            approx_line_on_any_surface(line, boundary, surface, tolerance)
-        }
+        _ => approx_path_adaptively(path, boundary, surface, 1, tolerance),
     };
 
     CurveApprox { points }
@@ -72,7 +120,7 @@ fn approx_circle_on_curved_surface() -> Vec<ApproxPoint<1>> {
 fn approx_circle_on_straight_surface(
     circle: &Circle<2>,
     boundary: CurveBoundary<Point<1>>,
-    surface: &SurfaceGeom,
+    surface: &SweptCurve,
     tolerance: impl Into<Tolerance>,
 ) -> Vec<ApproxPoint<1>> {
     approx_circle(circle, boundary, tolerance)
@@ -98,10 +146,25 @@ fn approx_circle_on_straight_surface(
         .collect()
 }
 
+fn approx_nurbs_on_straight_surface(
+    curve: &NurbsCurve<2>,
+    boundary: CurveBoundary<Point<1>>,
+    surface: &SweptCurve,
+    tolerance: impl Into<Tolerance>,
+) -> Vec<ApproxPoint<1>> {
+    approx_nurbs(curve, boundary, tolerance)
+        .into_iter()
+        .map(|(point_curve, point_surface)| {
+            let point_global = surface.point_from_surface_coords(point_surface);
+            ApproxPoint::new(point_curve, point_global)
+        })
+        .collect()
+}
+
 fn approx_line_on_any_surface(
     line: &Line<2>,
     boundary: CurveBoundary<Point<1>>,
-    surface: &SurfaceGeom,
+    surface: &SweptCurve,
     tolerance: impl Into<Tolerance>,
 ) -> Vec<ApproxPoint<1>> {
     let range_u = CurveBoundary::from(
@@ -110,11 +173,10 @@ fn approx_line_on_any_surface(
             .map(|point_curve| [line.point_from_line_coords(point_curve).u]),
     );
 
-    let approx_u = match surface.u {
-        GlobalPath::Circle(circle) => {
-            approx_circle(&circle, range_u, tolerance)
-        }
-        GlobalPath::Line(line) => approx_line(&line),
+    let approx_u = match &surface.u {
+        GlobalPath::Circle(circle) => approx_circle(circle, range_u, tolerance),
+        GlobalPath::Line(line) => approx_line(line),
+        GlobalPath::Nurbs(curve) => approx_nurbs(curve, range_u, tolerance),
     };
 
     let mut points = Vec::new();
@@ -128,6 +190,64 @@ fn approx_line_on_any_surface(
     points
 }
 
+/// Approximate a path on a surface, for which no specialized approach exists
+///
+/// The boundary is subdivided into segments of equal length, until the
+/// midpoint of each segment on the surface is within the tolerance of the
+/// midpoint of its approximation. Subdivision starts with the provided number
+/// of segments.
+fn approx_path_adaptively(
+    path: &SurfacePath,
+    boundary: CurveBoundary<Point<1>>,
+    surface: &SurfaceGeom,
+    min_segments: usize,
+    tolerance: impl Into<Tolerance>,
+) -> Vec<ApproxPoint<1>> {
+    // Limits the subdivision, in case the tolerance is unreasonably small.
+    const MAX_SEGMENTS: usize = 1 << 16;
+
+    let tolerance = tolerance.into();
+    let [start, end] = boundary.inner;
+
+    let point_at = |fraction: f64| {
+        let point_curve =
+            Point::from([start.t + (end.t - start.t) * Scalar::from(fraction)]);
+        let point_global = surface.point_from_surface_coords(
+            path.point_from_path_coords(point_curve),
+        );
+
+        ApproxPoint::new(point_curve, point_global)
+    };
+
+    // Paths that are straight in model space, like the rulings of a cone, must
+    // not end up with any additional points. Those would distort the
+    // triangulation of the face they bound. That's why callers should only
+    // request more than one segment, if the path or surface needs it.
+    let mut num_segments = min_segments.max(1);
+
+    loop {
+        let points = (0..=num_segments)
+            .map(|i| point_at(i as f64 / num_segments as f64))
+            .collect::<Vec<_>>();
+
+        let is_within_tolerance =
+            points.windows(2).enumerate().all(|(i, segment)| {
+                let [a, b] = [segment[0], segment[1]];
+                let midpoint = point_at((i as f64 + 0.5) / num_segments as f64);
+
+                let deviation = midpoint.global_form
+                    - (a.global_form + (b.global_form - a.global_form) / 2.);
+                deviation.magnitude() <= tolerance.inner()
+            });
+
+        if is_within_tolerance || num_segments >= MAX_SEGMENTS {
+            return points[1..num_segments].to_vec();
+        }
+
+        num_segments *= 2;
+    }
+}
+
 /// Approximation of a [`Curve`], within a specific boundary
 ///
 /// The approximation of the curve only includes points _within_ the boundary,
@@ -186,14 +306,17 @@ impl CurveApproxCache {
 mod tests {
     use std::f64::consts::TAU;
 
-    use fj_math::{Circle, Point, Vector};
+    use fj_math::{Circle, NurbsCurve, Point, Vector};
     use pretty_assertions::assert_eq;
 
     use crate::{
         algorithms::approx::{
-            circle::approx_circle, curve::approx_curve, ApproxPoint,
+            circle::approx_circle, curve::approx_curve, nurbs::approx_nurbs,
+            ApproxPoint,
+        },
+        geometry::{
+            CurveBoundary, GlobalPath, SurfaceGeom, SurfacePath, SweptCurve,
         },
-        geometry::{CurveBoundary, GlobalPath, SurfaceGeom, SurfacePath},
         operations::build::BuildSurface,
         topology::Surface,
         Core,
@@ -216,10 +339,10 @@ mod tests {
 
     #[test]
     fn approx_line_on_curved_surface_but_not_along_curve() {
-        let surface = SurfaceGeom {
+        let surface = SurfaceGeom::SweptCurve(SweptCurve {
             u: GlobalPath::circle_from_radius(1.),
             v: Vector::from([0., 0., 1.]),
-        };
+        });
         let (path, boundary) =
             SurfacePath::line_from_points([[1., 1.], [2., 1.]]);
         let boundary = CurveBoundary::from(boundary);
@@ -236,11 +359,11 @@ mod tests {
 
         let circle = Circle::from_center_and_radius(Point::origin(), 1.);
         let global_path = GlobalPath::Circle(circle);
-        let surface_geom = SurfaceGeom {
+        let surface_geom = SurfaceGeom::SweptCurve(SweptCurve {
             u: global_path,
             v: Vector::from([0., 0., 1.]),
-        };
-        let surface = Surface::from_geometry(surface_geom, &mut core);
+        });
+        let surface = Surface::from_geometry(surface_geom.clone(), &mut core);
         let path = SurfacePath::line_from_points_with_coords([
             ([0.], [0., 1.]),
             ([TAU], [TAU, 1.]),
@@ -269,8 +392,8 @@ mod tests {
     fn approx_circle_on_flat_surface() {
         let mut core = Core::new();
 
-        let surface_geom = *core.layers.geometry.xz_plane();
-        let surface = Surface::from_geometry(surface_geom, &mut core);
+        let surface_geom = core.layers.geometry.xz_plane().clone();
+        let surface = Surface::from_geometry(surface_geom.clone(), &mut core);
         let circle = Circle::from_center_and_radius([0., 0.], 1.);
         let path = SurfacePath::Circle(circle);
         let boundary = CurveBoundary::from([[0.], [TAU]]);
@@ -292,4 +415,31 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
     }
+
+    #[test]
+    fn approx_nurbs_on_flat_surface() {
+        let core = Core::new();
+
+        let surface = core.layers.geometry.xz_plane();
+        let curve = NurbsCurve::from_control_points(
+            3,
+            [[0., 0.], [1., 2.], [2., -2.], [3., 0.]],
+        );
+        let path = SurfacePath::Nurbs(curve.clone());
+        let boundary = CurveBoundary::from([[0.], [1.]]);
+
+        let tolerance = 0.01;
+        let approx = approx_curve(&path, surface, boundary, tolerance);
+
+        let expected_approx = approx_nurbs(&curve, boundary, tolerance)
+            .into_iter()
+            .map(|(point_local, point_surface)| {
+                let point_global =
+                    surface.point_from_surface_coords(point_surface);
+                ApproxPoint::new(point_local, point_global)
+            })
+            .collect::<Vec<_>>();
+        assert!(!expected_approx.is_empty());
+        assert_eq!(approx.points, expected_approx);
+    }
 }
@@ -13,6 +13,7 @@ use crate::{
 
 use super::{
     cycle::{approx_cycle, CycleApprox},
+    surface::approx_surface,
     Approx, ApproxCache, ApproxPoint, Tolerance,
 };
 
@@ -72,18 +73,14 @@ pub fn approx_face(
 ) -> FaceApprox {
     let tolerance = tolerance.into();
 
-    // Curved faces whose curvature is not fully defined by their edges
-    // are not supported yet. For that reason, we can fully ignore `face`'s
-    // `surface` field and just pass the edges to `Self::for_edges`.
+    // The curvature of some faces is fully defined by their edges. An example
+    // of this is the cylinder, whose curvature is fully defined by the edges
+    // (circles) that border it. The circle approximations are sufficient to
+    // triangulate the surface.
     //
-    // An example of a curved face that is supported, is the cylinder. Its
-    // curvature is fully defined be the edges (circles) that border it. The
-    // circle approximations are sufficient to triangulate the surface.
-    //
-    // An example of a curved face that is currently not supported, and thus
-    // doesn't need to be handled here, is a sphere. A spherical face would
-    // would need to provide its own approximation, as the edges that bound
-    // it have nothing to do with its curvature.
+    // Other faces, like spherical ones, need an approximation of their surface
+    // in addition to that of their edges, as the edges that bound them have
+    // nothing to do with their curvature.
 
     let exterior = approx_cycle(
         face.region().exterior().deref(),
@@ -105,11 +102,21 @@ pub fn approx_face(
         interiors.insert(cycle);
     }
 
+    let surface = approx_surface(
+        geometry.of_surface(face.surface()),
+        &[&exterior]
+            .into_iter()
+            .chain(&interiors)
+            .collect::<Vec<_>>(),
+        tolerance,
+    );
+
     let coord_handedness = face.coord_handedness(geometry);
     FaceApprox {
         face,
         exterior,
         interiors,
+        surface,
         coord_handedness,
     }
 }
@@ -126,6 +133,12 @@ pub struct FaceApprox {
     /// Approximations of the interior cycles
     pub interiors: BTreeSet<CycleApprox>,
 
+    /// Points that approximate the surface within the cycles
+    ///
+    /// Only surfaces whose curvature is not fully defined by the cycles that
+    /// bound them have those.
+    pub surface: Vec<ApproxPoint<2>>,
+
     /// The handedness of the approximated face's front-side coordinate system
     pub coord_handedness: Handedness,
 }
@@ -141,6 +154,8 @@ impl FaceApprox {
             points.extend(cycle_approx.points());
         }
 
+        points.extend(self.surface.iter().copied());
+
         points
     }
 }
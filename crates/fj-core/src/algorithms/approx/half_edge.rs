@@ -53,7 +53,10 @@ pub fn approx_half_edge(
         })
         .collect();
 
-    HalfEdgeApprox { points }
+    HalfEdgeApprox {
+        points,
+        half_edge: half_edge.clone(),
+    }
 }
 
 /// An approximation of a [`HalfEdge`]
@@ -66,4 +69,7 @@ pub fn approx_half_edge(
 pub struct HalfEdgeApprox {
     /// The points that approximate the half-edge
     pub points: Vec<ApproxPoint<2>>,
+
+    /// The half-edge that this approximates
+    pub half_edge: Handle<HalfEdge>,
 }
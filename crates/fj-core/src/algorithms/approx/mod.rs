@@ -1,5 +1,6 @@
 //! Approximation of objects
 
+pub mod curve;
 pub mod cycle;
 pub mod face;
 pub mod half_edge;
@@ -9,8 +10,9 @@ pub mod solid;
 pub mod tolerance;
 
 mod circle;
-mod curve;
 mod line;
+mod nurbs;
+mod surface;
 mod vertex;
 
 use std::{
@@ -0,0 +1,146 @@
+use fj_math::{NurbsCurve, Point, Scalar};
+
+use crate::geometry::CurveBoundary;
+
+use super::Tolerance;
+
+/// # Approximate a NURBS curve
+///
+/// ## Arguments
+///
+/// Besides a curve, this method takes two arguments:
+///
+/// - The `boundary` within which the curve should be approximated.
+/// - The `tolerance` that specifies how much the approximation is allowed to
+///   deviate from the actual curve.
+///
+/// ## Return Value
+///
+/// The approximation returns points within the provided boundary. The boundary
+/// points themselves are not included in the approximation.
+///
+/// ## Determinism
+///
+/// Like circle approximation, NURBS approximation produces a deterministic
+/// result for a given combination of curve and tolerance, regardless of the
+/// boundary. Each knot span of the curve is subdivided into a number of
+/// segments that only depends on the curve within that span. The boundary only
+/// controls which of the resulting points are returned, and in which order.
+pub fn approx_nurbs<const D: usize>(
+    curve: &NurbsCurve<D>,
+    boundary: impl Into<CurveBoundary<Point<1>>>,
+    tolerance: impl Into<Tolerance>,
+) -> Vec<(Point<1>, Point<D>)> {
+    let boundary = boundary.into();
+    let tolerance = tolerance.into();
+
+    let [a, b] = boundary.inner.map(|point| point.t);
+    let [min, max] = if a < b { [a, b] } else { [b, a] };
+
+    let mut points = params(curve, tolerance)
+        .into_iter()
+        .filter(|&t| t > min && t < max)
+        .map(|t| {
+            let point_curve = Point::from([t]);
+            (point_curve, curve.point_from_curve_coords(point_curve))
+        })
+        .collect::<Vec<_>>();
+
+    if a > b {
+        points.reverse();
+    }
+
+    points
+}
+
+/// Compute the curve coordinates of all points that approximate the curve
+fn params<const D: usize>(
+    curve: &NurbsCurve<D>,
+    tolerance: Tolerance,
+) -> Vec<Scalar> {
+    // Limits the subdivision, in case the tolerance is unreasonably small.
+    const MAX_SEGMENTS_PER_SPAN: usize = 1 << 12;
+
+    let [domain_start, domain_end] = curve.domain();
+    let mut params = Vec::new();
+
+    for span in curve.knots().windows(2) {
+        let [start, end] = [span[0], span[1]];
+
+        if start >= end || start < domain_start || end > domain_end {
+            continue;
+        }
+
+        let param_at = |i: usize, num_segments: usize| {
+            start + (end - start) * Scalar::from(i as f64 / num_segments as f64)
+        };
+
+        let mut num_segments = curve.degree();
+        loop {
+            let is_within_tolerance = (0..num_segments).all(|i| {
+                let [a, b] = [i, i + 1].map(|i| {
+                    curve.point_from_curve_coords([param_at(i, num_segments)])
+                });
+                let midpoint = curve.point_from_curve_coords([param_at(
+                    2 * i + 1,
+                    2 * num_segments,
+                )]);
+
+                (midpoint - (a + (b - a) / 2.)).magnitude() <= tolerance.inner()
+            });
+
+            if is_within_tolerance || num_segments >= MAX_SEGMENTS_PER_SPAN {
+                break;
+            }
+
+            num_segments *= 2;
+        }
+
+        params.extend((0..num_segments).map(|i| param_at(i, num_segments)));
+    }
+
+    params.push(domain_end);
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use fj_math::{NurbsCurve, Point, Scalar};
+
+    use crate::algorithms::approx::Tolerance;
+
+    use super::approx_nurbs;
+
+    #[test]
+    fn points_are_within_tolerance() {
+        let curve = NurbsCurve::<2>::new(
+            2,
+            [[1., 0.], [1., 1.], [0., 1.]],
+            [1., FRAC_1_SQRT_2, 1.],
+            [0., 0., 0., 1., 1., 1.],
+        );
+        let tolerance = Tolerance::from_scalar(0.001).unwrap();
+
+        let points = approx_nurbs(&curve, [[0.], [1.]], tolerance);
+        assert!(points.len() > 1);
+
+        for (point_curve, point) in &points {
+            assert!(
+                point_curve.t > Scalar::ZERO && point_curve.t < Scalar::ONE
+            );
+            assert_eq!(*point, curve.point_from_curve_coords(*point_curve));
+        }
+
+        let mut reversed = approx_nurbs(&curve, [[1.], [0.]], tolerance);
+        reversed.reverse();
+        assert_eq!(points, reversed);
+
+        let within = approx_nurbs(&curve, [[0.25], [0.75]], tolerance);
+        assert!(within.iter().all(|point| points.contains(point)));
+        assert!(within
+            .iter()
+            .all(|(point_curve, _)| *point_curve > Point::from([0.25])));
+    }
+}
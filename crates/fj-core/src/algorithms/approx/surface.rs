@@ -0,0 +1,229 @@
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::geometry::SurfaceGeom;
+
+use super::{cycle::CycleApprox, ApproxPoint, Tolerance};
+
+/// # Approximate a surface within the provided cycles
+///
+/// Returns points on the surface that lie within the area bounded by the
+/// cycles, spaced densely enough to approximate the surface within the
+/// provided tolerance.
+///
+/// Surfaces that are curved in at most one direction, like planes, cylinders,
+/// and cones, are sufficiently approximated by the approximation of the cycles
+/// that bound them. No points are returned for those.
+pub fn approx_surface(
+    surface: &SurfaceGeom,
+    cycles: &[&CycleApprox],
+    tolerance: impl Into<Tolerance>,
+) -> Vec<ApproxPoint<2>> {
+    let tolerance = tolerance.into();
+
+    let Some(exterior) = cycles.first() else {
+        return Vec::new();
+    };
+    let aabb = Aabb::<2>::from_points(
+        exterior.points().into_iter().map(|point| point.local_form),
+    );
+    let range = [0, 1]
+        .map(|i| [aabb.min, aabb.max].map(|point| point.coords.components[i]));
+
+    let num_steps = match surface {
+        SurfaceGeom::SweptCurve(_)
+        | SurfaceGeom::Cylinder(_)
+        | SurfaceGeom::Cone(_) => return Vec::new(),
+        SurfaceGeom::Sphere(sphere) => {
+            let radius = sphere.radius();
+            range.map(|range| num_steps_for_angle(range, radius, tolerance))
+        }
+        SurfaceGeom::Torus(torus) => [
+            num_steps_for_angle(
+                range[0],
+                torus.major_radius() + torus.minor_radius(),
+                tolerance,
+            ),
+            num_steps_for_angle(range[1], torus.minor_radius(), tolerance),
+        ],
+        SurfaceGeom::Nurbs(_) => {
+            [0, 1].map(|i| num_steps_for_any(surface, range, i, tolerance))
+        }
+    };
+
+    let [coords_u, coords_v] = [0, 1].map(|i| {
+        let [min, max] = range[i];
+
+        (1..num_steps[i])
+            .map(|step| {
+                min + (max - min)
+                    * Scalar::from(step as f64 / num_steps[i] as f64)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut points = Vec::new();
+
+    for &u in &coords_u {
+        for &v in &coords_v {
+            let point_surface = Point::from([u, v]);
+
+            if is_within(point_surface, cycles) {
+                let point_global =
+                    surface.point_from_surface_coords(point_surface);
+                points.push(ApproxPoint::new(point_surface, point_global));
+            }
+        }
+    }
+
+    points
+}
+
+/// Compute the number of steps for a coordinate that is an angle
+///
+/// This uses the same increment that is used for approximating circles.
+fn num_steps_for_angle(
+    [min, max]: [Scalar; 2],
+    radius: Scalar,
+    tolerance: Tolerance,
+) -> usize {
+    let increment = Scalar::TWO
+        * Scalar::max(Scalar::ONE - tolerance.inner() / radius, Scalar::ZERO)
+            .acos();
+
+    ((max - min) / increment).ceil().into_f64() as usize
+}
+
+/// Compute the number of steps for a coordinate of an arbitrary surface
+///
+/// Subdivides lines along the coordinate at the minimum, middle, and maximum
+/// of the other coordinate, until the midpoints of all segments are within the
+/// tolerance.
+fn num_steps_for_any(
+    surface: &SurfaceGeom,
+    range: [[Scalar; 2]; 2],
+    coord: usize,
+    tolerance: Tolerance,
+) -> usize {
+    // Limits the subdivision, in case the tolerance is unreasonably small.
+    const MAX_STEPS: usize = 1 << 10;
+
+    let [min, max] = range[coord];
+    let [other_min, other_max] = range[1 - coord];
+
+    let point_at = |t: Scalar, other: Scalar| {
+        let mut components = [other; 2];
+        components[coord] = t;
+        surface.point_from_surface_coords(components)
+    };
+
+    let mut num_steps = 2;
+
+    while num_steps < MAX_STEPS {
+        let t_at = |i: usize| {
+            min + (max - min) * Scalar::from(i as f64 / num_steps as f64)
+        };
+
+        let is_within_tolerance =
+            [other_min, (other_min + other_max) / 2., other_max]
+                .into_iter()
+                .all(|other| {
+                    (0..num_steps).all(|i| {
+                        let [a, b] =
+                            [i, i + 1].map(|i| point_at(t_at(i), other));
+                        let midpoint =
+                            point_at((t_at(i) + t_at(i + 1)) / 2., other);
+
+                        (midpoint - (a + (b - a) / 2.)).magnitude()
+                            <= tolerance.inner()
+                    })
+                });
+
+        if is_within_tolerance {
+            break;
+        }
+
+        num_steps *= 2;
+    }
+
+    num_steps
+}
+
+/// Determine whether the point is within the area bounded by the cycles
+fn is_within(point: Point<2>, cycles: &[&CycleApprox]) -> bool {
+    let mut is_within = false;
+
+    for cycle in cycles {
+        for segment in cycle.points().windows(2) {
+            let [a, b] = [segment[0].local_form, segment[1].local_form];
+
+            // Count the crossings of a ray from the point in positive
+            // u-direction.
+            if (a.v > point.v) != (b.v > point.v) {
+                let u = a.u + (point.v - a.v) * (b.u - a.u) / (b.v - a.v);
+
+                if point.u < u {
+                    is_within = !is_within;
+                }
+            }
+        }
+    }
+
+    is_within
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Sphere};
+
+    use crate::{
+        algorithms::approx::{
+            cycle::CycleApprox, half_edge::HalfEdgeApprox, ApproxPoint,
+        },
+        geometry::SurfaceGeom,
+        operations::{build::BuildHalfEdge, insert::Insert},
+        topology::HalfEdge,
+        Core,
+    };
+
+    use super::approx_surface;
+
+    #[test]
+    fn approx_sphere_within_cycle() {
+        let mut core = Core::new();
+
+        let surface = SurfaceGeom::Sphere(Sphere::from_center_and_radius(
+            [0., 0., 0.],
+            1.,
+        ));
+
+        let exterior = CycleApprox {
+            half_edges: [[0., -0.5], [1., -0.5], [1., 0.5], [0., 0.5]]
+                .into_iter()
+                .map(|point| {
+                    let point = Point::from(point);
+                    HalfEdgeApprox {
+                        points: vec![ApproxPoint::new(
+                            point,
+                            surface.point_from_surface_coords(point),
+                        )],
+                        half_edge: HalfEdge::unjoined(&mut core)
+                            .insert(&mut core),
+                    }
+                })
+                .collect(),
+        };
+
+        let points = approx_surface(&surface, &[&exterior], 0.01);
+
+        assert!(!points.is_empty());
+        for point in points {
+            let [u, v] = point.local_form.coords.components;
+            assert!(u > Scalar::ZERO && u < Scalar::ONE);
+            assert!(v > Scalar::from(-0.5) && v < Scalar::from(0.5));
+            assert_eq!(
+                point.global_form,
+                surface.point_from_surface_coords(point.local_form)
+            );
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use std::ops::Deref;
 
-use fj_math::Aabb;
+use fj_math::{Aabb, Circle, Scalar, Vector};
 
 use crate::{
-    geometry::{Geometry, GlobalPath},
+    geometry::{Geometry, GlobalPath, SurfaceGeom},
     topology::Face,
 };
 
@@ -12,26 +12,94 @@ impl super::BoundingVolume<3> for &Face {
         (self.region().exterior().deref(), self.surface())
             .aabb(geometry)
             .map(|aabb2| {
-                let surface = geometry.of_surface(self.surface());
+                match geometry.of_surface(self.surface()) {
+                    SurfaceGeom::SweptCurve(surface) => match &surface.u {
+                        GlobalPath::Circle(circle) => {
+                            // This is not the most precise way to calculate
+                            // the AABB, doing it for the whole circle, but it
+                            // should do.
 
-                match surface.u {
-                    GlobalPath::Circle(circle) => {
-                        // This is not the most precise way to calculate the
-                        // AABB, doing it for the whole circle, but it should
-                        // do.
+                            let aabb_bottom = circle.aabb();
+                            let aabb_top = Aabb {
+                                min: aabb_bottom.min + surface.v,
+                                max: aabb_bottom.max + surface.v,
+                            };
 
-                        let aabb_bottom = circle.aabb();
-                        let aabb_top = Aabb {
-                            min: aabb_bottom.min + surface.v,
-                            max: aabb_bottom.max + surface.v,
-                        };
+                            aabb_bottom.merged(&aabb_top)
+                        }
+                        GlobalPath::Line(_) => Aabb {
+                            min: surface.point_from_surface_coords(aabb2.min),
+                            max: surface.point_from_surface_coords(aabb2.max),
+                        },
+                        GlobalPath::Nurbs(curve) => {
+                            // Like for the circle, this covers the whole
+                            // curve.
+                            let aabb_bottom = curve.aabb();
+                            let aabb_top = Aabb {
+                                min: aabb_bottom.min + surface.v,
+                                max: aabb_bottom.max + surface.v,
+                            };
 
-                        aabb_bottom.merged(&aabb_top)
-                    }
-                    GlobalPath::Line(_) => Aabb {
-                        min: surface.point_from_surface_coords(aabb2.min),
-                        max: surface.point_from_surface_coords(aabb2.max),
+                            aabb_bottom.merged(&aabb_top)
+                        }
                     },
+                    // For the other surfaces, the AABB is computed for the
+                    // whole range of angles, similar to the swept circle
+                    // above.
+                    SurfaceGeom::Cylinder(cylinder) => {
+                        let [bottom, top] =
+                            [aabb2.min.v, aabb2.max.v].map(|v| {
+                                Circle::new(
+                                    cylinder.origin() + cylinder.axis() * v,
+                                    cylinder.a(),
+                                    cylinder.b(),
+                                )
+                                .aabb()
+                            });
+
+                        bottom.merged(&top)
+                    }
+                    SurfaceGeom::Cone(cone) => {
+                        let [bottom, top] =
+                            [aabb2.min.v, aabb2.max.v].map(|v| {
+                                let center = cone.apex() + cone.axis() * v;
+
+                                if v == Scalar::ZERO {
+                                    Aabb {
+                                        min: center,
+                                        max: center,
+                                    }
+                                } else {
+                                    Circle::new(
+                                        center,
+                                        cone.a() * v,
+                                        cone.b() * v,
+                                    )
+                                    .aabb()
+                                }
+                            });
+
+                        bottom.merged(&top)
+                    }
+                    SurfaceGeom::Sphere(sphere) => {
+                        let radius = Vector::from_component(sphere.radius());
+
+                        Aabb {
+                            min: sphere.center() - radius,
+                            max: sphere.center() + radius,
+                        }
+                    }
+                    SurfaceGeom::Torus(torus) => {
+                        let radius = Vector::from_component(
+                            torus.major_radius() + torus.minor_radius(),
+                        );
+
+                        Aabb {
+                            min: torus.center() - radius,
+                            max: torus.center() + radius,
+                        }
+                    }
+                    SurfaceGeom::Nurbs(surface) => surface.aabb(),
                 }
             })
     }
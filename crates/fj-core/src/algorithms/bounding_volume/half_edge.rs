@@ -17,9 +17,10 @@ impl super::BoundingVolume<2>
             .unwrap()
             .local_on(surface)
             .unwrap()
-            .path;
+            .path
+            .clone();
 
-        match path {
+        match &path {
             SurfacePath::Circle(circle) => {
                 // Just calculate the AABB of the whole circle. This is not the
                 // most precise, but it should do for now.
@@ -47,6 +48,11 @@ impl super::BoundingVolume<2>
 
                 Some(Aabb::<2>::from_points(points))
             }
+            SurfacePath::Nurbs(curve) => {
+                // The AABB of the control points contains the whole curve. As
+                // with the circle, this is not the most precise.
+                Some(curve.aabb())
+            }
         }
     }
 }
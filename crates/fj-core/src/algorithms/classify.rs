@@ -0,0 +1,439 @@
+//! Classify points relative to shells and solids
+//!
+//! See [`ClassifyPoint`] and [`SignedDistance`].
+
+use fj_math::{Point, Scalar, Triangle, Vector};
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Face, Shell, Solid},
+};
+
+use super::{
+    approx::{face::FaceApprox, Approx, Tolerance},
+    intersect::{ray_face::Ray, Intersect},
+};
+
+/// Classify a point relative to an object
+///
+/// The object must bound a closed volume, as is the case for a valid
+/// [`Solid`], or a closed [`Shell`]. Otherwise, the result is meaningless.
+pub trait ClassifyPoint {
+    /// Classify the point as inside, outside, or on the boundary of the object
+    ///
+    /// Curved faces are approximated, as defined by `tolerance`. Since the
+    /// approximation can deviate from the actual faces by that much, points
+    /// that are within `tolerance` of the approximated boundary are classified
+    /// as being on the boundary.
+    fn classify_point(
+        self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> PointClassification;
+
+    /// Classify multiple points relative to the object
+    ///
+    /// Same as [`ClassifyPoint::classify_point`], but only approximates the
+    /// object once, instead of for every point.
+    fn classify_points(
+        self,
+        points: impl IntoIterator<Item = Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Vec<PointClassification>;
+}
+
+impl ClassifyPoint for &Shell {
+    fn classify_point(
+        self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> PointClassification {
+        let tolerance = tolerance.into();
+        Boundary::new(self.approx(tolerance, geometry))
+            .classify(point.into(), tolerance)
+    }
+
+    fn classify_points(
+        self,
+        points: impl IntoIterator<Item = Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Vec<PointClassification> {
+        let tolerance = tolerance.into();
+        let boundary = Boundary::new(self.approx(tolerance, geometry));
+
+        points
+            .into_iter()
+            .map(|point| boundary.classify(point, tolerance))
+            .collect()
+    }
+}
+
+impl ClassifyPoint for &Solid {
+    fn classify_point(
+        self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> PointClassification {
+        let tolerance = tolerance.into();
+        Boundary::new(self.approx(tolerance, geometry))
+            .classify(point.into(), tolerance)
+    }
+
+    fn classify_points(
+        self,
+        points: impl IntoIterator<Item = Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Vec<PointClassification> {
+        let tolerance = tolerance.into();
+        let boundary = Boundary::new(self.approx(tolerance, geometry));
+
+        points
+            .into_iter()
+            .map(|point| boundary.classify(point, tolerance))
+            .collect()
+    }
+}
+
+/// The classification of a point relative to an object
+///
+/// Returned by [`ClassifyPoint::classify_point`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum PointClassification {
+    /// The point is inside of the volume that the object bounds
+    Inside,
+
+    /// The point is outside of the volume that the object bounds
+    Outside,
+
+    /// The point is on the boundary of the object
+    OnBoundary,
+}
+
+/// Compute the signed distance between a point and an object
+///
+/// The same requirements as for [`ClassifyPoint`] apply.
+pub trait SignedDistance {
+    /// Find the point on the boundary of the object that is closest to `point`
+    ///
+    /// Curved faces are approximated, as defined by `tolerance`, which limits
+    /// the accuracy of the result accordingly.
+    ///
+    /// Returns `None`, if the object has no faces.
+    fn closest_point(
+        self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<ClosestPoint>;
+}
+
+impl SignedDistance for &Shell {
+    fn closest_point(
+        self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<ClosestPoint> {
+        let tolerance = tolerance.into();
+        Boundary::new(self.approx(tolerance, geometry))
+            .closest_point(point.into(), tolerance)
+    }
+}
+
+impl SignedDistance for &Solid {
+    fn closest_point(
+        self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<ClosestPoint> {
+        let tolerance = tolerance.into();
+        Boundary::new(self.approx(tolerance, geometry))
+            .closest_point(point.into(), tolerance)
+    }
+}
+
+/// The point on the boundary of an object that is closest to another point
+///
+/// Returned by [`SignedDistance::closest_point`].
+#[derive(Clone, Debug)]
+pub struct ClosestPoint {
+    /// The closest point on the boundary
+    pub point: Point<3>,
+
+    /// The face that the closest point is on
+    pub face: Handle<Face>,
+
+    /// The distance to the closest point
+    ///
+    /// This is negative, if the point that the distance was computed for is
+    /// inside of the object.
+    pub signed_distance: Scalar,
+}
+
+/// The boundary of an object, as approximated by triangles
+struct Boundary {
+    triangles: Vec<(Handle<Face>, Triangle<3>)>,
+}
+
+impl Boundary {
+    fn new(faces: impl IntoIterator<Item = FaceApprox>) -> Self {
+        let triangles = faces
+            .into_iter()
+            .flat_map(|face| {
+                face.triangles()
+                    .into_iter()
+                    .filter_map(|points| Triangle::from_points(points).ok())
+                    .map(move |triangle| (face.face.clone(), triangle))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Self { triangles }
+    }
+
+    fn classify(
+        &self,
+        point: Point<3>,
+        tolerance: Tolerance,
+    ) -> PointClassification {
+        let Some((_, closest)) = self.closest(point) else {
+            return PointClassification::Outside;
+        };
+        if closest.distance_to(&point) <= tolerance.inner() {
+            return PointClassification::OnBoundary;
+        }
+
+        // A ray from a point inside crosses the boundary an odd number of
+        // times. This is only reliable, if the ray crosses cleanly. Hitting an
+        // edge might count one crossing twice, and grazing a triangle might
+        // not count as a crossing. Try another ray, if that happens.
+        let mut first_result = None;
+        for direction in RAY_DIRECTIONS {
+            let ray = Ray {
+                origin: point,
+                direction: Vector::from(direction),
+            };
+            let (crossings, ambiguous) = self.count_crossings(&ray);
+
+            if !ambiguous {
+                return Self::classify_crossings(crossings);
+            }
+            first_result.get_or_insert(crossings);
+        }
+
+        // All rays were ambiguous, which is very unlikely. Go with the first
+        // one then.
+        Self::classify_crossings(first_result.unwrap_or_default())
+    }
+
+    fn closest_point(
+        &self,
+        point: Point<3>,
+        tolerance: Tolerance,
+    ) -> Option<ClosestPoint> {
+        let (face, closest) = self.closest(point)?;
+
+        let distance = closest.distance_to(&point);
+        let signed_distance = match self.classify(point, tolerance) {
+            PointClassification::Inside => -distance,
+            PointClassification::Outside | PointClassification::OnBoundary => {
+                distance
+            }
+        };
+
+        Some(ClosestPoint {
+            point: closest,
+            face,
+            signed_distance,
+        })
+    }
+
+    fn closest(&self, point: Point<3>) -> Option<(Handle<Face>, Point<3>)> {
+        self.triangles
+            .iter()
+            .map(|(face, triangle)| (face, triangle.closest_point(point)))
+            .min_by_key(|(_, closest)| closest.distance_to(&point))
+            .map(|(face, closest)| (face.clone(), closest))
+    }
+
+    fn count_crossings(&self, ray: &Ray) -> (usize, bool) {
+        let mut crossings = 0;
+        let mut ambiguous = false;
+
+        for (_, triangle) in &self.triangles {
+            let Some(intersection) = (ray, triangle).intersect() else {
+                continue;
+            };
+
+            let grazing =
+                intersection.cos_angle.abs() < Scalar::from(AMBIGUITY_EPSILON);
+            let on_edge = intersection
+                .barycentric
+                .iter()
+                .any(|coord| *coord < Scalar::from(AMBIGUITY_EPSILON));
+            ambiguous |= grazing || on_edge;
+
+            crossings += 1;
+        }
+
+        (crossings, ambiguous)
+    }
+
+    fn classify_crossings(crossings: usize) -> PointClassification {
+        if crossings % 2 == 1 {
+            PointClassification::Inside
+        } else {
+            PointClassification::Outside
+        }
+    }
+}
+
+/// The directions of the rays used for classifying points
+///
+/// These are chosen to be unlikely to line up with the edges and faces of
+/// typical models.
+const RAY_DIRECTIONS: [[f64; 3]; 4] = [
+    [0.5381, 0.7124, 0.4503],
+    [-0.6829, 0.3211, 0.6560],
+    [0.2417, -0.8543, 0.4600],
+    [-0.3310, -0.2865, -0.8990],
+];
+
+/// How close a ray can come to an edge or a triangle's plane, before it becomes
+/// ambiguous whether it crosses the boundary there
+const AMBIGUITY_EPSILON: f64 = 1e-9;
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::approx::Tolerance,
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            sweep::SweepSketch,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        topology::{Cycle, Region, Sketch, Solid},
+        Core,
+    };
+
+    use super::{ClassifyPoint, PointClassification, SignedDistance};
+
+    #[test]
+    fn classify_point() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let solid = cube_with_hole(&mut core);
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let classify = |point: [f64; 3], core: &Core| {
+            (&solid).classify_point(point, tolerance, &core.layers.geometry)
+        };
+
+        assert_eq!(
+            classify([0.1, 0.1, 0.5], &core),
+            PointClassification::Inside
+        );
+        assert_eq!(
+            classify([2., 0.5, 0.5], &core),
+            PointClassification::Outside
+        );
+        assert_eq!(
+            classify([0.5, 0.5, 0.5], &core),
+            PointClassification::Outside,
+            "Point in hole",
+        );
+        assert_eq!(
+            classify([0., 0.5, 0.5], &core),
+            PointClassification::OnBoundary
+        );
+        assert_eq!(
+            classify([1., 1., 1.], &core),
+            PointClassification::OnBoundary
+        );
+
+        // The rays from these points go right through edges and vertices of
+        // the cube, if they point along an axis or a diagonal.
+        assert_eq!(
+            classify([0.1, 0.1, 0.1], &core),
+            PointClassification::Inside
+        );
+        assert_eq!(
+            classify([-1., 0.1, 0.], &core),
+            PointClassification::Outside
+        );
+
+        assert_eq!(
+            (&solid).classify_points(
+                [[0.1, 0.1, 0.5], [0.5, 0.5, 0.5], [0., 0.5, 0.5]]
+                    .map(Point::from),
+                tolerance,
+                &core.layers.geometry,
+            ),
+            [
+                PointClassification::Inside,
+                PointClassification::Outside,
+                PointClassification::OnBoundary,
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed_distance() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let solid = cube_with_hole(&mut core);
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let inside = (&solid)
+            .closest_point([0.1, 0.5, 0.6], tolerance, &core.layers.geometry)
+            .unwrap();
+        assert_eq!(inside.point, Point::from([0., 0.5, 0.6]));
+        assert_eq!(inside.signed_distance, Scalar::from(-0.1));
+
+        let outside = (&solid)
+            .closest_point([0.5, 0.5, 3.], tolerance, &core.layers.geometry)
+            .unwrap();
+        assert!(outside.signed_distance > Scalar::from(2.));
+
+        let above = (&solid)
+            .closest_point([0.1, 0.1, 1.5], tolerance, &core.layers.geometry)
+            .unwrap();
+        assert_eq!(above.point, Point::from([0.1, 0.1, 1.]));
+        assert_eq!(above.signed_distance, Scalar::from(0.5));
+
+        Ok(())
+    }
+
+    /// A unit cube with a square hole through it, from top to bottom
+    fn cube_with_hole(core: &mut Core) -> Solid {
+        let surface = core.layers.topology.surfaces.space_2d();
+        let region = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            surface.clone(),
+            core,
+        )
+        .add_interiors(
+            [Cycle::polygon(
+                [[0.25, 0.25], [0.25, 0.75], [0.75, 0.75], [0.75, 0.25]],
+                surface,
+                core,
+            )],
+            core,
+        );
+
+        let bottom_surface = core.layers.topology.surfaces.xy_plane();
+        Sketch::empty(&core.layers.topology)
+            .add_regions([region], core)
+            .sweep_sketch(bottom_surface, Vector::from([0., 0., 1.]), core)
+    }
+}
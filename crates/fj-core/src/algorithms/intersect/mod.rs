@@ -1,6 +1,8 @@
 //! Intersection algorithms
 
+pub mod ray_face;
 pub mod ray_segment;
+pub mod surface_surface;
 
 mod line_segment;
 
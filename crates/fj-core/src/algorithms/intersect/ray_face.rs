@@ -0,0 +1,236 @@
+//! Intersection between a ray and a face in 3D
+
+use fj_math::{Point, Scalar, Triangle, Vector};
+
+use crate::algorithms::approx::face::FaceApprox;
+
+use super::Intersect;
+
+/// A ray in 3D space
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    /// The point where the ray originates
+    pub origin: Point<3>,
+
+    /// The direction of the ray
+    ///
+    /// Doesn't need to be normalized. Distances along the ray are given in
+    /// multiples of this vector.
+    pub direction: Vector<3>,
+}
+
+impl Ray {
+    /// Compute the point at the provided distance along the ray
+    pub fn point_at(&self, t: impl Into<Scalar>) -> Point<3> {
+        self.origin + self.direction * t.into()
+    }
+}
+
+impl Intersect for (&Ray, &Triangle<3>) {
+    type Intersection = RayTriangleIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        // Algorithm by Möller and Trumbore, from their paper "Fast, Minimum
+        // Storage Ray/Triangle Intersection".
+
+        let (ray, triangle) = self;
+        let [a, b, c] = triangle.points();
+
+        let ab = b - a;
+        let ac = c - a;
+
+        let p = ray.direction.cross(&ac);
+        let determinant = ab.dot(&p);
+        if determinant == Scalar::ZERO {
+            // Ray and triangle are parallel.
+            return None;
+        }
+
+        let to_origin = ray.origin - a;
+        let u = to_origin.dot(&p) / determinant;
+        if u < Scalar::ZERO || u > Scalar::ONE {
+            return None;
+        }
+
+        let q = to_origin.cross(&ab);
+        let v = ray.direction.dot(&q) / determinant;
+        if v < Scalar::ZERO || u + v > Scalar::ONE {
+            return None;
+        }
+
+        let t = ac.dot(&q) / determinant;
+        if t < Scalar::ZERO {
+            return None;
+        }
+
+        Some(RayTriangleIntersection {
+            t,
+            barycentric: [Scalar::ONE - u - v, u, v],
+            cos_angle: ray.direction.normalize().dot(&triangle.normal()),
+        })
+    }
+}
+
+/// An intersection between a [`Ray`] and a [`Triangle`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayTriangleIntersection {
+    /// The distance along the ray, in multiples of its direction
+    pub t: Scalar,
+
+    /// The barycentric coordinates of the intersection on the triangle
+    ///
+    /// A coordinate of zero means that the ray hits the edge opposite of the
+    /// respective vertex.
+    pub barycentric: [Scalar; 3],
+
+    /// The cosine of the angle between the ray and the triangle's normal
+    ///
+    /// This is negative, if the ray hits the triangle from the front, and
+    /// positive, if it hits from the back.
+    pub cos_angle: Scalar,
+}
+
+impl Intersect for (&Ray, &FaceApprox) {
+    type Intersection = RayFaceIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (ray, face) = self;
+
+        face.triangles()
+            .into_iter()
+            .filter_map(|triangle| {
+                let triangle = Triangle::from_points(triangle).ok()?;
+                (ray, &triangle).intersect()
+            })
+            .min_by_key(|intersection| intersection.t)
+            .map(|intersection| RayFaceIntersection {
+                t: intersection.t,
+                point: ray.point_at(intersection.t),
+            })
+    }
+}
+
+/// An intersection between a [`Ray`] and a face
+///
+/// Faces are intersected by way of their approximation, so how close the
+/// intersection is to the actual face depends on the tolerance of that.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayFaceIntersection {
+    /// The distance along the ray, in multiples of its direction
+    pub t: Scalar,
+
+    /// The point where the ray hits the face
+    pub point: Point<3>,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Triangle, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::{face::approx_face, ApproxCache, Tolerance},
+            intersect::Intersect,
+        },
+        operations::{
+            build::{BuildCycle, BuildFace},
+            insert::Insert,
+            update::{UpdateFace, UpdateRegion},
+        },
+        topology::{Cycle, Face},
+        Core,
+    };
+
+    use super::Ray;
+
+    #[test]
+    fn ray_triangle() {
+        let triangle =
+            Triangle::from([[0., 0., 0.], [2., 0., 0.], [0., 2., 0.]]);
+
+        let hit = Ray {
+            origin: Point::from([0.5, 0.5, 1.]),
+            direction: Vector::from([0., 0., -2.]),
+        };
+        let intersection = (&hit, &triangle).intersect().unwrap();
+        assert_eq!(intersection.t, Scalar::from(0.5));
+        assert_eq!(
+            intersection.barycentric,
+            [0.5, 0.25, 0.25].map(Scalar::from)
+        );
+        assert!(intersection.cos_angle < Scalar::ZERO);
+
+        let miss = Ray {
+            origin: Point::from([1.5, 1.5, 1.]),
+            direction: Vector::from([0., 0., -1.]),
+        };
+        assert!((&miss, &triangle).intersect().is_none());
+
+        let behind = Ray {
+            origin: Point::from([0.5, 0.5, 1.]),
+            direction: Vector::from([0., 0., 1.]),
+        };
+        assert!((&behind, &triangle).intersect().is_none());
+
+        let parallel = Ray {
+            origin: Point::from([0.5, 0.5, 1.]),
+            direction: Vector::from([1., 0., 0.]),
+        };
+        assert!((&parallel, &triangle).intersect().is_none());
+    }
+
+    #[test]
+    fn ray_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::unbound(surface.clone(), &mut core)
+            .update_region(
+                |region, core| {
+                    region
+                        .update_exterior(
+                            |_, core| {
+                                Cycle::polygon(
+                                    [[0., 0.], [4., 0.], [4., 4.], [0., 4.]],
+                                    surface.clone(),
+                                    core,
+                                )
+                            },
+                            core,
+                        )
+                        .add_interiors(
+                            [Cycle::polygon(
+                                [[1., 1.], [1., 3.], [3., 3.], [3., 1.]],
+                                surface.clone(),
+                                core,
+                            )],
+                            core,
+                        )
+                },
+                &mut core,
+            )
+            .insert(&mut core);
+
+        let face = approx_face(
+            face,
+            Tolerance::from_scalar(1.)?,
+            &mut ApproxCache::default(),
+            &core.layers.geometry,
+        );
+
+        let hit = Ray {
+            origin: Point::from([0.5, 0.5, -1.]),
+            direction: Vector::from([0., 0., 1.]),
+        };
+        let intersection = (&hit, &face).intersect().unwrap();
+        assert_eq!(intersection.point, Point::from([0.5, 0.5, 0.]));
+
+        let through_hole = Ray {
+            origin: Point::from([2., 2., -1.]),
+            direction: Vector::from([0., 0., 1.]),
+        };
+        assert!((&through_hole, &face).intersect().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,613 @@
+//! Intersection between two surfaces
+
+use fj_math::{Circle, Line, Plane, Point, Scalar, Vector};
+
+use crate::geometry::{GlobalPath, SurfacePath, SweptCurve};
+
+use super::Intersect;
+
+/// Compute the intersection between two swept surfaces
+///
+/// Supports the intersection of two planes, of a plane with a surface that was
+/// extruded from a circle, and of two surfaces that were extruded from circles
+/// along the same direction.
+///
+/// Returns an [`UnsupportedIntersection`], if the intersection is of a kind
+/// that is not supported yet.
+impl Intersect for (&SweptCurve, &SweptCurve) {
+    type Intersection =
+        Result<SurfaceSurfaceIntersection, UnsupportedIntersection>;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (a, b) = self;
+
+        let intersection = match (&a.u, &b.u) {
+            (GlobalPath::Line(_), GlobalPath::Line(_)) => Ok(plane_plane(a, b)),
+            (GlobalPath::Line(_), _) => plane_extrusion(a, b),
+            (_, GlobalPath::Line(_)) => {
+                plane_extrusion(b, a).map(|intersection| {
+                    intersection.map(SurfaceSurfaceIntersection::swap)
+                })
+            }
+            _ => extrusion_extrusion(a, b),
+        };
+
+        intersection.transpose()
+    }
+}
+
+/// An intersection between two surfaces
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SurfaceSurfaceIntersection {
+    /// The surfaces intersect along one or more curves
+    ///
+    /// There is at least one curve.
+    Curves(Vec<IntersectionCurve>),
+
+    /// The surfaces are coincident
+    Coincident,
+}
+
+impl SurfaceSurfaceIntersection {
+    fn swap(self) -> Self {
+        match self {
+            Self::Curves(curves) => Self::Curves(
+                curves
+                    .into_iter()
+                    .map(|curve| {
+                        let [a, b] = curve.local;
+                        IntersectionCurve {
+                            global: curve.global,
+                            local: [b, a],
+                        }
+                    })
+                    .collect(),
+            ),
+            Self::Coincident => Self::Coincident,
+        }
+    }
+}
+
+/// An intersection between two surfaces that is not supported yet
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, thiserror::Error)]
+pub enum UnsupportedIntersection {
+    /// One of the surfaces was swept from a NURBS curve
+    #[error("Intersecting surfaces swept from NURBS curves is not supported")]
+    SweptNurbsCurve,
+
+    /// The intersection between a plane and an extruded circle is an ellipse
+    #[error("Intersections that result in an ellipse are not supported")]
+    Ellipse,
+
+    /// The surfaces were extruded in different directions
+    #[error(
+        "Intersecting surfaces with different extrusion directions is not \
+        supported"
+    )]
+    DifferentExtrusionDirections,
+
+    /// A circle is not perpendicular to the direction it was extruded in
+    #[error(
+        "Intersecting extruded circles is only supported, if the circles are \
+        perpendicular to the extrusion direction"
+    )]
+    SkewedExtrusion,
+
+    /// The coordinate system of a plane is skewed or scaled
+    ///
+    /// A circle would become an ellipse in the local coordinates of such a
+    /// plane.
+    #[error(
+        "Intersecting planes with skewed or scaled coordinate systems is not \
+        supported, if the result is a circle"
+    )]
+    SkewedPlane,
+}
+
+/// A curve along which two surfaces intersect
+///
+/// The curve coordinates of the global and local forms match. Converting the
+/// same curve coordinate into a point using any of them, then converting that
+/// into global coordinates, results in the same point.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct IntersectionCurve {
+    /// The curve in global coordinates
+    pub global: GlobalPath,
+
+    /// The curve in the local coordinates of each surface
+    ///
+    /// These are in the same order as the surfaces that were intersected.
+    pub local: [SurfacePath; 2],
+}
+
+fn plane_plane(
+    a: &SweptCurve,
+    b: &SweptCurve,
+) -> Option<SurfaceSurfaceIntersection> {
+    // Algorithm from Real-Time Collision Detection by Christer Ericson. See
+    // section 5.4.4, Intersection of Two Planes.
+    //
+    // Adaptations were made to get the intersection curves in local
+    // coordinates for each surface.
+
+    let planes = [a, b].map(to_plane);
+
+    let [(a_distance, a_normal), (b_distance, b_normal)] =
+        planes.map(|plane| plane.constant_normal_form());
+
+    let direction = a_normal.cross(&b_normal);
+
+    // The normals are of unit length, so this is the squared sine of the angle
+    // between the planes.
+    let denom = direction.dot(&direction);
+    if denom <= Scalar::from(EPSILON) {
+        let [_, b_origin] = planes.map(|plane| plane.origin());
+        let b_distance_from_a = a_normal.dot(&b_origin.coords) - a_distance;
+
+        return is_zero(b_distance_from_a)
+            .then_some(SurfaceSurfaceIntersection::Coincident);
+    }
+
+    let origin = (b_normal * a_distance - a_normal * b_distance)
+        .cross(&direction)
+        / denom;
+    let origin = Point { coords: origin };
+
+    let line = Line::from_origin_and_direction(origin, direction);
+
+    Some(SurfaceSurfaceIntersection::Curves(vec![
+        IntersectionCurve {
+            global: GlobalPath::Line(line),
+            local: planes
+                .map(|plane| SurfacePath::Line(plane.project_line(&line))),
+        },
+    ]))
+}
+
+fn plane_extrusion(
+    plane: &SweptCurve,
+    extrusion: &SweptCurve,
+) -> Result<Option<SurfaceSurfaceIntersection>, UnsupportedIntersection> {
+    let plane = to_plane(plane);
+    let (distance, normal) = plane.constant_normal_form();
+
+    let GlobalPath::Circle(circle) = &extrusion.u else {
+        return Err(UnsupportedIntersection::SweptNurbsCurve);
+    };
+
+    // A point on the extruded surface has the surface coordinates `(u, s)`,
+    // and is located at `c(u) + s * v`, where `c` is the circle. It's on the
+    // plane, if `n · (c(u) + s * v) = d`. Expanding the circle, that becomes
+    // `n · center + n · a * cos(u) + n · b * sin(u) + n · v * s = d`.
+    let center = normal.dot(&circle.center().coords);
+    let cos = normal.dot(&circle.a());
+    let sin = normal.dot(&circle.b());
+    let along_extrusion = normal.dot(&extrusion.v);
+
+    let circle_is_parallel =
+        is_zero(cos / circle.radius()) && is_zero(sin / circle.radius());
+    let extrusion_is_parallel =
+        is_zero(along_extrusion / extrusion.v.magnitude());
+
+    if extrusion_is_parallel {
+        if circle_is_parallel {
+            // The circle is parallel to the plane, and extruded along it.
+            // That's not a proper surface.
+            return Ok(None);
+        }
+
+        // The equation no longer depends on `s`. Any solution for `u`
+        // results in a line along the extrusion direction.
+        //
+        // `n · a * cos(u) + n · b * sin(u)` can be written as
+        // `amplitude * cos(u - phase)`, which makes it easy to solve.
+        let amplitude = (cos * cos + sin * sin).into_f64().sqrt();
+        let phase = sin.atan2(cos);
+        let ratio = (distance - center).into_f64() / amplitude;
+
+        if ratio.abs() > 1. + EPSILON {
+            return Ok(None);
+        }
+        let offset = Scalar::from(ratio.clamp(-1., 1.)).acos();
+
+        let mut angles = vec![phase - offset];
+        if !is_zero(offset) {
+            angles.push(phase + offset);
+        }
+
+        let curves = angles
+            .into_iter()
+            .map(|u| {
+                let origin = circle.point_from_circle_coords([u]);
+                let line = Line::from_origin_and_direction(origin, extrusion.v);
+
+                IntersectionCurve {
+                    global: GlobalPath::Line(line),
+                    local: [
+                        SurfacePath::Line(plane.project_line(&line)),
+                        SurfacePath::Line(Line::from_origin_and_direction(
+                            Point::from([u, Scalar::ZERO]),
+                            Vector::from([0., 1.]),
+                        )),
+                    ],
+                }
+            })
+            .collect();
+
+        return Ok(Some(SurfaceSurfaceIntersection::Curves(curves)));
+    }
+
+    if !circle_is_parallel {
+        return Err(UnsupportedIntersection::Ellipse);
+    }
+
+    // The circle is parallel to the plane, so the intersection is a copy of
+    // the circle, at a constant `s`.
+    let s = (distance - center) / along_extrusion;
+    let global =
+        Circle::new(circle.center() + extrusion.v * s, circle.a(), circle.b());
+
+    Ok(Some(SurfaceSurfaceIntersection::Curves(vec![
+        IntersectionCurve {
+            global: GlobalPath::Circle(global),
+            local: [
+                SurfacePath::Circle(project_circle(&plane, &global)?),
+                SurfacePath::Line(Line::from_origin_and_direction(
+                    Point::from([Scalar::ZERO, s]),
+                    Vector::from([1., 0.]),
+                )),
+            ],
+        },
+    ])))
+}
+
+fn extrusion_extrusion(
+    a: &SweptCurve,
+    b: &SweptCurve,
+) -> Result<Option<SurfaceSurfaceIntersection>, UnsupportedIntersection> {
+    let (GlobalPath::Circle(a_circle), GlobalPath::Circle(b_circle)) =
+        (&a.u, &b.u)
+    else {
+        return Err(UnsupportedIntersection::SweptNurbsCurve);
+    };
+
+    let direction = a.v.normalize();
+    if !is_zero(direction.cross(&b.v.normalize()).magnitude()) {
+        return Err(UnsupportedIntersection::DifferentExtrusionDirections);
+    }
+    for circle in [a_circle, b_circle] {
+        let radius = circle.radius();
+        if !is_zero(direction.dot(&circle.a()) / radius)
+            || !is_zero(direction.dot(&circle.b()) / radius)
+        {
+            return Err(UnsupportedIntersection::SkewedExtrusion);
+        }
+    }
+
+    // Both circles are in planes perpendicular to the extrusion direction. The
+    // problem reduces to intersecting them in 2D, after moving the second
+    // circle into the plane of the first.
+    let offset = b_circle.center() - a_circle.center();
+    let along = direction * offset.dot(&direction);
+    let offset = offset - along;
+
+    let distance = offset.magnitude();
+    let [a_radius, b_radius] = [a_circle, b_circle].map(|c| c.radius());
+
+    if is_zero(distance / (a_radius + b_radius)) {
+        return Ok(is_zero((a_radius - b_radius) / (a_radius + b_radius))
+            .then_some(SurfaceSurfaceIntersection::Coincident));
+    }
+
+    let [distance, a_radius, b_radius] =
+        [distance, a_radius, b_radius].map(Scalar::into_f64);
+    if distance > (a_radius + b_radius) * (1. + EPSILON)
+        || distance < (a_radius - b_radius).abs() * (1. - EPSILON)
+    {
+        return Ok(None);
+    }
+
+    // The points of intersection are `x` along the line between the centers,
+    // and `±y` perpendicular to it.
+    let x = (distance * distance + a_radius * a_radius - b_radius * b_radius)
+        / (2. * distance);
+    let y = (a_radius * a_radius - x * x).max(0.).sqrt();
+
+    let towards_b = offset / distance;
+    let perpendicular = direction.cross(&towards_b);
+
+    let mut points = vec![a_circle.center() + towards_b * x];
+    if !is_zero(Scalar::from(y / a_radius)) {
+        let [point] = points.try_into().expect("Pushed one point");
+        points = vec![point + perpendicular * y, point - perpendicular * y];
+    }
+
+    let curves = points
+        .into_iter()
+        .map(|point| {
+            let a_u = circle_coords(a_circle, point);
+            let b_u = circle_coords(b_circle, point + along);
+
+            // Express the point and the direction of the line in terms of the
+            // second surface's extrusion direction.
+            let b_v = b.v.dot(&b.v);
+            let b_s = (point - b_circle.point_from_circle_coords([b_u]))
+                .dot(&b.v)
+                / b_v;
+            let b_direction = a.v.dot(&b.v) / b_v;
+
+            IntersectionCurve {
+                global: GlobalPath::Line(Line::from_origin_and_direction(
+                    point, a.v,
+                )),
+                local: [
+                    SurfacePath::Line(Line::from_origin_and_direction(
+                        Point::from([a_u, Scalar::ZERO]),
+                        Vector::from([0., 1.]),
+                    )),
+                    SurfacePath::Line(Line::from_origin_and_direction(
+                        Point::from([b_u, b_s]),
+                        Vector::from([Scalar::ZERO, b_direction]),
+                    )),
+                ],
+            }
+        })
+        .collect();
+
+    Ok(Some(SurfaceSurfaceIntersection::Curves(curves)))
+}
+
+fn to_plane(surface: &SweptCurve) -> Plane {
+    let GlobalPath::Line(line) = &surface.u else {
+        unreachable!("Only calling this for planes")
+    };
+
+    Plane::from_parametric(line.origin(), line.direction(), surface.v)
+}
+
+/// Project a circle that is parallel to a plane into that plane
+fn project_circle(
+    plane: &Plane,
+    circle: &Circle<3>,
+) -> Result<Circle<2>, UnsupportedIntersection> {
+    let center = plane.project_point(circle.center());
+    let a = plane.project_vector(circle.a());
+    let b = plane.project_vector(circle.b());
+
+    // Projection into the plane keeps `b` perpendicular to `a`, unless the
+    // plane's coordinate system is skewed or scaled. Then the circle becomes an
+    // ellipse in surface coordinates. Otherwise, replace `b` with an exactly
+    // perpendicular vector, so numerical inaccuracy doesn't trip up the circle
+    // constructor.
+    let perpendicular = Vector::from([-a.v, a.u]);
+    let radius = a.magnitude();
+
+    let b = if is_zero((b - perpendicular).magnitude() / radius) {
+        perpendicular
+    } else if is_zero((b + perpendicular).magnitude() / radius) {
+        -perpendicular
+    } else {
+        return Err(UnsupportedIntersection::SkewedPlane);
+    };
+
+    Ok(Circle::new(center, a, b))
+}
+
+/// Compute the circle coordinate of a point that is on the circle
+fn circle_coords(circle: &Circle<3>, point: Point<3>) -> Scalar {
+    let vector = point - circle.center();
+    vector.dot(&circle.b()).atan2(vector.dot(&circle.a()))
+}
+
+fn is_zero(value: Scalar) -> bool {
+    value.abs() <= Scalar::from(EPSILON)
+}
+
+/// The relative tolerance for deciding whether geometry is parallel or
+/// coincident
+///
+/// Comparing against exact values would be fragile, due to numerical
+/// inaccuracy. This value seems to work for now, but maybe it needs to become
+/// configurable.
+const EPSILON: f64 = 1e-12;
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Line, NurbsCurve, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::intersect::Intersect,
+        geometry::{GlobalPath, SurfacePath, SweptCurve},
+    };
+
+    use super::{
+        IntersectionCurve, SurfaceSurfaceIntersection, UnsupportedIntersection,
+    };
+
+    #[test]
+    fn plane_plane() {
+        let xy = plane([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let xz = plane([0., 0., 0.], [1., 0., 0.], [0., 0., 1.]);
+
+        let Some(Ok(SurfaceSurfaceIntersection::Curves(curves))) =
+            (&xy, &xz).intersect()
+        else {
+            panic!("Expected intersection curve");
+        };
+        assert_eq!(
+            curves,
+            vec![IntersectionCurve {
+                global: GlobalPath::Line(Line::from_origin_and_direction(
+                    Point::from([0., 0., 0.]),
+                    Vector::from([1., 0., 0.]),
+                )),
+                local: [
+                    SurfacePath::Line(Line::from_origin_and_direction(
+                        Point::from([0., 0.]),
+                        Vector::from([1., 0.]),
+                    )),
+                    SurfacePath::Line(Line::from_origin_and_direction(
+                        Point::from([0., 0.]),
+                        Vector::from([1., 0.]),
+                    )),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn plane_plane_consistency() {
+        let a = plane([1., 2., 3.], [1., 1., 0.], [0., 1., 1.]);
+        let b = plane([0., -1., 2.], [1., 0., 0.], [0., 0., 2.]);
+        assert_consistent(&a, &b, 1);
+
+        let parallel = plane([0., 0., 5.], [1., 1., 0.], [0., 1., 1.]);
+        assert!((&a, &parallel).intersect().is_none());
+
+        let coincident = plane([2., 3., 3.], [0., 1., 1.], [1., 1., 0.]);
+        assert_eq!(
+            (&a, &coincident).intersect(),
+            Some(Ok(SurfaceSurfaceIntersection::Coincident))
+        );
+    }
+
+    #[test]
+    fn plane_cylinder() {
+        let cylinder = cylinder([0., 0., 0.], 1., [0., 0., 2.]);
+
+        // Plane along the axis, cutting the cylinder in two.
+        let cut = plane([0.5, 0., 0.], [0., 1., 0.], [0., 0., 1.]);
+        assert_consistent(&cut, &cylinder, 2);
+        assert_consistent(&cylinder, &cut, 2);
+
+        // Plane touching the cylinder.
+        let touching = plane([1., 0., 0.], [0., 1., 0.], [0., 0., 1.]);
+        assert_consistent(&touching, &cylinder, 1);
+
+        // Plane along the axis, missing the cylinder.
+        let miss = plane([2., 0., 0.], [0., 1., 0.], [0., 0., 1.]);
+        assert!((&miss, &cylinder).intersect().is_none());
+
+        // Plane across the axis.
+        let across = plane([0., 0., 3.], [1., 0., 0.], [0., 1., 0.]);
+        assert_consistent(&across, &cylinder, 1);
+        let Some(Ok(SurfaceSurfaceIntersection::Curves(curves))) =
+            (&across, &cylinder).intersect()
+        else {
+            panic!("Expected intersection curve");
+        };
+        assert_eq!(
+            curves[0].global,
+            GlobalPath::Circle(Circle::from_center_and_radius(
+                [0., 0., 3.],
+                1.
+            ))
+        );
+    }
+
+    #[test]
+    fn cylinder_cylinder() {
+        let a = cylinder([0., 0., 0.], 1., [0., 0., 1.]);
+
+        let b = cylinder([1., 1., 3.], 1., [0., 0., -2.]);
+        assert_consistent(&a, &b, 2);
+
+        let touching = cylinder([3., 0., 0.], 2., [0., 0., 1.]);
+        assert_consistent(&a, &touching, 1);
+
+        let inside = cylinder([0.1, 0., 0.], 0.5, [0., 0., 1.]);
+        assert!((&a, &inside).intersect().is_none());
+
+        let coincident = cylinder([0., 0., 5.], 1., [0., 0., 3.]);
+        assert_eq!(
+            (&a, &coincident).intersect(),
+            Some(Ok(SurfaceSurfaceIntersection::Coincident))
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let cylinder = cylinder([0., 0., 0.], 1., [0., 0., 1.]);
+
+        let tilted = plane([0., 0., 0.], [1., 0., 1.], [0., 1., 0.]);
+        assert_eq!(
+            (&tilted, &cylinder).intersect(),
+            Some(Err(UnsupportedIntersection::Ellipse))
+        );
+
+        let crossing = SweptCurve {
+            u: GlobalPath::Circle(Circle::new(
+                [0., 0., 0.],
+                [0., 1., 0.],
+                [0., 0., 1.],
+            )),
+            v: Vector::from([1., 0., 0.]),
+        };
+        assert_eq!(
+            (&cylinder, &crossing).intersect(),
+            Some(Err(UnsupportedIntersection::DifferentExtrusionDirections))
+        );
+
+        let nurbs = SweptCurve {
+            u: GlobalPath::Nurbs(NurbsCurve::from_control_points(
+                1,
+                [[0., 0., 0.], [1., 0., 0.]],
+            )),
+            v: Vector::from([0., 0., 1.]),
+        };
+        assert_eq!(
+            (&tilted, &nurbs).intersect(),
+            Some(Err(UnsupportedIntersection::SweptNurbsCurve))
+        );
+    }
+
+    fn plane(origin: [f64; 3], u: [f64; 3], v: [f64; 3]) -> SweptCurve {
+        SweptCurve {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                Point::from(origin),
+                Vector::from(u),
+            )),
+            v: Vector::from(v),
+        }
+    }
+
+    fn cylinder(center: [f64; 3], radius: f64, v: [f64; 3]) -> SweptCurve {
+        SweptCurve {
+            u: GlobalPath::Circle(Circle::from_center_and_radius(
+                center, radius,
+            )),
+            v: Vector::from(v),
+        }
+    }
+
+    /// Check that the intersection curves are on both surfaces, and that their
+    /// local and global forms agree
+    fn assert_consistent(
+        a: &SweptCurve,
+        b: &SweptCurve,
+        expected_num_curves: usize,
+    ) {
+        let Some(Ok(SurfaceSurfaceIntersection::Curves(curves))) =
+            (a, b).intersect()
+        else {
+            panic!("Expected intersection curves");
+        };
+        assert_eq!(curves.len(), expected_num_curves);
+
+        for curve in curves {
+            for t in [-1., 0., 0.5, 2.] {
+                let global = curve.global.point_from_path_coords([t]);
+
+                for (surface, local) in [a, b].into_iter().zip(&curve.local) {
+                    let local = surface.point_from_surface_coords(
+                        local.point_from_path_coords([t]),
+                    );
+                    assert!(
+                        (local - global).magnitude() < Scalar::from(1e-9),
+                        "{local:?} != {global:?}"
+                    );
+                }
+            }
+        }
+    }
+}
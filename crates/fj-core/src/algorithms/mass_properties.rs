@@ -0,0 +1,263 @@
+//! Compute the mass properties of an object
+//!
+//! See [`ComputeMassProperties`].
+
+use fj_interop::Mesh;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::Core;
+
+use super::{
+    approx::{face::FaceApprox, Approx, Tolerance},
+    triangulate::Triangulate,
+};
+
+/// Compute the mass properties of an object
+///
+/// The object must bound a closed volume, with its faces oriented outwards,
+/// as is the case for a valid [`Solid`]. Otherwise, the result is meaningless.
+///
+/// [`Solid`]: crate::topology::Solid
+pub trait ComputeMassProperties {
+    /// Compute the mass properties of the object
+    ///
+    /// The computation is based on a triangulation of the object. This is
+    /// exact for planar faces bounded by straight edges. Curved faces and edges
+    /// are approximated, as defined by `tolerance`.
+    fn mass_properties(
+        self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> MassProperties;
+}
+
+impl<T> ComputeMassProperties for T
+where
+    T: Approx,
+    T::Approximation: IntoIterator<Item = FaceApprox>,
+{
+    fn mass_properties(
+        self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> MassProperties {
+        let mesh = (self, tolerance.into()).triangulate(core);
+        MassProperties::from_mesh(&mesh)
+    }
+}
+
+/// The mass properties of an object
+///
+/// All properties assume a uniform density of `1`. Mass and inertia scale
+/// linearly with density, so for any other density, multiply [`volume`] and
+/// [`inertia_tensor`] by it.
+///
+/// [`volume`]: Self::volume
+/// [`inertia_tensor`]: Self::inertia_tensor
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MassProperties {
+    /// The volume that the object encloses
+    pub volume: Scalar,
+
+    /// The area of the object's surface
+    pub surface_area: Scalar,
+
+    /// The center of mass, also known as the centroid
+    ///
+    /// This is the origin, if the object doesn't enclose any volume.
+    pub center_of_mass: Point<3>,
+
+    /// The inertia tensor, relative to the center of mass
+    ///
+    /// The tensor is symmetric. Rows and columns refer to the x, y, and z axes,
+    /// in that order.
+    pub inertia_tensor: [[Scalar; 3]; 3],
+}
+
+impl MassProperties {
+    /// Compute the mass properties of the volume bounded by a triangle mesh
+    ///
+    /// The mesh must be closed and its triangles oriented outwards.
+    ///
+    /// Every triangle forms a tetrahedron with the origin. Summing up the
+    /// signed properties of those tetrahedra cancels out everything outside of
+    /// the mesh, leaving the properties of the volume it encloses.
+    pub fn from_mesh(mesh: &Mesh<Point<3>>) -> Self {
+        let mut volume = Scalar::ZERO;
+        let mut surface_area = Scalar::ZERO;
+        let mut first_moment = Vector::from([0., 0., 0.]);
+        let mut second_moment = [[Scalar::ZERO; 3]; 3];
+
+        for triangle in mesh.triangles() {
+            let [a, b, c] = triangle.inner.points().map(|point| point.coords);
+
+            let cross = b.cross(&c);
+            surface_area += (b - a).cross(&(c - a)).magnitude() / 2.;
+
+            let tetrahedron_volume = a.dot(&cross) / 6.;
+            let sum = a + b + c;
+
+            volume += tetrahedron_volume;
+            first_moment = first_moment + sum * tetrahedron_volume / 4.;
+
+            for (i, row) in second_moment.iter_mut().enumerate() {
+                for (j, entry) in row.iter_mut().enumerate() {
+                    let products = [a, b, c, sum]
+                        .into_iter()
+                        .map(|p| p.components[i] * p.components[j])
+                        .fold(Scalar::ZERO, |sum, product| sum + product);
+
+                    *entry += products * tetrahedron_volume / 20.;
+                }
+            }
+        }
+
+        let center_of_mass = if volume == Scalar::ZERO {
+            Point::origin()
+        } else {
+            Point {
+                coords: first_moment / volume,
+            }
+        };
+
+        // Move the second moment to the center of mass, using the parallel
+        // axis theorem, then derive the inertia tensor from it.
+        let center = center_of_mass.coords.components;
+        for (i, row) in second_moment.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry -= volume * center[i] * center[j];
+            }
+        }
+
+        let trace =
+            second_moment[0][0] + second_moment[1][1] + second_moment[2][2];
+        let mut inertia_tensor = [[Scalar::ZERO; 3]; 3];
+        for (i, row) in inertia_tensor.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                let diagonal = if i == j { trace } else { Scalar::ZERO };
+                *entry = diagonal - second_moment[i][j];
+            }
+        }
+
+        Self {
+            volume,
+            surface_area,
+            center_of_mass,
+            inertia_tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::approx::Tolerance,
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        topology::{Region, Sketch, Solid},
+        Core,
+    };
+
+    use super::ComputeMassProperties;
+
+    #[test]
+    fn cuboid() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let region = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 2.], [0., 2.]],
+            core.layers.topology.surfaces.space_2d(),
+            &mut core,
+        );
+        let solid = sweep(region, 3., &mut core);
+
+        let tolerance = Tolerance::from_scalar(0.1)?;
+        let properties = (&solid).mass_properties(tolerance, &mut core);
+
+        assert_close(properties.volume, 6.);
+        assert_close(properties.surface_area, 22.);
+        for (actual, expected) in properties
+            .center_of_mass
+            .coords
+            .components
+            .into_iter()
+            .zip([0.5, 1., 1.5])
+        {
+            assert_close(actual, expected);
+        }
+
+        // The inertia tensor of a cuboid with mass `m` and side lengths `a`,
+        // `b`, and `c` is diagonal, with `m * (b² + c²) / 12` for the x axis,
+        // and accordingly for the other axes.
+        let expected = [[6.5, 0., 0.], [0., 5., 0.], [0., 0., 2.5]];
+        for (actual, expected) in properties.inertia_tensor.iter().zip(expected)
+        {
+            for (actual, expected) in actual.iter().zip(expected) {
+                assert_close(*actual, expected);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let region = Region::circle(
+            [0., 0.],
+            1.,
+            core.layers.topology.surfaces.space_2d(),
+            &mut core,
+        );
+        let solid = sweep(region, 2., &mut core);
+
+        let tolerance = Tolerance::from_scalar(0.0001)?;
+        let properties = (&solid).mass_properties(tolerance, &mut core);
+
+        let pi = std::f64::consts::PI;
+        let relative_error = |actual: Scalar, expected: f64| {
+            ((actual.into_f64() - expected) / expected).abs()
+        };
+
+        assert!(relative_error(properties.volume, 2. * pi) < 1e-3);
+        assert!(relative_error(properties.surface_area, 6. * pi) < 1e-3);
+
+        // The approximation isn't perfectly symmetric, so neither is its
+        // center of mass.
+        let center_of_mass = properties.center_of_mass;
+        assert!(
+            center_of_mass.distance_to(&Point::from([0., 0., 1.]))
+                < tolerance.inner()
+        );
+
+        // For a cylinder with mass `m`, radius `r`, and height `h`, that is
+        // `m * r² / 2` around its axis, and `m * (3r² + h²) / 12` around the
+        // other axes.
+        let [[ixx, ..], [_, iyy, _], [.., izz]] = properties.inertia_tensor;
+        assert!(relative_error(ixx, 2. * pi * 7. / 12.) < 1e-3);
+        assert!(relative_error(iyy, 2. * pi * 7. / 12.) < 1e-3);
+        assert!(relative_error(izz, pi) < 1e-3);
+
+        Ok(())
+    }
+
+    fn sweep(region: Region, height: f64, core: &mut Core) -> Solid {
+        let bottom_surface = core.layers.topology.surfaces.xy_plane();
+        Sketch::empty(&core.layers.topology)
+            .add_regions([region], core)
+            .sweep_sketch(bottom_surface, Vector::from([0., 0., height]), core)
+    }
+
+    fn assert_close(actual: Scalar, expected: f64) {
+        assert!(
+            (actual.into_f64() - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+}
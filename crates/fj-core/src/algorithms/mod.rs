@@ -12,5 +12,7 @@
 
 pub mod approx;
 pub mod bounding_volume;
+pub mod classify;
 pub mod intersect;
+pub mod mass_properties;
 pub mod triangulate;
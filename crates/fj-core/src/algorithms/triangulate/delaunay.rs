@@ -3,11 +3,18 @@ use std::collections::BTreeMap;
 use fj_math::{Point, Scalar, Triangle, Winding};
 use spade::HasPosition;
 
-use crate::{algorithms::approx::cycle::CycleApprox, topology::Handedness};
+use crate::{
+    algorithms::approx::{cycle::CycleApprox, ApproxPoint},
+    topology::Handedness,
+};
 
 /// Create a Delaunay triangulation of all points
-pub fn triangulate(
-    cycles: impl IntoIterator<Item = CycleApprox>,
+///
+/// The cycles constrain the triangulation, while the additional points are
+/// inserted without constraints.
+pub fn triangulate<'r>(
+    cycles: impl IntoIterator<Item = &'r CycleApprox>,
+    additional_points: impl IntoIterator<Item = ApproxPoint<2>>,
     coord_handedness: Handedness,
 ) -> Vec<[TriangulationPoint; 3]> {
     use spade::Triangulation as _;
@@ -44,6 +51,15 @@ pub fn triangulate(
         }
     }
 
+    for point in additional_points {
+        triangulation
+            .insert(TriangulationPoint {
+                point_surface: point.local_form,
+                point_global: point.global_form,
+            })
+            .expect("Inserted invalid point into triangulation");
+    }
+
     let mut triangles = Vec::new();
     for triangle in triangulation.inner_faces() {
         let [v0, v1, v2] = triangle.vertices().map(|vertex| *vertex.data());
@@ -3,10 +3,12 @@
 mod delaunay;
 mod polygon;
 
-use fj_interop::Mesh;
+use std::collections::HashMap;
+
+use fj_interop::{Mesh, SourceId};
 use fj_math::Point;
 
-use crate::{operations::presentation::GetColor, Core};
+use crate::{operations::presentation::GetColor, storage::ObjectId, Core};
 
 use self::polygon::Polygon;
 
@@ -38,14 +40,32 @@ where
 
         let approx = approx.approx(tolerance, &core.layers.geometry);
 
+        let mut edges = Edges::default();
         for approx in approx {
-            approx.triangulate_into_mesh(mesh, core);
+            edges.add(&approx);
+            approx.push_triangles_into_mesh(mesh, core);
         }
+        edges.push_into_mesh(mesh);
     }
 }
 
 impl Triangulate for FaceApprox {
     fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>, core: &mut Core) {
+        let mut edges = Edges::default();
+        edges.add(&self);
+        edges.push_into_mesh(mesh);
+
+        self.push_triangles_into_mesh(mesh, core);
+    }
+}
+
+impl FaceApprox {
+    /// Triangulate the approximated face
+    ///
+    /// Returns the points of each triangle, in global coordinates. The
+    /// triangles are wound such that their normals point in the same direction
+    /// as the face's.
+    pub fn triangles(&self) -> Vec<[Point<3>; 3]> {
         let face_as_polygon = Polygon::new()
             .with_exterior(
                 self.exterior
@@ -57,37 +77,106 @@ impl Triangulate for FaceApprox {
                 interior.points().into_iter().map(|point| point.local_form)
             }));
 
-        let cycles = [self.exterior].into_iter().chain(self.interiors);
-        let mut triangles =
-            delaunay::triangulate(cycles, self.coord_handedness);
+        let cycles = [&self.exterior].into_iter().chain(&self.interiors);
+        let mut triangles = delaunay::triangulate(
+            cycles,
+            self.surface.iter().copied(),
+            self.coord_handedness,
+        );
         triangles.retain(|triangle| {
             face_as_polygon
                 .contains_triangle(triangle.map(|point| point.point_surface))
         });
 
+        triangles
+            .into_iter()
+            .map(|triangle| triangle.map(|point| point.point_global))
+            .collect()
+    }
+
+    fn push_triangles_into_mesh(
+        &self,
+        mesh: &mut Mesh<Point<3>>,
+        core: &mut Core,
+    ) {
         let color = self.face.region().get_color(core).unwrap_or_default();
+        let face = self.face.id().into();
+
+        for triangle in self.triangles() {
+            mesh.push_face_triangle(triangle, color, face);
+        }
+    }
+}
 
-        for triangle in triangles {
-            let points = triangle.map(|point| point.point_global);
-            mesh.push_triangle(points, color);
+/// The edges of the faces that are being triangulated
+///
+/// Each edge of a shell is bounded by two sibling half-edges, one for each of
+/// the adjacent faces. Those share the same curve and vertices, and are merged
+/// into a single edge of the mesh here.
+#[derive(Default)]
+struct Edges {
+    edges: Vec<(Vec<Point<3>>, SourceId, Option<SourceId>)>,
+    indices: HashMap<(ObjectId, [ObjectId; 2]), usize>,
+}
+
+impl Edges {
+    fn add(&mut self, face: &FaceApprox) {
+        // Each half-edge approximation leaves out the end vertex, which is the
+        // start of the next one in the cycle.
+        for cycle in [&face.exterior].into_iter().chain(&face.interiors) {
+            let half_edges = &cycle.half_edges;
+            let next = half_edges.iter().cycle().skip(1);
+
+            for (half_edge, next) in half_edges.iter().zip(next) {
+                let source = half_edge.half_edge.id().into();
+
+                let mut vertices = [
+                    half_edge.half_edge.start_vertex().id(),
+                    next.half_edge.start_vertex().id(),
+                ];
+                vertices.sort();
+                let key = (half_edge.half_edge.curve().id(), vertices);
+
+                if let Some(&index) = self.indices.get(&key) {
+                    let (_, _, sibling) = &mut self.edges[index];
+                    sibling.get_or_insert(source);
+                    continue;
+                }
+
+                let points = half_edge
+                    .points
+                    .iter()
+                    .chain(next.points.first())
+                    .map(|point| point.global_form)
+                    .collect();
+
+                self.indices.insert(key, self.edges.len());
+                self.edges.push((points, source, None));
+            }
+        }
+    }
+
+    fn push_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+        for (points, source, sibling) in self.edges {
+            mesh.push_edge(points, source, sibling);
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_interop::Mesh;
+    use fj_interop::{Mesh, SourceId};
     use fj_math::{Point, Scalar};
 
     use crate::{
         algorithms::approx::{face::approx_face, ApproxCache, Tolerance},
         operations::{
-            build::{BuildCycle, BuildFace},
+            build::{BuildCycle, BuildFace, BuildShell},
             insert::Insert,
             update::{UpdateFace, UpdateRegion},
         },
         storage::Handle,
-        topology::{Cycle, Face},
+        topology::{Cycle, Face, Shell},
         Core,
     };
 
@@ -304,6 +393,91 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn sources() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let face = Face::unbound(surface.clone(), &mut core)
+            .update_region(
+                |region, core| {
+                    region.update_exterior(
+                        |_, core| {
+                            Cycle::polygon(
+                                [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                                surface,
+                                core,
+                            )
+                        },
+                        core,
+                    )
+                },
+                &mut core,
+            )
+            .insert(&mut core);
+
+        let mesh = triangulate(face.clone(), &mut core)?;
+
+        let face_id = SourceId::from(face.id());
+        assert!(mesh
+            .triangles()
+            .all(|triangle| triangle.face == Some(face_id)));
+
+        let half_edges = face
+            .region()
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| SourceId::from(half_edge.id()))
+            .collect::<Vec<_>>();
+        let edges = mesh.edges().collect::<Vec<_>>();
+
+        assert_eq!(edges.len(), half_edges.len());
+        for (edge, half_edge) in edges.into_iter().zip(half_edges) {
+            assert_eq!(edge.source, half_edge);
+            assert_eq!(edge.points.len(), 2);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sibling_half_edges_are_merged() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let shell = tetrahedron.shell;
+
+        let mesh = (&shell, Tolerance::from_scalar(Scalar::ONE)?)
+            .triangulate(&mut core);
+        let edges = mesh.edges().collect::<Vec<_>>();
+
+        // A tetrahedron has 6 edges, each made up of two half-edges.
+        assert_eq!(edges.len(), 6);
+
+        let mut sources = edges
+            .iter()
+            .flat_map(|edge| edge.sources())
+            .collect::<Vec<_>>();
+        sources.sort();
+
+        let mut half_edges = shell
+            .faces()
+            .iter()
+            .flat_map(|face| face.region().exterior().half_edges().iter())
+            .map(|half_edge| SourceId::from(half_edge.id()))
+            .collect::<Vec<_>>();
+        half_edges.sort();
+
+        assert_eq!(sources, half_edges);
+
+        Ok(())
+    }
+
     fn triangulate(
         face: Handle<Face>,
         core: &mut Core,
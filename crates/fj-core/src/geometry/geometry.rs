@@ -9,7 +9,7 @@ use crate::{
 
 use super::{
     vertex::LocalVertexGeom, CurveGeom, GlobalPath, LocalCurveGeom,
-    SurfaceGeom, VertexGeom,
+    SurfaceGeom, SweptCurve, VertexGeom,
 };
 
 /// Geometric data that is associated with topological objects
@@ -42,24 +42,24 @@ impl Geometry {
 
         self_.define_surface_inner(
             self_.xy_plane.clone(),
-            SurfaceGeom {
+            SurfaceGeom::SweptCurve(SweptCurve {
                 u: GlobalPath::x_axis(),
                 v: Vector::unit_y(),
-            },
+            }),
         );
         self_.define_surface_inner(
             self_.xz_plane.clone(),
-            SurfaceGeom {
+            SurfaceGeom::SweptCurve(SweptCurve {
                 u: GlobalPath::x_axis(),
                 v: Vector::unit_z(),
-            },
+            }),
         );
         self_.define_surface_inner(
             self_.yz_plane.clone(),
-            SurfaceGeom {
+            SurfaceGeom::SweptCurve(SweptCurve {
                 u: GlobalPath::y_axis(),
                 v: Vector::unit_z(),
-            },
+            }),
         );
 
         self_
@@ -127,6 +127,19 @@ impl Geometry {
             .expect("Expected geometry of surface to be defined")
     }
 
+    /// # Access the geometry of the provided surface, if it is defined
+    ///
+    /// Surfaces that are not located in 3D space, like the one returned by
+    /// [`Surfaces::space_2d`], have no geometry.
+    ///
+    /// [`Surfaces::space_2d`]: crate::topology::Surfaces::space_2d
+    pub fn try_of_surface(
+        &self,
+        surface: &Handle<Surface>,
+    ) -> Option<&SurfaceGeom> {
+        self.surface.get(surface)
+    }
+
     /// # Access the geometry of the provided vertex
     pub fn of_vertex(&self, vertex: &Handle<Vertex>) -> Option<&VertexGeom> {
         self.vertex.get(vertex)
@@ -12,6 +12,6 @@ pub use self::{
     curve::{CurveGeom, LocalCurveGeom},
     geometry::Geometry,
     path::{GlobalPath, SurfacePath},
-    surface::SurfaceGeom,
+    surface::{SurfaceGeom, SweptCurve},
     vertex::{LocalVertexGeom, VertexGeom},
 };
@@ -2,16 +2,21 @@
 //!
 //! See [`SurfacePath`] and [`GlobalPath`].
 
-use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+use fj_math::{Circle, Line, NurbsCurve, Point, Scalar, Transform, Vector};
+
+use super::CurveBoundary;
 
 /// A path through surface (2D) space
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum SurfacePath {
     /// A circle
     Circle(Circle<2>),
 
     /// A line
     Line(Line<2>),
+
+    /// A NURBS curve
+    Nurbs(NurbsCurve<2>),
 }
 
 impl SurfacePath {
@@ -66,6 +71,27 @@ impl SurfacePath {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Nurbs(curve) => curve.point_from_curve_coords(point),
+        }
+    }
+
+    /// Convert the part of the path within the boundary into a NURBS curve
+    ///
+    /// The NURBS curve starts at the start of the boundary and ends at its end.
+    /// Its curve coordinates only match those of the path, if the path itself
+    /// is a NURBS curve.
+    pub fn to_nurbs(&self, boundary: CurveBoundary<Point<1>>) -> NurbsCurve<2> {
+        let [start, end] = boundary.inner.map(|point| point.t);
+
+        match self {
+            Self::Circle(circle) => {
+                NurbsCurve::circle_arc(circle, [start, end])
+            }
+            Self::Line(line) => NurbsCurve::from_control_points(
+                1,
+                [start, end].map(|t| line.point_from_line_coords([t])),
+            ),
+            Self::Nurbs(curve) => curve.segment([start, end]),
         }
     }
 
@@ -75,18 +101,22 @@ impl SurfacePath {
         match self {
             Self::Circle(circle) => Self::Circle(circle.reverse()),
             Self::Line(line) => Self::Line(line.reverse()),
+            Self::Nurbs(curve) => Self::Nurbs(curve.reverse()),
         }
     }
 }
 
 /// A path through global (3D) space
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum GlobalPath {
     /// A circle
     Circle(Circle<3>),
 
     /// A line
     Line(Line<3>),
+
+    /// A NURBS curve
+    Nurbs(NurbsCurve<3>),
 }
 
 impl GlobalPath {
@@ -132,10 +162,17 @@ impl GlobalPath {
     }
 
     /// Access the origin of the path's coordinate system
+    ///
+    /// For NURBS curves, whose domain doesn't necessarily include the curve
+    /// coordinate `0`, this is the start of the curve.
     pub fn origin(&self) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.center() + circle.a(),
             Self::Line(line) => line.origin(),
+            Self::Nurbs(curve) => {
+                let [start, _] = curve.domain();
+                curve.point_from_curve_coords([start])
+            }
         }
     }
 
@@ -147,17 +184,23 @@ impl GlobalPath {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Nurbs(curve) => curve.point_from_curve_coords(point),
         }
     }
 
-    /// Convert a vector on the path into global coordinates
+    /// Convert a vector at a point on the path into global coordinates
+    ///
+    /// The point is only required for NURBS curves, whose direction depends on
+    /// the location on the curve.
     pub fn vector_from_path_coords(
         &self,
+        point: impl Into<Point<1>>,
         vector: impl Into<Vector<1>>,
     ) -> Vector<3> {
         match self {
             Self::Circle(circle) => circle.vector_from_circle_coords(vector),
             Self::Line(line) => line.vector_from_line_coords(vector),
+            Self::Nurbs(curve) => curve.vector_from_curve_coords(point, vector),
         }
     }
 
@@ -169,6 +212,9 @@ impl GlobalPath {
                 Self::Circle(transform.transform_circle(&curve))
             }
             Self::Line(curve) => Self::Line(transform.transform_line(&curve)),
+            Self::Nurbs(curve) => {
+                Self::Nurbs(transform.transform_nurbs_curve(&curve))
+            }
         }
     }
 }
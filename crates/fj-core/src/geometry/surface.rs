@@ -1,12 +1,149 @@
 //! The geometry that defines a surface
 
-use fj_math::{Line, Plane, Point, Transform, Vector};
+use fj_math::{
+    Cone, Cylinder, Line, NurbsSurface, Plane, Point, Scalar, Sphere, Torus,
+    Transform, Vector,
+};
 
 use super::GlobalPath;
 
 /// The geometry that defines a surface
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct SurfaceGeom {
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SurfaceGeom {
+    /// A surface that is defined by sweeping a path along a straight line
+    ///
+    /// This includes planes, and any surface created by sweeping.
+    SweptCurve(SweptCurve),
+
+    /// A circular cylinder
+    Cylinder(Cylinder),
+
+    /// A circular cone
+    Cone(Cone),
+
+    /// A sphere
+    Sphere(Sphere),
+
+    /// A torus
+    Torus(Torus),
+
+    /// A NURBS surface
+    Nurbs(NurbsSurface),
+}
+
+impl SurfaceGeom {
+    /// Convert a point in surface coordinates to model coordinates
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        match self {
+            Self::SweptCurve(surface) => {
+                surface.point_from_surface_coords(point)
+            }
+            Self::Cylinder(cylinder) => {
+                cylinder.point_from_cylinder_coords(point)
+            }
+            Self::Cone(cone) => cone.point_from_cone_coords(point),
+            Self::Sphere(sphere) => sphere.point_from_sphere_coords(point),
+            Self::Torus(torus) => torus.point_from_torus_coords(point),
+            Self::Nurbs(surface) => surface.point_from_surface_coords(point),
+        }
+    }
+
+    /// Convert a vector at a point in surface coordinates to model coordinates
+    ///
+    /// The point is required, as the conversion of the vector depends on its
+    /// location, if the surface is curved.
+    pub fn vector_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let point = point.into();
+        let vector = vector.into();
+
+        match self {
+            Self::SweptCurve(surface) => {
+                let u = match &surface.u {
+                    GlobalPath::Circle(circle) => circle
+                        .vector_from_circle_coords([point.u + Scalar::PI / 2.]),
+                    GlobalPath::Line(line) => line.direction(),
+                    GlobalPath::Nurbs(curve) => {
+                        curve.vector_from_curve_coords([point.u], [1.])
+                    }
+                };
+
+                u * vector.u + surface.v * vector.v
+            }
+            Self::Cylinder(cylinder) => {
+                cylinder.vector_from_cylinder_coords(point, vector)
+            }
+            Self::Cone(cone) => cone.vector_from_cone_coords(point, vector),
+            Self::Sphere(sphere) => {
+                sphere.vector_from_sphere_coords(point, vector)
+            }
+            Self::Torus(torus) => torus.vector_from_torus_coords(point, vector),
+            Self::Nurbs(surface) => {
+                surface.vector_from_surface_coords(point, vector)
+            }
+        }
+    }
+
+    /// Compute the normal of the surface at the provided point
+    ///
+    /// Returns a vector of unit length, that points in the direction of the
+    /// cross product of the surface's u- and v-axes.
+    pub fn normal(&self, point: impl Into<Point<2>>) -> Vector<3> {
+        let point = point.into();
+
+        let u = self.vector_from_surface_coords(point, [1., 0.]);
+        let v = self.vector_from_surface_coords(point, [0., 1.]);
+
+        u.cross(&v).normalize()
+    }
+
+    /// Project the global point into the surface
+    pub fn project_global_point(&self, point: impl Into<Point<3>>) -> Point<2> {
+        match self {
+            Self::SweptCurve(surface) => surface.project_global_point(point),
+            Self::Cylinder(cylinder) => {
+                cylinder.point_to_cylinder_coords(point)
+            }
+            Self::Cone(cone) => cone.point_to_cone_coords(point),
+            Self::Sphere(sphere) => sphere.point_to_sphere_coords(point),
+            Self::Torus(torus) => torus.point_to_torus_coords(point),
+            Self::Nurbs(surface) => surface.point_to_surface_coords(point),
+        }
+    }
+
+    /// Transform the surface geometry
+    #[must_use]
+    pub fn transform(self, transform: &Transform) -> Self {
+        match self {
+            Self::SweptCurve(surface) => {
+                Self::SweptCurve(surface.transform(transform))
+            }
+            Self::Cylinder(cylinder) => {
+                Self::Cylinder(transform.transform_cylinder(&cylinder))
+            }
+            Self::Cone(cone) => Self::Cone(transform.transform_cone(&cone)),
+            Self::Sphere(sphere) => {
+                Self::Sphere(transform.transform_sphere(&sphere))
+            }
+            Self::Torus(torus) => {
+                Self::Torus(transform.transform_torus(&torus))
+            }
+            Self::Nurbs(surface) => {
+                Self::Nurbs(transform.transform_nurbs_surface(&surface))
+            }
+        }
+    }
+}
+
+/// A surface that is defined by sweeping a path along a straight line
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SweptCurve {
     /// The u-axis of the surface
     pub u: GlobalPath,
 
@@ -14,7 +151,7 @@ pub struct SurfaceGeom {
     pub v: Vector<3>,
 }
 
-impl SurfaceGeom {
+impl SweptCurve {
     /// Convert a point in surface coordinates to model coordinates
     pub fn point_from_surface_coords(
         &self,
@@ -25,13 +162,18 @@ impl SurfaceGeom {
             + self.path_to_line().vector_from_line_coords([point.v])
     }
 
-    /// Convert a vector in surface coordinates to model coordinates
+    /// Convert a vector at a point in surface coordinates to model coordinates
+    ///
+    /// The point is only required, if the u-axis of the surface is a NURBS
+    /// curve.
     pub fn vector_from_surface_coords(
         &self,
+        point: impl Into<Point<2>>,
         vector: impl Into<Vector<2>>,
     ) -> Vector<3> {
+        let point = point.into();
         let vector = vector.into();
-        self.u.vector_from_path_coords([vector.u])
+        self.u.vector_from_path_coords([point.u], [vector.u])
             + self.path_to_line().vector_from_line_coords([vector.v])
     }
 
@@ -40,14 +182,59 @@ impl SurfaceGeom {
     }
 
     /// Project the global point into the surface
+    ///
+    /// For surfaces that are not planes, this assumes that the point is on or
+    /// near the surface. The point is moved along the v-axis of the surface,
+    /// until it is on or near the u-axis.
     pub fn project_global_point(&self, point: impl Into<Point<3>>) -> Point<2> {
-        let GlobalPath::Line(line) = self.u else {
-            todo!("Projecting point into non-plane surface is not supported")
-        };
+        let point = point.into();
 
-        let plane =
-            Plane::from_parametric(line.origin(), line.direction(), self.v);
-        plane.project_point(point)
+        match &self.u {
+            GlobalPath::Circle(circle) => {
+                // Move the point along `v` into the plane of the circle. Then
+                // its angle relative to the circle is the u-coordinate.
+                let normal = circle.a().cross(&circle.b());
+                let v = (point - circle.center()).dot(&normal)
+                    / self.v.dot(&normal);
+
+                let r = point - self.v * v - circle.center();
+                let mut u =
+                    Scalar::atan2(r.dot(&circle.b()), r.dot(&circle.a()));
+                if u < Scalar::ZERO {
+                    u += Scalar::TAU;
+                }
+
+                Point::from([u, v])
+            }
+            GlobalPath::Line(line) => {
+                let plane = Plane::from_parametric(
+                    line.origin(),
+                    line.direction(),
+                    self.v,
+                );
+                plane.project_point(point)
+            }
+            GlobalPath::Nurbs(curve) => {
+                // Projecting onto a plane that is perpendicular to `v` is an
+                // affine transformation, so the projected curve can be
+                // computed from the projected control points. The closest
+                // point on it has the u-coordinate we're looking for.
+                let direction = self.v.normalize();
+                let flatten = |point: &Point<3>| {
+                    *point - direction * point.coords.dot(&direction)
+                };
+
+                let u = curve
+                    .map_control_points(flatten)
+                    .point_to_curve_coords(flatten(&point))
+                    .t;
+                let v = (point - curve.point_from_curve_coords([u]))
+                    .dot(&self.v)
+                    / self.v.dot(&self.v);
+
+                Point::from([u, v])
+            }
+        }
     }
 
     /// Transform the surface geometry
@@ -61,14 +248,16 @@ impl SurfaceGeom {
 
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::{Circle, Line, NurbsCurve, Point, Scalar, Sphere, Vector};
     use pretty_assertions::assert_eq;
 
-    use crate::geometry::{GlobalPath, SurfaceGeom};
+    use crate::geometry::{GlobalPath, SurfaceGeom, SweptCurve};
 
     #[test]
     fn point_from_surface_coords() {
-        let surface = SurfaceGeom {
+        let surface = SweptCurve {
             u: GlobalPath::Line(Line::from_origin_and_direction(
                 Point::from([1., 1., 1.]),
                 Vector::from([0., 2., 0.]),
@@ -84,7 +273,7 @@ mod tests {
 
     #[test]
     fn vector_from_surface_coords() {
-        let surface = SurfaceGeom {
+        let surface = SweptCurve {
             u: GlobalPath::Line(Line::from_origin_and_direction(
                 Point::from([1., 0., 0.]),
                 Vector::from([0., 2., 0.]),
@@ -93,8 +282,87 @@ mod tests {
         };
 
         assert_eq!(
-            surface.vector_from_surface_coords([2., 4.]),
+            surface.vector_from_surface_coords([0., 0.], [2., 4.]),
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn vector_from_surface_coords_with_nurbs_u() {
+        let surface = SweptCurve {
+            u: GlobalPath::Nurbs(NurbsCurve::from_control_points(
+                1,
+                [[0., 0., 0.], [2., 0., 0.]],
+            )),
+            v: Vector::from([0., 0., 1.]),
+        };
+
+        assert_eq!(
+            surface.vector_from_surface_coords([0.5, 0.], [1., 3.]),
+            Vector::from([2., 0., 3.]),
+        );
+    }
+
+    #[test]
+    fn project_global_point_into_extruded_circle() {
+        let surface = SweptCurve {
+            u: GlobalPath::Circle(Circle::from_center_and_radius(
+                [1., 2., 3.],
+                2.,
+            )),
+            v: Vector::from([0., 1., 3.]),
+        };
+
+        for point in [[0.5, 0.], [2., 1.5], [4., -2.], [6., 0.25]] {
+            let global = surface.point_from_surface_coords(point);
+            let projected = surface.project_global_point(global);
+
+            assert!(
+                (projected - Point::from(point)).magnitude()
+                    < Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn project_global_point_into_extruded_nurbs_curve() {
+        let surface = SweptCurve {
+            u: GlobalPath::Nurbs(NurbsCurve::from_control_points(
+                2,
+                [[0., 0., 0.], [1., 2., 0.], [2., 0., 1.]],
+            )),
+            v: Vector::from([0., 0., 2.]),
+        };
+
+        for point in [[0., 0.], [0.3, 1.5], [0.8, -2.], [1., 0.25]] {
+            let global = surface.point_from_surface_coords(point);
+            let projected = surface.project_global_point(global);
+
+            assert!(
+                (projected - Point::from(point)).magnitude()
+                    < Scalar::from(1e-9)
+            );
+        }
+    }
+
+    #[test]
+    fn normal_of_sphere() {
+        let surface = SurfaceGeom::Sphere(Sphere::from_center_and_radius(
+            [0., 0., 0.],
+            2.,
+        ));
+
+        let normal = surface.normal([FRAC_PI_2, 0.]);
+        assert!(
+            (normal - Vector::from([0., 1., 0.])).magnitude()
+                < Scalar::from(1e-12)
+        );
+
+        let point = surface.point_from_surface_coords([FRAC_PI_2, 0.5]);
+        let projected = surface.project_global_point(point);
+        assert!(
+            (projected - Point::from([FRAC_PI_2, 0.5])).magnitude()
+                < Scalar::from(1e-12)
+        );
+    }
 }
@@ -3,7 +3,7 @@
 use crate::{
     geometry::Geometry,
     topology::{AnyObject, Stored},
-    validation::{Validation, ValidationError, ValidationErrors},
+    validation::{Severity, Validation, ValidationError, ValidationErrors},
 };
 
 use super::{Command, Event, Layer};
@@ -13,6 +13,14 @@ impl Layer<Validation> {
     pub fn take_errors(&mut self) -> Result<(), ValidationErrors> {
         self.process(TakeErrors, &mut Vec::new())
     }
+
+    /// Take all warnings stored in the validation layer
+    ///
+    /// Warnings are produced by validation checks whose severity is configured
+    /// as [`Severity::Warning`].
+    pub fn take_warnings(&mut self) -> Vec<ValidationError> {
+        self.process(TakeWarnings, &mut Vec::new())
+    }
 }
 
 /// Validate an object
@@ -34,13 +42,16 @@ impl Command<Validation> for ValidateObject<'_> {
             .validate(&state.config, &mut errors, self.geometry);
 
         for err in errors {
-            if state.config.panic_on_error {
+            let severity = state.config.check(err.check_name()).severity;
+
+            if state.config.panic_on_error && severity == Severity::Error {
                 panic!("{:#?}", err);
             }
 
             events.push(ValidationFailed {
                 object: self.object.clone(),
                 err,
+                severity,
             });
         }
     }
@@ -78,6 +89,32 @@ impl Event<Validation> for TakeErrors {
     }
 }
 
+/// Take all warnings stored in the validation layer
+///
+/// Serves both as a command for and event produced by `Layer<Validation>`.
+pub struct TakeWarnings;
+
+impl Command<Validation> for TakeWarnings {
+    type Result = Vec<ValidationError>;
+    type Event = Self;
+
+    fn decide(
+        self,
+        state: &Validation,
+        events: &mut Vec<Self::Event>,
+    ) -> Self::Result {
+        let warnings = state.warnings.values().cloned().collect();
+        events.push(self);
+        warnings
+    }
+}
+
+impl Event<Validation> for TakeWarnings {
+    fn evolve(&self, state: &mut Validation) {
+        state.warnings.clear();
+    }
+}
+
 /// Validation of an object failed
 ///
 /// Event produced by `Layer<Validation>`.
@@ -88,10 +125,18 @@ pub struct ValidationFailed {
 
     /// The validation error
     pub err: ValidationError,
+
+    /// The severity of the validation error
+    pub severity: Severity,
 }
 
 impl Event<Validation> for ValidationFailed {
     fn evolve(&self, state: &mut Validation) {
-        state.errors.insert(self.object.id(), self.err.clone());
+        let errors = match self.severity {
+            Severity::Error => &mut state.errors,
+            Severity::Warning => &mut state.warnings,
+        };
+
+        errors.insert(self.object.id(), self.err.clone());
     }
 }
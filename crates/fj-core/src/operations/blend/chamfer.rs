@@ -0,0 +1,133 @@
+use fj_math::Scalar;
+
+use crate::{
+    storage::Handle,
+    topology::{Face, HalfEdge, Shell},
+    Core,
+};
+
+use super::edge::{blend_edge, Blend};
+
+/// # Bevel an edge of a [`Shell`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait Chamfer: Sized {
+    /// # Replace the edge with a planar or conical face
+    ///
+    /// The edge is defined by the provided half-edge and its sibling. Both
+    /// faces that share the edge are trimmed at the provided distance from it,
+    /// measured within each face, and a new face is inserted in between.
+    ///
+    /// Returns the updated shell, as well as the new face.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the half-edge or its sibling are not part of the shell, if
+    /// the edge doesn't meet the requirements listed in the module
+    /// documentation, or if the distance exceeds the length of the adjacent
+    /// edges or the radius of a curved edge.
+    #[must_use]
+    fn chamfer_edge(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        distance: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> (Self, Handle<Face>);
+}
+
+impl Chamfer for Shell {
+    fn chamfer_edge(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        distance: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> (Self, Handle<Face>) {
+        let distance = distance.into();
+        blend_edge(self, half_edge, Blend::Chamfer { distance }, core)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::mass_properties::ComputeMassProperties,
+        operations::{
+            blend::tests::{arc_edge, prism, quarter_cylinder, vertical_edge},
+            insert::Insert,
+        },
+        queries::SiblingOfHalfEdge,
+        Core,
+    };
+
+    use super::Chamfer;
+
+    #[test]
+    fn chamfer_edge_of_cube() {
+        let mut core = Core::new();
+
+        let shell = prism(&[[0., 0.], [1., 0.], [1., 1.], [0., 1.]], &mut core);
+        let half_edges = shell.faces().first().region().exterior().half_edges();
+
+        let (shell, _) =
+            shell.chamfer_edge(half_edges.first(), 0.25, &mut core);
+        let shell = shell.insert(&mut core);
+        assert_eq!(shell.faces().len(), 7);
+
+        let volume = shell.mass_properties(0.001, &mut core).volume;
+        let expected = 1. - 0.25 * 0.25 / 2.;
+        assert!((volume - Scalar::from(expected)).abs() < Scalar::from(1e-9));
+    }
+
+    #[test]
+    fn chamfer_edge_between_oblique_faces() {
+        let mut core = Core::new();
+
+        // The side faces of an equilateral triangular prism meet at 60°.
+        let h = 3f64.sqrt();
+        let shell = prism(&[[0., 0.], [2., 0.], [1., h]], &mut core);
+
+        let (shell, _) =
+            shell.chamfer_edge(&vertical_edge(&shell), 0.25, &mut core);
+        let shell = shell.insert(&mut core);
+        assert_eq!(shell.faces().len(), 6);
+
+        // The chamfer cuts off a triangle with two sides of length 0.25.
+        let volume = shell.mass_properties(0.001, &mut core).volume;
+        let expected = h - 0.25 * 0.25 * h / 4.;
+        assert!((volume - Scalar::from(expected)).abs() < Scalar::from(1e-9));
+    }
+
+    #[test]
+    fn chamfer_curved_edge() {
+        let mut core = Core::new();
+
+        // Blend the arc from the side of the cylinder, to cover the case where
+        // the planar face is on the other side.
+        let shell = quarter_cylinder(&mut core);
+        let half_edge = shell
+            .get_sibling_of(&arc_edge(&shell, &core))
+            .unwrap()
+            .sibling;
+
+        let (shell, _) = shell.chamfer_edge(&half_edge, 0.25, &mut core);
+        let shell = shell.insert(&mut core);
+        assert_eq!(shell.faces().len(), 6);
+
+        // The chamfer cuts off a triangle, rotated around the axis. Its
+        // centroid is at a distance of `1 - 0.25 / 3` from the axis.
+        let tolerance = 0.001;
+        let properties = shell.mass_properties(tolerance, &mut core);
+        let removed = 0.25 * 0.25 / 2. * (1. - 0.25 / 3.) * PI / 2.;
+        let expected = Scalar::from(PI / 4. - removed);
+        assert!(
+            properties.volume >= expected - properties.surface_area * tolerance
+        );
+        assert!(properties.volume <= expected + Scalar::from(1e-9));
+    }
+}
@@ -0,0 +1,1088 @@
+use fj_math::{Circle, Cone, Line, Point, Scalar, Torus, Vector};
+
+use crate::{
+    geometry::{
+        Geometry, GlobalPath, LocalCurveGeom, LocalVertexGeom, SurfaceGeom,
+        SurfacePath, SweptCurve,
+    },
+    operations::{
+        build::{BuildHalfEdge, BuildSurface},
+        derive::DeriveFrom,
+        insert::Insert,
+        presentation::{GetColor, SetColor},
+        update::UpdateShell,
+    },
+    queries::{Sibling, SiblingOfHalfEdge},
+    storage::Handle,
+    topology::{
+        Curve, Cycle, Face, HalfEdge, Handedness, Region, Shell, Surface,
+        Vertex,
+    },
+    Core,
+};
+
+/// The kind of face that replaces a blended edge
+#[derive(Clone, Copy)]
+pub enum Blend {
+    Chamfer { distance: Scalar },
+    Fillet { radius: Scalar },
+}
+
+/// Replace an edge of a shell with a new face
+///
+/// Please note that this function uses specific names for the objects involved,
+/// which are used throughout:
+///
+/// - The two faces that share the edge are "side" A and B. The provided
+///   half-edge is part of side A, its sibling is part of side B.
+/// - The provided half-edge starts at vertex 0 and ends at vertex 1. The faces
+///   at those vertices, that aren't side A or B, are "end" 0 and 1.
+/// - The new vertices, where the sides are trimmed, are named after the side
+///   and the vertex they replace.
+pub fn blend_edge(
+    shell: &Shell,
+    half_edge: &Handle<HalfEdge>,
+    blend: Blend,
+    core: &mut Core,
+) -> (Shell, Handle<Face>) {
+    let side_b = shell
+        .get_sibling_of(half_edge)
+        .expect("Expected half-edge and its sibling to be part of shell");
+    let side_a = shell
+        .get_sibling_of(&side_b.sibling)
+        .expect("Expected half-edge and its sibling to be part of shell");
+    let [a, b] = [side_a, side_b].map(Side::new);
+
+    // The end at each vertex contains the sibling of the previous half-edge of
+    // the side that starts there. If exactly three faces meet at that vertex,
+    // it also contains the sibling of the other side's next half-edge.
+    let [end_0, end_1] = [(&a, &b), (&b, &a)].map(|(side, other)| {
+        let end = shell
+            .get_sibling_of(&side.prev)
+            .expect("Expected half-edges of shell to have siblings");
+        let before = end
+            .cycle
+            .half_edges()
+            .before(&end.sibling)
+            .expect("Expected half-edge to be part of its cycle");
+
+        assert!(
+            shell.are_siblings(before, &other.next),
+            "Blending an edge is only supported, if exactly three faces meet \
+            at each of its vertices"
+        );
+
+        end
+    });
+
+    let geometry = &core.layers.geometry;
+
+    let [surface_end_0, surface_end_1] = [&end_0.face, &end_1.face]
+        .map(|face| geometry.of_surface(face.surface()).clone());
+
+    let shape = Shape::new(&a, &b, geometry);
+
+    let vertex_0 = a.position(&a.half_edge, geometry);
+    let vertex_1 = a.position(&a.next, geometry);
+
+    // The edge and the directions of the sides at each vertex. They only
+    // differ between the vertices, if the edge is curved.
+    let [frame_0, frame_1] =
+        [(0, &a.half_edge, &b.next), (1, &a.next, &b.half_edge)].map(
+            |(i, half_edge_a, half_edge_b)| {
+                let direction = match &shape {
+                    Shape::Straight => (vertex_1 - vertex_0).normalize(),
+                    Shape::Arc(arc) => arc.direction(i),
+                };
+
+                Frame {
+                    direction,
+                    inward_a: a.normal(half_edge_a, geometry).cross(&direction),
+                    inward_b: b
+                        .normal(half_edge_b, geometry)
+                        .cross(&-direction),
+                }
+            },
+        );
+
+    let cos_angle = frame_0.inward_a.dot(&frame_0.inward_b);
+    let sin_angle = frame_0.inward_a.cross(&frame_0.inward_b).magnitude();
+    assert!(
+        sin_angle > Scalar::from(EPSILON),
+        "Can't blend edge between faces that are parallel"
+    );
+    if let Shape::Arc(_) = shape {
+        assert!(
+            cos_angle.abs() < Scalar::from(EPSILON),
+            "Blending a curved edge is only supported, if the cylinder is \
+            perpendicular to the plane"
+        );
+    }
+
+    let distance = match blend {
+        Blend::Chamfer { distance } => distance,
+        Blend::Fillet { radius } => {
+            // The distance from the edge, where a circle of the given radius
+            // touches both faces.
+            radius * (Scalar::ONE + cos_angle) / sin_angle
+        }
+    };
+
+    let [a_0, a_1, b_1, b_0] = [
+        (&a, &a.prev, Trim::AtStart, frame_0.inward_a),
+        (&a, &a.next, Trim::AtEnd, frame_1.inward_a),
+        (&b, &b.prev, Trim::AtStart, frame_1.inward_b),
+        (&b, &b.next, Trim::AtEnd, frame_0.inward_b),
+    ]
+    .map(|(side, half_edge, trim, inward)| {
+        side.trim(half_edge, trim, inward, distance, geometry)
+    });
+
+    if let Blend::Fillet { .. } = blend {
+        for (point, frame) in [
+            (&a_0, &frame_0),
+            (&a_1, &frame_1),
+            (&b_1, &frame_1),
+            (&b_0, &frame_0),
+        ] {
+            assert!(
+                point.direction.dot(&frame.direction).abs()
+                    < Scalar::from(EPSILON),
+                "Filleting an edge is only supported, if the edges connected \
+                to it are perpendicular to it"
+            );
+        }
+    }
+
+    // The paths where the sides are trimmed, and the curve coordinates of the
+    // new vertices on them.
+    let trims = match &shape {
+        Shape::Straight => {
+            [(&a, [&a_0, &a_1]), (&b, [&b_1, &b_0])].map(|(side, points)| {
+                let surface = geometry.of_surface(side.face.surface());
+                let path =
+                    SurfacePath::line_from_points(points.map(|point| {
+                        surface.project_global_point(point.position)
+                    }))
+                    .0;
+
+                (path, [0., 1.].map(|coord| Point::from([coord])))
+            })
+        }
+        Shape::Arc(arc) => arc.trims([&a, &b], distance, &frame_0, geometry),
+    };
+
+    let blend_geometry = match (blend, &shape) {
+        (Blend::Chamfer { .. }, Shape::Straight) => {
+            let surface = SurfaceGeom::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(Line::from_origin_and_direction(
+                    a_0.position,
+                    b_0.position - a_0.position,
+                )),
+                v: a_1.position - a_0.position,
+            });
+
+            let line =
+                |surface: &SurfaceGeom, points: [&TrimPoint; 2]| {
+                    SurfacePath::line_from_points(points.map(|point| {
+                        surface.project_global_point(point.position)
+                    }))
+                    .0
+                };
+
+            BlendGeometry {
+                surface: surface.clone(),
+                sides: [
+                    line(&surface, [&a_0, &a_1]),
+                    line(&surface, [&b_1, &b_0]),
+                ],
+                ends: [
+                    [
+                        line(&surface_end_0, [&a_0, &b_0]),
+                        line(&surface, [&a_0, &b_0]),
+                    ],
+                    [
+                        line(&surface_end_1, [&a_1, &b_1]),
+                        line(&surface, [&a_1, &b_1]),
+                    ],
+                ],
+                b_coord: Scalar::ONE,
+            }
+        }
+        (Blend::Fillet { radius }, Shape::Straight) => {
+            // The center of the fillet is in the direction of side B, as seen
+            // from side A.
+            let normal_a = a.normal(&a.half_edge, geometry);
+            let towards_b = if normal_a.dot(&frame_0.inward_b) > Scalar::ZERO {
+                normal_a
+            } else {
+                -normal_a
+            };
+            let center = a_0.position + towards_b * radius;
+
+            let a = a_0.position - center;
+            let b = {
+                let to_b = b_0.position - center;
+                let b = to_b - a * (to_b.dot(&a) / a.dot(&a));
+                b.normalize() * a.magnitude()
+            };
+            let angle = Scalar::PI - cos_angle.acos();
+
+            let surface = SurfaceGeom::SweptCurve(SweptCurve {
+                u: GlobalPath::Circle(Circle::new(center, a, b)),
+                v: vertex_1 - vertex_0,
+            });
+
+            let line = |points: [[Scalar; 2]; 2]| {
+                SurfacePath::line_from_points(points).0
+            };
+            let arc = |surface: &SurfaceGeom, offset: Vector<3>| {
+                circle_on_plane(surface, center + offset, a, b)
+            };
+            let along_u = |v: f64| {
+                SurfacePath::Line(Line::from_origin_and_direction(
+                    Point::from([0., v]),
+                    Vector::from([1., 0.]),
+                ))
+            };
+
+            BlendGeometry {
+                surface,
+                sides: [
+                    line([
+                        [Scalar::ZERO, Scalar::ZERO],
+                        [Scalar::ZERO, Scalar::ONE],
+                    ]),
+                    line([[angle, Scalar::ONE], [angle, Scalar::ZERO]]),
+                ],
+                ends: [
+                    [
+                        arc(&surface_end_0, Vector::from([0., 0., 0.])),
+                        along_u(0.),
+                    ],
+                    [arc(&surface_end_1, vertex_1 - vertex_0), along_u(1.)],
+                ],
+                b_coord: angle,
+            }
+        }
+        (blend, Shape::Arc(arc)) => arc.blend_geometry(
+            blend,
+            distance,
+            [&frame_0, &frame_1],
+            [&a_0, &a_1, &b_1, &b_0],
+            [&surface_end_0, &surface_end_1],
+        ),
+    };
+
+    let surface = Surface::from_geometry(blend_geometry.surface, core);
+
+    let [vertex_a_0, vertex_a_1, vertex_b_1, vertex_b_0] =
+        [&a_0, &a_1, &b_1, &b_0].map(|point| {
+            let vertex = Vertex::new().insert(core);
+
+            core.layers.geometry.define_vertex(
+                vertex.clone(),
+                point.curve.clone(),
+                LocalVertexGeom {
+                    position: point.coord,
+                },
+            );
+
+            vertex
+        });
+
+    // The curves where the sides are trimmed. Each one runs in the direction
+    // of the half-edge on its side.
+    let [trim_on_a, trim_on_b] = trims;
+    let [curve_a, curve_b] = [
+        (
+            &a,
+            trim_on_a,
+            blend_geometry.sides[0].clone(),
+            [&vertex_a_0, &vertex_a_1],
+        ),
+        (
+            &b,
+            trim_on_b,
+            blend_geometry.sides[1].clone(),
+            [&vertex_b_1, &vertex_b_0],
+        ),
+    ]
+    .map(|(side, (path_on_side, coords), path_on_blend, vertices)| {
+        let curve = Curve::new().insert(core);
+
+        for (surface, path) in [
+            (side.face.surface().clone(), path_on_side),
+            (surface.clone(), path_on_blend),
+        ] {
+            core.layers.geometry.define_curve(
+                curve.clone(),
+                surface,
+                LocalCurveGeom { path },
+            );
+        }
+        for (vertex, position) in vertices.into_iter().zip(coords) {
+            core.layers.geometry.define_vertex(
+                vertex.clone(),
+                curve.clone(),
+                LocalVertexGeom { position },
+            );
+        }
+
+        curve
+    });
+
+    // The curves where the ends are trimmed. Each one runs from side A to
+    // side B.
+    let [curve_end_0, curve_end_1] = [
+        (
+            &end_0,
+            blend_geometry.ends[0].clone(),
+            [&vertex_a_0, &vertex_b_0],
+        ),
+        (
+            &end_1,
+            blend_geometry.ends[1].clone(),
+            [&vertex_a_1, &vertex_b_1],
+        ),
+    ]
+    .map(|(end, [path_on_end, path_on_blend], vertices)| {
+        let curve = Curve::new().insert(core);
+
+        for (surface, path) in [
+            (end.face.surface().clone(), path_on_end),
+            (surface.clone(), path_on_blend),
+        ] {
+            core.layers.geometry.define_curve(
+                curve.clone(),
+                surface,
+                LocalCurveGeom { path },
+            );
+        }
+        for (vertex, position) in vertices
+            .into_iter()
+            .zip([Scalar::ZERO, blend_geometry.b_coord])
+        {
+            core.layers.geometry.define_vertex(
+                vertex.clone(),
+                curve.clone(),
+                LocalVertexGeom {
+                    position: Point::from([position]),
+                },
+            );
+        }
+
+        curve
+    });
+
+    let trim_a = HalfEdge::new(curve_a, vertex_a_0.clone()).insert(core);
+    let trim_b = HalfEdge::new(curve_b, vertex_b_1.clone()).insert(core);
+    let trim_end_0 =
+        HalfEdge::new(curve_end_0, vertex_b_0.clone()).insert(core);
+    let trim_end_1 =
+        HalfEdge::new(curve_end_1, vertex_a_1.clone()).insert(core);
+
+    let replacements = [
+        (a.half_edge.clone(), vec![trim_a.clone()]),
+        (
+            a.next.clone(),
+            vec![HalfEdge::new(a.next.curve().clone(), vertex_a_1.clone())
+                .insert(core)
+                .derive_from(&a.next, core)],
+        ),
+        (b.half_edge.clone(), vec![trim_b.clone()]),
+        (
+            b.next.clone(),
+            vec![HalfEdge::new(b.next.curve().clone(), vertex_b_0.clone())
+                .insert(core)
+                .derive_from(&b.next, core)],
+        ),
+        (
+            end_0.sibling.clone(),
+            vec![
+                trim_end_0.clone(),
+                HalfEdge::new(
+                    end_0.sibling.curve().clone(),
+                    vertex_a_0.clone(),
+                )
+                .insert(core)
+                .derive_from(&end_0.sibling, core),
+            ],
+        ),
+        (
+            end_1.sibling.clone(),
+            vec![
+                trim_end_1.clone(),
+                HalfEdge::new(
+                    end_1.sibling.curve().clone(),
+                    vertex_b_1.clone(),
+                )
+                .insert(core)
+                .derive_from(&end_1.sibling, core),
+            ],
+        ),
+    ];
+
+    let mut updated_shell = shell.clone();
+    for face in shell.faces() {
+        let is_affected = face.region().all_cycles().any(|cycle| {
+            cycle.half_edges().iter().any(|half_edge| {
+                replacements
+                    .iter()
+                    .any(|(original, _)| original == half_edge)
+            })
+        });
+        if !is_affected {
+            continue;
+        }
+
+        updated_shell = updated_shell.update_face(
+            face,
+            |face, core| {
+                let mut update_cycle = |cycle: &Handle<Cycle>| {
+                    let half_edges =
+                        cycle.half_edges().iter().flat_map(|half_edge| {
+                            replacements
+                                .iter()
+                                .find(|(original, _)| original == half_edge)
+                                .map(|(_, replacement)| replacement.clone())
+                                .unwrap_or_else(|| vec![half_edge.clone()])
+                        });
+
+                    Cycle::new(half_edges).insert(core).derive_from(cycle, core)
+                };
+
+                let exterior = update_cycle(face.region().exterior());
+                let interiors = face
+                    .region()
+                    .interiors()
+                    .iter()
+                    .map(&mut update_cycle)
+                    .collect::<Vec<_>>();
+
+                let region = Region::new(exterior, interiors)
+                    .insert(core)
+                    .derive_from(face.region(), core);
+
+                [Face::new(face.surface().clone(), region)]
+            },
+            core,
+        );
+    }
+
+    let blend_face = {
+        let cycle = Cycle::new([
+            HalfEdge::from_sibling(&trim_a, vertex_a_1, core),
+            HalfEdge::from_sibling(&trim_end_0, vertex_a_0, core),
+            HalfEdge::from_sibling(&trim_b, vertex_b_0, core),
+            HalfEdge::from_sibling(&trim_end_1, vertex_b_1, core),
+        ])
+        .insert(core);
+        let region = Region::new(cycle, []).insert(core);
+
+        if let Some(color) = a.face.region().get_color(core) {
+            region.set_color(color, core);
+        }
+
+        Face::new(surface, region).insert(core)
+    };
+
+    let shell = updated_shell.add_faces([blend_face.clone()], core);
+
+    (shell, blend_face)
+}
+
+/// One of the faces that share the blended edge
+struct Side {
+    face: Handle<Face>,
+    cycle: Handle<Cycle>,
+    half_edge: Handle<HalfEdge>,
+    prev: Handle<HalfEdge>,
+    next: Handle<HalfEdge>,
+}
+
+impl Side {
+    fn new(sibling: Sibling) -> Self {
+        let Sibling {
+            sibling: half_edge,
+            cycle,
+            face,
+        } = sibling;
+
+        let half_edges = cycle.half_edges();
+        let prev = half_edges
+            .before(&half_edge)
+            .expect("Expected half-edge to be part of its cycle")
+            .clone();
+        let next = half_edges
+            .after(&half_edge)
+            .expect("Expected half-edge to be part of its cycle")
+            .clone();
+
+        Self {
+            face,
+            cycle,
+            half_edge,
+            prev,
+            next,
+        }
+    }
+
+    /// Compute the position of the start vertex of one of the side's half-edges
+    fn position(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        geometry: &Geometry,
+    ) -> Point<3> {
+        position_on_face(
+            half_edge.start_vertex(),
+            half_edge.curve(),
+            self.face.surface(),
+            geometry,
+        )
+    }
+
+    /// Compute the path of one of the side's half-edges on its surface
+    fn path<'r>(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        geometry: &'r Geometry,
+    ) -> &'r SurfacePath {
+        &geometry
+            .of_curve(half_edge.curve())
+            .unwrap()
+            .local_on(self.face.surface())
+            .unwrap()
+            .path
+    }
+
+    /// Compute the normal of the side at the start vertex of a half-edge,
+    /// pointing to its front
+    fn normal(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        geometry: &Geometry,
+    ) -> Vector<3> {
+        let point = self.path(half_edge, geometry).point_from_path_coords(
+            coord_on_curve(
+                half_edge.start_vertex(),
+                half_edge.curve(),
+                geometry,
+            ),
+        );
+        let normal = geometry.of_surface(self.face.surface()).normal(point);
+
+        match self.face.coord_handedness(geometry) {
+            Handedness::RightHanded => normal,
+            Handedness::LeftHanded => -normal,
+        }
+    }
+
+    /// Find the point where a connected half-edge needs to be trimmed
+    fn trim(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        trim: Trim,
+        inward: Vector<3>,
+        distance: Scalar,
+        geometry: &Geometry,
+    ) -> TrimPoint {
+        let curve = half_edge.curve();
+        let surface = self.face.surface();
+
+        let SurfacePath::Line(_) = self.path(half_edge, geometry) else {
+            panic!(
+                "Blending an edge is only supported, if the edges connected \
+                to it are straight"
+            );
+        };
+
+        let start = half_edge.start_vertex();
+        let end = self
+            .cycle
+            .half_edges()
+            .after(half_edge)
+            .expect("Expected half-edge to be part of its cycle")
+            .start_vertex();
+
+        // The vertex that is removed, and the one that stays.
+        let [removed, remaining] = match trim {
+            Trim::AtStart => [end, start],
+            Trim::AtEnd => [start, end],
+        }
+        .map(|vertex| {
+            (
+                coord_on_curve(vertex, curve, geometry),
+                position_on_face(vertex, curve, surface, geometry),
+            )
+        });
+
+        let along = remaining.1 - removed.1;
+        let fraction = distance / along.dot(&inward);
+        assert!(
+            fraction > Scalar::ZERO && fraction < Scalar::ONE,
+            "Blend is too large for the edges connected to the blended edge"
+        );
+
+        TrimPoint {
+            curve: curve.clone(),
+            coord: removed.0 + (remaining.0 - removed.0) * fraction,
+            position: removed.1 + along * fraction,
+            direction: along.normalize(),
+        }
+    }
+}
+
+/// The shape of the blended edge
+enum Shape {
+    /// The edge is straight, and the faces that share it are planar
+    Straight,
+
+    /// The edge is a circular arc
+    Arc(ArcEdge),
+}
+
+impl Shape {
+    fn new(a: &Side, b: &Side, geometry: &Geometry) -> Self {
+        let is_plane = |side: &Side| {
+            matches!(
+                geometry.of_surface(side.face.surface()),
+                SurfaceGeom::SweptCurve(SweptCurve {
+                    u: GlobalPath::Line(_),
+                    ..
+                })
+            )
+        };
+
+        if is_plane(a) && is_plane(b) {
+            return Self::Straight;
+        }
+
+        let arc = ArcEdge::new(a, b, geometry).unwrap_or_else(|| {
+            panic!(
+                "Blending an edge is only supported, if the faces that share \
+                it are planar, or if it is a circular arc between a plane and \
+                a cylinder"
+            )
+        });
+
+        Self::Arc(arc)
+    }
+}
+
+/// A blended edge that is a circular arc
+///
+/// The face on one side of the edge is planar. The face on the other side is a
+/// cylinder around the axis of the arc.
+struct ArcEdge {
+    /// The circle that the edge is on
+    ///
+    /// Its coordinates are the same as those of the edge's curve.
+    circle: Circle<3>,
+
+    /// The curve coordinates of vertex 0 and 1
+    coords: [Point<1>; 2],
+
+    /// Whether side A is the plane, and side B the cylinder, or vice versa
+    a_is_plane: bool,
+}
+
+impl ArcEdge {
+    fn new(a: &Side, b: &Side, geometry: &Geometry) -> Option<Self> {
+        [(a, b, true), (b, a, false)].into_iter().find_map(
+            |(plane, cylinder, a_is_plane)| {
+                let SurfaceGeom::SweptCurve(
+                    surface @ SweptCurve {
+                        u: GlobalPath::Line(_),
+                        ..
+                    },
+                ) = geometry.of_surface(plane.face.surface())
+                else {
+                    return None;
+                };
+                let SurfaceGeom::SweptCurve(SweptCurve {
+                    u: GlobalPath::Circle(_),
+                    ..
+                }) = geometry.of_surface(cylinder.face.surface())
+                else {
+                    return None;
+                };
+                let (SurfacePath::Circle(circle), SurfacePath::Line(_)) = (
+                    plane.path(&plane.half_edge, geometry),
+                    cylinder.path(&cylinder.half_edge, geometry),
+                ) else {
+                    return None;
+                };
+
+                let circle = Circle::new(
+                    surface.point_from_surface_coords(circle.center()),
+                    surface.vector_from_surface_coords(
+                        circle.center(),
+                        circle.a(),
+                    ),
+                    surface.vector_from_surface_coords(
+                        circle.center(),
+                        circle.b(),
+                    ),
+                );
+                let coords = [&a.half_edge, &a.next].map(|half_edge| {
+                    coord_on_curve(
+                        half_edge.start_vertex(),
+                        a.half_edge.curve(),
+                        geometry,
+                    )
+                });
+
+                Some(Self {
+                    circle,
+                    coords,
+                    a_is_plane,
+                })
+            },
+        )
+    }
+
+    /// Compute the direction of side A's half-edge at vertex 0 or 1
+    fn direction(&self, i: usize) -> Vector<3> {
+        let [start, end] = self.coords;
+        let tangent = self
+            .circle
+            .vector_from_circle_coords([self.coords[i].t + Scalar::PI / 2.])
+            .normalize();
+
+        if end.t > start.t {
+            tangent
+        } else {
+            -tangent
+        }
+    }
+
+    /// Compute the axis of the arc, pointing into the cylinder
+    fn axis(&self, frame: &Frame) -> Vector<3> {
+        let inward = if self.a_is_plane {
+            frame.inward_b
+        } else {
+            frame.inward_a
+        };
+        let normal = self.circle.a().cross(&self.circle.b()).normalize();
+
+        if normal.dot(&inward) > Scalar::ZERO {
+            normal
+        } else {
+            -normal
+        }
+    }
+
+    /// Determine whether the plane extends away from the axis (`1`) or towards
+    /// it (`-1`)
+    fn outward(&self, frame: &Frame) -> Scalar {
+        let inward = if self.a_is_plane {
+            frame.inward_a
+        } else {
+            frame.inward_b
+        };
+
+        if inward
+            .dot(&self.circle.vector_from_circle_coords(self.coords[0].coords))
+            > Scalar::ZERO
+        {
+            Scalar::ONE
+        } else {
+            -Scalar::ONE
+        }
+    }
+
+    /// Compute the paths where sides A and B are trimmed
+    ///
+    /// Each path has the same coordinates as the edge. Also returns the curve
+    /// coordinates of the new vertices on each path.
+    fn trims(
+        &self,
+        sides: [&Side; 2],
+        distance: Scalar,
+        frame: &Frame,
+        geometry: &Geometry,
+    ) -> [(SurfacePath, [Point<1>; 2]); 2] {
+        let [start, end] = self.coords;
+        let radius = self.circle.radius();
+
+        let [a, b] = sides.map(|side| {
+            match (
+                side.path(&side.half_edge, geometry),
+                geometry.of_surface(side.face.surface()),
+            ) {
+                (SurfacePath::Circle(circle), _) => {
+                    // The plane is trimmed at a concentric circle.
+                    let scale =
+                        (radius + self.outward(frame) * distance) / radius;
+
+                    SurfacePath::Circle(Circle::new(
+                        circle.center(),
+                        circle.a() * scale,
+                        circle.b() * scale,
+                    ))
+                }
+                (SurfacePath::Line(line), SurfaceGeom::SweptCurve(surface)) => {
+                    // The cylinder is trimmed at a line that is moved along
+                    // its axis.
+                    let offset = self.axis(frame).dot(&surface.v)
+                        / surface.v.dot(&surface.v)
+                        * distance;
+
+                    SurfacePath::Line(Line::from_origin_and_direction(
+                        line.origin() + Vector::from([Scalar::ZERO, offset]),
+                        line.direction(),
+                    ))
+                }
+                _ => unreachable!("Checked, when constructing `ArcEdge`"),
+            }
+        });
+
+        [(a, [start, end]), (b, [end, start])]
+    }
+
+    /// Compute the geometry of the new face
+    ///
+    /// The new face is a cone for a chamfer, and a torus for a fillet. The
+    /// first coordinate of either is the same as that of the edge.
+    fn blend_geometry(
+        &self,
+        blend: Blend,
+        distance: Scalar,
+        frames: [&Frame; 2],
+        [a_0, a_1, b_1, b_0]: [&TrimPoint; 4],
+        surfaces_end: [&SurfaceGeom; 2],
+    ) -> BlendGeometry {
+        let center = self.circle.center();
+        let radius = self.circle.radius();
+        let axis = self.axis(frames[0]);
+        let outward = self.outward(frames[0]);
+
+        // The radius of the circle, where the plane is trimmed.
+        let radius_trim = radius + outward * distance;
+        let scale = radius_trim / radius;
+
+        let (surface, v_plane, v_cylinder) = match blend {
+            Blend::Chamfer { .. } => {
+                assert!(
+                    radius_trim > Scalar::ZERO,
+                    "Blend is too large for the radius of the blended edge"
+                );
+
+                // The apex of the cone is on the axis. The plane is trimmed at
+                // the second coordinate `1`.
+                let apex = center + axis * outward * radius_trim;
+                let cone = Cone::new(
+                    apex,
+                    self.circle.a() * scale,
+                    self.circle.b() * scale,
+                    center - apex,
+                );
+
+                (
+                    SurfaceGeom::Cone(cone),
+                    Scalar::ONE,
+                    Scalar::ONE - outward * distance / radius_trim,
+                )
+            }
+            Blend::Fillet { .. } => {
+                assert!(
+                    radius_trim > distance,
+                    "Blend is too large for the radius of the blended edge"
+                );
+
+                // The plane is trimmed on the side of the tube that faces
+                // away from the cylinder. The cylinder is trimmed on its
+                // outside or inside.
+                let torus = Torus::new(
+                    center + axis * distance,
+                    self.circle.a() * scale,
+                    self.circle.b() * scale,
+                    -axis * radius_trim,
+                    distance,
+                );
+                let v_cylinder = if outward > Scalar::ZERO {
+                    Scalar::PI
+                } else {
+                    Scalar::ZERO
+                };
+
+                (SurfaceGeom::Torus(torus), Scalar::PI / 2., v_cylinder)
+            }
+        };
+
+        let [v_a, v_b] = if self.a_is_plane {
+            [v_plane, v_cylinder]
+        } else {
+            [v_cylinder, v_plane]
+        };
+
+        let along_u = |v: Scalar| {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([Scalar::ZERO, v]),
+                Vector::from([1., 0.]),
+            ))
+        };
+
+        let [end_0, end_1] = [
+            (0, a_0, b_0, surfaces_end[0]),
+            (1, a_1, b_1, surfaces_end[1]),
+        ]
+        .map(|(i, a, b, surface_end)| {
+            let u = self.coords[i].t;
+
+            match blend {
+                Blend::Chamfer { .. } => [
+                    SurfacePath::line_from_points([a, b].map(|point| {
+                        surface_end.project_global_point(point.position)
+                    }))
+                    .0,
+                    SurfacePath::line_from_points([[u, v_a], [u, v_b]]).0,
+                ],
+                Blend::Fillet { .. } => {
+                    let frame = frames[i];
+                    let center =
+                        self.circle.point_from_circle_coords(self.coords[i])
+                            + (frame.inward_a + frame.inward_b) * distance;
+
+                    [
+                        circle_on_plane(
+                            surface_end,
+                            center,
+                            a.position - center,
+                            b.position - center,
+                        ),
+                        SurfacePath::Line(Line::from_origin_and_direction(
+                            Point::from([u, v_a]),
+                            Vector::from([
+                                Scalar::ZERO,
+                                (v_b - v_a) / (Scalar::PI / 2.),
+                            ]),
+                        )),
+                    ]
+                }
+            }
+        });
+
+        BlendGeometry {
+            surface,
+            sides: [along_u(v_a), along_u(v_b)],
+            ends: [end_0, end_1],
+            b_coord: match blend {
+                Blend::Chamfer { .. } => Scalar::ONE,
+                Blend::Fillet { .. } => Scalar::PI / 2.,
+            },
+        }
+    }
+}
+
+/// The direction of the blended edge at one of its vertices, and the
+/// directions in which the sides extend away from it there
+struct Frame {
+    direction: Vector<3>,
+    inward_a: Vector<3>,
+    inward_b: Vector<3>,
+}
+
+/// Which vertex of a connected half-edge gets trimmed
+#[derive(Clone, Copy)]
+enum Trim {
+    /// The half-edge precedes the blended edge; its end is trimmed
+    AtStart,
+
+    /// The half-edge follows the blended edge; its start is trimmed
+    AtEnd,
+}
+
+/// A point where a connected half-edge is trimmed
+struct TrimPoint {
+    curve: Handle<Curve>,
+    coord: Point<1>,
+    position: Point<3>,
+    direction: Vector<3>,
+}
+
+/// The geometry of the new face, and of the curves that bound it
+struct BlendGeometry {
+    surface: SurfaceGeom,
+
+    /// The paths on the new face, where sides A and B are trimmed
+    sides: [SurfacePath; 2],
+
+    /// The paths on end 0 and 1, and on the new face, where the ends are
+    /// trimmed
+    ends: [[SurfacePath; 2]; 2],
+
+    /// The curve coordinate of the side B vertex on the end curves
+    ///
+    /// The side A vertex is always at zero.
+    b_coord: Scalar,
+}
+
+fn coord_on_curve(
+    vertex: &Handle<Vertex>,
+    curve: &Handle<Curve>,
+    geometry: &Geometry,
+) -> Point<1> {
+    geometry
+        .of_vertex(vertex)
+        .unwrap()
+        .local_on(curve)
+        .unwrap()
+        .position
+}
+
+fn position_on_face(
+    vertex: &Handle<Vertex>,
+    curve: &Handle<Curve>,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+) -> Point<3> {
+    let path = &geometry
+        .of_curve(curve)
+        .unwrap()
+        .local_on(surface)
+        .unwrap()
+        .path;
+    let point_surface =
+        path.point_from_path_coords(coord_on_curve(vertex, curve, geometry));
+
+    geometry
+        .of_surface(surface)
+        .point_from_surface_coords(point_surface)
+}
+
+/// Express a circle in the coordinates of a plane
+fn circle_on_plane(
+    surface: &SurfaceGeom,
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+) -> SurfacePath {
+    let center_surface = surface.project_global_point(center);
+    let a_surface = surface.project_global_point(center + a) - center_surface;
+
+    let b_surface = Vector::from([-a_surface.v, a_surface.u]);
+    let b_surface = if surface
+        .vector_from_surface_coords(center_surface, b_surface)
+        .dot(&b)
+        > Scalar::ZERO
+    {
+        b_surface
+    } else {
+        -b_surface
+    };
+
+    assert!(
+        (surface.vector_from_surface_coords(center_surface, b_surface) - b)
+            .magnitude()
+            < b.magnitude() * EPSILON,
+        "Filleting an edge is only supported, if the faces at the ends of the \
+        edge have uniformly scaled surface coordinates"
+    );
+
+    SurfacePath::Circle(Circle::new(center_surface, a_surface, b_surface))
+}
+
+/// Used to check geometric conditions, where exact equality is not practical
+const EPSILON: f64 = 1e-8;
@@ -0,0 +1,138 @@
+use fj_math::Scalar;
+
+use crate::{
+    storage::Handle,
+    topology::{Face, HalfEdge, Shell},
+    Core,
+};
+
+use super::edge::{blend_edge, Blend};
+
+/// # Round an edge of a [`Shell`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait Fillet: Sized {
+    /// # Replace the edge with a cylindrical or toroidal face
+    ///
+    /// The edge is defined by the provided half-edge and its sibling. Both
+    /// faces that share the edge are trimmed where a circle of the provided
+    /// radius, swept along the edge, touches them. The new face is inserted in
+    /// between.
+    ///
+    /// Returns the updated shell, as well as the new face.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the half-edge or its sibling are not part of the shell, if
+    /// the edge doesn't meet the requirements listed in the module
+    /// documentation, or if the radius is too large for the adjacent edges or
+    /// for the radius of a curved edge.
+    #[must_use]
+    fn fillet_edge(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        radius: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> (Self, Handle<Face>);
+}
+
+impl Fillet for Shell {
+    fn fillet_edge(
+        &self,
+        half_edge: &Handle<HalfEdge>,
+        radius: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> (Self, Handle<Face>) {
+        let radius = radius.into();
+        blend_edge(self, half_edge, Blend::Fillet { radius }, core)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::mass_properties::ComputeMassProperties,
+        operations::{
+            blend::tests::{arc_edge, prism, quarter_cylinder, vertical_edge},
+            insert::Insert,
+        },
+        Core,
+    };
+
+    use super::Fillet;
+
+    #[test]
+    fn fillet_edge_of_cube() {
+        let mut core = Core::new();
+
+        let shell = prism(&[[0., 0.], [1., 0.], [1., 1.], [0., 1.]], &mut core);
+        let half_edges = shell.faces().first().region().exterior().half_edges();
+
+        let (shell, _) = shell.fillet_edge(half_edges.first(), 0.25, &mut core);
+        let shell = shell.insert(&mut core);
+        assert_eq!(shell.faces().len(), 7);
+
+        let volume = shell.mass_properties(0.0001, &mut core).volume;
+
+        // The approximation of the fillet cuts off a little more material.
+        let expected = 1. - 0.25 * 0.25 * (1. - PI / 4.);
+        assert!((volume - Scalar::from(expected)).abs() < Scalar::from(1e-3));
+    }
+
+    #[test]
+    fn fillet_edge_between_oblique_faces() {
+        let mut core = Core::new();
+
+        // The side faces of an equilateral triangular prism meet at 60°.
+        let h = 3f64.sqrt();
+        let shell = prism(&[[0., 0.], [2., 0.], [1., h]], &mut core);
+
+        let (shell, _) =
+            shell.fillet_edge(&vertical_edge(&shell), 0.25, &mut core);
+        let shell = shell.insert(&mut core);
+        assert_eq!(shell.faces().len(), 6);
+
+        // The fillet touches the sides at a distance of `0.25 * h` from the
+        // edge. It cuts off the two triangles between the edge, the points
+        // where it touches, and its center, minus the arc between them.
+        let tolerance = 0.001;
+        let properties = shell.mass_properties(tolerance, &mut core);
+        let removed = 0.25 * 0.25 * (h - PI / 3.);
+        let expected = Scalar::from(h - removed);
+        assert!(
+            properties.volume >= expected - properties.surface_area * tolerance
+        );
+        assert!(properties.volume <= expected + Scalar::from(1e-9));
+    }
+
+    #[test]
+    fn fillet_curved_edge() {
+        let mut core = Core::new();
+
+        let shell = quarter_cylinder(&mut core);
+        let half_edge = arc_edge(&shell, &core);
+
+        let (shell, _) = shell.fillet_edge(&half_edge, 0.25, &mut core);
+        let shell = shell.insert(&mut core);
+        assert_eq!(shell.faces().len(), 6);
+
+        // The fillet cuts off the same cross-section as for a straight edge,
+        // rotated around the axis. Its centroid is at a distance of
+        // `1 - offset` from the axis.
+        let tolerance = 0.001;
+        let properties = shell.mass_properties(tolerance, &mut core);
+        let area = 0.25 * 0.25 * (1. - PI / 4.);
+        let offset = 0.25 * (5. / 6. - PI / 4.) / (1. - PI / 4.);
+        let expected = Scalar::from(PI / 4. - area * (1. - offset) * PI / 2.);
+        assert!(
+            properties.volume >= expected - properties.surface_area * tolerance
+        );
+        assert!(properties.volume <= expected + Scalar::from(1e-9));
+    }
+}
@@ -0,0 +1,144 @@
+//! Round or bevel the edges of a shell
+//!
+//! Blending an edge removes it from the shell. The faces on both sides of the
+//! edge are trimmed, and a new face is inserted between them. For a straight
+//! edge, this face is planar for a [chamfer], and cylindrical for a [fillet].
+//! For an edge that is a circular arc, it is conical or toroidal, respectively.
+//!
+//! # Implementation Note
+//!
+//! Only a subset of all possible edges can be blended right now. The following
+//! requirements must be met:
+//!
+//! - Either the edge is straight, and the faces on both sides of it are planar.
+//!   Or the edge is a circular arc, the face on one side is planar, and the
+//!   face on the other side is a cylinder around the arc's axis, perpendicular
+//!   to the plane. This is the case for the top edge of a cylinder, for
+//!   example.
+//! - The edges connected to the edge must be straight.
+//! - Exactly three faces must meet at each of the edge's vertices. This is the
+//!   case for the corners of a cuboid, for example.
+//!
+//! Fillets have the additional requirement that the edges connected to the
+//! filleted edge are perpendicular to it, and that the surfaces of the faces
+//! at either end of the edge have uniformly scaled coordinates. Otherwise the
+//! boundaries of the fillet would be ellipses, which are not supported.
+//!
+//! [chamfer]: Chamfer
+//! [fillet]: Fillet
+
+mod chamfer;
+mod edge;
+mod fillet;
+
+pub use self::{chamfer::Chamfer, fillet::Fillet};
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        geometry::SurfacePath,
+        operations::{
+            build::{BuildCycle, BuildHalfEdge, BuildSketch},
+            insert::Insert,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        queries::SiblingOfHalfEdge,
+        storage::Handle,
+        topology::{Cycle, HalfEdge, Region, Shell, Sketch},
+        Core,
+    };
+
+    /// Sweep a polygon along the z-axis, into a prism of height 1
+    pub fn prism(points: &[[f64; 2]], core: &mut Core) -> Handle<Shell> {
+        let surface = core.layers.topology.surfaces.space_2d();
+        let exterior = Cycle::polygon(points.iter().copied(), surface, core);
+
+        sweep(exterior, core)
+    }
+
+    /// Sweep a quarter of a unit circle along the z-axis
+    pub fn quarter_cylinder(core: &mut Core) -> Handle<Shell> {
+        let surface = core.layers.topology.surfaces.space_2d();
+        let exterior = Cycle::from_half_edges_and_boundaries(
+            [
+                HalfEdge::line_segment(
+                    [[0., 0.], [1., 0.]],
+                    surface.clone(),
+                    core,
+                ),
+                HalfEdge::arc(
+                    [1., 0.],
+                    [0., 1.],
+                    Scalar::PI / 2.,
+                    surface.clone(),
+                    core,
+                ),
+                HalfEdge::line_segment([[0., 1.], [0., 0.]], surface, core),
+            ],
+            core,
+        );
+
+        sweep(exterior, core)
+    }
+
+    /// Find a half-edge of the prism that runs along the z-axis
+    pub fn vertical_edge(shell: &Shell) -> Handle<HalfEdge> {
+        // The side faces are swept from the edges of the bottom face. The
+        // second half-edge of each runs upwards from the bottom face.
+        let bottom = shell.faces().first().region().exterior().half_edges();
+        let side = shell
+            .get_sibling_of(bottom.first())
+            .expect("Expected half-edge of shell to have a sibling");
+
+        side.cycle
+            .half_edges()
+            .after(&side.sibling)
+            .expect("Expected half-edge to be part of its cycle")
+            .clone()
+    }
+
+    /// Find a half-edge of a planar face, that is a circular arc
+    pub fn arc_edge(shell: &Shell, core: &Core) -> Handle<HalfEdge> {
+        shell
+            .faces()
+            .iter()
+            .find_map(|face| {
+                face.region()
+                    .exterior()
+                    .half_edges()
+                    .iter()
+                    .find(|half_edge| {
+                        let path = &core
+                            .layers
+                            .geometry
+                            .of_curve(half_edge.curve())
+                            .unwrap()
+                            .local_on(face.surface())
+                            .unwrap()
+                            .path;
+
+                        matches!(path, SurfacePath::Circle(_))
+                    })
+            })
+            .expect("Expected shell to have an arc")
+            .clone()
+    }
+
+    fn sweep(exterior: Cycle, core: &mut Core) -> Handle<Shell> {
+        let region = Region::new(exterior.insert(core), []);
+
+        Sketch::empty(&core.layers.topology)
+            .add_regions([region], core)
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                [0., 0., 1.],
+                core,
+            )
+            .shells()
+            .only()
+            .clone()
+    }
+}
@@ -0,0 +1,561 @@
+use std::collections::BTreeSet;
+
+use fj_math::{Aabb, Circle, Line, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::classify::PointClassification,
+    geometry::{Geometry, SurfaceGeom, SurfacePath},
+    storage::Handle,
+    topology::{Curve, Face, HalfEdge, Vertex},
+};
+
+use super::BooleanError;
+
+/// # A face, in the coordinates of its surface
+///
+/// Provides the operations on the boundary of a face that are required to
+/// trim intersection curves to it, and to split it along them. These work on
+/// the exact lines and circles that bound the face, not on an approximation.
+pub struct FaceOnSurface {
+    /// The face
+    pub face: Handle<Face>,
+
+    /// The geometry of the face's surface
+    pub surface: SurfaceGeom,
+
+    /// The half-edges that bound the face, from all of its cycles
+    pub edges: Vec<Edge>,
+
+    /// The signed area of the face's exterior
+    ///
+    /// This is positive, if the exterior winds counter-clockwise in surface
+    /// coordinates.
+    pub exterior_area: Scalar,
+
+    /// The bounding box of the face, in surface coordinates
+    pub aabb: Aabb<2>,
+}
+
+impl FaceOnSurface {
+    /// Collect the boundary of the provided face
+    pub fn new(face: &Handle<Face>, geometry: &Geometry) -> Self {
+        let surface = geometry.of_surface(face.surface()).clone();
+
+        let cycles = [face.region().exterior()]
+            .into_iter()
+            .chain(face.region().interiors());
+
+        let mut edges = Vec::new();
+        let mut exterior_area = Scalar::ZERO;
+
+        for (i, cycle) in cycles.enumerate() {
+            let start = edges.len();
+
+            for (half_edge, next) in cycle.half_edges().pairs() {
+                let path = geometry
+                    .of_curve(half_edge.curve())
+                    .unwrap()
+                    .local_on(face.surface())
+                    .unwrap()
+                    .path
+                    .clone();
+                let boundary = [half_edge, next].map(|h| {
+                    geometry
+                        .of_vertex(h.start_vertex())
+                        .unwrap()
+                        .local_on(half_edge.curve())
+                        .unwrap()
+                        .position
+                        .t
+                });
+
+                edges.push(Edge {
+                    half_edge: half_edge.clone(),
+                    path,
+                    boundary,
+                    vertices: [
+                        half_edge.start_vertex().clone(),
+                        next.start_vertex().clone(),
+                    ],
+                });
+            }
+
+            if i == 0 {
+                exterior_area = signed_area(&edges[start..]);
+            }
+        }
+
+        let aabb = edges
+            .iter()
+            .map(Edge::aabb)
+            .reduce(|a, b| a.merged(&b))
+            .expect("Face has at least one edge");
+
+        Self {
+            face: face.clone(),
+            surface,
+            edges,
+            exterior_area,
+            aabb,
+        }
+    }
+
+    /// Convert a point in surface coordinates into global coordinates
+    pub fn point_from_surface_coords(&self, point: Point<2>) -> Point<3> {
+        self.surface.point_from_surface_coords(point)
+    }
+
+    /// Find the curves of the face's edges
+    pub fn curves(&self) -> BTreeSet<Handle<Curve>> {
+        self.edges
+            .iter()
+            .map(|edge| edge.half_edge.curve().clone())
+            .collect()
+    }
+
+    /// Classify a point in surface coordinates relative to the face
+    ///
+    /// Counts how often a ray from the point crosses the boundary of the face.
+    /// Like [`ClassifyPoint`] does for solids, this tries another ray, if it
+    /// passes through a vertex.
+    ///
+    /// [`ClassifyPoint`]: crate::algorithms::classify::ClassifyPoint
+    pub fn classify(
+        &self,
+        point: Point<2>,
+    ) -> Result<PointClassification, BooleanError> {
+        let mut first_result = None;
+
+        for direction in RAY_DIRECTIONS {
+            let ray = SurfacePath::Line(Line::from_origin_and_direction(
+                point,
+                Vector::from(direction),
+            ));
+
+            let mut crossings = 0;
+            let mut ambiguous = false;
+
+            for edge in &self.edges {
+                let Some(intersections) = intersect_paths(&ray, &edge.path)?
+                else {
+                    ambiguous = true;
+                    continue;
+                };
+
+                for [s, t] in intersections {
+                    let Some(t) = edge.normalize(t) else {
+                        continue;
+                    };
+
+                    if s.abs() <= Scalar::from(EPSILON) {
+                        return Ok(PointClassification::OnBoundary);
+                    }
+                    if s < Scalar::ZERO {
+                        continue;
+                    }
+
+                    ambiguous |= edge
+                        .boundary
+                        .iter()
+                        .any(|b| (t - *b).abs() <= Scalar::from(EPSILON));
+                    crossings += 1;
+                }
+            }
+
+            let result = if crossings % 2 == 1 {
+                PointClassification::Inside
+            } else {
+                PointClassification::Outside
+            };
+
+            if !ambiguous {
+                return Ok(result);
+            }
+            first_result.get_or_insert(result);
+        }
+
+        Ok(first_result.unwrap_or(PointClassification::Outside))
+    }
+
+    /// Find the coordinates on a path, where it crosses the face's boundary
+    ///
+    /// If the path runs along an edge, the coordinates of the edge's vertices
+    /// are returned instead.
+    pub fn crossings(
+        &self,
+        path: &SurfacePath,
+    ) -> Result<Vec<Scalar>, BooleanError> {
+        let mut crossings = Vec::new();
+
+        for edge in &self.edges {
+            match intersect_paths(path, &edge.path)? {
+                Some(intersections) => {
+                    crossings.extend(
+                        intersections
+                            .into_iter()
+                            .filter_map(|[s, t]| edge.normalize(t).map(|_| s)),
+                    );
+                }
+                None => {
+                    crossings.extend(edge.boundary.map(|t| {
+                        path_coords(path, edge.path.point_from_path_coords([t]))
+                    }));
+                }
+            }
+        }
+
+        Ok(crossings)
+    }
+
+    /// Find the range of coordinates on a line, within the face's bounding box
+    ///
+    /// Returns `None`, if the line misses the bounding box.
+    pub fn range_on_line(&self, line: &Line<2>) -> Option<[Scalar; 2]> {
+        let epsilon = Scalar::from(EPSILON);
+
+        let mut range = [-Scalar::MAX, Scalar::MAX];
+
+        for ((origin, direction), (min, max)) in line
+            .origin()
+            .coords
+            .components
+            .into_iter()
+            .zip(line.direction().components)
+            .zip(
+                self.aabb
+                    .min
+                    .coords
+                    .components
+                    .into_iter()
+                    .zip(self.aabb.max.coords.components),
+            )
+        {
+            let [min, max] = [min - epsilon, max + epsilon];
+
+            if direction.abs() <= epsilon {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let [a, b] = [min, max].map(|bound| (bound - origin) / direction);
+            let [a, b] = if a < b { [a, b] } else { [b, a] };
+
+            range = [range[0].max(a), range[1].min(b)];
+        }
+
+        (range[0] <= range[1]).then_some(range)
+    }
+
+    /// Find the coordinate of a point on one of the face's edges
+    ///
+    /// Expects the point in both surface and global coordinates. Returns
+    /// `None`, if the point is not on the edge, within the provided distance.
+    pub fn coord_on_edge(
+        &self,
+        edge: &Edge,
+        point_surface: Point<2>,
+        point_global: Point<3>,
+        epsilon: f64,
+    ) -> Option<Scalar> {
+        let t = edge.normalize(path_coords(&edge.path, point_surface))?;
+
+        let point_on_edge = self
+            .point_from_surface_coords(edge.path.point_from_path_coords([t]));
+
+        (point_on_edge.distance_to(&point_global).into_f64() <= epsilon)
+            .then_some(t)
+    }
+}
+
+/// A half-edge that bounds a [`FaceOnSurface`]
+pub struct Edge {
+    /// The half-edge
+    pub half_edge: Handle<HalfEdge>,
+
+    /// The path of the half-edge's curve, on the face's surface
+    pub path: SurfacePath,
+
+    /// The coordinates of the start and end of the half-edge, on its curve
+    pub boundary: [Scalar; 2],
+
+    /// The vertices at the start and end of the half-edge
+    pub vertices: [Handle<Vertex>; 2],
+}
+
+impl Edge {
+    /// Move a coordinate on the edge's path into its boundary
+    ///
+    /// Circle coordinates are periodic, and the intersection of a circle with
+    /// another path is only known modulo a full turn. Returns `None`, if the
+    /// coordinate is not within the boundary.
+    pub fn normalize(&self, t: Scalar) -> Option<Scalar> {
+        let epsilon = Scalar::from(EPSILON);
+        let [min, max] = ordered(self.boundary);
+
+        let t = match self.path {
+            SurfacePath::Circle(_) => {
+                t + Scalar::TAU * ((min - epsilon - t) / Scalar::TAU).ceil()
+            }
+            _ => t,
+        };
+
+        (t >= min - epsilon && t <= max + epsilon).then_some(t)
+    }
+
+    fn aabb(&self) -> Aabb<2> {
+        match &self.path {
+            SurfacePath::Circle(circle) => {
+                let radius = Vector::from([circle.radius(), circle.radius()]);
+
+                Aabb {
+                    min: circle.center() - radius,
+                    max: circle.center() + radius,
+                }
+            }
+            SurfacePath::Line(line) => Aabb::<2>::from_points(
+                self.boundary.map(|t| line.point_from_line_coords([t])),
+            ),
+            SurfacePath::Nurbs(curve) => curve.aabb(),
+        }
+    }
+}
+
+/// Compute the points where two paths intersect
+///
+/// Returns the coordinates of each point on both paths. A path that touches a
+/// circle results in the same point twice, which keeps the number of crossings
+/// consistent. Returns `None`, if the paths are coincident.
+pub fn intersect_paths(
+    a: &SurfacePath,
+    b: &SurfacePath,
+) -> Result<Option<Vec<[Scalar; 2]>>, BooleanError> {
+    let intersections = match (a, b) {
+        (SurfacePath::Line(a), SurfacePath::Line(b)) => line_line(a, b),
+        (SurfacePath::Line(line), SurfacePath::Circle(circle)) => {
+            line_circle(line, circle)?
+        }
+        (SurfacePath::Circle(circle), SurfacePath::Line(line)) => {
+            line_circle(line, circle)?.map(|intersections| {
+                intersections.into_iter().map(|[s, t]| [t, s]).collect()
+            })
+        }
+        (SurfacePath::Circle(a), SurfacePath::Circle(b)) => {
+            circle_circle(a, b)?
+        }
+        (SurfacePath::Nurbs(_), _) | (_, SurfacePath::Nurbs(_)) => {
+            return Err(BooleanError::UnsupportedEdge);
+        }
+    };
+
+    Ok(intersections)
+}
+
+/// Compute the tangent of a path, pointing towards increasing coordinates
+pub fn tangent(path: &SurfacePath, t: Scalar) -> Vector<2> {
+    match path {
+        SurfacePath::Circle(circle) => {
+            circle.vector_from_circle_coords([t + Scalar::PI / 2.])
+        }
+        SurfacePath::Line(line) => line.direction(),
+        SurfacePath::Nurbs(_) => {
+            unreachable!("Faces with NURBS edges are rejected before")
+        }
+    }
+}
+
+/// Sample points along a path, from the start of the boundary to its end
+///
+/// The end itself is not included.
+pub fn sample(path: &SurfacePath, [start, end]: [Scalar; 2]) -> Vec<Point<2>> {
+    let num_points = match path {
+        SurfacePath::Circle(_) => {
+            ((end - start).abs() / Scalar::PI * 32.).ceil().into_f64() as usize
+        }
+        _ => 1,
+    }
+    .max(1);
+
+    (0..num_points)
+        .map(|i| {
+            let t = start + (end - start) * i as f64 / num_points as f64;
+            path.point_from_path_coords([t])
+        })
+        .collect()
+}
+
+/// Compute the coordinate of the point on a path that is closest to a point
+pub fn path_coords(path: &SurfacePath, point: Point<2>) -> Scalar {
+    match path {
+        SurfacePath::Circle(circle) => circle_coords(circle, point),
+        SurfacePath::Line(line) => line.point_to_line_coords(point).t,
+        SurfacePath::Nurbs(_) => {
+            unreachable!("Faces with NURBS edges are rejected before")
+        }
+    }
+}
+
+fn signed_area(edges: &[Edge]) -> Scalar {
+    let points = edges
+        .iter()
+        .flat_map(|edge| sample(&edge.path, edge.boundary))
+        .collect::<Vec<_>>();
+
+    polygon_area(&points)
+}
+
+/// Compute the signed area of a polygon, using the shoelace formula
+pub fn polygon_area(points: &[Point<2>]) -> Scalar {
+    let next = points.iter().cycle().skip(1);
+
+    points.iter().zip(next).fold(Scalar::ZERO, |area, (a, b)| {
+        area + a.coords.cross2d(&b.coords)
+    }) / 2.
+}
+
+fn line_line(a: &Line<2>, b: &Line<2>) -> Option<Vec<[Scalar; 2]>> {
+    let [da, db] = [a.direction(), b.direction()];
+    let offset = b.origin() - a.origin();
+
+    let denom = da.cross2d(&db);
+    if denom.abs() <= Scalar::from(EPSILON) * da.magnitude() * db.magnitude() {
+        let distance = offset.cross2d(&da).abs() / da.magnitude();
+        return if distance <= Scalar::from(EPSILON) {
+            None
+        } else {
+            Some(Vec::new())
+        };
+    }
+
+    let s = offset.cross2d(&db) / denom;
+    let t = offset.cross2d(&da) / denom;
+
+    Some(vec![[s, t]])
+}
+
+fn line_circle(
+    line: &Line<2>,
+    circle: &Circle<2>,
+) -> Result<Option<Vec<[Scalar; 2]>>, BooleanError> {
+    let radius = check_circle(circle)?;
+
+    let direction = line.direction();
+    let offset = line.origin() - circle.center();
+
+    // Solve `|offset + direction * s| = radius` for `s`.
+    let a = direction.dot(&direction);
+    let b = offset.dot(&direction);
+    let c = offset.dot(&offset) - radius * radius;
+
+    let discriminant = b * b - a * c;
+    let threshold = Scalar::from(EPSILON) * a * radius * radius;
+
+    let roots = if discriminant < -threshold {
+        return Ok(Some(Vec::new()));
+    } else if discriminant <= threshold {
+        let s = -b / a;
+        [s, s]
+    } else {
+        let root = Scalar::from(discriminant.into_f64().sqrt());
+        [(-b - root) / a, (-b + root) / a]
+    };
+
+    Ok(Some(
+        roots
+            .into_iter()
+            .map(|s| {
+                let point = line.point_from_line_coords([s]);
+                [s, circle_coords(circle, point)]
+            })
+            .collect(),
+    ))
+}
+
+fn circle_circle(
+    a: &Circle<2>,
+    b: &Circle<2>,
+) -> Result<Option<Vec<[Scalar; 2]>>, BooleanError> {
+    let [a_radius, b_radius] = [check_circle(a)?, check_circle(b)?];
+    let epsilon = Scalar::from(EPSILON);
+
+    let offset = b.center() - a.center();
+    let distance = offset.magnitude();
+
+    if distance <= epsilon {
+        return Ok(if (a_radius - b_radius).abs() <= epsilon {
+            None
+        } else {
+            Some(Vec::new())
+        });
+    }
+    if distance > a_radius + b_radius + epsilon
+        || distance < (a_radius - b_radius).abs() - epsilon
+    {
+        return Ok(Some(Vec::new()));
+    }
+
+    // The points of intersection are `x` along the line between the centers,
+    // and `±y` perpendicular to it.
+    let x = (distance * distance + a_radius * a_radius - b_radius * b_radius)
+        / (distance * 2.);
+    let y =
+        Scalar::from((a_radius * a_radius - x * x).into_f64().max(0.).sqrt());
+
+    let towards_b = offset / distance;
+    let perpendicular = Vector::from([-towards_b.v, towards_b.u]);
+
+    let point = a.center() + towards_b * x;
+    let points = [point + perpendicular * y, point - perpendicular * y];
+
+    Ok(Some(
+        points
+            .into_iter()
+            .map(|point| [circle_coords(a, point), circle_coords(b, point)])
+            .collect(),
+    ))
+}
+
+/// Compute the radius of a circle, rejecting circles that are ellipses
+///
+/// [`Circle`] only requires `a` and `b` to have the same length. Unless they
+/// are also perpendicular, the circle is actually an ellipse.
+fn check_circle(circle: &Circle<2>) -> Result<Scalar, BooleanError> {
+    let radius = circle.radius();
+
+    if circle.a().dot(&circle.b()).abs() > Scalar::from(EPSILON) * radius {
+        return Err(BooleanError::UnsupportedEdge);
+    }
+
+    Ok(radius)
+}
+
+/// Compute the coordinate of a point on a circle
+///
+/// Unlike [`Circle::point_to_circle_coords`], this takes the orientation of
+/// the circle into account.
+fn circle_coords(circle: &Circle<2>, point: Point<2>) -> Scalar {
+    let vector = point - circle.center();
+    vector.dot(&circle.b()).atan2(vector.dot(&circle.a()))
+}
+
+fn ordered([a, b]: [Scalar; 2]) -> [Scalar; 2] {
+    if a < b {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+/// The directions of the rays used for classifying points
+///
+/// These are chosen to be unlikely to line up with the edges of typical faces.
+const RAY_DIRECTIONS: [[f64; 2]; 3] =
+    [[0.8364, 0.5481], [-0.3118, 0.9501], [-0.6020, -0.7985]];
+
+/// The distance below which points in surface coordinates are identical
+///
+/// Also used as the threshold for deciding whether paths are parallel or
+/// coincident. Like the tolerances of the surface-surface intersection, this
+/// seems to work for now, but maybe it needs to become configurable.
+pub const EPSILON: f64 = 1e-9;
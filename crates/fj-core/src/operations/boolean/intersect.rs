@@ -0,0 +1,249 @@
+use fj_math::{Aabb, Line, Scalar};
+
+use crate::{
+    algorithms::{
+        classify::PointClassification,
+        intersect::{surface_surface::SurfaceSurfaceIntersection, Intersect},
+    },
+    geometry::{GlobalPath, SurfaceGeom, SurfacePath},
+};
+
+use super::{face::FaceOnSurface, BooleanError};
+
+/// A piece of an intersection curve, that is within both intersected faces
+pub struct Segment {
+    /// The intersection curve, in global coordinates
+    pub global: GlobalPath,
+
+    /// The intersection curve, in the surface coordinates of each face
+    pub local: [SurfacePath; 2],
+
+    /// The coordinates of the start and end of the segment, on the curve
+    pub boundary: [Scalar; 2],
+}
+
+/// Compute where two faces intersect
+///
+/// Intersects the surfaces of the faces, then trims the resulting curves to
+/// the parts that are within both faces. Those parts are where the faces need
+/// to be split.
+///
+/// Faces on coincident surfaces don't result in any segments. This is only an
+/// error, if the faces overlap, as indicated by their bounding boxes.
+pub fn intersect_faces(
+    faces: [&FaceOnSurface; 2],
+    aabbs: [&Aabb<3>; 2],
+    epsilon: f64,
+) -> Result<Vec<Segment>, BooleanError> {
+    let [a, b] = faces;
+
+    let (
+        SurfaceGeom::SweptCurve(a_surface),
+        SurfaceGeom::SweptCurve(b_surface),
+    ) = (&a.surface, &b.surface)
+    else {
+        return Err(BooleanError::UnsupportedSurface);
+    };
+
+    let curves = match (a_surface, b_surface).intersect().transpose()? {
+        None => return Ok(Vec::new()),
+        Some(SurfaceSurfaceIntersection::Coincident) => {
+            if overlap_in_area(aabbs, epsilon) {
+                return Err(BooleanError::Touching);
+            }
+            return Ok(Vec::new());
+        }
+        Some(SurfaceSurfaceIntersection::Curves(curves)) => curves,
+    };
+
+    let mut segments = Vec::new();
+
+    for curve in curves {
+        let local = [0, 1].map(|i| shift_into_face(&curve.local[i], faces[i]));
+
+        let Some(range) = range(faces, &local) else {
+            continue;
+        };
+
+        let mut breakpoints = Vec::new();
+        for (face, path) in faces.into_iter().zip(&local) {
+            for t in face.crossings(path)? {
+                match path {
+                    SurfacePath::Circle(_) => {
+                        let mut t = t + Scalar::TAU
+                            * ((range.start - t) / Scalar::TAU).ceil();
+                        while t <= range.end {
+                            breakpoints.push(t);
+                            t += Scalar::TAU;
+                        }
+                    }
+                    _ => {
+                        if t >= range.start && t <= range.end {
+                            breakpoints.push(t);
+                        }
+                    }
+                }
+            }
+        }
+
+        let speed = match &curve.global {
+            GlobalPath::Circle(circle) => circle.radius(),
+            GlobalPath::Line(line) => line.direction().magnitude(),
+            GlobalPath::Nurbs(_) => {
+                unreachable!("Surface-surface intersection only returns lines and circles")
+            }
+        };
+
+        // Breakpoints that were computed from different edges might be
+        // slightly apart, even though they refer to the same point. Merging
+        // them prevents windows that are too short to classify.
+        let is_same = |a: Scalar, b: Scalar| {
+            ((a - b) * speed).abs().into_f64() <= epsilon
+        };
+        breakpoints.sort();
+        breakpoints.dedup_by(|a, b| is_same(*a, *b));
+
+        // The ends of the range are only needed where they don't coincide with
+        // a crossing. They are approximate, while the crossings are exactly
+        // where the vertices of the resulting edges need to be.
+        if breakpoints
+            .first()
+            .map_or(true, |&t| !is_same(t, range.start))
+        {
+            breakpoints.insert(0, range.start);
+        }
+        if breakpoints.last().map_or(true, |&t| !is_same(t, range.end)) {
+            breakpoints.push(range.end);
+        }
+
+        let mut boundaries = Vec::new();
+        for window in breakpoints.windows(2) {
+            let [start, end] = [window[0], window[1]];
+            let middle = (start + end) / 2.;
+
+            let classifications = [0, 1].map(|i| {
+                faces[i].classify(local[i].point_from_path_coords([middle]))
+            });
+
+            match classifications {
+                [Ok(PointClassification::Inside), Ok(PointClassification::Inside)] =>
+                {
+                    boundaries.push([start, end]);
+                }
+                [Ok(PointClassification::Outside), _]
+                | [_, Ok(PointClassification::Outside)] => {}
+                [Err(err), _] | [_, Err(err)] => return Err(err),
+                _ => {
+                    // The curve runs along an edge of one face, while being
+                    // within the other. That face would have to be split along
+                    // the edge.
+                    return Err(BooleanError::Touching);
+                }
+            }
+        }
+
+        // A closed curve that is completely within both faces needs to be
+        // split, as a segment can't start and end at the same vertex.
+        if let [[start, end]] = boundaries.as_slice() {
+            if range.is_periodic {
+                let middle = (*start + *end) / 2.;
+                boundaries = vec![[*start, middle], [middle, *end]];
+            }
+        }
+
+        segments.extend(boundaries.into_iter().map(|boundary| Segment {
+            global: curve.global.clone(),
+            local: local.clone(),
+            boundary,
+        }));
+    }
+
+    Ok(segments)
+}
+
+/// The range of curve coordinates that could be within both faces
+struct CurveRange {
+    start: Scalar,
+    end: Scalar,
+
+    /// Whether the curve is closed within the range
+    is_periodic: bool,
+}
+
+fn range(
+    faces: [&FaceOnSurface; 2],
+    local: &[SurfacePath; 2],
+) -> Option<CurveRange> {
+    let mut range: Option<[Scalar; 2]> = None;
+
+    for (face, path) in faces.into_iter().zip(local) {
+        if let SurfacePath::Line(line) = path {
+            let [start, end] = face.range_on_line(line)?;
+            let [start, end] = match range {
+                Some([other_start, other_end]) => {
+                    [start.max(other_start), end.min(other_end)]
+                }
+                None => [start, end],
+            };
+
+            if start > end {
+                return None;
+            }
+            range = Some([start, end]);
+        }
+    }
+
+    Some(match range {
+        Some([start, end]) => CurveRange {
+            start,
+            end,
+            is_periodic: false,
+        },
+        None => CurveRange {
+            start: Scalar::ZERO,
+            end: Scalar::TAU,
+            is_periodic: true,
+        },
+    })
+}
+
+/// Shift a line along a circle's extrusion direction into the face's range
+///
+/// On a surface that was extruded from a circle, the first coordinate is an
+/// angle. The surface-surface intersection computes lines along the extrusion
+/// direction for one specific angle, while the face might use coordinates
+/// that are offset by full turns.
+fn shift_into_face(path: &SurfacePath, face: &FaceOnSurface) -> SurfacePath {
+    let SurfaceGeom::SweptCurve(surface) = &face.surface else {
+        return path.clone();
+    };
+    let (GlobalPath::Circle(_), SurfacePath::Line(line)) = (&surface.u, path)
+    else {
+        return path.clone();
+    };
+    if line.direction().u != Scalar::ZERO {
+        return path.clone();
+    }
+
+    let middle = (face.aabb.min.u + face.aabb.max.u) / 2.;
+    let turns = ((middle - line.origin().u) / Scalar::TAU).round();
+
+    let mut origin = line.origin();
+    origin.u += Scalar::TAU * turns;
+
+    SurfacePath::Line(Line::from_origin_and_direction(origin, line.direction()))
+}
+
+/// Determine whether two bounding boxes overlap in more than a line
+fn overlap_in_area([a, b]: [&Aabb<3>; 2], epsilon: f64) -> bool {
+    let overlapping_dimensions = (0..3)
+        .filter(|&i| {
+            let overlap = a.max.coords.components[i]
+                .min(b.max.coords.components[i])
+                - a.min.coords.components[i].max(b.min.coords.components[i]);
+            overlap.into_f64() > epsilon
+        })
+        .count();
+
+    overlapping_dimensions >= 2
+}
@@ -0,0 +1,787 @@
+//! # Boolean operations on shapes
+//!
+//! Boolean operations (also known as constructive solid geometry) combine two
+//! shapes into a new one, by computing their union, difference, or
+//! intersection.
+//!
+//! See [`Boolean`] for more information.
+//!
+//! ## Implementation Note
+//!
+//! The faces of both shapes are intersected pairwise, using the
+//! [surface-surface intersection]. The resulting curves are trimmed to the
+//! segments that are within both faces, and each face is split along those
+//! segments. The pieces are then classified as inside or outside of the other
+//! shape, and the ones that make up the result are stitched into new shells.
+//!
+//! The pieces keep the surfaces of the faces they were split from, so curved
+//! faces stay curved. But this limits the operations to faces on planes and
+//! surfaces extruded from circles, bounded by lines and circles, whose
+//! intersections are supported by the surface-surface intersection. Faces of
+//! both shapes that touch, instead of crossing each other, are not supported
+//! either. All of these cases result in a [`BooleanError`].
+//!
+//! [surface-surface intersection]: crate::algorithms::intersect::surface_surface
+
+mod face;
+mod intersect;
+mod split;
+mod vertices;
+
+use std::collections::BTreeMap;
+
+use fj_math::{Aabb, Point, Triangle, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{face::approx_face, ApproxCache, Tolerance},
+        classify::{ClassifyPoint, PointClassification},
+        intersect::surface_surface::UnsupportedIntersection,
+    },
+    geometry::{Geometry, LocalCurveGeom, LocalVertexGeom},
+    storage::Handle,
+    topology::{Curve, Face, Shell, Solid},
+    Core,
+};
+
+use self::{
+    face::FaceOnSurface,
+    intersect::intersect_faces,
+    split::{split_face, Cut, Splits},
+    vertices::VertexMap,
+};
+
+use super::{derive::DeriveFrom, insert::Insert, reverse::Reverse};
+
+/// Combine shapes using boolean operations
+///
+/// The shapes must be closed (i.e. form the boundary of a volume), and must be
+/// valid. Both are required to reliably tell apart the inside of a shape from
+/// its outside.
+///
+/// The result is a [`Solid`], with one shell for each connected part of its
+/// boundary. Subtracting a shape from the inside of another, for example,
+/// results in a separate shell for the cavity.
+///
+/// All methods take a [`Tolerance`], which is used to approximate curved faces,
+/// when classifying them as inside or outside of the other shape.
+///
+/// # Errors
+///
+/// All methods return an error, if the faces of the shapes can't be
+/// intersected. See [module documentation] for more information.
+///
+/// [module documentation]: self
+pub trait Boolean {
+    /// Compute the union of this shape and another
+    ///
+    /// The result contains all space that is inside either of the two shapes.
+    fn union(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError>;
+
+    /// Compute the difference of this shape and another
+    ///
+    /// The result contains all space that is inside of this shape, but outside
+    /// of the other.
+    fn difference(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError>;
+
+    /// Compute the intersection of this shape and another
+    ///
+    /// The result contains all space that is inside of both shapes.
+    fn intersection(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError>;
+}
+
+impl Boolean for Shell {
+    fn union(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError> {
+        boolean(self, other, BooleanOp::Union, tolerance, core)
+    }
+
+    fn difference(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError> {
+        boolean(self, other, BooleanOp::Difference, tolerance, core)
+    }
+
+    fn intersection(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError> {
+        boolean(self, other, BooleanOp::Intersection, tolerance, core)
+    }
+}
+
+impl Boolean for Solid {
+    fn union(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError> {
+        boolean(self, other, BooleanOp::Union, tolerance, core)
+    }
+
+    fn difference(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError> {
+        boolean(self, other, BooleanOp::Difference, tolerance, core)
+    }
+
+    fn intersection(
+        &self,
+        other: &Self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Solid, BooleanError> {
+        boolean(self, other, BooleanOp::Intersection, tolerance, core)
+    }
+}
+
+/// An error that occurred while combining shapes
+///
+/// See [`Boolean`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, thiserror::Error)]
+pub enum BooleanError {
+    /// A face that needs to be intersected is not on a swept surface
+    #[error(
+        "Intersecting faces is only supported for planes and extruded circles"
+    )]
+    UnsupportedSurface,
+
+    /// Intersecting the surfaces of two faces is not supported
+    #[error(transparent)]
+    UnsupportedIntersection(#[from] UnsupportedIntersection),
+
+    /// A face that needs to be split has an edge that is not a line or circle
+    ///
+    /// This includes circles that are ellipses in the coordinates of their
+    /// surface, because its coordinate system is skewed or scaled.
+    #[error(
+        "Splitting faces is only supported, if they are bounded by lines and \
+        circles"
+    )]
+    UnsupportedEdge,
+
+    /// Faces of both shapes touch instead of crossing each other
+    ///
+    /// This is the case, if faces overlap on coincident surfaces, or if an
+    /// edge of one shape lies on a face of the other.
+    #[error("Combining shapes whose faces or edges touch is not supported")]
+    Touching,
+}
+
+#[derive(Clone, Copy)]
+enum BooleanOp {
+    Union,
+    Difference,
+    Intersection,
+}
+
+trait AllFaces {
+    fn all_faces(&self) -> Vec<Handle<Face>>;
+}
+
+impl AllFaces for Shell {
+    fn all_faces(&self) -> Vec<Handle<Face>> {
+        self.faces().iter().cloned().collect()
+    }
+}
+
+impl AllFaces for Solid {
+    fn all_faces(&self) -> Vec<Handle<Face>> {
+        self.shells()
+            .iter()
+            .flat_map(|shell| shell.faces().iter().cloned())
+            .collect()
+    }
+}
+
+fn boolean<T>(
+    a: &T,
+    b: &T,
+    op: BooleanOp,
+    tolerance: impl Into<Tolerance>,
+    core: &mut Core,
+) -> Result<Solid, BooleanError>
+where
+    T: AllFaces,
+    for<'r> &'r T: ClassifyPoint,
+{
+    let tolerance = tolerance.into();
+
+    // The distance below which two points are considered to be identical. This
+    // needs to be small compared to the tolerance, so it doesn't distort the
+    // result. But it also needs to be large enough to absorb the numerical
+    // error of intersecting the faces.
+    let epsilon = tolerance.inner().into_f64() * 1e-3;
+
+    let mut cache = ApproxCache::default();
+    let [faces_a, faces_b] = [a, b].map(|shape| {
+        shape
+            .all_faces()
+            .iter()
+            .map(|face| {
+                let aabb =
+                    aabb(face, tolerance, &mut cache, &core.layers.geometry);
+                (FaceOnSurface::new(face, &core.layers.geometry), aabb)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut vertices = VertexMap::new(epsilon);
+    for (face, _) in faces_a.iter().chain(&faces_b) {
+        for edge in &face.edges {
+            let point = face.point_from_surface_coords(
+                edge.path.point_from_path_coords([edge.boundary[0]]),
+            );
+            vertices.insert(point, edge.vertices[0].clone());
+        }
+    }
+
+    let mut cuts_a = faces_a.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+    let mut cuts_b = faces_b.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+    let mut splits = Splits::new();
+
+    for (i, (face_a, aabb_a)) in faces_a.iter().enumerate() {
+        for (j, (face_b, aabb_b)) in faces_b.iter().enumerate() {
+            if !aabb_a.intersects(aabb_b) {
+                continue;
+            }
+
+            let segments =
+                intersect_faces([face_a, face_b], [aabb_a, aabb_b], epsilon)?;
+
+            for segment in segments {
+                let points = segment
+                    .boundary
+                    .map(|t| segment.global.point_from_path_coords([t]));
+                let segment_vertices =
+                    points.map(|point| vertices.get_or_insert(point, core));
+                if segment_vertices[0] == segment_vertices[1] {
+                    continue;
+                }
+
+                let curve = Curve::new().insert(core);
+
+                for (face, path) in
+                    [face_a, face_b].into_iter().zip(&segment.local)
+                {
+                    core.layers.geometry.define_curve(
+                        curve.clone(),
+                        face.face.surface().clone(),
+                        LocalCurveGeom { path: path.clone() },
+                    );
+
+                    // The segment might end on edges of the face, which then
+                    // need to be split there. Any other edges that the segment
+                    // ends on are siblings of those, or belong to other faces
+                    // that are intersected by the same curve.
+                    for ((vertex, point), t) in segment_vertices
+                        .iter()
+                        .zip(points)
+                        .zip(segment.boundary)
+                    {
+                        let point_surface = path.point_from_path_coords([t]);
+
+                        for edge in &face.edges {
+                            if edge.vertices.contains(vertex) {
+                                continue;
+                            }
+
+                            if let Some(t) = face.coord_on_edge(
+                                edge,
+                                point_surface,
+                                point,
+                                epsilon,
+                            ) {
+                                splits
+                                    .entry(edge.half_edge.curve().clone())
+                                    .or_default()
+                                    .insert(vertex.clone(), t);
+                            }
+                        }
+                    }
+                }
+
+                for (vertex, t) in segment_vertices.iter().zip(segment.boundary)
+                {
+                    core.layers.geometry.define_vertex(
+                        vertex.clone(),
+                        curve.clone(),
+                        LocalVertexGeom {
+                            position: Point::from([t]),
+                        },
+                    );
+                }
+
+                let [local_a, local_b] = segment.local;
+                cuts_a[i].push(Cut {
+                    curve: curve.clone(),
+                    path: local_a,
+                    boundary: segment.boundary,
+                    vertices: segment_vertices.clone(),
+                });
+                cuts_b[j].push(Cut {
+                    curve,
+                    path: local_b,
+                    boundary: segment.boundary,
+                    vertices: segment_vertices,
+                });
+            }
+        }
+    }
+
+    for (curve, points) in &splits {
+        for (vertex, t) in points {
+            let is_defined = core
+                .layers
+                .geometry
+                .of_vertex(vertex)
+                .and_then(|vertex| vertex.local_on(curve))
+                .is_some();
+
+            if !is_defined {
+                core.layers.geometry.define_vertex(
+                    vertex.clone(),
+                    curve.clone(),
+                    LocalVertexGeom {
+                        position: Point::from([*t]),
+                    },
+                );
+            }
+        }
+    }
+
+    let [pieces_a, pieces_b] =
+        [(&faces_a, cuts_a), (&faces_b, cuts_b)].map(|(faces, cuts)| {
+            let mut pieces = Vec::new();
+            for ((face, _), cuts) in faces.iter().zip(cuts) {
+                pieces.extend(split_face(face, &cuts, &splits, core));
+            }
+            pieces
+        });
+
+    let [classifications_a, classifications_b] =
+        [(&pieces_a, b), (&pieces_b, a)].map(|(pieces, other)| {
+            let points = pieces
+                .iter()
+                .map(|face| {
+                    interior_point(
+                        face,
+                        tolerance,
+                        &mut cache,
+                        &core.layers.geometry,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            other.classify_points(points, tolerance, &core.layers.geometry)
+        });
+
+    let (keep_a, keep_b) = match op {
+        BooleanOp::Union => {
+            (PointClassification::Outside, PointClassification::Outside)
+        }
+        BooleanOp::Difference => {
+            (PointClassification::Outside, PointClassification::Inside)
+        }
+        BooleanOp::Intersection => {
+            (PointClassification::Inside, PointClassification::Inside)
+        }
+    };
+
+    // The faces of the other shape that are inside of this one bound the
+    // difference from the inside, so they need to face the other way.
+    let reverse_b = matches!(op, BooleanOp::Difference);
+
+    let mut faces = Vec::new();
+    for (pieces, classifications, keep, reverse) in [
+        (pieces_a, classifications_a, keep_a, false),
+        (pieces_b, classifications_b, keep_b, reverse_b),
+    ] {
+        for (face, classification) in pieces.into_iter().zip(classifications) {
+            if classification == PointClassification::OnBoundary {
+                return Err(BooleanError::Touching);
+            }
+            if classification != keep {
+                continue;
+            }
+
+            let face = if reverse {
+                face.reverse(core).insert(core).derive_from(&face, core)
+            } else {
+                face
+            };
+            faces.push(face);
+        }
+    }
+
+    let shells = into_shells(faces, core);
+    Ok(Solid::new(shells))
+}
+
+/// Compute the bounding box of a face, from its approximation
+///
+/// [`BoundingVolume`] covers whole circles, where curved faces are involved.
+/// That would result in many faces being intersected unnecessarily.
+///
+/// [`BoundingVolume`]: crate::algorithms::bounding_volume::BoundingVolume
+fn aabb(
+    face: &Handle<Face>,
+    tolerance: Tolerance,
+    cache: &mut ApproxCache,
+    geometry: &Geometry,
+) -> Aabb<3> {
+    let approx = approx_face(face.clone(), tolerance, cache, geometry);
+    let aabb = Aabb::<3>::from_points(
+        approx.points().into_iter().map(|point| point.global_form),
+    );
+
+    // The approximation can deviate from the face by up to the tolerance.
+    let margin = Vector::from_component(tolerance.inner());
+    Aabb {
+        min: aabb.min - margin,
+        max: aabb.max + margin,
+    }
+}
+
+/// Find a point within a face, to classify it by
+///
+/// Uses the center of the largest triangle of the face's triangulation. Even
+/// if the face borders the other shape, that is unlikely to be close to it.
+fn interior_point(
+    face: &Handle<Face>,
+    tolerance: Tolerance,
+    cache: &mut ApproxCache,
+    geometry: &Geometry,
+) -> Point<3> {
+    let [a, b, c] = approx_face(face.clone(), tolerance, cache, geometry)
+        .triangles()
+        .into_iter()
+        .filter_map(|points| Triangle::from_points(points).ok())
+        .max_by_key(|triangle| {
+            let [a, b, c] = triangle.points();
+            (b - a).cross(&(c - a)).magnitude()
+        })
+        .expect("Face has at least one triangle")
+        .points();
+
+    Point {
+        coords: (a.coords + b.coords + c.coords) / 3.,
+    }
+}
+
+/// Group faces into shells, one for each connected component
+///
+/// Faces are connected, if they have an edge in common, which means that their
+/// half-edges share a curve.
+fn into_shells(
+    faces: Vec<Handle<Face>>,
+    core: &mut Core,
+) -> Vec<Handle<Shell>> {
+    let mut parents = (0..faces.len()).collect::<Vec<_>>();
+
+    fn root(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+
+    let mut faces_by_curve = BTreeMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        for cycle in face.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let j = *faces_by_curve
+                    .entry(half_edge.curve().clone())
+                    .or_insert(i);
+
+                let [i, j] = [i, j].map(|k| root(&mut parents, k));
+                parents[i] = j;
+            }
+        }
+    }
+
+    let mut components = BTreeMap::<_, Vec<_>>::new();
+    for (i, face) in faces.into_iter().enumerate() {
+        let root = root(&mut parents, i);
+        components.entry(root).or_default().push(face);
+    }
+
+    components
+        .into_values()
+        .map(|faces| Shell::new(faces).insert(core))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, mass_properties::ComputeMassProperties,
+            triangulate::Triangulate,
+        },
+        geometry::{GlobalPath, SurfaceGeom},
+        operations::{
+            build::{BuildShell, BuildSketch},
+            insert::Insert,
+            sweep::SweepSketch,
+            transform::TransformObject,
+        },
+        storage::Handle,
+        topology::{Shell, Sketch, Solid},
+        Core,
+    };
+
+    use super::Boolean;
+
+    fn cube(min: [f64; 3], size: f64, core: &mut Core) -> Solid {
+        let [x, y, z] = min;
+        let [x2, y2, z2] = [x + size, y + size, z + size];
+
+        let shell = Shell::from_vertices_and_indices(
+            [
+                [x, y, z],
+                [x2, y, z],
+                [x2, y2, z],
+                [x, y2, z],
+                [x, y, z2],
+                [x2, y, z2],
+                [x2, y2, z2],
+                [x, y2, z2],
+            ],
+            [
+                [0, 2, 1],
+                [0, 3, 2],
+                [4, 5, 6],
+                [4, 6, 7],
+                [0, 1, 5],
+                [0, 5, 4],
+                [1, 2, 6],
+                [1, 6, 5],
+                [2, 3, 7],
+                [2, 7, 6],
+                [3, 0, 4],
+                [3, 4, 7],
+            ],
+            core,
+        )
+        .insert(core);
+
+        Solid::new([shell])
+    }
+
+    /// Sweep a sketch along the z-axis, from `bottom` to `top`
+    fn prism(
+        sketch: Sketch,
+        [bottom, top]: [f64; 2],
+        core: &mut Core,
+    ) -> Handle<Solid> {
+        let surface = core.layers.topology.surfaces.xy_plane();
+        sketch
+            .sweep_sketch(surface, [0., 0., top - bottom], core)
+            .insert(core)
+            .translate([0., 0., bottom], core)
+    }
+
+    fn aabb(solid: &Solid, core: &mut Core) -> Aabb<3> {
+        // Not using `BoundingVolume` here, as that is not precise for curved
+        // faces.
+        let mesh =
+            (solid, Tolerance::from_scalar(0.001).unwrap()).triangulate(core);
+        Aabb::<3>::from_points(mesh.vertices())
+    }
+
+    fn volume(solid: &Solid, core: &mut Core) -> Scalar {
+        solid.mass_properties(0.001, core).volume
+    }
+
+    #[test]
+    fn union() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cube([0., 0., 0.], 2., &mut core);
+        let b = cube([1., 1., 1.], 2., &mut core);
+
+        let union = a.union(&b, 0.001, &mut core)?.insert(&mut core);
+
+        assert_eq!(union.shells().len(), 1);
+        assert_eq!(
+            aabb(&union, &mut core),
+            Aabb {
+                min: Point::from([0., 0., 0.]),
+                max: Point::from([3., 3., 3.]),
+            },
+        );
+        assert!((volume(&union, &mut core) - 15.).abs() < Scalar::from(1e-9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cube([0., 0., 0.], 3., &mut core);
+        let b = cube([1., 1., 1.], 1., &mut core);
+
+        let difference = a.difference(&b, 0.001, &mut core)?.insert(&mut core);
+
+        // The result is a cube with a cube-shaped cavity.
+        assert_eq!(difference.shells().len(), 2);
+        assert!(
+            (volume(&difference, &mut core) - 26.).abs() < Scalar::from(1e-9)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cube([0., 0., 0.], 2., &mut core);
+        let b = cube([1., 1., 1.], 2., &mut core);
+
+        let intersection =
+            a.intersection(&b, 0.001, &mut core)?.insert(&mut core);
+
+        let aabb = aabb(&intersection, &mut core);
+        assert!(
+            aabb.min.distance_to(&Point::from([1., 1., 1.]))
+                < Scalar::from(1e-9)
+        );
+        assert!(
+            aabb.max.distance_to(&Point::from([2., 2., 2.]))
+                < Scalar::from(1e-9)
+        );
+        assert!(
+            (volume(&intersection, &mut core) - 1.).abs() < Scalar::from(1e-9)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_disjoint_shapes_is_empty() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cube([0., 0., 0.], 1., &mut core);
+        let b = cube([2., 2., 2.], 1., &mut core);
+
+        let intersection =
+            a.intersection(&b, 0.001, &mut core)?.insert(&mut core);
+
+        assert!(intersection.shells().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_keeps_curved_surfaces() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = Sketch::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut core,
+        );
+        let cube = prism(cube, [0., 2.], &mut core);
+        let cylinder = Sketch::circle([1., 1.], 0.5, &mut core);
+        let cylinder = prism(cylinder, [-1., 3.], &mut core);
+
+        let difference = cube
+            .difference(&cylinder, 0.001, &mut core)?
+            .insert(&mut core);
+
+        // The cube with a hole through it.
+        assert_eq!(difference.shells().len(), 1);
+        let is_cylindrical = |surface: &SurfaceGeom| {
+            matches!(
+                surface,
+                SurfaceGeom::SweptCurve(surface)
+                    if matches!(surface.u, GlobalPath::Circle(_))
+            )
+        };
+        assert!(difference.shells().iter().any(|shell| {
+            shell.faces().iter().any(|face| {
+                is_cylindrical(core.layers.geometry.of_surface(face.surface()))
+            })
+        }));
+
+        // The approximation of the hole is inscribed in it, so it removes less
+        // volume than the exact hole would.
+        let properties = difference.mass_properties(0.001, &mut core);
+        let expected = Scalar::from(8.) - Scalar::PI * 0.25 * 2.;
+        assert!(properties.volume >= expected - 1e-9);
+        assert!(
+            properties.volume <= expected + properties.surface_area * 0.001
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_curved_surfaces() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = Sketch::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut core,
+        );
+        let cube = prism(cube, [0., 2.], &mut core);
+        let cylinder = Sketch::circle([1., 1.], 0.5, &mut core);
+        let cylinder = prism(cylinder, [-1., 3.], &mut core);
+
+        let union = cube.union(&cylinder, 0.001, &mut core)?.insert(&mut core);
+
+        assert_eq!(union.shells().len(), 1);
+
+        let properties = union.mass_properties(0.001, &mut core);
+        let expected = Scalar::from(8.) + Scalar::PI * 0.25 * 2.;
+        assert!(properties.volume <= expected + 1e-9);
+        assert!(
+            properties.volume >= expected - properties.surface_area * 0.001
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn shapes_with_touching_faces_are_rejected() {
+        let mut core = Core::new();
+
+        let a = cube([0., 0., 0.], 2., &mut core);
+        let b = cube([1., 1., 0.], 2., &mut core);
+
+        assert!(a.union(&b, 0.001, &mut core).is_err());
+    }
+}
@@ -0,0 +1,441 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    geometry::SurfacePath,
+    operations::{derive::DeriveFrom, insert::Insert},
+    storage::Handle,
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Vertex},
+    Core,
+};
+
+use super::face::{polygon_area, sample, tangent, FaceOnSurface, EPSILON};
+
+/// A segment of an intersection curve, that a face needs to be split along
+pub struct Cut {
+    /// The curve that the segment is on
+    pub curve: Handle<Curve>,
+
+    /// The path of the curve, on the face's surface
+    pub path: SurfacePath,
+
+    /// The coordinates of the start and end of the segment, on the curve
+    pub boundary: [Scalar; 2],
+
+    /// The vertices at the start and end of the segment
+    pub vertices: [Handle<Vertex>; 2],
+}
+
+/// The vertices that the edges of the original faces need to be split at
+///
+/// Sibling half-edges share their curve, so keeping track of the vertices per
+/// curve makes sure that both are split in the same way.
+pub type Splits = BTreeMap<Handle<Curve>, BTreeMap<Handle<Vertex>, Scalar>>;
+
+/// Split a face along the provided cuts
+///
+/// Builds a graph from the edges of the face and the cuts, then traces the
+/// regions that they bound. Returns the original face, if it doesn't need to
+/// be split.
+///
+/// The geometry of all curves and vertices involved must already be defined.
+pub fn split_face(
+    face: &FaceOnSurface,
+    cuts: &[Cut],
+    splits: &Splits,
+    core: &mut Core,
+) -> Vec<Handle<Face>> {
+    let is_split = face.curves().iter().any(|curve| splits.contains_key(curve));
+    if cuts.is_empty() && !is_split {
+        return vec![face.face.clone()];
+    }
+
+    let mut graph = Graph::new(face, cuts, splits);
+    graph.remove_dangling_cuts();
+
+    let orientation = if face.exterior_area < Scalar::ZERO {
+        -Scalar::ONE
+    } else {
+        Scalar::ONE
+    };
+    let loops = graph.trace_loops(orientation);
+
+    let areas = loops
+        .iter()
+        .map(|edges| polygon_area(&graph.polygon(edges)) * orientation)
+        .collect::<Vec<_>>();
+    let components = graph.components();
+
+    // Every loop that winds the same way as the original exterior bounds a new
+    // face. The others are holes, that need to go into the smallest of those
+    // faces that contains them, and that isn't connected to them.
+    let mut regions = loops
+        .iter()
+        .zip(&areas)
+        .filter(|(_, area)| **area > Scalar::ZERO)
+        .map(|(edges, area)| (edges, *area, Vec::new()))
+        .collect::<Vec<_>>();
+
+    for (hole, _) in loops
+        .iter()
+        .zip(&areas)
+        .filter(|(_, area)| **area <= Scalar::ZERO)
+    {
+        let vertex = &graph.edges[hole[0]].vertices[0];
+        let point = graph.positions[vertex];
+        let component = components[vertex];
+
+        let (_, _, holes) = regions
+            .iter_mut()
+            .filter(|(exterior, _, _)| {
+                let other = &graph.edges[exterior[0]].vertices[0];
+                components[other] != component
+                    && polygon_contains(&graph.polygon(exterior), point)
+            })
+            .min_by_key(|(_, area, _)| *area)
+            .expect("Hole in split face is not inside of any region");
+        holes.push(hole);
+    }
+
+    regions
+        .into_iter()
+        .map(|(exterior, _, interiors)| {
+            let exterior = graph.cycle(exterior, core);
+            let interiors = interiors
+                .into_iter()
+                .map(|interior| graph.cycle(interior, core))
+                .collect::<Vec<_>>();
+
+            let region = Region::new(exterior, interiors)
+                .insert(core)
+                .derive_from(face.face.region(), core);
+
+            Face::new(face.face.surface().clone(), region)
+                .insert(core)
+                .derive_from(&face.face, core)
+        })
+        .collect()
+}
+
+/// The edges of a face and the cuts through it, connected at their vertices
+struct Graph {
+    edges: Vec<GraphEdge>,
+    positions: BTreeMap<Handle<Vertex>, Point<2>>,
+}
+
+impl Graph {
+    fn new(face: &FaceOnSurface, cuts: &[Cut], splits: &Splits) -> Self {
+        let mut edges = Vec::new();
+
+        for edge in &face.edges {
+            let [start, end] = edge.boundary;
+            let direction = end - start;
+
+            let mut points = splits
+                .get(edge.half_edge.curve())
+                .into_iter()
+                .flatten()
+                .filter(|(vertex, _)| !edge.vertices.contains(vertex))
+                .filter_map(|(vertex, t)| {
+                    let t = edge.normalize(*t)?;
+                    let along = (t - start) / direction;
+
+                    (along > Scalar::from(EPSILON)
+                        && along < Scalar::ONE - EPSILON)
+                        .then_some((along, t, vertex.clone()))
+                })
+                .collect::<Vec<_>>();
+            points.sort_by_key(|(along, _, _)| *along);
+
+            if points.is_empty() {
+                edges.push(GraphEdge {
+                    curve: edge.half_edge.curve().clone(),
+                    path: edge.path.clone(),
+                    boundary: edge.boundary,
+                    vertices: edge.vertices.clone(),
+                    half_edge: Some(edge.half_edge.clone()),
+                    twin: None,
+                    is_active: true,
+                });
+                continue;
+            }
+
+            let [start_vertex, end_vertex] = edge.vertices.clone();
+            let points = [(start, start_vertex)]
+                .into_iter()
+                .chain(points.into_iter().map(|(_, t, vertex)| (t, vertex)))
+                .chain([(end, end_vertex)])
+                .collect::<Vec<_>>();
+
+            for piece in points.windows(2) {
+                let [(start, start_vertex), (end, end_vertex)] =
+                    [&piece[0], &piece[1]];
+
+                edges.push(GraphEdge {
+                    curve: edge.half_edge.curve().clone(),
+                    path: edge.path.clone(),
+                    boundary: [*start, *end],
+                    vertices: [start_vertex.clone(), end_vertex.clone()],
+                    half_edge: None,
+                    twin: None,
+                    is_active: true,
+                });
+            }
+        }
+
+        for cut in cuts {
+            let index = edges.len();
+            let [start, end] = cut.boundary;
+            let [start_vertex, end_vertex] = cut.vertices.clone();
+
+            edges.push(GraphEdge {
+                curve: cut.curve.clone(),
+                path: cut.path.clone(),
+                boundary: [start, end],
+                vertices: [start_vertex.clone(), end_vertex.clone()],
+                half_edge: None,
+                twin: Some(index + 1),
+                is_active: true,
+            });
+            edges.push(GraphEdge {
+                curve: cut.curve.clone(),
+                path: cut.path.clone(),
+                boundary: [end, start],
+                vertices: [end_vertex, start_vertex],
+                half_edge: None,
+                twin: Some(index),
+                is_active: true,
+            });
+        }
+
+        let positions = edges
+            .iter()
+            .map(|edge| {
+                (
+                    edge.vertices[0].clone(),
+                    edge.path.point_from_path_coords([edge.boundary[0]]),
+                )
+            })
+            .collect();
+
+        Self { edges, positions }
+    }
+
+    /// Remove cuts that don't connect to anything at one of their ends
+    ///
+    /// Cuts should always form a connected network with the edges of a face.
+    /// But if numerical inaccuracy prevented the intersection of the faces
+    /// from detecting some intersection, they could end up dangling.
+    fn remove_dangling_cuts(&mut self) {
+        loop {
+            let mut degrees = BTreeMap::<_, usize>::new();
+            for (i, edge) in self.edges.iter().enumerate() {
+                let is_first_of_pair = edge.twin.map_or(true, |twin| i < twin);
+
+                if edge.is_active && is_first_of_pair {
+                    for vertex in &edge.vertices {
+                        *degrees.entry(vertex.clone()).or_default() += 1;
+                    }
+                }
+            }
+
+            let mut removed_any = false;
+            for edge in &mut self.edges {
+                if !edge.is_active || edge.twin.is_none() {
+                    continue;
+                }
+
+                let is_dangling =
+                    edge.vertices.iter().any(|vertex| degrees[vertex] < 2);
+                if is_dangling {
+                    edge.is_active = false;
+                    removed_any = true;
+                }
+            }
+
+            if !removed_any {
+                break;
+            }
+        }
+    }
+
+    /// Trace the loops that bound the regions of the graph
+    ///
+    /// Every edge is traversed once, with the region it bounds on its left, if
+    /// the orientation is positive. At each vertex, the loop continues with
+    /// the edge that turns the most towards that side.
+    fn trace_loops(&self, orientation: Scalar) -> Vec<Vec<usize>> {
+        let mut outgoing = BTreeMap::<_, Vec<usize>>::new();
+        for (i, edge) in self.edges.iter().enumerate() {
+            if edge.is_active {
+                outgoing
+                    .entry(edge.vertices[0].clone())
+                    .or_default()
+                    .push(i);
+            }
+        }
+
+        let mut is_traced = vec![false; self.edges.len()];
+        let mut loops = Vec::new();
+
+        for first in 0..self.edges.len() {
+            if !self.edges[first].is_active || is_traced[first] {
+                continue;
+            }
+
+            let mut edges = Vec::new();
+            let mut current = first;
+
+            loop {
+                is_traced[current] = true;
+                edges.push(current);
+
+                let edge = &self.edges[current];
+                let back = -edge.direction_at(1);
+
+                let next = outgoing[&edge.vertices[1]]
+                    .iter()
+                    .copied()
+                    .filter(|&next| Some(next) != edge.twin)
+                    .min_by_key(|&next| {
+                        turn(
+                            back,
+                            self.edges[next].direction_at(0),
+                            orientation,
+                        )
+                    })
+                    .or(edge.twin)
+                    .expect("Edge of split face doesn't connect to another");
+
+                if next == first {
+                    break;
+                }
+                assert!(
+                    !is_traced[next],
+                    "Edges of split face don't form closed loops"
+                );
+
+                current = next;
+            }
+
+            loops.push(edges);
+        }
+
+        loops
+    }
+
+    /// Find the connected component that each vertex belongs to
+    fn components(&self) -> BTreeMap<Handle<Vertex>, usize> {
+        let mut components = BTreeMap::new();
+        let mut next_component = 0;
+
+        let mut neighbors = BTreeMap::<_, Vec<&Handle<Vertex>>>::new();
+        for edge in self.edges.iter().filter(|edge| edge.is_active) {
+            let [a, b] = &edge.vertices;
+            neighbors.entry(a).or_default().push(b);
+            neighbors.entry(b).or_default().push(a);
+        }
+
+        for &vertex in neighbors.keys() {
+            if components.contains_key(vertex) {
+                continue;
+            }
+
+            let mut stack = vec![vertex];
+            while let Some(vertex) = stack.pop() {
+                if components.contains_key(vertex) {
+                    continue;
+                }
+                components.insert(vertex.clone(), next_component);
+                stack.extend(neighbors[vertex].iter().copied());
+            }
+
+            next_component += 1;
+        }
+
+        components
+    }
+
+    fn polygon(&self, edges: &[usize]) -> Vec<Point<2>> {
+        edges
+            .iter()
+            .flat_map(|&i| sample(&self.edges[i].path, self.edges[i].boundary))
+            .collect()
+    }
+
+    fn cycle(&self, edges: &[usize], core: &mut Core) -> Handle<Cycle> {
+        let half_edges = edges
+            .iter()
+            .map(|&i| {
+                let edge = &self.edges[i];
+                edge.half_edge.clone().unwrap_or_else(|| {
+                    HalfEdge::new(edge.curve.clone(), edge.vertices[0].clone())
+                        .insert(core)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Cycle::new(half_edges).insert(core)
+    }
+}
+
+struct GraphEdge {
+    curve: Handle<Curve>,
+    path: SurfacePath,
+    boundary: [Scalar; 2],
+    vertices: [Handle<Vertex>; 2],
+
+    /// The original half-edge, if this edge is identical to it
+    half_edge: Option<Handle<HalfEdge>>,
+
+    /// The edge going the other way, if this is a cut
+    twin: Option<usize>,
+
+    is_active: bool,
+}
+
+impl GraphEdge {
+    /// The direction of the edge at its start (`0`) or end (`1`)
+    fn direction_at(&self, end: usize) -> Vector<2> {
+        let [start, end_coord] = self.boundary;
+        let direction = tangent(&self.path, self.boundary[end]);
+
+        if end_coord < start {
+            -direction
+        } else {
+            direction
+        }
+    }
+}
+
+/// Compute how far `direction` is turned from `back`, in the turning direction
+///
+/// Returns a value in the range `(0, 2π]`, which is smallest for the edge that
+/// turns the most towards the side of the region that is being traced.
+fn turn(back: Vector<2>, direction: Vector<2>, orientation: Scalar) -> Scalar {
+    let angle = back.cross2d(&direction).atan2(back.dot(&direction));
+    let turn = -angle * orientation;
+
+    if turn <= Scalar::from(EPSILON) {
+        turn + Scalar::TAU
+    } else {
+        turn
+    }
+}
+
+fn polygon_contains(polygon: &[Point<2>], point: Point<2>) -> bool {
+    let next = polygon.iter().cycle().skip(1);
+
+    let mut contains = false;
+    for (a, b) in polygon.iter().zip(next) {
+        if (a.v > point.v) != (b.v > point.v) {
+            let u = a.u + (point.v - a.v) * (b.u - a.u) / (b.v - a.v);
+            if point.u < u {
+                contains = !contains;
+            }
+        }
+    }
+
+    contains
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use fj_math::Point;
+
+use crate::{
+    operations::insert::Insert, storage::Handle, topology::Vertex, Core,
+};
+
+/// # The vertices of a boolean operation, looked up by their position
+///
+/// Intersection curves of different pairs of faces meet in shared points, and
+/// end on the edges of the original faces. All of these need to end up as the
+/// same vertex, even though their positions were computed separately.
+///
+/// The positions are hashed into a grid, whose cells are as large as the
+/// distance below which two points are considered identical. This means any
+/// matching vertex is in the same cell as the point that is looked up, or in
+/// one of the neighboring cells.
+pub struct VertexMap {
+    cells: HashMap<[i64; 3], Vec<usize>>,
+    vertices: Vec<(Point<3>, Handle<Vertex>)>,
+    epsilon: f64,
+}
+
+impl VertexMap {
+    /// Create an empty vertex map
+    pub fn new(epsilon: f64) -> Self {
+        Self {
+            cells: HashMap::new(),
+            vertices: Vec::new(),
+            epsilon,
+        }
+    }
+
+    /// Add an existing vertex, unless there already is one at its position
+    pub fn insert(&mut self, point: Point<3>, vertex: Handle<Vertex>) {
+        if self.get(point).is_none() {
+            self.push(point, vertex);
+        }
+    }
+
+    /// Get the vertex at the provided position, creating it if necessary
+    pub fn get_or_insert(
+        &mut self,
+        point: Point<3>,
+        core: &mut Core,
+    ) -> Handle<Vertex> {
+        if let Some(vertex) = self.get(point) {
+            return vertex.clone();
+        }
+
+        let vertex = Vertex::new().insert(core);
+        self.push(point, vertex.clone());
+        vertex
+    }
+
+    fn get(&self, point: Point<3>) -> Option<&Handle<Vertex>> {
+        let [x, y, z] = self.cell(point);
+
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(indices) =
+                        self.cells.get(&[x + dx, y + dy, z + dz])
+                    else {
+                        continue;
+                    };
+
+                    for &index in indices {
+                        let (other, vertex) = &self.vertices[index];
+                        if other.distance_to(&point).into_f64() <= self.epsilon
+                        {
+                            return Some(vertex);
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn push(&mut self, point: Point<3>, vertex: Handle<Vertex>) {
+        let index = self.vertices.len();
+        self.vertices.push((point, vertex));
+        self.cells.entry(self.cell(point)).or_default().push(index);
+    }
+
+    fn cell(&self, point: Point<3>) -> [i64; 3] {
+        point
+            .coords
+            .components
+            .map(|coord| (coord.into_f64() / self.epsilon).floor() as i64)
+    }
+}
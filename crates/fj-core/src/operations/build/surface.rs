@@ -1,7 +1,7 @@
-use fj_math::{Point, Scalar, Vector};
+use fj_math::{Cone, Cylinder, Point, Scalar, Sphere, Torus, Vector};
 
 use crate::{
-    geometry::{GlobalPath, SurfaceGeom},
+    geometry::{GlobalPath, SurfaceGeom, SweptCurve},
     operations::insert::Insert,
     storage::Handle,
     topology::Surface,
@@ -35,10 +35,10 @@ pub trait BuildSurface {
         core: &mut Core,
     ) -> Handle<Surface> {
         Self::from_geometry(
-            SurfaceGeom {
+            SurfaceGeom::SweptCurve(SweptCurve {
                 u: u.into(),
                 v: v.into(),
-            },
+            }),
             core,
         )
     }
@@ -65,6 +65,80 @@ pub trait BuildSurface {
 
         (surface, points_surface)
     }
+
+    /// Build a cylindrical surface
+    fn cylinder(cylinder: Cylinder, core: &mut Core) -> Handle<Surface> {
+        Self::from_geometry(SurfaceGeom::Cylinder(cylinder), core)
+    }
+
+    /// Build a conical surface
+    fn cone(cone: Cone, core: &mut Core) -> Handle<Surface> {
+        Self::from_geometry(SurfaceGeom::Cone(cone), core)
+    }
+
+    /// Build a spherical surface
+    fn sphere(sphere: Sphere, core: &mut Core) -> Handle<Surface> {
+        Self::from_geometry(SurfaceGeom::Sphere(sphere), core)
+    }
+
+    /// Build a toroidal surface
+    fn torus(torus: Torus, core: &mut Core) -> Handle<Surface> {
+        Self::from_geometry(SurfaceGeom::Torus(torus), core)
+    }
 }
 
 impl BuildSurface for Surface {}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Cone, Cylinder, Point, Scalar, Sphere, Torus};
+
+    use crate::{
+        geometry::SurfaceGeom, storage::Handle, topology::Surface, Core,
+    };
+
+    use super::BuildSurface;
+
+    #[test]
+    fn analytic_surfaces() {
+        let mut core = Core::new();
+
+        let surfaces = [
+            Surface::cylinder(
+                Cylinder::from_origin_and_radius([1., 0., 0.], 2.),
+                &mut core,
+            ),
+            Surface::cone(
+                Cone::from_apex_and_half_angle([0., 1., 0.], 0.5),
+                &mut core,
+            ),
+            Surface::sphere(
+                Sphere::from_center_and_radius([0., 0., 1.], 3.),
+                &mut core,
+            ),
+            Surface::torus(
+                Torus::from_center_and_radii([1., 1., 1.], 3., 1.),
+                &mut core,
+            ),
+        ];
+
+        for surface in surfaces {
+            assert_projection_round_trips(&surface, &core);
+        }
+    }
+
+    fn assert_projection_round_trips(surface: &Handle<Surface>, core: &Core) {
+        let geometry: &SurfaceGeom = core.layers.geometry.of_surface(surface);
+
+        for point in [[0.5, 0.25], [2., 0.5], [4., 1.]] {
+            let global = geometry.point_from_surface_coords(point);
+            let projected = geometry.project_global_point(global);
+
+            assert!(
+                (projected - Point::from(point)).magnitude()
+                    < Scalar::from(1e-9),
+                "{geometry:?}: {point:?} projected to {projected:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,782 @@
+//! # Loft between cycles
+//!
+//! See [`Loft`].
+
+use fj_interop::Color;
+use fj_math::{NurbsCurve, NurbsSurface, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::approx::{
+        curve::{approx_curve_with_cache, CurveApproxCache},
+        Tolerance,
+    },
+    geometry::{
+        CurveBoundary, Geometry, GlobalPath, LocalVertexGeom, SurfaceGeom,
+        SurfacePath, SweptCurve,
+    },
+    operations::{
+        build::BuildSurface, geometry::UpdateCurveGeometry, insert::Insert,
+        presentation::SetColor, reverse::Reverse, update::UpdateCycle,
+    },
+    storage::Handle,
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Shell, Surface, Vertex},
+    Core,
+};
+
+/// # Loft between [`Cycle`]s, creating a [`Shell`]
+///
+/// Lofting connects a series of cycles, which act as the cross-sections of the
+/// resulting shape. This can create shapes that sweeping can't, like
+/// transitions between differently shaped cross-sections.
+pub trait Loft {
+    /// # Loft between the cycles
+    ///
+    /// Each cycle is provided together with the surface it is on. The cycles
+    /// are connected in the order provided, by ruled side faces between each
+    /// pair of consecutive cycles. The first and last cycle close the ends of
+    /// the shell, as faces on their respective surfaces.
+    ///
+    /// If all cycles have the same number of half-edges, the half-edges are
+    /// connected with each other in order, starting with the first half-edge of
+    /// each cycle. Otherwise, each vertex is connected to the points at the
+    /// same fraction of the length of the other cycles, measured from the start
+    /// vertex of their first half-edge. The half-edges of the other cycles are
+    /// split at those points, which preserves the vertices of all cycles.
+    ///
+    /// The cycles don't need to go around in the same direction. The direction
+    /// of each cycle is adjusted, so the resulting shell points outwards. The
+    /// tolerance is used to approximate the cycles, to determine their
+    /// direction and length.
+    ///
+    /// # Implementation Note
+    ///
+    /// The coordinates of a circle don't match the coordinates of a NURBS
+    /// surface that goes through it, which means circles can't be shared with
+    /// the ruled side faces. Half-edges on circles are replaced with half-edges
+    /// on NURBS curves, which describe the same arcs.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if fewer than two cycles are provided, or if any of the
+    /// surfaces is not a plane.
+    fn loft(
+        &self,
+        color: Option<Color>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Shell;
+}
+
+impl Loft for [(Handle<Cycle>, Handle<Surface>)] {
+    fn loft(
+        &self,
+        color: Option<Color>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Shell {
+        assert!(self.len() >= 2, "Lofting requires at least two cycles");
+        let tolerance = tolerance.into();
+
+        let mut cache = CurveApproxCache::default();
+
+        let mut sections = self
+            .iter()
+            .map(|(cycle, surface)| {
+                Section::new(
+                    cycle.clone(),
+                    surface.clone(),
+                    tolerance,
+                    &mut cache,
+                    core,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Make all cycles go around the direction of the loft in the same way,
+        // counter-clockwise when looking against it.
+        let direction =
+            sections[sections.len() - 1].centroid() - sections[0].centroid();
+        for section in &mut sections {
+            if section.normal().dot(&direction) < Scalar::ZERO {
+                let cycle = section.cycle.reverse(core).insert(core);
+                *section = Section::new(
+                    cycle,
+                    section.surface.clone(),
+                    tolerance,
+                    &mut cache,
+                    core,
+                );
+            }
+        }
+
+        let num_edges = sections[0].edges.len();
+        if sections
+            .iter()
+            .any(|section| section.edges.len() != num_edges)
+        {
+            let mut fractions = sections
+                .iter()
+                .flat_map(Section::fractions)
+                .collect::<Vec<_>>();
+            fractions.sort();
+            fractions.dedup_by(|a, b| *a - *b < Scalar::from(EPSILON));
+
+            for section in &mut sections {
+                let cycle = section.split_at(&fractions, core);
+                *section = Section::new(
+                    cycle,
+                    section.surface.clone(),
+                    tolerance,
+                    &mut cache,
+                    core,
+                );
+            }
+        }
+
+        for section in &mut sections {
+            let cycle = section.replace_circles(core);
+            *section = Section::new(
+                cycle,
+                section.surface.clone(),
+                tolerance,
+                &mut cache,
+                core,
+            );
+        }
+
+        let mut faces = Vec::new();
+
+        for pair in sections.windows(2) {
+            let [bottom, top] = [&pair[0], &pair[1]];
+            faces.extend(build_side_faces(bottom, top, core));
+        }
+
+        let first = &sections[0];
+        let last = &sections[sections.len() - 1];
+
+        // The cycles go around the direction of the loft counter-clockwise.
+        // That's right for the last face, but the first one needs to point the
+        // other way.
+        for (cycle, surface) in [
+            (
+                first.cycle.reverse(core).insert(core),
+                first.surface.clone(),
+            ),
+            (last.cycle.clone(), last.surface.clone()),
+        ] {
+            let region = Region::new(cycle, []).insert(core);
+            faces.push(Face::new(surface, region).insert(core));
+        }
+
+        if let Some(color) = color {
+            for face in &faces {
+                face.region().set_color(color, core);
+            }
+        }
+
+        Shell::new(faces)
+    }
+}
+
+const EPSILON: f64 = 1e-8;
+
+/// A cycle that the loft goes through, on its plane
+struct Section {
+    cycle: Handle<Cycle>,
+    surface: Handle<Surface>,
+    plane: SweptCurve,
+    edges: Vec<Edge>,
+}
+
+impl Section {
+    fn new(
+        cycle: Handle<Cycle>,
+        surface: Handle<Surface>,
+        tolerance: Tolerance,
+        cache: &mut CurveApproxCache,
+        core: &Core,
+    ) -> Self {
+        let geometry = &core.layers.geometry;
+
+        let plane = match geometry.of_surface(&surface) {
+            SurfaceGeom::SweptCurve(
+                plane @ SweptCurve {
+                    u: GlobalPath::Line(_),
+                    ..
+                },
+            ) => plane.clone(),
+            _ => panic!("Lofting is only supported between cycles on planes"),
+        };
+
+        let edges = cycle
+            .half_edges()
+            .pairs()
+            .map(|(half_edge, next)| {
+                Edge::new(
+                    half_edge, next, &surface, &plane, tolerance, cache,
+                    geometry,
+                )
+            })
+            .collect();
+
+        Self {
+            cycle,
+            surface,
+            plane,
+            edges,
+        }
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point<3>> + '_ {
+        self.edges.iter().flat_map(|edge| {
+            let (_, points) =
+                edge.points.split_last().expect("Edge has points");
+            points.iter().map(|&(_, point)| point)
+        })
+    }
+
+    fn centroid(&self) -> Point<3> {
+        let points = self.points().collect::<Vec<_>>();
+        let sum = points
+            .iter()
+            .fold(Vector::from([0., 0., 0.]), |sum, point| sum + point.coords);
+
+        Point {
+            coords: sum / points.len() as f64,
+        }
+    }
+
+    /// Compute the normal of the approximated cycle, using Newell's method
+    fn normal(&self) -> Vector<3> {
+        let points = self.points().collect::<Vec<_>>();
+
+        points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| a.coords.cross(&b.coords))
+            .fold(Vector::from([0., 0., 0.]), |normal, element| {
+                normal + element
+            })
+    }
+
+    fn length(&self) -> Scalar {
+        self.edges
+            .iter()
+            .map(Edge::length)
+            .fold(Scalar::ZERO, |total, length| total + length)
+    }
+
+    /// Compute the fraction of the length of the cycle, at which each vertex is
+    fn fractions(&self) -> Vec<Scalar> {
+        let total = self.length();
+
+        self.edges
+            .iter()
+            .scan(Scalar::ZERO, |length, edge| {
+                let fraction = *length / total;
+                *length += edge.length();
+                Some(fraction)
+            })
+            .collect()
+    }
+
+    /// Split the half-edges, so there's a vertex at each provided fraction
+    fn split_at(&self, fractions: &[Scalar], core: &mut Core) -> Handle<Cycle> {
+        let total = self.length();
+        let vertices = self.fractions();
+
+        let mut cycle = self.cycle.clone();
+
+        for (i, edge) in self.edges.iter().enumerate() {
+            let start = vertices[i];
+            let end = vertices.get(i + 1).copied().unwrap_or(Scalar::ONE);
+
+            let points = fractions
+                .iter()
+                .filter(|&&fraction| {
+                    fraction - start > Scalar::from(EPSILON)
+                        && end - fraction > Scalar::from(EPSILON)
+                })
+                .map(|&fraction| edge.point_at((fraction - start) * total))
+                .collect::<Vec<_>>();
+
+            if points.is_empty() {
+                continue;
+            }
+
+            let next = &self.edges[(i + 1) % self.edges.len()];
+            let vertices = [edge.half_edge.start_vertex().clone()]
+                .into_iter()
+                .chain(points.iter().map(|_| Vertex::new().insert(core)))
+                .chain([next.half_edge.start_vertex().clone()])
+                .collect::<Vec<_>>();
+            let [start, end] = edge.boundary.inner;
+            let points = [start]
+                .into_iter()
+                .chain(points)
+                .chain([end])
+                .collect::<Vec<_>>();
+
+            // Each part of the half-edge is going to be on a different side
+            // face. Those are bounded by the part, so each part needs its own
+            // curve, which doesn't extend beyond it.
+            let half_edges = vertices
+                .windows(2)
+                .zip(points.windows(2))
+                .map(|(vertices, points)| {
+                    let curve = Curve::new().insert(core).copy_geometry_from(
+                        edge.half_edge.curve(),
+                        &mut core.layers.geometry,
+                    );
+
+                    for (vertex, &position) in vertices.iter().zip(points) {
+                        core.layers.geometry.define_vertex(
+                            vertex.clone(),
+                            curve.clone(),
+                            LocalVertexGeom { position },
+                        );
+                    }
+
+                    HalfEdge::new(curve, vertices[0].clone())
+                })
+                .collect::<Vec<_>>();
+
+            cycle = cycle
+                .update_half_edge(&edge.half_edge, |_, _| half_edges, core)
+                .insert(core);
+        }
+
+        cycle
+    }
+
+    /// Replace the half-edges on circles with ones on equivalent NURBS curves
+    fn replace_circles(&self, core: &mut Core) -> Handle<Cycle> {
+        let mut cycle = self.cycle.clone();
+
+        for (i, edge) in self.edges.iter().enumerate() {
+            if !matches!(edge.path, SurfacePath::Circle(_)) {
+                continue;
+            }
+
+            let next = &self.edges[(i + 1) % self.edges.len()];
+
+            let curve = Curve::new().insert(core).make_path_on_surface(
+                SurfacePath::Nurbs(edge.path.to_nurbs(edge.boundary)),
+                self.surface.clone(),
+                &mut core.layers.geometry,
+            );
+            for (half_edge, position) in
+                [(&edge.half_edge, 0.), (&next.half_edge, 1.)]
+            {
+                core.layers.geometry.define_vertex(
+                    half_edge.start_vertex().clone(),
+                    curve.clone(),
+                    LocalVertexGeom {
+                        position: Point::from([position]),
+                    },
+                );
+            }
+
+            let half_edge =
+                HalfEdge::new(curve, edge.half_edge.start_vertex().clone());
+
+            cycle = cycle
+                .update_half_edge(&edge.half_edge, |_, _| [half_edge], core)
+                .insert(core);
+        }
+
+        cycle
+    }
+
+    /// Convert the half-edges into NURBS curves in 3D space
+    ///
+    /// The domain of each curve is `0..=1`, from the start vertex of the
+    /// half-edge to the start vertex of the next one.
+    fn curves(&self) -> Vec<NurbsCurve<3>> {
+        self.edges
+            .iter()
+            .map(|edge| {
+                edge.path
+                    .to_nurbs(edge.boundary)
+                    .with_domain([0., 1.])
+                    .map_control_points(|&point| {
+                        self.plane.point_from_surface_coords(point)
+                    })
+            })
+            .collect()
+    }
+}
+
+/// A half-edge of a [`Section`]
+struct Edge {
+    half_edge: Handle<HalfEdge>,
+    path: SurfacePath,
+    boundary: CurveBoundary<Point<1>>,
+
+    /// The approximation of the half-edge, including its start and end
+    ///
+    /// Contains the curve coordinates and the global position of each point.
+    points: Vec<(Point<1>, Point<3>)>,
+}
+
+impl Edge {
+    fn new(
+        half_edge: &Handle<HalfEdge>,
+        next: &Handle<HalfEdge>,
+        surface: &Handle<Surface>,
+        plane: &SweptCurve,
+        tolerance: Tolerance,
+        cache: &mut CurveApproxCache,
+        geometry: &Geometry,
+    ) -> Self {
+        let boundary = CurveBoundary {
+            inner: [half_edge, next].map(|h| {
+                geometry
+                    .of_vertex(h.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position
+            }),
+        };
+        let path = geometry
+            .of_curve(half_edge.curve())
+            .unwrap()
+            .local_on(surface)
+            .unwrap()
+            .path
+            .clone();
+
+        let [start, end] = boundary.inner.map(|point| {
+            let point_surface = path.point_from_path_coords(point);
+            (point, plane.point_from_surface_coords(point_surface))
+        });
+        let approx = approx_curve_with_cache(
+            half_edge.curve(),
+            surface,
+            boundary,
+            tolerance,
+            cache,
+            geometry,
+        );
+        let points = [start]
+            .into_iter()
+            .chain(
+                approx
+                    .points
+                    .into_iter()
+                    .map(|point| (point.local_form, point.global_form)),
+            )
+            .chain([end])
+            .collect();
+
+        Self {
+            half_edge: half_edge.clone(),
+            path,
+            boundary,
+            points,
+        }
+    }
+
+    fn length(&self) -> Scalar {
+        self.points
+            .windows(2)
+            .map(|pair| pair[0].1.distance_to(&pair[1].1))
+            .fold(Scalar::ZERO, |total, length| total + length)
+    }
+
+    /// Find the point at the provided length along the half-edge
+    ///
+    /// Returns the curve coordinates of the point.
+    fn point_at(&self, length: Scalar) -> Point<1> {
+        let mut remaining = length;
+
+        for pair in self.points.windows(2) {
+            let [(a, a_global), (b, b_global)] = [pair[0], pair[1]];
+            let segment = a_global.distance_to(&b_global);
+
+            if remaining <= segment {
+                return a + (b - a) * (remaining / segment);
+            }
+
+            remaining -= segment;
+        }
+
+        let (end, _) = self.points[self.points.len() - 1];
+        end
+    }
+}
+
+/// Build the ruled faces that connect two sections
+///
+/// Both sections go around the direction of the loft counter-clockwise. The
+/// side faces point outwards.
+fn build_side_faces(
+    bottom: &Section,
+    top: &Section,
+    core: &mut Core,
+) -> Vec<Handle<Face>> {
+    let num_edges = bottom.edges.len();
+
+    // The rulings connect the vertices of both sections. Each one is shared by
+    // the two side faces on either side of it.
+    let rulings = bottom
+        .edges
+        .iter()
+        .zip(&top.edges)
+        .map(|(a, b)| {
+            let curve = Curve::new().insert(core);
+
+            for (edge, position) in [(a, 0.), (b, 1.)] {
+                core.layers.geometry.define_vertex(
+                    edge.half_edge.start_vertex().clone(),
+                    curve.clone(),
+                    LocalVertexGeom {
+                        position: Point::from([position]),
+                    },
+                );
+            }
+
+            curve
+        })
+        .collect::<Vec<_>>();
+
+    let curves = [bottom, top].map(Section::curves);
+
+    (0..num_edges)
+        .map(|i| {
+            let j = (i + 1) % num_edges;
+
+            let surface = Surface::from_geometry(
+                SurfaceGeom::Nurbs(NurbsSurface::ruled([
+                    &curves[0][i],
+                    &curves[1][i],
+                ])),
+                core,
+            );
+
+            // The u-coordinate of the surface follows the half-edges of the
+            // sections, the v-coordinate the rulings.
+            let [a, b] = [&bottom.edges[i], &top.edges[i]];
+            let ruling = CurveBoundary::default();
+            let edges = [
+                (a.half_edge.curve(), a.boundary, [[0., 0.], [1., 0.]]),
+                (&rulings[j], ruling, [[1., 0.], [1., 1.]]),
+                (b.half_edge.curve(), b.boundary, [[0., 1.], [1., 1.]]),
+                (&rulings[i], ruling, [[0., 0.], [0., 1.]]),
+            ];
+            let start_vertices = [
+                &bottom.edges[i],
+                &bottom.edges[j],
+                &top.edges[j],
+                &top.edges[i],
+            ]
+            .map(|edge| edge.half_edge.start_vertex().clone());
+
+            let half_edges = edges.into_iter().zip(start_vertices).map(
+                |((curve, boundary, points_surface), vertex)| {
+                    let curve = curve.clone().make_line_on_surface(
+                        points_surface,
+                        boundary,
+                        surface.clone(),
+                        &mut core.layers.geometry,
+                    );
+
+                    HalfEdge::new(curve, vertex).insert(core)
+                },
+            );
+            let exterior =
+                Cycle::new(half_edges.collect::<Vec<_>>()).insert(core);
+
+            let region = Region::new(exterior, []).insert(core);
+            Face::new(surface, region).insert(core)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::mass_properties::ComputeMassProperties,
+        operations::{
+            build::BuildCycle, insert::Insert, transform::TransformObject,
+        },
+        storage::Handle,
+        topology::{Cycle, Shell, Surface},
+        Core,
+    };
+
+    use super::Loft;
+
+    #[test]
+    fn loft_between_squares() {
+        let mut core = Core::new();
+
+        let [bottom, top] = planes(&mut core);
+        let cycles = [
+            (
+                Cycle::polygon(
+                    [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]],
+                    bottom.clone(),
+                    &mut core,
+                ),
+                bottom,
+            ),
+            (
+                Cycle::polygon(
+                    [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]],
+                    top.clone(),
+                    &mut core,
+                ),
+                top,
+            ),
+        ]
+        .map(|(cycle, surface)| (cycle.insert(&mut core), surface));
+
+        let shell = cycles.loft(None, 0.001, &mut core).insert(&mut core);
+        assert_eq!(shell.faces().len(), 6);
+
+        let expected = (4. + 1. + 2.) / 3.;
+        let volume = shell.mass_properties(0.001, &mut core).volume;
+        assert!((volume - Scalar::from(expected)).abs() < Scalar::from(1e-9));
+    }
+
+    #[test]
+    fn loft_between_cycles_with_different_numbers_of_edges() {
+        let mut core = Core::new();
+
+        // The top square has a vertex in the middle of each side. The bottom
+        // square needs to be split there, to match.
+        let [bottom, top] = planes(&mut core);
+        let corners = [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]];
+        let midpoints = [[0., -0.5], [0.5, 0.], [0., 0.5], [-0.5, 0.]];
+        let cycles = [
+            (Cycle::polygon(corners, bottom.clone(), &mut core), bottom),
+            (
+                Cycle::polygon(
+                    corners
+                        .into_iter()
+                        .zip(midpoints)
+                        .flat_map(|([x, y], midpoint)| {
+                            [[x / 2., y / 2.], midpoint]
+                        })
+                        .collect::<Vec<_>>(),
+                    top.clone(),
+                    &mut core,
+                ),
+                top,
+            ),
+        ]
+        .map(|(cycle, surface)| (cycle.insert(&mut core), surface));
+
+        let shell = cycles.loft(None, 0.001, &mut core).insert(&mut core);
+        assert_eq!(shell.faces().len(), 10);
+
+        let vertices = vertex_positions(&shell, &core);
+        for [x, y] in corners {
+            assert_has_vertex(&vertices, [x, y, 0.]);
+            assert_has_vertex(&vertices, [x / 2., y / 2., 1.]);
+        }
+        for [x, y] in midpoints {
+            assert_has_vertex(&vertices, [x * 2., y * 2., 0.]);
+            assert_has_vertex(&vertices, [x, y, 1.]);
+        }
+
+        let expected = (4. + 1. + 2.) / 3.;
+        let volume = shell.mass_properties(0.001, &mut core).volume;
+        assert!((volume - Scalar::from(expected)).abs() < Scalar::from(1e-9));
+    }
+
+    #[test]
+    fn loft_between_square_and_circle() {
+        let mut core = Core::new();
+
+        // The circle consists of four arcs. The corners of the square are
+        // lined up with their ends.
+        let [bottom, top] = planes(&mut core);
+        let r = 2f64.sqrt();
+        let corners = [[r, 0.], [0., r], [-r, 0.], [0., -r]];
+        let cycles = [
+            (Cycle::polygon(corners, bottom.clone(), &mut core), bottom),
+            (Cycle::circle([0., 0.], 1., top.clone(), &mut core), top),
+        ]
+        .map(|(cycle, surface)| (cycle.insert(&mut core), surface));
+
+        let tolerance = 0.01;
+        let shell = cycles.loft(None, tolerance, &mut core).insert(&mut core);
+        assert_eq!(shell.faces().len(), 6);
+
+        let vertices = vertex_positions(&shell, &core);
+        for [x, y] in corners {
+            assert_has_vertex(&vertices, [x, y, 0.]);
+        }
+
+        // Each side face connects a side of the square with a quarter circle.
+        // Both are represented as NURBS curves of degree 2, and points with
+        // the same curve coordinates are connected. Integrating the area of
+        // the horizontal cross-sections over the height results in the
+        // following volume:
+        let expected = (2. + 2f64.sqrt()) * PI / 3.;
+
+        // The curved faces are approximated by triangles within the tolerance,
+        // which can only reduce the volume.
+        let mass_properties = shell.mass_properties(tolerance, &mut core);
+        let error = mass_properties.surface_area * tolerance;
+        assert!(mass_properties.volume <= Scalar::from(expected + 1e-9));
+        assert!(mass_properties.volume >= Scalar::from(expected) - error);
+    }
+
+    fn planes(core: &mut Core) -> [Handle<Surface>; 2] {
+        let bottom = core.layers.topology.surfaces.xy_plane();
+        let top = bottom.clone().translate([0., 0., 1.], core).insert(core);
+
+        [bottom, top]
+    }
+
+    fn vertex_positions(shell: &Shell, core: &Core) -> Vec<Point<3>> {
+        shell
+            .faces()
+            .iter()
+            .flat_map(|face| {
+                face.region()
+                    .exterior()
+                    .half_edges()
+                    .iter()
+                    .map(|half_edge| {
+                        let surface =
+                            core.layers.geometry.of_surface(face.surface());
+                        let position = core
+                            .layers
+                            .geometry
+                            .of_vertex(half_edge.start_vertex())
+                            .unwrap()
+                            .local_on(half_edge.curve())
+                            .unwrap()
+                            .position;
+                        let path = &core
+                            .layers
+                            .geometry
+                            .of_curve(half_edge.curve())
+                            .unwrap()
+                            .local_on(face.surface())
+                            .unwrap()
+                            .path;
+
+                        surface.point_from_surface_coords(
+                            path.point_from_path_coords(position),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    fn assert_has_vertex(vertices: &[Point<3>], expected: [f64; 3]) {
+        assert!(vertices.iter().any(|vertex| {
+            vertex.distance_to(&Point::from(expected)) < Scalar::from(1e-12)
+        }));
+    }
+}
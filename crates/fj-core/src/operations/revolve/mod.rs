@@ -0,0 +1,15 @@
+//! Revolve objects around an axis to create new objects
+//!
+//! Revolves 2D objects around an axis, creating a 3D object. This is the
+//! rotational counterpart to [sweeping], which moves objects along a straight
+//! path.
+//!
+//! [sweeping]: super::sweep
+
+mod region;
+mod sketch;
+
+pub use self::{
+    region::{RevolveError, RevolveRegion, RevolvedRegion},
+    sketch::RevolveSketch,
+};
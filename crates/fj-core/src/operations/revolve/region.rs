@@ -0,0 +1,1180 @@
+use fj_interop::Color;
+use fj_math::{Circle, Cone, Line, Point, Scalar, Sphere, Torus, Vector};
+
+use crate::{
+    geometry::{
+        Geometry, GlobalPath, LocalCurveGeom, LocalVertexGeom, SurfaceGeom,
+        SurfacePath, SweptCurve,
+    },
+    operations::{build::BuildSurface, insert::Insert, presentation::SetColor},
+    storage::Handle,
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Surface, Vertex},
+    Core,
+};
+
+/// # Revolve a [`Region`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait RevolveRegion {
+    /// # Revolve the [`Region`]
+    ///
+    /// Rotate the region around the provided axis, creating faces of revolution
+    /// from each of its edges. The rotation follows the right-hand rule around
+    /// the axis' direction. A negative angle rotates the other way.
+    ///
+    /// If the angle amounts to a full turn (or more), the result is closed.
+    /// Otherwise, the faces at the start and the end of the rotation are
+    /// included as caps.
+    ///
+    /// Requires the surface that the curves of the region are defined on. The
+    /// region must lie in a plane that contains the axis, and must be located
+    /// completely on one side of the axis. Edges of the region may lie on the
+    /// axis.
+    ///
+    /// Line segments result in planar, cylindrical, and conical faces. Circular
+    /// arcs result in toroidal faces or, if the center of their circle is on
+    /// the axis, spherical ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the angle is zero, if the region does not meet the
+    /// requirements stated above, or if it is bounded by edges that can't be
+    /// revolved. See [`RevolveError`].
+    fn revolve_region(
+        &self,
+        surface: Handle<Surface>,
+        color: Option<Color>,
+        axis: Line<3>,
+        angle_rad: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Result<RevolvedRegion, RevolveError>;
+}
+
+impl RevolveRegion for Region {
+    fn revolve_region(
+        &self,
+        surface: Handle<Surface>,
+        color: Option<Color>,
+        axis: Line<3>,
+        angle_rad: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Result<RevolvedRegion, RevolveError> {
+        let angle_rad = angle_rad.into();
+        if angle_rad == Scalar::ZERO {
+            return Err(RevolveError::ZeroAngle);
+        }
+
+        // Rotating by a negative angle is the same as rotating by a positive
+        // one around the reversed axis.
+        let (direction, angle_rad) = if angle_rad < Scalar::ZERO {
+            (-axis.direction(), -angle_rad)
+        } else {
+            (axis.direction(), angle_rad)
+        };
+
+        let is_full_turn = angle_rad >= Scalar::TAU;
+        let angle_rad = if is_full_turn { Scalar::TAU } else { angle_rad };
+
+        // Curves must be split, so that no half-edge covers a full circle. We
+        // go further than that and split them into segments of at most a
+        // quarter turn, same as `BuildCycle::circle` does.
+        let num_segments =
+            (angle_rad / (Scalar::PI / 2.)).ceil().into_f64().max(1.) as usize;
+        let angles = (0..=num_segments)
+            .map(|i| angle_rad * (i as f64 / num_segments as f64))
+            .collect::<Vec<_>>();
+
+        let cycles = self
+            .all_cycles()
+            .map(|cycle| cycle_edges(cycle, &surface, &core.layers.geometry))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let frame = Frame::new(
+            axis.origin(),
+            direction,
+            cycles
+                .iter()
+                .flatten()
+                .flat_map(|edge| [edge.start, edge.middle]),
+        )?;
+
+        let mut profiles = cycles
+            .iter()
+            .map(|edges| {
+                Profile::new(edges, &frame, num_segments, is_full_turn, core)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The code below assumes that the exterior of the region is wound
+        // counter-clockwise, in profile coordinates.
+        if profiles[0].signed_area() < Scalar::ZERO {
+            for profile in &mut profiles {
+                profile.reverse();
+            }
+        }
+
+        for profile in &profiles {
+            profile.define_vertices(&angles, core);
+        }
+
+        let mut side_faces = Vec::new();
+        for profile in &profiles {
+            for i in 0..profile.points.len() {
+                for k in 0..num_segments {
+                    if let Some(face) = revolve_edge(
+                        profile,
+                        i,
+                        [k, k + 1],
+                        &angles,
+                        &frame,
+                        color,
+                        core,
+                    ) {
+                        side_faces.push(face);
+                    }
+                }
+            }
+        }
+
+        let caps = if is_full_turn {
+            None
+        } else {
+            let start = build_cap(&profiles, 0, &angles, &frame, color, core);
+            let end = build_cap(
+                &profiles,
+                num_segments,
+                &angles,
+                &frame,
+                color,
+                core,
+            );
+
+            Some([start, end])
+        };
+
+        Ok(RevolvedRegion { side_faces, caps })
+    }
+}
+
+/// The result of revolving a [`Region`]
+///
+/// See [`RevolveRegion`].
+#[derive(Clone)]
+pub struct RevolvedRegion {
+    /// The faces created by revolving the edges of the region
+    pub side_faces: Vec<Face>,
+
+    /// The faces at the start and the end of the rotation
+    ///
+    /// This is `None`, if the region was revolved by a full turn.
+    pub caps: Option<[Face; 2]>,
+}
+
+impl RevolvedRegion {
+    /// Return an iterator over all of the faces
+    pub fn all_faces(self) -> impl Iterator<Item = Face> {
+        self.side_faces
+            .into_iter()
+            .chain(self.caps.into_iter().flatten())
+    }
+}
+
+/// A region that can't be revolved
+///
+/// See [`RevolveRegion::revolve_region`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, thiserror::Error)]
+pub enum RevolveError {
+    /// The angle to revolve by is zero
+    #[error("Can't revolve by zero angle")]
+    ZeroAngle,
+
+    /// The region is not defined on a plane
+    #[error("Only regions on planes can be revolved")]
+    NonPlanarSurface,
+
+    /// An edge of the region is neither a line segment nor a circular arc
+    ///
+    /// This includes arcs of circles that become ellipses in 3D, because the
+    /// coordinate system of the plane is skewed or scaled.
+    #[error(
+        "Only regions bounded by line segments and circular arcs can be \
+        revolved"
+    )]
+    UnsupportedEdge,
+
+    /// The region doesn't lie in a plane with the axis, or on one side of it
+    #[error(
+        "Region to revolve must lie in a plane with the axis, and on one side \
+        of it"
+    )]
+    NotInPlaneWithAxis,
+
+    /// The region lies completely on the axis
+    #[error("Can't revolve region that lies completely on the axis")]
+    OnAxis,
+
+    /// An arc is part of a circle that crosses the axis
+    ///
+    /// Revolving it would result in a self-intersecting torus. This is only
+    /// supported, if the circle is centered on the axis, which results in a
+    /// sphere.
+    #[error(
+        "Can't revolve arc of a circle that crosses the axis, unless the \
+        circle is centered on it"
+    )]
+    ArcCrossesAxis,
+}
+
+/// Distance from the axis, below which points are considered to be on it
+const EPSILON: f64 = 1e-8;
+
+/// The coordinate system in which the revolution takes place
+///
+/// Points in the plane of the region are described in profile coordinates,
+/// which are the distance from the axis (`r`) and the position along the axis
+/// (`h`). Rotated points are additionally described by their angle.
+struct Frame {
+    origin: Point<3>,
+    axis: Vector<3>,
+    radial: Vector<3>,
+    tangential: Vector<3>,
+}
+
+impl Frame {
+    fn new(
+        origin: Point<3>,
+        axis: Vector<3>,
+        points: impl IntoIterator<Item = Point<3>>,
+    ) -> Result<Self, RevolveError> {
+        let axis = axis.normalize();
+
+        let radial = points
+            .into_iter()
+            .map(|point| {
+                let offset = point - origin;
+                offset - axis * offset.dot(&axis)
+            })
+            .find(|radial| radial.magnitude() > Scalar::from(EPSILON))
+            .ok_or(RevolveError::OnAxis)?
+            .normalize();
+        let tangential = axis.cross(&radial);
+
+        Ok(Self {
+            origin,
+            axis,
+            radial,
+            tangential,
+        })
+    }
+
+    /// Convert a point of the region into profile coordinates
+    ///
+    /// Points that are on the axis, within [`EPSILON`], are snapped to it.
+    fn to_profile_coords(
+        &self,
+        point: Point<3>,
+    ) -> Result<Point<2>, RevolveError> {
+        let [r, h] = self.project(point)?.coords.components;
+
+        if r < -Scalar::from(EPSILON) {
+            return Err(RevolveError::NotInPlaneWithAxis);
+        }
+        let r = if r <= Scalar::from(EPSILON) {
+            Scalar::ZERO
+        } else {
+            r
+        };
+
+        Ok(Point::from([r, h]))
+    }
+
+    /// Convert any point in the plane of the profile into profile coordinates
+    ///
+    /// Unlike [`Frame::to_profile_coords`], this accepts points on the other
+    /// side of the axis.
+    fn project(&self, point: Point<3>) -> Result<Point<2>, RevolveError> {
+        let offset = point - self.origin;
+        let [r, h] = self.project_vector(offset)?.components;
+        Ok(Point::from([r, h]))
+    }
+
+    fn project_vector(
+        &self,
+        vector: Vector<3>,
+    ) -> Result<Vector<2>, RevolveError> {
+        let h = vector.dot(&self.axis);
+        let r = vector.dot(&self.radial);
+
+        let remainder = vector - self.axis * h - self.radial * r;
+        if remainder.magnitude() > Scalar::from(EPSILON) {
+            return Err(RevolveError::NotInPlaneWithAxis);
+        }
+
+        Ok(Vector::from([r, h]))
+    }
+
+    fn center(&self, h: Scalar) -> Point<3> {
+        self.origin + self.axis * h
+    }
+
+    fn direction(&self, angle: Scalar) -> Vector<3> {
+        let (sin, cos) = angle.sin_cos();
+        self.radial * cos + self.tangential * sin
+    }
+}
+
+/// A cycle of the revolved region, and the objects created from it
+///
+/// All objects are indexed by the point of the profile they belong to, and the
+/// index of the angle they are located at. Points on the axis have the same
+/// vertex at every angle, as do edges on the axis. If the region is revolved by
+/// a full turn, the objects at the last angle are the same as at the first.
+struct Profile {
+    points: Vec<Point<2>>,
+
+    /// The paths of the edges starting at each point
+    paths: Vec<ProfilePath>,
+
+    /// The vertices at each point, for each angle
+    vertices: Vec<Vec<Handle<Vertex>>>,
+
+    /// The curves of the edges starting at each point, for each angle
+    edges: Vec<Vec<Handle<Curve>>>,
+
+    /// The arcs created by each point, for each segment between two angles
+    arcs: Vec<Vec<Handle<Curve>>>,
+}
+
+impl Profile {
+    fn new(
+        cycle: &[CycleEdge],
+        frame: &Frame,
+        num_segments: usize,
+        is_full_turn: bool,
+        core: &mut Core,
+    ) -> Result<Self, RevolveError> {
+        let mut points = Vec::new();
+        let mut paths = Vec::new();
+
+        for edge in cycle {
+            let start = frame.to_profile_coords(edge.start)?;
+
+            // Only the ends of the edges are used from here on, but checking
+            // the middle too catches arcs that bulge across the axis.
+            frame.to_profile_coords(edge.middle)?;
+
+            points.push(start);
+            paths.push(ProfilePath::new(edge, start, frame)?);
+        }
+
+        let mut vertices = Vec::new();
+        let mut edges = Vec::new();
+        let mut arcs = Vec::new();
+
+        for (i, point) in points.iter().enumerate() {
+            let next = points[(i + 1) % points.len()];
+            let is_edge_on_axis = matches!(paths[i], ProfilePath::Line)
+                && is_on_axis(point)
+                && is_on_axis(&next);
+
+            vertices.push(objects_per_angle(
+                is_on_axis(point),
+                num_segments,
+                is_full_turn,
+                |core| Vertex::new().insert(core),
+                core,
+            ));
+            edges.push(objects_per_angle(
+                is_edge_on_axis,
+                num_segments,
+                is_full_turn,
+                |core| Curve::new().insert(core),
+                core,
+            ));
+            arcs.push(
+                (0..num_segments)
+                    .map(|_| Curve::new().insert(core))
+                    .collect(),
+            );
+        }
+
+        Ok(Self {
+            points,
+            paths,
+            vertices,
+            edges,
+            arcs,
+        })
+    }
+
+    fn signed_area(&self) -> Scalar {
+        let mut area = Scalar::ZERO;
+
+        for (i, a) in self.points.iter().enumerate() {
+            let b = self.points[self.next(i)];
+
+            area += match self.paths[i] {
+                ProfilePath::Line => a.u * b.v - b.u * a.v,
+                ProfilePath::Arc {
+                    center,
+                    radius,
+                    boundary: [start, end],
+                } => {
+                    // This is the integral of `r dh - h dr` along the arc,
+                    // which is what the term for line segments amounts to.
+                    let (sin_start, cos_start) = start.sin_cos();
+                    let (sin_end, cos_end) = end.sin_cos();
+
+                    radius * center.u * (sin_end - sin_start)
+                        - radius * center.v * (cos_end - cos_start)
+                        + radius * radius * (end - start)
+                }
+            };
+        }
+
+        area / 2.
+    }
+
+    fn reverse(&mut self) {
+        // The edge starting at a point becomes the edge ending at it, so the
+        // edges need to be shifted by one.
+        self.points.reverse();
+        self.vertices.reverse();
+        self.arcs.reverse();
+        self.edges.reverse();
+        self.edges.rotate_left(1);
+        self.paths.reverse();
+        self.paths.rotate_left(1);
+
+        for path in &mut self.paths {
+            path.reverse();
+        }
+    }
+
+    fn next(&self, i: usize) -> usize {
+        (i + 1) % self.points.len()
+    }
+
+    fn define_vertices(&self, angles: &[Scalar], core: &mut Core) {
+        for i in 0..self.points.len() {
+            let j = self.next(i);
+            let [start, end] = self.paths[i].boundary();
+
+            for k in 0..angles.len() {
+                for (vertex, position) in
+                    [(&self.vertices[i][k], start), (&self.vertices[j][k], end)]
+                {
+                    core.layers.geometry.define_vertex(
+                        vertex.clone(),
+                        self.edges[i][k].clone(),
+                        LocalVertexGeom {
+                            position: Point::from([position]),
+                        },
+                    );
+                }
+            }
+
+            if is_on_axis(&self.points[i]) {
+                continue;
+            }
+
+            for k in 0..angles.len() - 1 {
+                for l in [k, k + 1] {
+                    core.layers.geometry.define_vertex(
+                        self.vertices[i][l].clone(),
+                        self.arcs[i][k].clone(),
+                        LocalVertexGeom {
+                            position: Point::from([angles[l]]),
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// The path of an edge of a [`Profile`], in profile coordinates
+#[derive(Clone, Copy)]
+enum ProfilePath {
+    /// A line segment, parametrized from 0 at its start to 1 at its end
+    Line,
+
+    /// A circular arc, parametrized by the angle around its center
+    Arc {
+        center: Point<2>,
+        radius: Scalar,
+        boundary: [Scalar; 2],
+    },
+}
+
+impl ProfilePath {
+    fn new(
+        edge: &CycleEdge,
+        start: Point<2>,
+        frame: &Frame,
+    ) -> Result<Self, RevolveError> {
+        let Some(arc) = &edge.arc else {
+            return Ok(Self::Line);
+        };
+
+        let center = frame.project(arc.center)?;
+        let [a, b] = [arc.a, arc.b].map(|v| frame.project_vector(v));
+        let [a, b] = [a?, b?];
+
+        let radius = a.magnitude();
+        let is_circle = (b.magnitude() - radius).abs()
+            <= radius * Scalar::from(EPSILON)
+            && a.dot(&b).abs() <= radius * radius * Scalar::from(EPSILON);
+        if !is_circle {
+            return Err(RevolveError::UnsupportedEdge);
+        }
+
+        // Snap centers to the axis, same as `Frame::to_profile_coords` does
+        // with points.
+        let center = if center.u.abs() <= Scalar::from(EPSILON) {
+            Point::from([Scalar::ZERO, center.v])
+        } else if center.u - radius > Scalar::from(EPSILON) {
+            center
+        } else {
+            return Err(RevolveError::ArcCrossesAxis);
+        };
+
+        // The angle in profile coordinates changes with the position on the
+        // circle, in the same or in the opposite direction, depending on how
+        // the circle is oriented in the profile plane.
+        let direction = if a.u * b.v - a.v * b.u > Scalar::ZERO {
+            Scalar::ONE
+        } else {
+            -Scalar::ONE
+        };
+        let [t_start, t_end] = arc.boundary;
+
+        let start = {
+            let offset = start - center;
+            offset.v.atan2(offset.u)
+        };
+        let end = start + direction * (t_end - t_start);
+
+        // On a sphere, the angle must stay within the latitudes of its poles.
+        // Anything else would cross the axis.
+        let max_latitude = Scalar::PI / 2. + Scalar::from(EPSILON);
+        if center.u == Scalar::ZERO && end.abs() > max_latitude {
+            return Err(RevolveError::ArcCrossesAxis);
+        }
+
+        Ok(Self::Arc {
+            center,
+            radius,
+            boundary: [start, end],
+        })
+    }
+
+    fn boundary(&self) -> [Scalar; 2] {
+        match self {
+            Self::Line => [Scalar::ZERO, Scalar::ONE],
+            Self::Arc { boundary, .. } => *boundary,
+        }
+    }
+
+    fn reverse(&mut self) {
+        if let Self::Arc { boundary, .. } = self {
+            boundary.reverse();
+        }
+    }
+}
+
+fn objects_per_angle<T>(
+    is_on_axis: bool,
+    num_segments: usize,
+    is_full_turn: bool,
+    mut create: impl FnMut(&mut Core) -> Handle<T>,
+    core: &mut Core,
+) -> Vec<Handle<T>> {
+    let mut objects: Vec<Handle<T>> = Vec::new();
+
+    for k in 0..=num_segments {
+        let is_same_as_first =
+            k > 0 && (is_on_axis || (k == num_segments && is_full_turn));
+
+        let object = if is_same_as_first {
+            objects[0].clone()
+        } else {
+            create(core)
+        };
+
+        objects.push(object);
+    }
+
+    objects
+}
+
+fn is_on_axis(point: &Point<2>) -> bool {
+    point.u == Scalar::ZERO
+}
+
+/// An edge of a cycle of the region, in model coordinates
+struct CycleEdge {
+    start: Point<3>,
+    middle: Point<3>,
+
+    /// The arc that the edge follows, or `None`, if it is a line segment
+    arc: Option<CycleArc>,
+}
+
+/// A circular arc, as defined by a [`Circle`] and its boundary on it
+///
+/// The circle is not constructed, as its vectors might not be of equal length
+/// in model coordinates. That is checked and reported as an error later.
+struct CycleArc {
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    boundary: [Scalar; 2],
+}
+
+fn cycle_edges(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+) -> Result<Vec<CycleEdge>, RevolveError> {
+    let surface_geom = geometry.of_surface(surface);
+    let SurfaceGeom::SweptCurve(
+        plane @ SweptCurve {
+            u: GlobalPath::Line(_),
+            ..
+        },
+    ) = surface_geom
+    else {
+        return Err(RevolveError::NonPlanarSurface);
+    };
+
+    cycle
+        .half_edges()
+        .pairs()
+        .map(|(half_edge, next_half_edge)| {
+            let path = &geometry
+                .of_curve(half_edge.curve())
+                .unwrap()
+                .local_on(surface)
+                .unwrap()
+                .path;
+            let [start, end] = [half_edge, next_half_edge].map(|h| {
+                geometry
+                    .of_vertex(h.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position
+            });
+            let middle = Point::from([(start.t + end.t) / 2.]);
+
+            let arc = match path {
+                SurfacePath::Line(_) => None,
+                SurfacePath::Circle(circle) => {
+                    let center = circle.center();
+
+                    Some(CycleArc {
+                        center: plane.point_from_surface_coords(center),
+                        a: plane.vector_from_surface_coords(center, circle.a()),
+                        b: plane.vector_from_surface_coords(center, circle.b()),
+                        boundary: [start.t, end.t],
+                    })
+                }
+                SurfacePath::Nurbs(_) => {
+                    return Err(RevolveError::UnsupportedEdge);
+                }
+            };
+
+            let [start, middle] = [start, middle].map(|position| {
+                plane.point_from_surface_coords(
+                    path.point_from_path_coords(position),
+                )
+            });
+
+            Ok(CycleEdge { start, middle, arc })
+        })
+        .collect()
+}
+
+/// Create the face that results from revolving one edge between two angles
+///
+/// Returns `None`, if the edge lies on the axis, as no face results from it.
+fn revolve_edge(
+    profile: &Profile,
+    i: usize,
+    [k, l]: [usize; 2],
+    angles: &[Scalar],
+    frame: &Frame,
+    color: Option<Color>,
+    core: &mut Core,
+) -> Option<Face> {
+    let j = profile.next(i);
+    let [a, b] = [profile.points[i], profile.points[j]];
+
+    let path = profile.paths[i];
+    if matches!(path, ProfilePath::Line) && is_on_axis(&a) && is_on_axis(&b) {
+        return None;
+    }
+
+    let [r_a, h_a] = a.coords.components;
+    let [r_b, h_b] = b.coords.components;
+
+    // The paths of the arcs and the edges on the new surface, as well as the
+    // surface itself. In all cases, the arcs are parametrized by their angle,
+    // and the edges are parametrized like the path of the profile, from 0 (at
+    // `a`) to 1 (at `b`) for line segments.
+    let (surface, arc_paths, edge_paths) = if let ProfilePath::Arc {
+        center,
+        radius,
+        boundary,
+    } = path
+    {
+        // The edge is a circular arc, resulting in a torus or, if the
+        // circle is centered on the axis, a sphere. Both are parametrized
+        // by the angle of rotation and the angle on the circle.
+        let [r_c, h_c] = center.coords.components;
+        let surface = if r_c == Scalar::ZERO {
+            Surface::sphere(
+                Sphere::new(
+                    frame.center(h_c),
+                    frame.radial * radius,
+                    frame.tangential * radius,
+                    frame.axis * radius,
+                ),
+                core,
+            )
+        } else {
+            Surface::torus(
+                Torus::new(
+                    frame.center(h_c),
+                    frame.radial * r_c,
+                    frame.tangential * r_c,
+                    frame.axis * r_c,
+                    radius,
+                ),
+                core,
+            )
+        };
+
+        let arc_paths = boundary.map(|v| {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([Scalar::ZERO, v]),
+                Vector::from([1., 0.]),
+            ))
+        });
+        let edge_paths = [k, l].map(|k| {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([angles[k], Scalar::ZERO]),
+                Vector::from([0., 1.]),
+            ))
+        });
+
+        (surface, arc_paths, edge_paths)
+    } else if (r_a - r_b).abs() <= Scalar::from(EPSILON) {
+        // The edge is parallel to the axis, resulting in a cylinder.
+        let surface = Surface::from_geometry(
+            SurfaceGeom::SweptCurve(SweptCurve {
+                u: GlobalPath::Circle(Circle::new(
+                    frame.center(h_a),
+                    frame.radial * r_a,
+                    frame.tangential * r_a,
+                )),
+                v: frame.axis * (h_b - h_a),
+            }),
+            core,
+        );
+
+        let arc_paths = [0., 1.].map(|v| {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([Scalar::ZERO, Scalar::from(v)]),
+                Vector::from([1., 0.]),
+            ))
+        });
+        let edge_paths = [k, l].map(|k| {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([angles[k], Scalar::ZERO]),
+                Vector::from([0., 1.]),
+            ))
+        });
+
+        (surface, arc_paths, edge_paths)
+    } else if (h_a - h_b).abs() <= Scalar::from(EPSILON) {
+        // The edge is perpendicular to the axis, resulting in a plane.
+        let surface = Surface::from_uv(
+            GlobalPath::Line(Line::from_origin_and_direction(
+                frame.center(h_a),
+                frame.radial,
+            )),
+            frame.tangential,
+            core,
+        );
+
+        let arc_paths = [r_a, r_b].map(|r| {
+            // The path is not going to be used, if the point is on the
+            // axis. We still need to return something valid here though.
+            let r = if r == Scalar::ZERO { Scalar::ONE } else { r };
+            SurfacePath::circle_from_center_and_radius([0., 0.], r)
+        });
+        let edge_paths = [k, l].map(|k| {
+            let direction = {
+                let (sin, cos) = angles[k].sin_cos();
+                Vector::from([cos, sin])
+            };
+
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::origin() + direction * r_a,
+                direction * (r_b - r_a),
+            ))
+        });
+
+        (surface, arc_paths, edge_paths)
+    } else {
+        // The edge is oblique to the axis, resulting in a cone. The cone is
+        // parametrized by angle and distance from the axis.
+        let slope = (h_b - h_a) / (r_b - r_a);
+        let surface = Surface::from_geometry(
+            SurfaceGeom::Cone(Cone::new(
+                frame.center(h_a - r_a * slope),
+                frame.radial,
+                frame.tangential,
+                frame.axis * slope,
+            )),
+            core,
+        );
+
+        let arc_paths = [r_a, r_b].map(|r| {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([Scalar::ZERO, r]),
+                Vector::from([1., 0.]),
+            ))
+        });
+        let edge_paths = [k, l].map(|k| {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                Point::from([angles[k], r_a]),
+                Vector::from([Scalar::ZERO, r_b - r_a]),
+            ))
+        });
+
+        (surface, arc_paths, edge_paths)
+    };
+
+    // The cycle is built from the arc at `a`, the edge at the end angle, the
+    // arc at `b` in reverse, then the edge at the start angle in reverse. This
+    // results in a face that points away from the region's material, given
+    // that the profile is wound counter-clockwise.
+    let mut half_edges = Vec::new();
+
+    let [arc_path_a, arc_path_b] = arc_paths;
+    let [edge_path_k, edge_path_l] = edge_paths;
+    let arcs = [(i, arc_path_a), (j, arc_path_b)];
+    let edges = [(l, edge_path_l), (k, edge_path_k)];
+
+    for ((point, arc_path), (angle, edge_path)) in arcs.into_iter().zip(edges) {
+        if !is_on_axis(&profile.points[point]) {
+            let curve = profile.arcs[point][k].clone();
+            let start = if point == i { k } else { l };
+
+            core.layers.geometry.define_curve(
+                curve.clone(),
+                surface.clone(),
+                LocalCurveGeom { path: arc_path },
+            );
+            half_edges.push(
+                HalfEdge::new(curve, profile.vertices[point][start].clone())
+                    .insert(core),
+            );
+        }
+
+        let curve = profile.edges[i][angle].clone();
+        let start = if point == i { i } else { j };
+
+        core.layers.geometry.define_curve(
+            curve.clone(),
+            surface.clone(),
+            LocalCurveGeom { path: edge_path },
+        );
+        half_edges.push(
+            HalfEdge::new(curve, profile.vertices[start][angle].clone())
+                .insert(core),
+        );
+    }
+
+    let exterior = Cycle::new(half_edges).insert(core);
+    let region = Region::new(exterior, []).insert(core);
+
+    if let Some(color) = color {
+        region.set_color(color, core);
+    }
+
+    Some(Face::new(surface, region))
+}
+
+/// Create the face at the provided angle, that closes a partial revolution
+fn build_cap(
+    profiles: &[Profile],
+    k: usize,
+    angles: &[Scalar],
+    frame: &Frame,
+    color: Option<Color>,
+    core: &mut Core,
+) -> Face {
+    let is_start = k == 0;
+
+    let surface = Surface::from_uv(
+        GlobalPath::Line(Line::from_origin_and_direction(
+            frame.origin,
+            frame.direction(angles[k]),
+        )),
+        frame.axis,
+        core,
+    );
+
+    let mut cycles = profiles.iter().map(|profile| {
+        let mut half_edges = Vec::new();
+
+        for i in 0..profile.points.len() {
+            let j = profile.next(i);
+            let curve = profile.edges[i][k].clone();
+
+            let path = match profile.paths[i] {
+                ProfilePath::Line => {
+                    SurfacePath::line_from_points([
+                        profile.points[i],
+                        profile.points[j],
+                    ])
+                    .0
+                }
+                ProfilePath::Arc { center, radius, .. } => {
+                    SurfacePath::circle_from_center_and_radius(center, radius)
+                }
+            };
+
+            core.layers.geometry.define_curve(
+                curve.clone(),
+                surface.clone(),
+                LocalCurveGeom { path },
+            );
+
+            // The profile is wound counter-clockwise, which results in a face
+            // pointing against the direction of rotation. That is right for the
+            // start, but at the end, we need to reverse the cycle.
+            let start = if is_start { i } else { j };
+            half_edges.push(
+                HalfEdge::new(curve, profile.vertices[start][k].clone())
+                    .insert(core),
+            );
+        }
+
+        if !is_start {
+            half_edges.reverse();
+        }
+
+        Cycle::new(half_edges).insert(core)
+    });
+
+    let exterior = cycles.next().expect("Region has an exterior");
+    let interiors = cycles.collect::<Vec<_>>();
+
+    let region = Region::new(exterior, interiors).insert(core);
+
+    if let Some(color) = color {
+        region.set_color(color, core);
+    }
+
+    Face::new(surface, region)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, mass_properties::ComputeMassProperties,
+        },
+        operations::{
+            build::{BuildCycle, BuildHalfEdge, BuildRegion, BuildSketch},
+            insert::Insert,
+            update::UpdateSketch,
+        },
+        storage::Handle,
+        topology::{Cycle, HalfEdge, Region, Sketch, Surface},
+        Core,
+    };
+
+    use super::{super::RevolveSketch, RevolveError};
+
+    fn revolve(points: [[f64; 2]; 4], angle: Scalar) -> Scalar {
+        revolve_region(
+            |surface, core| Region::polygon(points, surface, core),
+            angle,
+            Tolerance::from_scalar(0.0001).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn revolve_region(
+        region: impl FnOnce(Handle<Surface>, &mut Core) -> Region,
+        angle: Scalar,
+        tolerance: Tolerance,
+    ) -> Result<Scalar, RevolveError> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let axis = Line::from_origin_and_direction(
+            Point::from([0., 0., 0.]),
+            Vector::from([0., 0., 1.]),
+        );
+
+        let region =
+            region(core.layers.topology.surfaces.space_2d(), &mut core);
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([region], &mut core)
+            .revolve_sketch(surface, axis, angle, &mut core)?
+            .insert(&mut core);
+
+        // Computing the volume of the solid makes sure that all faces are
+        // there, and that they are all pointing outwards.
+        let volume = solid.mass_properties(tolerance, &mut core).volume;
+
+        Ok(volume)
+    }
+
+    fn assert_volume(volume: Scalar, expected: Scalar) {
+        assert!(
+            (volume - expected).abs() < Scalar::from(0.001),
+            "Expected volume {expected}, got {volume}"
+        );
+    }
+
+    #[test]
+    fn revolve_tube() {
+        let tube = [[1., 0.], [2., 0.], [2., 1.], [1., 1.]];
+        let full_volume = Scalar::PI * 3.;
+
+        assert_volume(revolve(tube, Scalar::TAU), full_volume);
+        assert_volume(revolve(tube, Scalar::PI * 1.5), full_volume * 0.75);
+        assert_volume(revolve(tube, -Scalar::PI / 3.), full_volume / 6.);
+    }
+
+    #[test]
+    fn revolve_shaft() {
+        let shaft = [[0., 0.], [1., 0.], [1., 2.], [0., 2.]];
+        let full_volume = Scalar::PI * 2.;
+
+        assert_volume(revolve(shaft, Scalar::TAU), full_volume);
+        assert_volume(revolve(shaft, Scalar::PI / 2.), full_volume / 4.);
+    }
+
+    #[test]
+    fn revolve_frustum() {
+        let frustum = [[0., 0.], [2., 0.], [1., 1.], [0., 1.]];
+        let full_volume = Scalar::PI * 7. / 3.;
+
+        assert_volume(revolve(frustum, Scalar::TAU), full_volume);
+        assert_volume(revolve(frustum, Scalar::PI), full_volume / 2.);
+    }
+
+    #[test]
+    fn revolve_torus() {
+        let circle = |surface, core: &mut Core| {
+            Region::circle([2., 0.], 1., surface, core)
+        };
+        let full_volume = Scalar::PI * Scalar::PI * 4.;
+        let full_area = Scalar::PI * Scalar::PI * 8.;
+
+        let volume = revolve_region(circle, Scalar::TAU, tolerance()).unwrap();
+        assert_curved_volume(volume, full_volume, full_area);
+
+        let volume =
+            revolve_region(circle, -Scalar::PI / 2., tolerance()).unwrap();
+        assert_curved_volume(volume, full_volume / 4., full_area / 4.);
+    }
+
+    #[test]
+    fn revolve_sphere() {
+        // A half disk, bounded by two quarter circles and the axis.
+        let half_disk = |surface: Handle<Surface>, core: &mut Core| {
+            let [a, b, c] = [[0., -1.], [1., 0.], [0., 1.]];
+            let angle = Scalar::PI / 2.;
+
+            let half_edges = vec![
+                HalfEdge::arc(a, b, angle, surface.clone(), core),
+                HalfEdge::arc(b, c, angle, surface.clone(), core),
+                HalfEdge::line_segment([c, a], surface, core),
+            ];
+            let exterior =
+                Cycle::from_half_edges_and_boundaries(half_edges, core)
+                    .insert(core);
+
+            Region::new(exterior, [])
+        };
+        let full_volume = Scalar::PI * 4. / 3.;
+        let full_area = Scalar::PI * 4.;
+
+        let volume =
+            revolve_region(half_disk, Scalar::TAU, tolerance()).unwrap();
+        assert_curved_volume(volume, full_volume, full_area);
+
+        // The cut surfaces of the half sphere don't contribute to the error,
+        // as they are flat.
+        let volume =
+            revolve_region(half_disk, Scalar::PI, tolerance()).unwrap();
+        assert_curved_volume(volume, full_volume / 2., full_area / 2.);
+    }
+
+    #[test]
+    fn revolve_unsupported_profiles() {
+        let crossing_circle = |surface, core: &mut Core| {
+            Region::circle([1., 0.], 2., surface, core)
+        };
+        let crossing_polygon = |surface, core: &mut Core| {
+            Region::polygon(
+                [[-1., 0.], [1., 0.], [1., 1.], [-1., 1.]],
+                surface,
+                core,
+            )
+        };
+
+        assert_eq!(
+            revolve_region(crossing_circle, Scalar::TAU, tolerance()),
+            Err(RevolveError::ArcCrossesAxis),
+        );
+        assert_eq!(
+            revolve_region(crossing_polygon, Scalar::TAU, tolerance()),
+            Err(RevolveError::NotInPlaneWithAxis),
+        );
+
+        let square = |surface, core: &mut Core| {
+            Region::polygon(
+                [[1., 0.], [2., 0.], [2., 1.], [1., 1.]],
+                surface,
+                core,
+            )
+        };
+        assert_eq!(
+            revolve_region(square, Scalar::ZERO, tolerance()),
+            Err(RevolveError::ZeroAngle),
+        );
+    }
+
+    fn tolerance() -> Tolerance {
+        Tolerance::from_scalar(0.01).unwrap()
+    }
+
+    /// Assert the volume of a solid bounded by surfaces that are curved
+    ///
+    /// Faces that are curved in two directions are only approximated within
+    /// the tolerance, by triangles whose corners are on the surface. The
+    /// triangulated volume is smaller than the actual one by at most the
+    /// tolerance times the area of those faces.
+    fn assert_curved_volume(volume: Scalar, expected: Scalar, area: Scalar) {
+        let min = expected - area * tolerance().inner();
+        assert!(
+            volume <= expected && volume >= min,
+            "Expected volume between {min} and {expected}, got {volume}"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use fj_math::{Line, Scalar};
+
+use crate::{
+    operations::{insert::Insert, presentation::GetColor},
+    storage::Handle,
+    topology::{Shell, Sketch, Solid, Surface},
+    Core,
+};
+
+use super::{RevolveError, RevolveRegion};
+
+/// # Revolve a [`Sketch`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait RevolveSketch {
+    /// # Revolve the [`Sketch`]
+    ///
+    /// Places the sketch on the provided surface, then revolves each of its
+    /// regions around the provided axis, creating one shell per region. See
+    /// [`RevolveRegion::revolve_region`] for the requirements on the axis and
+    /// the angle, and for the errors that can result.
+    fn revolve_sketch(
+        &self,
+        surface: Handle<Surface>,
+        axis: Line<3>,
+        angle_rad: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Result<Solid, RevolveError>;
+}
+
+impl RevolveSketch for Sketch {
+    fn revolve_sketch(
+        &self,
+        surface: Handle<Surface>,
+        axis: Line<3>,
+        angle_rad: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Result<Solid, RevolveError> {
+        let angle_rad = angle_rad.into();
+
+        let mut shells = Vec::new();
+        for region in self.regions() {
+            for cycle in region.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let curve_geom = core
+                        .layers
+                        .geometry
+                        .of_curve(half_edge.curve())
+                        .unwrap()
+                        .local_on(self.surface())
+                        .unwrap();
+
+                    core.layers.geometry.define_curve(
+                        half_edge.curve().clone(),
+                        surface.clone(),
+                        curve_geom.clone(),
+                    );
+                }
+            }
+
+            let color = region.get_color(core);
+            let faces = region
+                .revolve_region(surface.clone(), color, axis, angle_rad, core)?
+                .all_faces()
+                .map(|face| face.insert(core))
+                .collect::<Vec<_>>();
+
+            shells.push(Shell::new(faces).insert(core));
+        }
+
+        Ok(Solid::new(shells))
+    }
+}
@@ -0,0 +1,1292 @@
+use fj_interop::Color;
+use fj_math::{Circle, Line, NurbsCurve, NurbsSurface, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{face::approx_face, ApproxCache, Tolerance},
+        triangulate::Triangulate,
+    },
+    geometry::{
+        CurveBoundary, GlobalPath, LocalCurveGeom, LocalVertexGeom,
+        SurfaceGeom, SurfacePath, SweptCurve,
+    },
+    operations::{
+        build::BuildSurface,
+        derive::DeriveFrom,
+        geometry::UpdateCurveGeometry,
+        insert::Insert,
+        presentation::{GetColor, SetColor},
+        reverse::Reverse,
+    },
+    storage::Handle,
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Shell, Surface, Vertex},
+    Core,
+};
+
+use super::{vertex::SweepVertex, SweepCache, SweepRegion};
+
+/// # Sweep a [`Face`] along a [`SweepPath`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait SweepFaceAlongPath {
+    /// # Sweep the [`Face`] along the path, into a [`Shell`]
+    ///
+    /// The start of the path acts as the pivot point of the face. The face is
+    /// moved along the path, and rotated according to the provided
+    /// [`SweepFrame`]. It does not need to be located at the start of the path.
+    ///
+    /// Curved segments of the path are approximated by straight segments,
+    /// within the provided tolerance.
+    ///
+    /// # Implementation Note
+    ///
+    /// With [`SweepFrame::Fixed`], the face does not rotate, and the sweep is
+    /// implemented as a series of straight sweeps, each of which is exact. The
+    /// resulting shell includes the original face, or its reversed version, if
+    /// the original face points in the direction of the sweep.
+    ///
+    /// With any other frame, the face is placed at each point of the
+    /// approximated path. Where two straight segments of the approximated path
+    /// meet, the face is cut by the plane that bisects the corner between
+    /// them, resulting in a mitred corner. Each edge of the face becomes a
+    /// ruled surface between two consecutive placements.
+    ///
+    /// Those surfaces are NURBS surfaces, which require all edges to be NURBS
+    /// curves too. For that reason, the shell starts with a copy of the face,
+    /// with all edges converted, instead of the original face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the face is not planar and the frame is not
+    /// [`SweepFrame::Fixed`], or if the orientation of the face can't be
+    /// determined at some point of the path. See [`SweepPathError`].
+    fn sweep_face_along_path(
+        &self,
+        path: &SweepPath,
+        frame: SweepFrame,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut SweepCache,
+        core: &mut Core,
+    ) -> Result<Shell, SweepPathError>;
+}
+
+impl SweepFaceAlongPath for Handle<Face> {
+    fn sweep_face_along_path(
+        &self,
+        path: &SweepPath,
+        frame: SweepFrame,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut SweepCache,
+        core: &mut Core,
+    ) -> Result<Shell, SweepPathError> {
+        let tolerance = tolerance.into();
+        let points = path.points(tolerance);
+
+        match frame {
+            SweepFrame::Fixed => Ok(sweep_with_fixed_frame(
+                self, &points, tolerance, cache, core,
+            )),
+            frame => sweep_with_moving_frame(
+                self, &points, frame, tolerance, cache, core,
+            ),
+        }
+    }
+}
+
+/// A path that faces can be swept along
+///
+/// Consists of connected segments. See [`SweepFaceAlongPath`].
+#[derive(Clone, Debug)]
+pub struct SweepPath {
+    segments: Vec<SweepPathSegment>,
+}
+
+impl SweepPath {
+    /// Create a path from the provided segments
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if no segments are provided, or if any segment
+    /// doesn't start where the previous one ends.
+    pub fn new(
+        segments: impl IntoIterator<Item = SweepPathSegment>,
+    ) -> Result<Self, SweepPathError> {
+        let segments = segments.into_iter().collect::<Vec<_>>();
+
+        if segments.is_empty() {
+            return Err(SweepPathError::Empty);
+        }
+        for pair in segments.windows(2) {
+            let distance = pair[0].end().distance_to(&pair[1].start());
+            if distance > Scalar::from(EPSILON) {
+                return Err(SweepPathError::Disconnected);
+            }
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Create a path that connects the provided points with straight lines
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if fewer than two points are provided.
+    pub fn polyline(
+        points: impl IntoIterator<Item = impl Into<Point<3>>>,
+    ) -> Result<Self, SweepPathError> {
+        let points = points.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        Self::new(
+            points
+                .windows(2)
+                .map(|points| SweepPathSegment::line([points[0], points[1]])),
+        )
+    }
+
+    /// Access the segments of the path
+    pub fn segments(&self) -> &[SweepPathSegment] {
+        &self.segments
+    }
+
+    /// Approximate the path by the points where the swept face is placed
+    fn points(&self, tolerance: Tolerance) -> Vec<Point<3>> {
+        let mut points: Vec<Point<3>> = Vec::new();
+
+        for segment in &self.segments {
+            let segment_points = segment.points(tolerance);
+
+            // Each segment starts where the previous one ends.
+            let skip = usize::from(!points.is_empty());
+            points.extend(segment_points.into_iter().skip(skip));
+        }
+
+        points
+    }
+}
+
+/// A segment of a [`SweepPath`]
+#[derive(Clone, Debug)]
+pub struct SweepPathSegment {
+    /// The path that the segment follows
+    pub path: GlobalPath,
+
+    /// The part of the path that is covered by the segment
+    pub boundary: CurveBoundary<Point<1>>,
+
+    /// An offset that is added to the path gradually, over the segment
+    ///
+    /// This is zero for most segments. Combined with a circular path, it can
+    /// be used to define a helix.
+    pub rise: Vector<3>,
+}
+
+impl SweepPathSegment {
+    /// Create a straight segment between two points
+    pub fn line(points: [impl Into<Point<3>>; 2]) -> Self {
+        let (path, coords) = GlobalPath::line_from_points(points);
+
+        Self {
+            path,
+            boundary: CurveBoundary { inner: coords },
+            rise: Vector::from([0., 0., 0.]),
+        }
+    }
+
+    /// Create a segment that follows a circle between two angles
+    pub fn arc(circle: Circle<3>, angles_rad: [impl Into<Scalar>; 2]) -> Self {
+        Self::helix(circle, angles_rad, [0., 0., 0.])
+    }
+
+    /// Create a segment that follows a helix
+    ///
+    /// The helix is defined by a circle, the angles between which it follows
+    /// that circle, and its rise over the whole segment. The rise is usually
+    /// perpendicular to the circle, but doesn't need to be.
+    pub fn helix(
+        circle: Circle<3>,
+        angles_rad: [impl Into<Scalar>; 2],
+        rise: impl Into<Vector<3>>,
+    ) -> Self {
+        Self {
+            path: GlobalPath::Circle(circle),
+            boundary: CurveBoundary {
+                inner: angles_rad.map(|angle| Point::from([angle.into()])),
+            },
+            rise: rise.into(),
+        }
+    }
+
+    /// Compute the start point of the segment
+    pub fn start(&self) -> Point<3> {
+        self.point_at(self.boundary.inner[0].t)
+    }
+
+    /// Compute the end point of the segment
+    pub fn end(&self) -> Point<3> {
+        self.point_at(self.boundary.inner[1].t)
+    }
+
+    fn point_at(&self, t: Scalar) -> Point<3> {
+        self.path.point_from_path_coords([t]) + self.rise * self.progress(t)
+    }
+
+    fn progress(&self, t: Scalar) -> Scalar {
+        let [start, end] = self.boundary.inner.map(|point| point.t);
+        (t - start) / (end - start)
+    }
+
+    fn points(&self, tolerance: Tolerance) -> Vec<Point<3>> {
+        let [start, end] = self.boundary.inner.map(|point| point.t);
+
+        let t_at = |i: usize, num_segments: usize| {
+            start + (end - start) * Scalar::from(i as f64 / num_segments as f64)
+        };
+
+        let num_segments = match &self.path {
+            GlobalPath::Line(_) => 1,
+            GlobalPath::Circle(circle) => {
+                // The maximum angle between two points, for which the
+                // distance between the chord and the circle stays within the
+                // tolerance.
+                let radius = circle.radius();
+                let max_angle = if tolerance.inner() < radius {
+                    (Scalar::ONE - tolerance.inner() / radius).acos() * 2.
+                } else {
+                    Scalar::PI / 2.
+                };
+
+                ((end - start).abs() / max_angle).ceil().into_f64().max(1.)
+                    as usize
+            }
+            GlobalPath::Nurbs(curve) => {
+                // Limits the subdivision, in case the tolerance is
+                // unreasonably small.
+                const MAX_SEGMENTS: usize = 1 << 12;
+
+                let mut num_segments = curve.control_points().len() - 1;
+                while num_segments < MAX_SEGMENTS {
+                    let is_within_tolerance = (0..num_segments).all(|i| {
+                        let [a, b] = [i, i + 1]
+                            .map(|i| self.point_at(t_at(i, num_segments)));
+                        let midpoint =
+                            self.point_at(t_at(2 * i + 1, 2 * num_segments));
+
+                        (midpoint - (a + (b - a) / 2.)).magnitude()
+                            <= tolerance.inner()
+                    });
+
+                    if is_within_tolerance {
+                        break;
+                    }
+
+                    num_segments *= 2;
+                }
+
+                num_segments
+            }
+        };
+
+        (0..=num_segments)
+            .map(|i| self.point_at(t_at(i, num_segments)))
+            .collect()
+    }
+}
+
+/// The frame that defines how a face is rotated, while swept along a path
+#[derive(Clone, Copy, Debug)]
+pub enum SweepFrame {
+    /// Keep the orientation of the face fixed
+    Fixed,
+
+    /// Rotate the face with the Frenet frame of the path
+    ///
+    /// The face keeps its orientation relative to the tangent of the path and
+    /// the direction in which the path curves. Where the path doesn't curve,
+    /// the face keeps its orientation relative to the tangent only.
+    ///
+    /// This twists the face around the path, wherever the path doesn't stay
+    /// in one plane. Where the path starts curving the other way, the face
+    /// flips around.
+    Frenet,
+
+    /// Rotate the face with the tangent of the path, as little as possible
+    ///
+    /// The face keeps its orientation relative to the tangent of the path.
+    /// Wherever the path changes direction, the face is only rotated around
+    /// the axis that is perpendicular to the path before and after.
+    ///
+    /// Unlike [`SweepFrame::Frenet`], this frame doesn't twist the face around
+    /// the path, and doesn't flip it where the path starts curving the other
+    /// way.
+    RotationMinimizing,
+
+    /// Rotate the face with the tangent of the path, keeping it upright
+    ///
+    /// The face keeps its orientation relative to the tangent of the path and
+    /// the provided up vector. The up vector must never be parallel to the
+    /// path.
+    UpVector(Vector<3>),
+}
+
+/// A face that can't be swept along a path
+///
+/// See [`SweepPath::new`] and [`SweepFaceAlongPath::sweep_face_along_path`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, thiserror::Error)]
+pub enum SweepPathError {
+    /// The path has no segments
+    #[error("Sweep path must not be empty")]
+    Empty,
+
+    /// A segment of the path doesn't start where the previous one ends
+    #[error("Segments of sweep path must be connected")]
+    Disconnected,
+
+    /// The face is not planar, but needs to rotate along the path
+    #[error("Sweeping with a moving frame requires a planar face")]
+    NonPlanarFace,
+
+    /// The path reverses its direction at some point
+    #[error("Can't sweep along path that reverses its direction")]
+    ReversesDirection,
+
+    /// The up vector is parallel to the path at some point
+    #[error(
+        "Can't determine orientation of swept face. Up vector is parallel to \
+        path."
+    )]
+    UpVectorParallelToPath,
+}
+
+/// Distance below which points are considered to be identical
+const EPSILON: f64 = 1e-8;
+
+fn sweep_with_fixed_frame(
+    face: &Handle<Face>,
+    points: &[Point<3>],
+    tolerance: Tolerance,
+    cache: &mut SweepCache,
+    core: &mut Core,
+) -> Shell {
+    let bottom_face = if points_along_path(face, points, tolerance, core) {
+        face.reverse(core).insert(core).derive_from(face, core)
+    } else {
+        face.clone()
+    };
+    let color = bottom_face.region().get_color(core);
+
+    let mut faces = vec![bottom_face.clone()];
+
+    let mut surface = bottom_face.surface().clone();
+    let mut region = bottom_face.region().clone();
+
+    for (i, pair) in points.windows(2).enumerate() {
+        let path = pair[1] - pair[0];
+        let swept_region =
+            region.sweep_region(surface.clone(), color, path, cache, core);
+
+        faces.extend(
+            swept_region
+                .side_faces
+                .into_iter()
+                .map(|face| face.insert(core)),
+        );
+
+        let top_face = swept_region.top_face;
+        if i == points.len() - 2 {
+            faces.push(top_face.insert(core));
+        } else {
+            // The top face points along the path. To sweep it further, we need
+            // a version that points backwards, like the original bottom face.
+            surface = top_face.surface().clone();
+            region = top_face
+                .region()
+                .reverse(core)
+                .insert(core)
+                .derive_from(top_face.region(), core);
+        }
+    }
+
+    Shell::new(faces)
+}
+
+fn sweep_with_moving_frame(
+    face: &Handle<Face>,
+    points: &[Point<3>],
+    frame: SweepFrame,
+    tolerance: Tolerance,
+    cache: &mut SweepCache,
+    core: &mut Core,
+) -> Result<Shell, SweepPathError> {
+    let surface_geom = core.layers.geometry.of_surface(face.surface()).clone();
+    let SurfaceGeom::SweptCurve(
+        plane @ SweptCurve {
+            u: GlobalPath::Line(_),
+            ..
+        },
+    ) = surface_geom
+    else {
+        return Err(SweepPathError::NonPlanarFace);
+    };
+
+    let color = face.region().get_color(core);
+    let profile = Profile::new(
+        face,
+        points_along_path(face, points, tolerance, core),
+        core,
+    );
+    let placements = placements(points, frame)?;
+
+    let mut section = Section::new(&profile, &plane, &placements[0], core);
+
+    let mut faces = vec![profile
+        .build_cap(&section, face.surface().clone(), false, color, core)
+        .insert(core)
+        .derive_from(face, core)];
+
+    for placement in &placements[1..] {
+        let (next_section, side_faces) =
+            section.sweep(&profile, &plane, placement, color, cache, core);
+
+        faces.extend(side_faces.into_iter().map(|face| face.insert(core)));
+        section = next_section;
+    }
+
+    let end_surface = {
+        let placement = placements.last().expect("Path has points");
+        let GlobalPath::Line(line) = plane.u else {
+            unreachable!("Checked that the surface is a plane");
+        };
+
+        Surface::from_uv(
+            GlobalPath::Line(Line::from_origin_and_direction(
+                placement.place(line.origin()),
+                placement.rotate(line.direction()),
+            )),
+            placement.rotate(plane.v),
+            core,
+        )
+    };
+    faces.push(
+        profile
+            .build_cap(&section, end_surface, true, color, core)
+            .insert(core),
+    );
+
+    Ok(Shell::new(faces))
+}
+
+/// The edges of a face, as NURBS curves in surface coordinates
+///
+/// Contains one list of edges per cycle of the face, starting with the
+/// exterior. Like the bottom face of other sweeps, the profile points against
+/// the direction of the sweep.
+struct Profile {
+    cycles: Vec<Vec<NurbsCurve<2>>>,
+}
+
+impl Profile {
+    fn new(face: &Handle<Face>, is_reversed: bool, core: &Core) -> Self {
+        let geometry = &core.layers.geometry;
+
+        let cycles = [face.region().exterior()]
+            .into_iter()
+            .chain(face.region().interiors())
+            .map(|cycle| {
+                let mut edges = cycle
+                    .half_edges()
+                    .pairs()
+                    .map(|(half_edge, next)| {
+                        let boundary = [half_edge, next].map(|h| {
+                            geometry
+                                .of_vertex(h.start_vertex())
+                                .unwrap()
+                                .local_on(half_edge.curve())
+                                .unwrap()
+                                .position
+                        });
+
+                        geometry
+                            .of_curve(half_edge.curve())
+                            .unwrap()
+                            .local_on(face.surface())
+                            .unwrap()
+                            .path
+                            .to_nurbs(CurveBoundary { inner: boundary })
+                    })
+                    .collect::<Vec<_>>();
+
+                if is_reversed {
+                    edges.reverse();
+                    edges =
+                        edges.into_iter().map(NurbsCurve::reverse).collect();
+                }
+
+                edges
+            })
+            .collect();
+
+        Self { cycles }
+    }
+
+    /// Build a face that closes the swept shell at the provided section
+    ///
+    /// The face points against the direction of the sweep, unless it is
+    /// reversed.
+    fn build_cap(
+        &self,
+        section: &Section,
+        surface: Handle<Surface>,
+        reverse: bool,
+        color: Option<Color>,
+        core: &mut Core,
+    ) -> Face {
+        let mut cycles = self.cycles.iter().enumerate().map(|(c, edges)| {
+            let mut half_edges = Vec::new();
+
+            for (i, edge) in edges.iter().enumerate() {
+                let j = (i + 1) % edges.len();
+                let curve = section.curves[c][i].clone();
+
+                core.layers.geometry.define_curve(
+                    curve.clone(),
+                    surface.clone(),
+                    LocalCurveGeom {
+                        path: SurfacePath::Nurbs(edge.clone()),
+                    },
+                );
+
+                let start = if reverse { j } else { i };
+                half_edges.push(
+                    HalfEdge::new(curve, section.vertices[c][start].clone())
+                        .insert(core),
+                );
+            }
+
+            if reverse {
+                half_edges.reverse();
+            }
+
+            Cycle::new(half_edges).insert(core)
+        });
+
+        let exterior = cycles.next().expect("Face has an exterior");
+        let interiors = cycles.collect::<Vec<_>>();
+
+        let region = Region::new(exterior, interiors).insert(core);
+
+        if let Some(color) = color {
+            region.set_color(color, core);
+        }
+
+        Face::new(surface, region)
+    }
+}
+
+/// The edges of the profile, placed at a point of the path
+struct Section {
+    vertices: Vec<Vec<Handle<Vertex>>>,
+    curves: Vec<Vec<Handle<Curve>>>,
+    paths: Vec<Vec<NurbsCurve<3>>>,
+}
+
+impl Section {
+    /// Create the section at the start of the path
+    fn new(
+        profile: &Profile,
+        plane: &SweptCurve,
+        placement: &Placement,
+        core: &mut Core,
+    ) -> Self {
+        let vertices = profile
+            .cycles
+            .iter()
+            .map(|edges| {
+                edges.iter().map(|_| Vertex::new().insert(core)).collect()
+            })
+            .collect();
+
+        Self::with_vertices(profile, plane, placement, vertices, core)
+    }
+
+    fn with_vertices(
+        profile: &Profile,
+        plane: &SweptCurve,
+        placement: &Placement,
+        vertices: Vec<Vec<Handle<Vertex>>>,
+        core: &mut Core,
+    ) -> Self {
+        let mut curves = Vec::new();
+        let mut paths = Vec::new();
+
+        for (c, edges) in profile.cycles.iter().enumerate() {
+            let mut cycle_curves = Vec::new();
+            let mut cycle_paths = Vec::new();
+
+            for (i, edge) in edges.iter().enumerate() {
+                let j = (i + 1) % edges.len();
+                let curve = Curve::new().insert(core);
+
+                let [start, end] = edge.domain();
+                for (vertex, position) in
+                    [(&vertices[c][i], start), (&vertices[c][j], end)]
+                {
+                    core.layers.geometry.define_vertex(
+                        vertex.clone(),
+                        curve.clone(),
+                        LocalVertexGeom {
+                            position: Point::from([position]),
+                        },
+                    );
+                }
+
+                // The plane is an affine map, and so is the placement. That
+                // means mapping the control points maps the whole curve.
+                cycle_paths.push(edge.map_control_points(|point| {
+                    placement.place(plane.point_from_surface_coords(*point))
+                }));
+                cycle_curves.push(curve);
+            }
+
+            curves.push(cycle_curves);
+            paths.push(cycle_paths);
+        }
+
+        Self {
+            vertices,
+            curves,
+            paths,
+        }
+    }
+
+    /// Sweep the section to the next placement
+    ///
+    /// Returns the next section, and the faces between both.
+    fn sweep(
+        &self,
+        profile: &Profile,
+        plane: &SweptCurve,
+        placement: &Placement,
+        color: Option<Color>,
+        cache: &mut SweepCache,
+        core: &mut Core,
+    ) -> (Self, Vec<Face>) {
+        let mut rulings = Vec::new();
+        let mut vertices = Vec::new();
+
+        for cycle in &self.vertices {
+            let (cycle_rulings, cycle_vertices): (Vec<_>, Vec<_>) = cycle
+                .iter()
+                .map(|vertex| {
+                    let (ruling, next) = vertex.sweep_vertex(cache, core);
+
+                    for (vertex, position) in [(vertex, 0.), (&next, 1.)] {
+                        core.layers.geometry.define_vertex(
+                            vertex.clone(),
+                            ruling.clone(),
+                            LocalVertexGeom {
+                                position: Point::from([position]),
+                            },
+                        );
+                    }
+
+                    (ruling, next)
+                })
+                .unzip();
+
+            rulings.push(cycle_rulings);
+            vertices.push(cycle_vertices);
+        }
+
+        let next =
+            Self::with_vertices(profile, plane, placement, vertices, core);
+
+        let mut faces = Vec::new();
+
+        for (c, paths) in self.paths.iter().enumerate() {
+            for (i, path) in paths.iter().enumerate() {
+                let j = (i + 1) % paths.len();
+
+                let surface = Surface::from_geometry(
+                    SurfaceGeom::Nurbs(NurbsSurface::ruled([
+                        path,
+                        &next.paths[c][i],
+                    ])),
+                    core,
+                );
+
+                // The u-coordinate of the surface matches the curve coordinates
+                // of the sections, the v-coordinate those of the rulings.
+                let [start, end] = path.domain();
+                let [bottom, top] = [Scalar::ZERO, Scalar::ONE];
+                let edges = [
+                    (&self.curves[c][i], [[start, bottom], [end, bottom]]),
+                    (&rulings[c][i], [[start, bottom], [start, top]]),
+                    (&next.curves[c][i], [[start, top], [end, top]]),
+                    (&rulings[c][j], [[end, bottom], [end, top]]),
+                ];
+
+                // The profile points against the direction of the sweep, like
+                // the face at the start. To point outwards, the side faces need
+                // to go around the other way.
+                let start_vertices = [
+                    &self.vertices[c][j],
+                    &self.vertices[c][i],
+                    &next.vertices[c][i],
+                    &next.vertices[c][j],
+                ];
+
+                let half_edges = edges.into_iter().zip(start_vertices).map(
+                    |((curve, points_surface), vertex)| {
+                        let [a, b] = points_surface.map(Point::from);
+                        let boundary =
+                            if a.u == b.u { [a.v, b.v] } else { [a.u, b.u] };
+
+                        let curve = curve.clone().make_line_on_surface(
+                            [a, b],
+                            CurveBoundary {
+                                inner: boundary.map(|t| Point::from([t])),
+                            },
+                            surface.clone(),
+                            &mut core.layers.geometry,
+                        );
+
+                        HalfEdge::new(curve, vertex.clone()).insert(core)
+                    },
+                );
+                let exterior =
+                    Cycle::new(half_edges.collect::<Vec<_>>()).insert(core);
+
+                let region = Region::new(exterior, []).insert(core);
+
+                if let Some(color) = color {
+                    region.set_color(color, core);
+                }
+
+                faces.push(Face::new(surface, region));
+            }
+        }
+
+        (next, faces)
+    }
+}
+
+/// The placement of the face at a point of the path
+struct Placement {
+    /// The point of the path that the face is placed at
+    position: Point<3>,
+
+    /// The start of the path, which is the pivot point of the face
+    start: Point<3>,
+
+    /// The orientation of the face, relative to its original orientation
+    ///
+    /// This is the rotation of the first frame into this one. Each frame
+    /// consists of the tangent of the path, and two axes perpendicular to it.
+    frames: [[Vector<3>; 3]; 2],
+
+    /// The directions of the path before and after, if this is a corner
+    corner: Option<[Vector<3>; 2]>,
+}
+
+impl Placement {
+    /// Place a point of the original face
+    fn place(&self, point: Point<3>) -> Point<3> {
+        let offset = self.rotate(point - self.start);
+
+        let Some([before, after]) = self.corner else {
+            return self.position + offset;
+        };
+
+        // At a corner, the face is cut by the plane that bisects the corner.
+        // That cut lies in the plane, which is perpendicular to the tangent,
+        // and is stretched by `1 / cos(half angle)` in the direction in which
+        // the path bends.
+        let [tangent, ..] = self.frames[1];
+        let offset = offset - tangent * offset.dot(&tangent);
+
+        let axis = before.cross(&after);
+        if axis.magnitude() <= Scalar::from(EPSILON) {
+            return self.position + offset;
+        }
+
+        let bend = axis.cross(&tangent).normalize();
+        let stretch = Scalar::ONE / tangent.dot(&after) - Scalar::ONE;
+
+        self.position + offset + bend * offset.dot(&bend) * stretch
+    }
+
+    /// Rotate a vector of the original face
+    fn rotate(&self, vector: Vector<3>) -> Vector<3> {
+        let [from, to] = self.frames;
+
+        from.into_iter()
+            .zip(to)
+            .fold(Vector::from([0., 0., 0.]), |rotated, (from, to)| {
+                rotated + to * vector.dot(&from)
+            })
+    }
+}
+
+/// Determine whether the face points in the direction of the sweep
+fn points_along_path(
+    face: &Handle<Face>,
+    points: &[Point<3>],
+    tolerance: Tolerance,
+    core: &mut Core,
+) -> bool {
+    let approx = approx_face(
+        face.clone(),
+        tolerance,
+        &mut ApproxCache::default(),
+        &core.layers.geometry,
+    );
+
+    let normal = approx.triangulate(core).triangles().fold(
+        Vector::from([0., 0., 0.]),
+        |normal, triangle| {
+            let [a, b, c] = triangle.inner.points();
+            normal + (b - a).cross(&(c - a))
+        },
+    );
+
+    normal.dot(&(points[1] - points[0])) > Scalar::ZERO
+}
+
+/// Compute the placement of the face at each point of the path
+fn placements(
+    points: &[Point<3>],
+    frame: SweepFrame,
+) -> Result<Vec<Placement>, SweepPathError> {
+    let directions = points
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).normalize())
+        .collect::<Vec<_>>();
+    let last = directions.len();
+
+    // At the corners between segments, the tangent bisects the corner.
+    let tangents = (0..=last)
+        .map(|i| {
+            let tangent =
+                directions[i.saturating_sub(1)] + directions[i.min(last - 1)];
+            if tangent.magnitude() <= Scalar::from(EPSILON) {
+                return Err(SweepPathError::ReversesDirection);
+            }
+            Ok(tangent.normalize())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let frames = match frame {
+        SweepFrame::Fixed => {
+            unreachable!("Fixed frame doesn't require computing placements")
+        }
+        SweepFrame::Frenet => {
+            // The direction in which the path curves at a corner bisects the
+            // corner, same as the tangent.
+            let curvature = |i: usize| {
+                (i > 0 && i < last)
+                    .then(|| directions[i] - directions[i - 1])
+                    .filter(|curvature| {
+                        curvature.magnitude() > Scalar::from(EPSILON)
+                    })
+            };
+
+            // Where the path starts out straight, the face is already oriented
+            // according to where it curves first.
+            let mut normal = (0..=last)
+                .find_map(curvature)
+                .map(|curvature| {
+                    curvature - tangents[0] * curvature.dot(&tangents[0])
+                })
+                .filter(|normal| normal.magnitude() > Scalar::from(EPSILON))
+                .unwrap_or_else(|| any_perpendicular(tangents[0]))
+                .normalize();
+            let mut frames = Vec::new();
+
+            for i in 0..=last {
+                if let Some(curvature) = curvature(i) {
+                    normal = curvature.normalize();
+                } else if i > 0 {
+                    normal = rotate_minimally(
+                        normal,
+                        [tangents[i - 1], tangents[i]],
+                    );
+                }
+
+                frames.push(frame_from(tangents[i], normal));
+            }
+
+            frames
+        }
+        SweepFrame::RotationMinimizing => {
+            // Each segment is rotated relative to the previous one, by the
+            // minimal rotation between their directions. At a corner, the face
+            // is rotated halfway, into the plane that bisects the corner.
+            let mut normal = any_perpendicular(directions[0]).normalize();
+            let mut frames = vec![frame_from(tangents[0], normal)];
+
+            for i in 1..=last {
+                let before = directions[i - 1];
+
+                frames.push(frame_from(
+                    tangents[i],
+                    rotate_minimally(normal, [before, tangents[i]]),
+                ));
+
+                if i < last {
+                    normal = rotate_minimally(normal, [before, directions[i]]);
+                }
+            }
+
+            frames
+        }
+        SweepFrame::UpVector(up) => tangents
+            .iter()
+            .map(|&tangent| {
+                let normal = up - tangent * up.dot(&tangent);
+                if normal.magnitude() <= Scalar::from(EPSILON) {
+                    return Err(SweepPathError::UpVectorParallelToPath);
+                }
+
+                Ok(frame_from(tangent, normal.normalize()))
+            })
+            .collect::<Result<_, _>>()?,
+    };
+
+    Ok(points
+        .iter()
+        .zip(&frames)
+        .enumerate()
+        .map(|(i, (&position, &frame))| Placement {
+            position,
+            start: points[0],
+            frames: [frames[0], frame],
+            corner: (i > 0 && i < last)
+                .then(|| [directions[i - 1], directions[i]]),
+        })
+        .collect())
+}
+
+fn frame_from(tangent: Vector<3>, normal: Vector<3>) -> [Vector<3>; 3] {
+    [tangent, normal, tangent.cross(&normal)]
+}
+
+/// Rotate a vector by the minimal rotation between two unit vectors
+///
+/// This is the rotation around the axis that is perpendicular to both. It is
+/// computed by reflecting the vector twice; first in the plane perpendicular
+/// to the first unit vector, then in the plane that bisects both.
+fn rotate_minimally(
+    vector: Vector<3>,
+    [from, to]: [Vector<3>; 2],
+) -> Vector<3> {
+    let reflect = |vector: Vector<3>, normal: Vector<3>| {
+        vector - normal * (vector.dot(&normal) * 2. / normal.dot(&normal))
+    };
+
+    reflect(reflect(vector, from), from + to)
+}
+
+fn any_perpendicular(vector: Vector<3>) -> Vector<3> {
+    let candidates = [Vector::unit_x(), Vector::unit_y(), Vector::unit_z()];
+
+    let least_parallel = candidates
+        .into_iter()
+        .min_by_key(|candidate| candidate.dot(&vector).abs())
+        .expect("Array is not empty");
+
+    vector.cross(&least_parallel)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, mass_properties::ComputeMassProperties,
+        },
+        geometry::{GlobalPath, SurfaceGeom, SweptCurve},
+        operations::{
+            build::{BuildCycle, BuildFace, BuildSketch},
+            insert::Insert,
+            reverse::Reverse,
+            sweep::{SweepCache, SweepSketch},
+            update::{UpdateFace, UpdateRegion},
+        },
+        storage::Handle,
+        topology::{Cycle, Face, Sketch, Solid},
+        Core,
+    };
+
+    use super::{
+        SweepFaceAlongPath, SweepFrame, SweepPath, SweepPathError,
+        SweepPathSegment,
+    };
+
+    #[test]
+    fn sweep_along_polyline() -> anyhow::Result<()> {
+        let path =
+            SweepPath::polyline([[0., 0., 0.], [0., 2., 0.], [1., 3., 0.]])?;
+
+        // With a fixed frame, only the movement perpendicular to the face
+        // contributes to the volume.
+        let volume = sweep(square(0.), path.clone(), SweepFrame::Fixed, 0.01)?;
+        assert!((volume - Scalar::from(3.)).abs() < Scalar::from(1e-6));
+
+        // The face is cut at the corner, which doesn't change the volume, as
+        // long as the path passes through its center.
+        let expected = Scalar::from(2. + 2f64.sqrt());
+        for frame in [
+            SweepFrame::Frenet,
+            SweepFrame::RotationMinimizing,
+            SweepFrame::UpVector(Vector::unit_z()),
+        ] {
+            let volume = sweep(square(0.), path.clone(), frame, 0.01)?;
+            assert!((volume - expected).abs() < Scalar::from(1e-6));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_helix() -> anyhow::Result<()> {
+        let circle = Circle::new(
+            [-3., 0., 0.],
+            Vector::from([3., 0., 0.]),
+            Vector::from([0., 3., 0.]),
+        );
+        let path = SweepPath::new([SweepPathSegment::helix(
+            circle,
+            [Scalar::ZERO, Scalar::TAU],
+            [0., 0., 4.],
+        )])?;
+
+        // The path is approximated quite coarsely here, as validating the
+        // resulting shell gets expensive quickly.
+        let tolerance = Tolerance::from_scalar(0.1).unwrap();
+
+        // The face is not perpendicular to the helix, and keeps its angle to
+        // it. Only the part of the face perpendicular to the path contributes
+        // to the volume.
+        let points = path.points(tolerance);
+        let direction = (points[1] - points[0]).normalize();
+        let expected = direction.dot(&Vector::unit_y()) * length(&points);
+
+        let volume = sweep(
+            square(0.),
+            path.clone(),
+            SweepFrame::RotationMinimizing,
+            tolerance,
+        )?;
+        assert!((volume - expected).abs() < Scalar::from(1e-6));
+
+        // The Frenet frame twists the face around the helix, which bends the
+        // side faces and changes the volume a bit.
+        let volume = sweep(square(0.), path, SweepFrame::Frenet, tolerance)?;
+        assert!((volume - expected).abs() / expected < Scalar::from(0.01));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_through_inflection() -> anyhow::Result<()> {
+        // Two quarter circles that curve in opposite directions.
+        let a = Circle::new(
+            [2., 0., 0.],
+            Vector::from([-2., 0., 0.]),
+            Vector::from([0., 2., 0.]),
+        );
+        let b = Circle::new(
+            [2., 4., 0.],
+            Vector::from([0., -2., 0.]),
+            Vector::from([2., 0., 0.]),
+        );
+        let path = SweepPath::new([a, b].map(|circle| {
+            SweepPathSegment::arc(circle, [Scalar::ZERO, Scalar::PI / 2.])
+        }))?;
+
+        // The face is offset from the path, perpendicular to the plane it
+        // curves in. A frame that flipped at the inflection point would make
+        // the side faces intersect.
+        let tolerance = Tolerance::from_scalar(0.01).unwrap();
+        let volume = sweep(
+            square(1.),
+            path.clone(),
+            SweepFrame::RotationMinimizing,
+            tolerance,
+        )?;
+
+        // The face is perpendicular to the path, but the first segment of its
+        // approximation is not.
+        let points = path.points(tolerance);
+        let direction = (points[1] - points[0]).normalize();
+        let expected = direction.dot(&Vector::unit_y()) * length(&points);
+        assert!((volume - expected).abs() < Scalar::from(1e-6));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_disk_with_hole() -> anyhow::Result<()> {
+        let path =
+            SweepPath::polyline([[0., 0., 0.], [0., 2., 0.], [1., 3., 0.]])?;
+
+        let tolerance = 0.01;
+        let volume = {
+            let mut core = Core::new();
+
+            let surface = core.layers.topology.surfaces.xz_plane();
+            let face = Face::circle(surface.clone(), [0., 0.], 0.5, &mut core)
+                .update_region(
+                    |region, core| {
+                        let hole = Cycle::circle([0., 0.], 0.25, surface, core)
+                            .reverse(core);
+                        region.add_interiors([hole], core)
+                    },
+                    &mut core,
+                )
+                .insert(&mut core);
+
+            volume_of(
+                face,
+                path,
+                SweepFrame::RotationMinimizing,
+                tolerance,
+                &mut core,
+            )?
+        };
+
+        // The approximation of the swept circles is inscribed in them, which
+        // makes the volume a bit smaller than the exact one.
+        let length = 2. + 2f64.sqrt();
+        let area = Scalar::PI * (0.5 * 0.5 - 0.25 * 0.25);
+        let expected = area * length;
+        let max_error = Scalar::PI * (0.5 + 0.25) * 2. * length * tolerance;
+        assert!(volume <= expected + Scalar::from(1e-6));
+        assert!(volume >= expected - max_error);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_paths_are_rejected() {
+        let empty: [[f64; 3]; 0] = [];
+        assert!(matches!(
+            SweepPath::polyline(empty),
+            Err(SweepPathError::Empty)
+        ));
+
+        let disconnected = SweepPath::new([
+            SweepPathSegment::line([[0., 0., 0.], [0., 1., 0.]]),
+            SweepPathSegment::line([[0., 2., 0.], [0., 3., 0.]]),
+        ]);
+        assert!(matches!(disconnected, Err(SweepPathError::Disconnected)));
+    }
+
+    #[test]
+    fn invalid_sweeps_are_rejected() -> anyhow::Result<()> {
+        let reversing =
+            SweepPath::polyline([[0., 0., 0.], [0., 2., 0.], [0., 1., 0.]])?;
+        assert!(matches!(
+            sweep(square(0.), reversing, SweepFrame::RotationMinimizing, 0.01),
+            Err(SweepPathError::ReversesDirection)
+        ));
+
+        let straight = SweepPath::polyline([[0., 0., 0.], [0., 2., 0.]])?;
+        assert!(matches!(
+            sweep(
+                square(0.),
+                straight.clone(),
+                SweepFrame::UpVector(Vector::unit_y()),
+                0.01,
+            ),
+            Err(SweepPathError::UpVectorParallelToPath)
+        ));
+
+        let mut core = Core::new();
+        let cylinder = Sketch::circle([0., 0.], 1., &mut core).sweep_sketch(
+            core.layers.topology.surfaces.xy_plane(),
+            [0., 0., 1.],
+            &mut core,
+        );
+        let curved_face = cylinder
+            .shells()
+            .iter()
+            .flat_map(|shell| shell.faces().iter())
+            .find(|face| {
+                !matches!(
+                    core.layers.geometry.of_surface(face.surface()),
+                    SurfaceGeom::SweptCurve(SweptCurve {
+                        u: GlobalPath::Line(_),
+                        ..
+                    })
+                )
+            })
+            .expect("Cylinder has curved faces")
+            .clone();
+        assert!(matches!(
+            volume_of(
+                curved_face,
+                straight,
+                SweepFrame::RotationMinimizing,
+                0.01,
+                &mut core,
+            ),
+            Err(SweepPathError::NonPlanarFace)
+        ));
+
+        Ok(())
+    }
+
+    fn square(offset: f64) -> [[f64; 2]; 4] {
+        [[-0.5, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]]
+            .map(|[x, z]| [x, z + offset])
+    }
+
+    fn sweep(
+        polygon: [[f64; 2]; 4],
+        path: SweepPath,
+        frame: SweepFrame,
+        tolerance: impl Into<Tolerance>,
+    ) -> Result<Scalar, SweepPathError> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let face = Face::polygon(surface, polygon, &mut core).insert(&mut core);
+
+        volume_of(face, path, frame, tolerance, &mut core)
+    }
+
+    fn volume_of(
+        face: Handle<Face>,
+        path: SweepPath,
+        frame: SweepFrame,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Scalar, SweepPathError> {
+        let tolerance = tolerance.into();
+
+        let shell = face
+            .sweep_face_along_path(
+                &path,
+                frame,
+                tolerance,
+                &mut SweepCache::default(),
+                core,
+            )?
+            .insert(core);
+
+        // Computing the volume makes sure that all faces are there, and that
+        // they are all pointing outwards.
+        Ok(Solid::new([shell]).mass_properties(tolerance, core).volume)
+    }
+
+    fn length(points: &[Point<3>]) -> Scalar {
+        points
+            .windows(2)
+            .map(|pair| pair[0].distance_to(&pair[1]))
+            .fold(Scalar::ZERO, |length, segment| length + segment)
+    }
+}
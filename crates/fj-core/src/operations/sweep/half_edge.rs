@@ -83,7 +83,7 @@ impl SweepHalfEdge for Handle<HalfEdge> {
             .local_on(&surface)
             .unwrap()
             .clone();
-        let surface_geom = *core.layers.geometry.of_surface(&surface);
+        let surface_geom = core.layers.geometry.of_surface(&surface).clone();
         let surface =
             curve_geom
                 .path
@@ -1,8 +1,10 @@
 //! Sweep objects along a path to create new objects
 //!
 //! Sweeps 1D or 2D objects along a straight path, creating a 2D or 3D object,
-//! respectively.
+//! respectively. Faces can also be swept along curved paths, see
+//! [`SweepFaceAlongPath`].
 
+mod along_path;
 mod cycle;
 mod face;
 mod half_edge;
@@ -13,6 +15,10 @@ mod sketch;
 mod vertex;
 
 pub use self::{
+    along_path::{
+        SweepFaceAlongPath, SweepFrame, SweepPath, SweepPathError,
+        SweepPathSegment,
+    },
     cycle::{SweepCycle, SweptCycle},
     face::SweepFace,
     half_edge::{SweepHalfEdge, SweptHalfEdge},
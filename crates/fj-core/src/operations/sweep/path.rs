@@ -39,8 +39,15 @@ impl SweepSurfacePath for SurfacePath {
         path: impl Into<Vector<3>>,
         core: &mut Core,
     ) -> Handle<Surface> {
+        let SurfaceGeom::SweptCurve(surface) = surface else {
+            todo!(
+                "Sweeping a curve that is defined on a curved surface is not \
+                supported yet."
+            )
+        };
+
         match surface.u {
-            GlobalPath::Circle(_) => {
+            GlobalPath::Circle(_) | GlobalPath::Nurbs(_) => {
                 // Sweeping a `Curve` creates a `Surface`. The u-axis of that
                 // `Surface` is a `GlobalPath`, which we are computing below.
                 // That computation might or might not work with an arbitrary
@@ -66,8 +73,10 @@ impl SweepSurfacePath for SurfacePath {
         let u = match self {
             SurfacePath::Circle(circle) => {
                 let center = surface.point_from_surface_coords(circle.center());
-                let a = surface.vector_from_surface_coords(circle.a());
-                let b = surface.vector_from_surface_coords(circle.b());
+                let a = surface
+                    .vector_from_surface_coords(circle.center(), circle.a());
+                let b = surface
+                    .vector_from_surface_coords(circle.center(), circle.b());
 
                 let circle = Circle::new(center, a, b);
 
@@ -75,13 +84,24 @@ impl SweepSurfacePath for SurfacePath {
             }
             SurfacePath::Line(line) => {
                 let origin = surface.point_from_surface_coords(line.origin());
-                let direction =
-                    surface.vector_from_surface_coords(line.direction());
+                let direction = surface.vector_from_surface_coords(
+                    line.origin(),
+                    line.direction(),
+                );
 
                 let line = Line::from_origin_and_direction(origin, direction);
 
                 GlobalPath::Line(line)
             }
+            SurfacePath::Nurbs(curve) => {
+                // The surface is flat, so mapping the control points into
+                // global coordinates results in the same curve.
+                let curve = curve.map_control_points(|point| {
+                    surface.point_from_surface_coords(*point)
+                });
+
+                GlobalPath::Nurbs(curve)
+            }
         };
 
         Surface::from_uv(u, path, core)
@@ -1,7 +1,7 @@
 use fj_math::{Scalar, Vector};
 
 use crate::{
-    geometry::GlobalPath,
+    geometry::{GlobalPath, SurfaceGeom, SweptCurve},
     operations::{derive::DeriveFrom, insert::Insert, reverse::Reverse},
     storage::Handle,
     topology::{Face, Sketch, Solid, Surface},
@@ -46,16 +46,18 @@ impl SweepSketch for Sketch {
                     .is_ccw());
 
                 let is_negative_sweep = {
-                    let u = match core.layers.geometry.of_surface(&surface).u {
-                        GlobalPath::Circle(_) => todo!(
+                    let SurfaceGeom::SweptCurve(SweptCurve {
+                        u: GlobalPath::Line(u),
+                        v,
+                    }) = core.layers.geometry.of_surface(&surface)
+                    else {
+                        todo!(
                             "Sweeping sketch from a rounded surfaces is not \
                             supported"
-                        ),
-                        GlobalPath::Line(line) => line.direction(),
+                        )
                     };
-                    let v = core.layers.geometry.of_surface(&surface).v;
 
-                    let normal = u.cross(&v);
+                    let normal = u.direction().cross(v);
 
                     normal.dot(&path) < Scalar::ZERO
                 };
@@ -20,8 +20,12 @@ impl TransformObject for &Handle<Surface> {
             .or_insert_with(|| {
                 let surface = Surface::new().insert(core);
 
-                let geometry =
-                    core.layers.geometry.of_surface(self).transform(transform);
+                let geometry = core
+                    .layers
+                    .geometry
+                    .of_surface(self)
+                    .clone()
+                    .transform(transform);
                 core.layers
                     .geometry
                     .define_surface(surface.clone(), geometry);
@@ -190,6 +190,12 @@ impl ObjectId {
     }
 }
 
+impl From<ObjectId> for fj_interop::SourceId {
+    fn from(id: ObjectId) -> Self {
+        Self(id.0)
+    }
+}
+
 impl fmt::Debug for ObjectId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let id = self.0;
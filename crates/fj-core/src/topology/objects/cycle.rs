@@ -38,7 +38,8 @@ impl Cycle {
     ) -> Winding {
         // The cycle could be made up of one or two circles. If that is the
         // case, the winding of the cycle is determined by the winding of the
-        // first circle.
+        // first circle. Cycles made up of NURBS curves are handled like any
+        // other polygon below.
         if self.half_edges.len() < 3 {
             let first = self
                 .half_edges()
@@ -78,18 +79,25 @@ impl Cycle {
             ];
             let edge_direction_positive = a < b;
 
-            let circle = match curve_geom.path {
-                SurfacePath::Circle(circle) => circle,
+            match curve_geom.path {
+                SurfacePath::Circle(circle) => {
+                    let cross_positive =
+                        circle.a().cross2d(&circle.b()) > Scalar::ZERO;
+
+                    if edge_direction_positive == cross_positive {
+                        return Winding::Ccw;
+                    } else {
+                        return Winding::Cw;
+                    }
+                }
                 SurfacePath::Line(_) => unreachable!(
-                    "Invalid cycle: less than 3 edges, but not all are circles"
+                    "Invalid cycle: less than 3 edges, but not all are circles \
+                    or NURBS curves"
                 ),
-            };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
-
-            if edge_direction_positive == cross_positive {
-                return Winding::Ccw;
-            } else {
-                return Winding::Cw;
+                SurfacePath::Nurbs(_) => {
+                    // NURBS curves are sampled below, which makes the cycle
+                    // a valid polygon.
+                }
             }
         }
 
@@ -97,26 +105,45 @@ impl Cycle {
         // cycle as a polygon:
         // https://stackoverflow.com/a/1165943
 
-        let mut sum = Scalar::ZERO;
+        // NURBS curves can bulge out arbitrarily between their vertices, so
+        // they are represented by a number of samples.
+        const SAMPLES_PER_NURBS: usize = 16;
+
+        let mut points = Vec::new();
 
-        for (a, b) in self.half_edges().pairs() {
-            let [a, b] = [a, b].map(|half_edge| {
+        for (half_edge, next) in self.half_edges().pairs() {
+            let path = &geometry
+                .of_curve(half_edge.curve())
+                .unwrap()
+                .local_on(surface)
+                .unwrap()
+                .path;
+            let [start, end] = [half_edge, next].map(|vertex_of| {
                 geometry
-                    .of_curve(half_edge.curve())
+                    .of_vertex(vertex_of.start_vertex())
                     .unwrap()
-                    .local_on(surface)
+                    .local_on(half_edge.curve())
                     .unwrap()
-                    .path
-                    .point_from_path_coords(
-                        geometry
-                            .of_vertex(half_edge.start_vertex())
-                            .unwrap()
-                            .local_on(half_edge.curve())
-                            .unwrap()
-                            .position,
-                    )
+                    .position
             });
 
+            let num_samples = match path {
+                SurfacePath::Nurbs(_) => SAMPLES_PER_NURBS,
+                SurfacePath::Circle(_) | SurfacePath::Line(_) => 1,
+            };
+
+            points.extend((0..num_samples).map(|i| {
+                let t = start.t
+                    + (end.t - start.t)
+                        * Scalar::from(i as f64 / num_samples as f64);
+                path.point_from_path_coords([t])
+            }));
+        }
+
+        let mut sum = Scalar::ZERO;
+
+        for (i, a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
             sum += (b.u - a.u) * (b.v + a.v);
         }
 
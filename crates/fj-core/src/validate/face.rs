@@ -6,11 +6,11 @@ use crate::{
             AdjacentHalfEdgesNotConnected, FaceHasNoBoundary,
             InteriorCycleHasInvalidWinding,
         },
-        ValidationCheck, ValidationConfig, ValidationError,
+        ValidationConfig, ValidationError,
     },
 };
 
-use super::Validate;
+use super::{run_check, Validate};
 
 impl Validate for Face {
     fn validate(
@@ -19,16 +19,12 @@ impl Validate for Face {
         errors: &mut Vec<ValidationError>,
         geometry: &Geometry,
     ) {
-        errors.extend(
-            AdjacentHalfEdgesNotConnected::check(self, geometry, config)
-                .map(Into::into),
+        run_check::<AdjacentHalfEdgesNotConnected, _>(
+            self, config, errors, geometry,
         );
-        errors.extend(
-            FaceHasNoBoundary::check(self, geometry, config).map(Into::into),
-        );
-        errors.extend(
-            InteriorCycleHasInvalidWinding::check(self, geometry, config)
-                .map(Into::into),
+        run_check::<FaceHasNoBoundary, _>(self, config, errors, geometry);
+        run_check::<InteriorCycleHasInvalidWinding, _>(
+            self, config, errors, geometry,
         );
     }
 }
@@ -3,11 +3,9 @@
 //! ## Structure
 //!
 //! Every single requirement is checked by a dedicated function. These functions
-//! are called **validation checks**. Validation checks are currently not
-//! visible in the public API, but their structure is reflected in the variants
-//! of the different enums that make up [`ValidationError`] (each validation
-//! check produces one of the types of validation error, that these nested enums
-//! represent).
+//! are called **validation checks**. Each validation check is defined in
+//! [`crate::validation::checks`], and produces its own type of error, which is
+//! represented by a variant of [`ValidationError`].
 //!
 //! In principle, the absence of validation errors should guarantee, that an
 //! object can be exported to an external file format without problems (which
@@ -74,19 +72,23 @@ mod vertex;
 
 use crate::{
     geometry::Geometry,
-    validation::{ValidationConfig, ValidationError},
+    validation::{ValidationCheck, ValidationConfig, ValidationError},
 };
 
-pub use self::{sketch::SketchValidationError, solid::SolidValidationError};
-
 /// Assert that some object has a validation error which matches a specific
-/// pattern. This is preferred to matching on [`Validate::validate_and_return_first_error`], since usually we don't care about the order.
+/// pattern
+///
+/// The pattern is matched against the [`ValidationError`]s that
+/// [`Validate::validate`] produces with the default configuration. This is
+/// preferred to matching on [`Validate::validate_and_return_first_error`],
+/// since usually we don't care about the order.
 #[macro_export]
 macro_rules! assert_contains_err {
     ($core:expr, $o:expr, $p:pat) => {
         assert!({
             let mut errors = Vec::new();
-            $o.validate(
+            $crate::validate::Validate::validate(
+                &$o,
                 &$crate::validation::ValidationConfig::default(),
                 &mut errors,
                 &$core.layers.geometry,
@@ -124,3 +126,24 @@ pub trait Validate: Sized {
         geometry: &Geometry,
     );
 }
+
+/// Run a validation check, as configured for it, and collect its errors
+///
+/// Does nothing, if the check is disabled in the configuration. Otherwise, runs
+/// the check with any thresholds that have been overridden for it.
+fn run_check<C, T>(
+    object: &T,
+    config: &ValidationConfig,
+    errors: &mut Vec<ValidationError>,
+    geometry: &Geometry,
+) where
+    C: ValidationCheck<T>,
+    ValidationError: From<C>,
+{
+    if !config.check(C::NAME).enabled {
+        return;
+    }
+
+    let config = config.for_check(C::NAME);
+    errors.extend(C::check(object, geometry, &config).map(Into::into));
+}
@@ -1,16 +1,14 @@
 use crate::{
     geometry::Geometry,
     topology::Shell,
-    validation::{
-        checks::{
-            CoincidentHalfEdgesAreNotSiblings, CurveGeometryMismatch,
-            HalfEdgeHasNoSibling,
-        },
-        ValidationCheck,
+    validation::checks::{
+        CoincidentHalfEdgesAreNotSiblings, CurveGeometryMismatch,
+        HalfEdgeHasNoSibling, ShellIsNotClosed, ShellIsNotConsistentlyOriented,
+        ShellIsNotManifold, ShellIsSelfIntersecting,
     },
 };
 
-use super::{Validate, ValidationConfig, ValidationError};
+use super::{run_check, Validate, ValidationConfig, ValidationError};
 
 impl Validate for Shell {
     fn validate(
@@ -19,16 +17,16 @@ impl Validate for Shell {
         errors: &mut Vec<ValidationError>,
         geometry: &Geometry,
     ) {
-        errors.extend(
-            CurveGeometryMismatch::check(self, geometry, config)
-                .map(Into::into),
+        run_check::<CurveGeometryMismatch, _>(self, config, errors, geometry);
+        run_check::<HalfEdgeHasNoSibling, _>(self, config, errors, geometry);
+        run_check::<CoincidentHalfEdgesAreNotSiblings, _>(
+            self, config, errors, geometry,
         );
-        errors.extend(
-            HalfEdgeHasNoSibling::check(self, geometry, config).map(Into::into),
-        );
-        errors.extend(
-            CoincidentHalfEdgesAreNotSiblings::check(self, geometry, config)
-                .map(Into::into),
+        run_check::<ShellIsNotClosed, _>(self, config, errors, geometry);
+        run_check::<ShellIsNotManifold, _>(self, config, errors, geometry);
+        run_check::<ShellIsNotConsistentlyOriented, _>(
+            self, config, errors, geometry,
         );
+        run_check::<ShellIsSelfIntersecting, _>(self, config, errors, geometry);
     }
 }
@@ -81,6 +81,8 @@ impl fmt::Display for CoincidentHalfEdgesAreNotSiblings {
 }
 
 impl ValidationCheck<Shell> for CoincidentHalfEdgesAreNotSiblings {
+    const NAME: &'static str = "CoincidentHalfEdgesAreNotSiblings";
+
     fn check<'r>(
         object: &'r Shell,
         geometry: &'r crate::geometry::Geometry,
@@ -196,7 +198,7 @@ fn distances(
                 .position,
         ];
         let path_coords = start + (end - start) * percent;
-        let path = geometry
+        let path = &geometry
             .of_curve(half_edge.curve())?
             .local_on(surface)?
             .path;
@@ -55,6 +55,8 @@ pub struct CurveGeometryMismatch {
 }
 
 impl ValidationCheck<Shell> for CurveGeometryMismatch {
+    const NAME: &'static str = "CurveGeometryMismatch";
+
     fn check<'r>(
         object: &'r Shell,
         geometry: &'r Geometry,
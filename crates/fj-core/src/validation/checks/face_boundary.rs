@@ -17,6 +17,8 @@ use crate::{
 pub struct FaceHasNoBoundary {}
 
 impl ValidationCheck<Face> for FaceHasNoBoundary {
+    const NAME: &'static str = "FaceHasNoBoundary";
+
     fn check<'r>(
         object: &'r Face,
         _: &'r Geometry,
@@ -37,6 +37,8 @@ pub struct InteriorCycleHasInvalidWinding {
 }
 
 impl ValidationCheck<Face> for InteriorCycleHasInvalidWinding {
+    const NAME: &'static str = "InteriorCycleHasInvalidWinding";
+
     fn check<'r>(
         object: &'r Face,
         geometry: &'r Geometry,
@@ -17,6 +17,11 @@ use crate::{
 /// However, the *position* of that shared vertex is redundantly defined in both
 /// [`HalfEdge`]s. This check verifies that both positions are the same.
 ///
+/// Positions are compared in surface coordinates. At singular points of the
+/// surface, like the poles of a sphere or the apex of a cone, different surface
+/// coordinates refer to the same point. There, positions are accepted, if they
+/// are the same in 3D.
+///
 /// ## Implementation Note
 ///
 /// Having the vertex positions redundantly defined is not desirable, but
@@ -55,9 +60,14 @@ pub struct AdjacentHalfEdgesNotConnected {
 
     /// The edges
     pub unconnected_half_edges: [Handle<HalfEdge>; 2],
+
+    /// The surface that the positions are defined on
+    pub surface: Handle<Surface>,
 }
 
 impl ValidationCheck<Face> for AdjacentHalfEdgesNotConnected {
+    const NAME: &'static str = "AdjacentHalfEdgesNotConnected";
+
     fn check<'r>(
         object: &'r Face,
         geometry: &'r Geometry,
@@ -68,6 +78,8 @@ impl ValidationCheck<Face> for AdjacentHalfEdgesNotConnected {
 }
 
 impl ValidationCheck<Sketch> for AdjacentHalfEdgesNotConnected {
+    const NAME: &'static str = "AdjacentHalfEdgesNotConnected";
+
     fn check<'r>(
         object: &'r Sketch,
         geometry: &'r Geometry,
@@ -139,12 +151,20 @@ fn check_cycle<'r>(
             - start_pos_of_second_half_edge)
             .magnitude();
 
-        if distance_between_positions > config.identical_max_distance {
+        if distance_between_positions > config.identical_max_distance
+            && !is_same_singular_point(
+                [end_pos_of_first_half_edge, start_pos_of_second_half_edge],
+                surface,
+                geometry,
+                config,
+            )
+        {
             return Some(AdjacentHalfEdgesNotConnected {
                 end_pos_of_first_half_edge,
                 start_pos_of_second_half_edge,
                 distance_between_positions,
                 unconnected_half_edges: [first.clone(), second.clone()],
+                surface: surface.clone(),
             });
         }
 
@@ -152,6 +172,31 @@ fn check_cycle<'r>(
     })
 }
 
+fn is_same_singular_point(
+    positions: [Point<2>; 2],
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+    config: &ValidationConfig,
+) -> bool {
+    // Surfaces that are not located in 3D space have no singular points.
+    let Some(surface) = geometry.try_of_surface(surface) else {
+        return false;
+    };
+
+    let is_singular = positions.into_iter().all(|position| {
+        [[1., 0.], [0., 1.]].into_iter().any(|direction| {
+            surface
+                .vector_from_surface_coords(position, direction)
+                .magnitude()
+                <= config.identical_max_distance
+        })
+    });
+    let [a, b] =
+        positions.map(|position| surface.point_from_surface_coords(position));
+
+    is_singular && a.distance_to(&b) <= config.identical_max_distance
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -25,6 +25,8 @@ pub struct HalfEdgeHasNoSibling {
 }
 
 impl ValidationCheck<Shell> for HalfEdgeHasNoSibling {
+    const NAME: &'static str = "HalfEdgeHasNoSibling";
+
     fn check<'r>(
         object: &'r Shell,
         _: &'r Geometry,
@@ -9,6 +9,13 @@ mod face_winding;
 mod half_edge_connection;
 mod half_edge_has_no_sibling;
 mod multiple_references;
+mod shell_closed;
+mod shell_edges;
+mod shell_manifold;
+mod shell_orientation;
+mod shell_self_intersection;
+mod sketch_winding;
+mod vertex_coincidence;
 
 pub use self::{
     coincident_half_edges_are_not_siblings::CoincidentHalfEdgesAreNotSiblings,
@@ -18,4 +25,15 @@ pub use self::{
     half_edge_connection::AdjacentHalfEdgesNotConnected,
     half_edge_has_no_sibling::HalfEdgeHasNoSibling,
     multiple_references::MultipleReferencesToObject,
+    shell_closed::ShellIsNotClosed,
+    shell_manifold::ShellIsNotManifold,
+    shell_orientation::ShellIsNotConsistentlyOriented,
+    shell_self_intersection::ShellIsSelfIntersecting,
+    sketch_winding::{
+        ExteriorCycleHasClockwiseWinding,
+        InteriorCycleHasCounterClockwiseWinding,
+    },
+    vertex_coincidence::{
+        DistinctVerticesCoincide, IdenticalVerticesNotCoincident,
+    },
 };
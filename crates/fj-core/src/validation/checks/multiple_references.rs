@@ -18,6 +18,18 @@ pub struct MultipleReferencesToObject<T, U> {
     referenced_by: Vec<Handle<U>>,
 }
 
+impl<T, U> MultipleReferencesToObject<T, U> {
+    /// Access the object that is referenced multiple times
+    pub fn object(&self) -> &Handle<T> {
+        &self.object
+    }
+
+    /// Access the objects that reference the object
+    pub fn referenced_by(&self) -> &[Handle<U>] {
+        &self.referenced_by
+    }
+}
+
 impl<T, U> fmt::Display for MultipleReferencesToObject<T, U>
 where
     T: fmt::Debug,
@@ -36,6 +48,8 @@ where
 }
 
 impl ValidationCheck<Sketch> for MultipleReferencesToObject<Cycle, Region> {
+    const NAME: &'static str = "MultipleReferencesToCycle";
+
     fn check<'r>(
         object: &'r Sketch,
         _: &'r Geometry,
@@ -54,6 +68,8 @@ impl ValidationCheck<Sketch> for MultipleReferencesToObject<Cycle, Region> {
 }
 
 impl ValidationCheck<Sketch> for MultipleReferencesToObject<HalfEdge, Cycle> {
+    const NAME: &'static str = "MultipleReferencesToHalfEdge";
+
     fn check<'r>(
         object: &'r Sketch,
         _: &'r Geometry,
@@ -74,6 +90,8 @@ impl ValidationCheck<Sketch> for MultipleReferencesToObject<HalfEdge, Cycle> {
 }
 
 impl ValidationCheck<Solid> for MultipleReferencesToObject<Face, Shell> {
+    const NAME: &'static str = "MultipleReferencesToFace";
+
     fn check<'r>(
         object: &'r Solid,
         _: &'r Geometry,
@@ -92,6 +110,8 @@ impl ValidationCheck<Solid> for MultipleReferencesToObject<Face, Shell> {
 }
 
 impl ValidationCheck<Solid> for MultipleReferencesToObject<Region, Face> {
+    const NAME: &'static str = "MultipleReferencesToRegion";
+
     fn check<'r>(
         object: &'r Solid,
         _: &'r Geometry,
@@ -110,6 +130,8 @@ impl ValidationCheck<Solid> for MultipleReferencesToObject<Region, Face> {
 }
 
 impl ValidationCheck<Solid> for MultipleReferencesToObject<Cycle, Region> {
+    const NAME: &'static str = "MultipleReferencesToCycle";
+
     fn check<'r>(
         object: &'r Solid,
         _: &'r Geometry,
@@ -130,6 +152,8 @@ impl ValidationCheck<Solid> for MultipleReferencesToObject<Cycle, Region> {
 }
 
 impl ValidationCheck<Solid> for MultipleReferencesToObject<HalfEdge, Cycle> {
+    const NAME: &'static str = "MultipleReferencesToHalfEdge";
+
     fn check<'r>(
         object: &'r Solid,
         _: &'r Geometry,
@@ -0,0 +1,85 @@
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Face, HalfEdge, Shell},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+use super::shell_edges::half_edges_by_edge;
+
+/// A [`Shell`] is not closed
+///
+/// In a closed shell, every edge is shared by two faces. If a [`HalfEdge`] is
+/// the only one on its edge, the shell has a hole there, meaning it is not
+/// watertight and can't bound a volume.
+///
+/// This overlaps with [`HalfEdgeHasNoSibling`], but doesn't depend on the
+/// orientation of the faces. Each of them is reported once per half-edge.
+///
+/// [`HalfEdgeHasNoSibling`]: super::HalfEdgeHasNoSibling
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Shell` is not closed; no other face shares the edge of this half-edge\n\
+    Half-edge: {half_edge:#?}\n\
+    Face: {face:#?}"
+)]
+pub struct ShellIsNotClosed {
+    /// The half-edge that is alone on its edge
+    pub half_edge: Handle<HalfEdge>,
+
+    /// The face that the half-edge is in
+    pub face: Handle<Face>,
+}
+
+impl ValidationCheck<Shell> for ShellIsNotClosed {
+    const NAME: &'static str = "ShellIsNotClosed";
+
+    fn check<'r>(
+        object: &'r Shell,
+        _: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        half_edges_by_edge(object).into_values().filter_map(|uses| {
+            let [edge_use] = <[_; 1]>::try_from(uses).ok()?;
+
+            Some(ShellIsNotClosed {
+                half_edge: edge_use.half_edge,
+                face: edge_use.face,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operations::{build::BuildShell, update::UpdateShell},
+        topology::Shell,
+        validation::{
+            checks::ShellIsNotClosed, ValidationCheck, ValidationConfig,
+        },
+        Core,
+    };
+
+    #[test]
+    fn shell_is_not_closed() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let config = ValidationConfig::default();
+
+        let valid = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        ShellIsNotClosed::check_and_return_first_error(
+            &valid.shell,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = valid.shell.remove_face(&valid.abc.face);
+        let errors =
+            ShellIsNotClosed::check(&invalid, &core.layers.geometry, &config);
+        assert_eq!(errors.count(), 3);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    geometry::CurveBoundary,
+    queries::BoundingVerticesOfHalfEdge,
+    storage::Handle,
+    topology::{Curve, Face, HalfEdge, Shell, Vertex},
+};
+
+/// A half-edge of a shell, along with the information needed to check edges
+pub struct EdgeUse {
+    /// The half-edge
+    pub half_edge: Handle<HalfEdge>,
+
+    /// The face that the half-edge is in
+    pub face: Handle<Face>,
+
+    /// The vertices that bound the half-edge, in its direction
+    pub vertices: CurveBoundary<Vertex>,
+}
+
+/// Group the half-edges of a shell by the edge they are on
+///
+/// Two half-edges are on the same edge, if they are on the same curve and are
+/// bounded by the same vertices, regardless of direction. In a closed,
+/// 2-manifold, and consistently oriented shell, each edge has exactly two
+/// half-edges, going in opposite directions.
+pub fn half_edges_by_edge(
+    shell: &Shell,
+) -> BTreeMap<(Handle<Curve>, CurveBoundary<Vertex>), Vec<EdgeUse>> {
+    let mut edges: BTreeMap<_, Vec<_>> = BTreeMap::new();
+
+    for face in shell.faces() {
+        for cycle in face.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let vertices = cycle
+                    .bounding_vertices_of_half_edge(half_edge)
+                    .expect("`half_edge` came from `cycle`, must exist there");
+
+                let key =
+                    (half_edge.curve().clone(), vertices.clone().normalize());
+                edges.entry(key).or_default().push(EdgeUse {
+                    half_edge: half_edge.clone(),
+                    face: face.clone(),
+                    vertices,
+                });
+            }
+        }
+    }
+
+    edges
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Curve, Face, HalfEdge, Shell},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+use super::shell_edges::half_edges_by_edge;
+
+/// A [`Shell`] is not 2-manifold
+///
+/// In a 2-manifold shell, every edge is shared by no more than two faces. If
+/// more [`HalfEdge`]s share an edge, it's not defined which side of the edge
+/// is inside of the shell, and which side is outside.
+#[derive(Clone, Debug, thiserror::Error)]
+pub struct ShellIsNotManifold {
+    /// The curve of the edge
+    pub curve: Handle<Curve>,
+
+    /// The half-edges that share the edge
+    pub half_edges: Vec<Handle<HalfEdge>>,
+
+    /// The faces that the half-edges are in, in the same order
+    pub faces: Vec<Handle<Face>>,
+}
+
+impl fmt::Display for ShellIsNotManifold {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "`Shell` is not 2-manifold; {} half-edges share an edge, but no \
+            more than 2 are allowed",
+            self.half_edges.len(),
+        )?;
+        writeln!(f, "Curve: {:#?}", self.curve)?;
+
+        for (i, (half_edge, face)) in
+            self.half_edges.iter().zip(&self.faces).enumerate()
+        {
+            writeln!(f, "Half-edge {}: {half_edge:#?}", i + 1)?;
+            writeln!(f, "Face {}: {face:#?}", i + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ValidationCheck<Shell> for ShellIsNotManifold {
+    const NAME: &'static str = "ShellIsNotManifold";
+
+    fn check<'r>(
+        object: &'r Shell,
+        _: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        half_edges_by_edge(object).into_iter().filter_map(
+            |((curve, _), uses)| {
+                if uses.len() <= 2 {
+                    return None;
+                }
+
+                let (half_edges, faces) = uses
+                    .into_iter()
+                    .map(|edge_use| (edge_use.half_edge, edge_use.face))
+                    .unzip();
+
+                Some(ShellIsNotManifold {
+                    curve,
+                    half_edges,
+                    faces,
+                })
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operations::{
+            build::BuildShell, insert::Insert, reverse::Reverse,
+            update::UpdateShell,
+        },
+        topology::Shell,
+        validation::{
+            checks::ShellIsNotManifold, ValidationCheck, ValidationConfig,
+        },
+        Core,
+    };
+
+    #[test]
+    fn shell_is_not_manifold() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let config = ValidationConfig::default();
+
+        let valid = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        ShellIsNotManifold::check_and_return_first_error(
+            &valid.shell,
+            &core.layers.geometry,
+        )?;
+
+        // Adding another face on the same edges as an existing one, puts
+        // three half-edges on each of those edges.
+        let extra_face = valid.abc.face.reverse(&mut core).insert(&mut core);
+        let invalid = valid.shell.add_faces([extra_face], &mut core);
+        let errors =
+            ShellIsNotManifold::check(&invalid, &core.layers.geometry, &config)
+                .collect::<Vec<_>>();
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|err| err.half_edges.len() == 3));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Face, HalfEdge, Shell},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+use super::shell_edges::half_edges_by_edge;
+
+/// A [`Shell`] is not consistently oriented
+///
+/// The two [`HalfEdge`]s that share an edge must go in opposite directions.
+/// If they go in the same direction, one of their faces is flipped relative to
+/// the other, and the shell has no well-defined inside and outside.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Shell` is not consistently oriented; half-edges that share an edge go in \
+    the same direction\n\
+    Half-edge 1: {half_edge_a:#?}\n\
+    Half-edge 2: {half_edge_b:#?}\n\
+    Face 1: {face_a:#?}\n\
+    Face 2: {face_b:#?}"
+)]
+pub struct ShellIsNotConsistentlyOriented {
+    /// The first half-edge
+    pub half_edge_a: Handle<HalfEdge>,
+
+    /// The second half-edge
+    pub half_edge_b: Handle<HalfEdge>,
+
+    /// The face of the first half-edge
+    pub face_a: Handle<Face>,
+
+    /// The face of the second half-edge
+    pub face_b: Handle<Face>,
+}
+
+impl ValidationCheck<Shell> for ShellIsNotConsistentlyOriented {
+    const NAME: &'static str = "ShellIsNotConsistentlyOriented";
+
+    fn check<'r>(
+        object: &'r Shell,
+        _: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        // Edges with more than two half-edges are not 2-manifold, and there's
+        // another check for that. There's no sensible orientation to check for
+        // them.
+        half_edges_by_edge(object).into_values().filter_map(|uses| {
+            let [a, b] = <[_; 2]>::try_from(uses).ok()?;
+
+            if a.vertices != b.vertices {
+                return None;
+            }
+
+            Some(ShellIsNotConsistentlyOriented {
+                half_edge_a: a.half_edge,
+                half_edge_b: b.half_edge,
+                face_a: a.face,
+                face_b: b.face,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operations::{
+            build::BuildShell, reverse::Reverse, update::UpdateShell,
+        },
+        topology::Shell,
+        validation::{
+            checks::ShellIsNotConsistentlyOriented, ValidationCheck,
+            ValidationConfig,
+        },
+        Core,
+    };
+
+    #[test]
+    fn shell_is_not_consistently_oriented() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let config = ValidationConfig::default();
+
+        let valid = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        ShellIsNotConsistentlyOriented::check_and_return_first_error(
+            &valid.shell,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = valid.shell.update_face(
+            &valid.abc.face,
+            |face, core| [face.reverse(core)],
+            &mut core,
+        );
+        let errors = ShellIsNotConsistentlyOriented::check(
+            &invalid,
+            &core.layers.geometry,
+            &config,
+        );
+        assert_eq!(errors.count(), 3);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,304 @@
+use std::collections::BTreeSet;
+
+use fj_math::{Aabb, Point, Scalar, Segment, Triangle};
+
+use crate::{
+    algorithms::approx::{face::approx_face, ApproxCache},
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Face, Shell},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// A [`Shell`] intersects itself
+///
+/// Two faces of a shell must not intersect, except where they share edges or
+/// vertices. Otherwise, the shell doesn't bound a well-defined volume.
+///
+/// # Implementation Note
+///
+/// This check works with an approximation of the shell's faces, as defined by
+/// [`ValidationConfig::approximation_tolerance`]. Vertices of that
+/// approximation that are closer than
+/// [`ValidationConfig::distinct_min_distance`] count as shared. Triangles of
+/// different faces that share vertices may touch there, but intersecting
+/// anywhere else is an error.
+///
+/// Only triangles whose bounding boxes overlap are compared, which are found by
+/// sorting the bounding boxes of all triangles of the shell along one axis.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Shell` intersects itself\n\
+    Face 1: {face_a:#?}\n\
+    Face 2: {face_b:#?}"
+)]
+pub struct ShellIsSelfIntersecting {
+    /// The first of the intersecting faces
+    pub face_a: Handle<Face>,
+
+    /// The second of the intersecting faces
+    pub face_b: Handle<Face>,
+}
+
+impl ValidationCheck<Shell> for ShellIsSelfIntersecting {
+    const NAME: &'static str = "ShellIsSelfIntersecting";
+
+    fn check<'r>(
+        object: &'r Shell,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        // Approximate the faces one by one, instead of the whole shell. The
+        // approximation of a shell validates the result, which is not our job
+        // here, and panics if it finds something.
+        let mut cache = ApproxCache::default();
+        let mut faces = Vec::new();
+        let mut triangles = Vec::new();
+
+        for (i, face) in object.faces().into_iter().enumerate() {
+            let face = approx_face(
+                face.clone(),
+                config.approximation_tolerance,
+                &mut cache,
+                geometry,
+            );
+
+            for points in face.triangles() {
+                let Ok(triangle) = Triangle::from_points(points) else {
+                    continue;
+                };
+                triangles.push((i, triangle, Aabb::<3>::from_points(points)));
+            }
+
+            faces.push(face.face);
+        }
+
+        // Sort the triangles by where their bounding boxes start along the x
+        // axis. Then each triangle only needs to be compared to those that
+        // follow it, until one starts beyond its end.
+        triangles.sort_by_key(|(_, _, aabb)| aabb.min.x);
+
+        let mut intersecting = BTreeSet::new();
+
+        for (i, (face_a, a, aabb_a)) in triangles.iter().enumerate() {
+            for (face_b, b, aabb_b) in &triangles[i + 1..] {
+                if aabb_b.min.x > aabb_a.max.x {
+                    break;
+                }
+
+                let pair = (*face_a.min(face_b), *face_a.max(face_b));
+                if face_a == face_b
+                    || intersecting.contains(&pair)
+                    || !aabb_a.intersects(aabb_b)
+                {
+                    continue;
+                }
+
+                if triangles_intersect(a, b, config.distinct_min_distance) {
+                    intersecting.insert(pair);
+                }
+            }
+        }
+
+        intersecting
+            .into_iter()
+            .map(move |(a, b)| ShellIsSelfIntersecting {
+                face_a: faces[a].clone(),
+                face_b: faces[b].clone(),
+            })
+    }
+}
+
+/// Determine whether two triangles intersect, other than at shared vertices
+///
+/// Triangles of neighboring faces share a vertex or a whole edge. They touch
+/// there, which is fine, as long as that is all they do.
+fn triangles_intersect(
+    a: &Triangle<3>,
+    b: &Triangle<3>,
+    distinct_min_distance: Scalar,
+) -> bool {
+    let is_shared = |point: &Point<3>, other: &Triangle<3>| {
+        other
+            .points()
+            .iter()
+            .any(|other| point.distance_to(other) < distinct_min_distance)
+    };
+
+    let (shared, unshared_a): (Vec<_>, Vec<_>) = a
+        .points()
+        .into_iter()
+        .partition(|point| is_shared(point, b));
+    let unshared_b = b
+        .points()
+        .into_iter()
+        .filter(|point| !is_shared(point, a))
+        .collect::<Vec<_>>();
+
+    match (
+        shared.as_slice(),
+        unshared_a.as_slice(),
+        unshared_b.as_slice(),
+    ) {
+        ([], _, _) => a.intersects(b),
+        (&[_], &[a1, a2], &[b1, b2]) => {
+            // Both triangles extend from the shared vertex. If they overlap,
+            // the edge across from that vertex of one of them intersects the
+            // other.
+            a.intersects_segment(&Segment::from_points([b1, b2]))
+                || b.intersects_segment(&Segment::from_points([a1, a2]))
+        }
+        (&[p, q], &[r_a], &[r_b]) => {
+            // Unless the triangles are coplanar, the planes they are in only
+            // meet along the shared edge. If they are, they overlap, if they
+            // are on the same side of it.
+            let normal = (q - p).cross(&(r_a - p));
+            if normal.magnitude() < distinct_min_distance * (q - p).magnitude()
+            {
+                // The triangle is degenerate at this scale, as can happen
+                // where a curve meets a line tangentially.
+                return false;
+            }
+            let normal = normal.normalize();
+
+            let is_coplanar =
+                (r_b - p).dot(&normal).abs() < distinct_min_distance;
+            let side = |r: Point<3>| (q - p).cross(&(r - p)).dot(&normal);
+
+            is_coplanar && side(r_a) * side(r_b) > Scalar::ZERO
+        }
+        // All vertices are shared, meaning the triangles are the same.
+        (&[_, _, _], [], []) => true,
+        // Vertices of one triangle are close to multiple vertices of the
+        // other. At this scale, the triangles are degenerate, and there's
+        // nothing to tell.
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Scalar, Triangle, Vector};
+
+    use crate::{
+        operations::{
+            build::{BuildRegion, BuildShell, BuildSketch},
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        topology::{Region, Shell, Sketch},
+        validation::{checks::ShellIsSelfIntersecting, ValidationCheck},
+        Core,
+    };
+
+    use super::triangles_intersect;
+
+    #[test]
+    fn shell_is_self_intersecting() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let valid = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        ShellIsSelfIntersecting::check_and_return_first_error(
+            &valid.shell,
+            &core.layers.geometry,
+        )?;
+
+        // Two tetrahedra that overlap, in one shell.
+        let invalid = Shell::from_vertices_and_indices(
+            [
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [0., 2., 0.],
+                [0., 0., 2.],
+                [0.5, 0.5, 0.5],
+                [2.5, 0.5, 0.5],
+                [0.5, 2.5, 0.5],
+                [0.5, 0.5, 2.5],
+            ],
+            [
+                [0, 2, 1],
+                [0, 1, 3],
+                [0, 3, 2],
+                [1, 2, 3],
+                [4, 6, 5],
+                [4, 5, 7],
+                [4, 7, 6],
+                [5, 6, 7],
+            ],
+            &mut core,
+        );
+        assert!(ShellIsSelfIntersecting::check_and_return_first_error(
+            &invalid,
+            &core.layers.geometry,
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn curved_faces_are_not_self_intersecting() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let region = Region::circle(
+            [0., 0.],
+            1.,
+            core.layers.topology.surfaces.space_2d(),
+            &mut core,
+        );
+        let bottom_surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([region], &mut core)
+            .sweep_sketch(
+                bottom_surface,
+                Vector::from([0., 0., 1.]),
+                &mut core,
+            );
+
+        for shell in solid.shells() {
+            ShellIsSelfIntersecting::check_and_return_first_error(
+                shell,
+                &core.layers.geometry,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn triangles_that_share_vertices() {
+        let intersect = |a: [[f64; 3]; 3], b: [[f64; 3]; 3]| {
+            triangles_intersect(
+                &Triangle::from(a),
+                &Triangle::from(b),
+                Scalar::from(1e-9),
+            )
+        };
+
+        let a = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]];
+
+        // Shared edge, at an angle.
+        assert!(!intersect(a, [[1., 0., 0.], [0., 0., 0.], [0., 0., 1.]]));
+
+        // Shared edge, coplanar on either side of it.
+        assert!(!intersect(a, [[1., 0., 0.], [0., 0., 0.], [0., -1., 0.]]));
+
+        // Shared edge, folded onto each other.
+        assert!(intersect(a, [[1., 0., 0.], [0., 0., 0.], [1., 1., 0.]]));
+
+        // Shared vertex, next to each other.
+        assert!(!intersect(a, [[0., 0., 0.], [0., -1., 0.], [1., -1., 1.]]));
+
+        // Shared vertex, one piercing the other.
+        assert!(intersect(
+            a,
+            [[0., 0., 0.], [0.5, 0.5, -1.], [0.5, 0.5, 1.]]
+        ));
+
+        // Shared vertex, overlapping in the same plane.
+        assert!(intersect(a, [[0., 0., 0.], [2., 1., 0.], [1., 2., 0.]]));
+    }
+}
@@ -0,0 +1,167 @@
+use fj_math::Winding;
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Cycle, Sketch},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// Exterior [`Cycle`] of a region in a [`Sketch`] has clockwise winding
+///
+/// Sketches are swept or extruded into solids, which relies on a consistent
+/// orientation of their regions. The exterior cycle of each region must have
+/// counter-clockwise winding.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "Exterior cycle within sketch region has clockwise winding\n\
+    Cycle: {cycle:#?}"
+)]
+pub struct ExteriorCycleHasClockwiseWinding {
+    /// The cycle with clockwise winding
+    pub cycle: Handle<Cycle>,
+}
+
+impl ValidationCheck<Sketch> for ExteriorCycleHasClockwiseWinding {
+    const NAME: &'static str = "ExteriorCycleHasClockwiseWinding";
+
+    fn check<'r>(
+        object: &'r Sketch,
+        geometry: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        object.regions().iter().filter_map(|region| {
+            let cycle = region.exterior();
+
+            (cycle.winding(geometry, object.surface()) == Winding::Cw).then(
+                || ExteriorCycleHasClockwiseWinding {
+                    cycle: cycle.clone(),
+                },
+            )
+        })
+    }
+}
+
+/// Interior [`Cycle`] of a region in a [`Sketch`] has counter-clockwise
+/// winding
+///
+/// The interior cycles of a region must have the opposite winding of its
+/// exterior cycle. Since that must be counter-clockwise (see
+/// [`ExteriorCycleHasClockwiseWinding`]), interior cycles must have clockwise
+/// winding.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "Interior cycle within sketch region has counter-clockwise winding\n\
+    Cycle: {cycle:#?}"
+)]
+pub struct InteriorCycleHasCounterClockwiseWinding {
+    /// The cycle with counter-clockwise winding
+    pub cycle: Handle<Cycle>,
+}
+
+impl ValidationCheck<Sketch> for InteriorCycleHasCounterClockwiseWinding {
+    const NAME: &'static str = "InteriorCycleHasCounterClockwiseWinding";
+
+    fn check<'r>(
+        object: &'r Sketch,
+        geometry: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        object.regions().iter().flat_map(move |region| {
+            region
+                .interiors()
+                .iter()
+                .filter(move |cycle| {
+                    cycle.winding(geometry, object.surface()) == Winding::Ccw
+                })
+                .map(|cycle| InteriorCycleHasCounterClockwiseWinding {
+                    cycle: cycle.clone(),
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            insert::Insert,
+            reverse::Reverse,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        topology::{Cycle, Region, Sketch},
+        validation::{
+            checks::{
+                ExteriorCycleHasClockwiseWinding,
+                InteriorCycleHasCounterClockwiseWinding,
+            },
+            ValidationCheck,
+        },
+        Core,
+    };
+
+    #[test]
+    fn exterior_cycle_has_clockwise_winding() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let valid = Sketch::circle([0., 0.], 1., &mut core);
+        ExteriorCycleHasClockwiseWinding::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = valid.update_region(
+            valid.regions().first(),
+            |region, core| {
+                [region
+                    .update_exterior(|cycle, core| cycle.reverse(core), core)]
+            },
+            &mut core,
+        );
+        ExteriorCycleHasClockwiseWinding::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn interior_cycle_has_counter_clockwise_winding() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        let region = Region::circle([0., 0.], 2., surface.clone(), &mut core)
+            .add_interiors(
+                [Cycle::circle([0., 0.], 1., surface.clone(), &mut core)
+                    .reverse(&mut core)],
+                &mut core,
+            )
+            .insert(&mut core);
+        let valid = Sketch::new(surface.clone(), vec![region]);
+        InteriorCycleHasCounterClockwiseWinding::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = valid.update_region(
+            valid.regions().first(),
+            |region, core| {
+                [region.update_interior(
+                    region.interiors().first(),
+                    |cycle, core| [cycle.reverse(core)],
+                    core,
+                )]
+            },
+            &mut core,
+        );
+        InteriorCycleHasCounterClockwiseWinding::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+use std::iter::repeat;
+
+use fj_math::Point;
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Solid, Vertex},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// A [`Solid`] contains [`Vertex`] objects that are coincident, but not
+/// identical
+///
+/// Vertices that are at the same position must be represented by the same
+/// object. Otherwise, the relation between the objects that reference them is
+/// not explicit.
+///
+/// What counts as the same position is defined by
+/// [`ValidationConfig::distinct_min_distance`].
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Solid` contains `Vertex` objects that are coincident but not identical\n\
+    Vertex 1: {vertex_a:#?} ({position_a:?})\n\
+    Vertex 2: {vertex_b:#?} ({position_b:?})"
+)]
+pub struct DistinctVerticesCoincide {
+    /// The first vertex
+    pub vertex_a: Handle<Vertex>,
+
+    /// The second vertex
+    pub vertex_b: Handle<Vertex>,
+
+    /// The position of the first vertex
+    pub position_a: Point<3>,
+
+    /// The position of the second vertex
+    pub position_b: Point<3>,
+}
+
+impl ValidationCheck<Solid> for DistinctVerticesCoincide {
+    const NAME: &'static str = "DistinctVerticesCoincide";
+
+    fn check<'r>(
+        object: &'r Solid,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let vertices = vertices_with_positions(object, geometry);
+        let mut errors = Vec::new();
+
+        // This is O(N^2) which isn't great, but we can't use a HashMap since we
+        // need to deal with float inaccuracies. Maybe we could use some smarter
+        // data-structure like an octree.
+        for (position_a, vertex_a) in &vertices {
+            for (position_b, vertex_b) in &vertices {
+                let vertices_are_identical = vertex_a.id() == vertex_b.id();
+                let too_close_to_be_distinct = position_a
+                    .distance_to(position_b)
+                    < config.distinct_min_distance;
+
+                if !vertices_are_identical && too_close_to_be_distinct {
+                    errors.push(DistinctVerticesCoincide {
+                        vertex_a: vertex_a.clone(),
+                        vertex_b: vertex_b.clone(),
+                        position_a: *position_a,
+                        position_b: *position_b,
+                    });
+                }
+            }
+        }
+
+        errors.into_iter()
+    }
+}
+
+/// A [`Solid`] contains a [`Vertex`] that is not at a single position
+///
+/// A vertex is referenced by multiple half-edges, each of which defines its
+/// position in terms of its own curve. All those positions must be the same.
+///
+/// What counts as the same position is defined by
+/// [`ValidationConfig::identical_max_distance`].
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Solid` contains `Vertex` objects that are identical but do not coincide\n\
+    Vertex 1: {vertex_a:#?} ({position_a:?})\n\
+    Vertex 2: {vertex_b:#?} ({position_b:?})"
+)]
+pub struct IdenticalVerticesNotCoincident {
+    /// The first vertex
+    pub vertex_a: Handle<Vertex>,
+
+    /// The second vertex
+    pub vertex_b: Handle<Vertex>,
+
+    /// The position of the first vertex
+    pub position_a: Point<3>,
+
+    /// The position of the second vertex
+    pub position_b: Point<3>,
+}
+
+impl ValidationCheck<Solid> for IdenticalVerticesNotCoincident {
+    const NAME: &'static str = "IdenticalVerticesNotCoincident";
+
+    fn check<'r>(
+        object: &'r Solid,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let vertices = vertices_with_positions(object, geometry);
+        let mut errors = Vec::new();
+
+        // This is O(N^2) which isn't great, but we can't use a HashMap since we
+        // need to deal with float inaccuracies. Maybe we could use some smarter
+        // data-structure like an octree.
+        for (position_a, vertex_a) in &vertices {
+            for (position_b, vertex_b) in &vertices {
+                let vertices_are_identical = vertex_a.id() == vertex_b.id();
+                let too_far_to_be_identical = position_a
+                    .distance_to(position_b)
+                    > config.identical_max_distance;
+
+                if vertices_are_identical && too_far_to_be_identical {
+                    errors.push(IdenticalVerticesNotCoincident {
+                        vertex_a: vertex_a.clone(),
+                        vertex_b: vertex_b.clone(),
+                        position_a: *position_a,
+                        position_b: *position_b,
+                    });
+                }
+            }
+        }
+
+        errors.into_iter()
+    }
+}
+
+/// Compute the position of the start vertex of every half-edge in the solid
+fn vertices_with_positions(
+    solid: &Solid,
+    geometry: &Geometry,
+) -> Vec<(Point<3>, Handle<Vertex>)> {
+    solid
+        .shells()
+        .iter()
+        .flat_map(|s| s.faces())
+        .flat_map(|face| {
+            face.region()
+                .all_cycles()
+                .flat_map(|cycle| cycle.half_edges().iter().cloned())
+                .zip(repeat(face.surface()))
+        })
+        .filter_map(|(h, s)| {
+            let Some(local_curve_geometry) =
+                geometry.of_curve(h.curve()).unwrap().local_on(s)
+            else {
+                // If the curve geometry has no local definition, there's
+                // nothing we can check.
+                return None;
+            };
+
+            Some((
+                geometry.of_surface(s).point_from_surface_coords(
+                    local_curve_geometry.path.point_from_path_coords(
+                        geometry
+                            .of_vertex(h.start_vertex())
+                            .unwrap()
+                            .local_on(h.curve())
+                            .unwrap()
+                            .position,
+                    ),
+                ),
+                h.start_vertex().clone(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Vector;
+
+    use crate::{
+        geometry::LocalVertexGeom,
+        operations::{build::BuildShell, insert::Insert},
+        topology::{Shell, Solid},
+        validation::{
+            checks::{
+                DistinctVerticesCoincide, IdenticalVerticesNotCoincident,
+            },
+            ValidationCheck,
+        },
+        Core,
+    };
+
+    #[test]
+    fn distinct_vertices_coincide() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        )
+        .shell
+        .insert(&mut core);
+        let valid = Solid::new([a.clone()]);
+        DistinctVerticesCoincide::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        // The first vertex of this tetrahedron is at the same position as the
+        // last vertex of the other one, but they are different objects.
+        let b = Shell::tetrahedron(
+            [[0., 0., 1.], [0., 1., 1.], [1., 0., 1.], [0., 0., 2.]],
+            &mut core,
+        )
+        .shell
+        .insert(&mut core);
+        let invalid = Solid::new([a, b]);
+        assert!(DistinctVerticesCoincide::check_and_return_first_error(
+            &invalid,
+            &core.layers.geometry,
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn identical_vertices_not_coincident() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let tetrahedron = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let half_edge = tetrahedron
+            .abc
+            .face
+            .region()
+            .exterior()
+            .half_edges()
+            .nth_circular(0)
+            .clone();
+        let solid = Solid::new([tetrahedron.shell.insert(&mut core)]);
+        IdenticalVerticesNotCoincident::check_and_return_first_error(
+            &solid,
+            &core.layers.geometry,
+        )?;
+
+        // Move the vertex along the curve of one of the half-edges that start
+        // there, but not the others.
+        let vertex = half_edge.start_vertex().clone();
+        let position = core
+            .layers
+            .geometry
+            .of_vertex(&vertex)
+            .unwrap()
+            .local_on(half_edge.curve())
+            .unwrap()
+            .position;
+        core.layers.geometry.define_vertex(
+            vertex,
+            half_edge.curve().clone(),
+            LocalVertexGeom {
+                position: position + Vector::from([0.5]),
+            },
+        );
+        assert!(
+            IdenticalVerticesNotCoincident::check_and_return_first_error(
+                &solid,
+                &core.layers.geometry,
+            )
+            .is_err()
+        );
+
+        Ok(())
+    }
+}
@@ -1,7 +1,11 @@
+use std::collections::BTreeMap;
+
 use fj_math::Scalar;
 
+use crate::algorithms::approx::Tolerance;
+
 /// Configuration required for the validation process
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ValidationConfig {
     /// Panic on first validation error, instead of storing it
     ///
@@ -16,6 +20,9 @@ pub struct ValidationConfig {
     /// error, to get the code that caused it into a stack trace. This is what
     /// happens, if this option is set to `true`.
     ///
+    /// Validation checks whose [`Severity`] is [`Severity::Warning`] never
+    /// cause a panic.
+    ///
     /// Defaults to `false`.
     pub panic_on_error: bool,
 
@@ -32,6 +39,58 @@ pub struct ValidationConfig {
     /// that distance is less than the one defined in this field, can not be
     /// considered identical.
     pub identical_max_distance: Scalar,
+
+    /// The tolerance used to approximate curved geometry
+    ///
+    /// Some validation checks, like the one for self-intersecting shells, work
+    /// with an approximation of the object they check. Their results are only
+    /// as accurate as that approximation.
+    pub approximation_tolerance: Tolerance,
+
+    /// Configuration of individual validation checks
+    ///
+    /// Maps the name of a validation check (see [`ValidationCheck::NAME`]) to
+    /// its configuration. Checks that have no entry here use the default
+    /// [`CheckConfig`].
+    ///
+    /// [`ValidationCheck::NAME`]: super::ValidationCheck::NAME
+    pub checks: BTreeMap<String, CheckConfig>,
+}
+
+impl ValidationConfig {
+    /// Access the configuration of a specific validation check
+    pub fn check(&self, name: &str) -> CheckConfig {
+        self.checks.get(name).copied().unwrap_or_default()
+    }
+
+    /// Access the configuration of a specific validation check mutably
+    ///
+    /// Inserts the default configuration for the check, if there is none yet.
+    pub fn check_mut(&mut self, name: &str) -> &mut CheckConfig {
+        self.checks.entry(name.to_string()).or_default()
+    }
+
+    /// Compute the configuration that a specific validation check runs with
+    ///
+    /// Replaces the global thresholds with those defined for the check, where
+    /// that is the case.
+    pub fn for_check(&self, name: &str) -> Self {
+        let check = self.check(name);
+
+        Self {
+            panic_on_error: self.panic_on_error,
+            distinct_min_distance: check
+                .distinct_min_distance
+                .unwrap_or(self.distinct_min_distance),
+            identical_max_distance: check
+                .identical_max_distance
+                .unwrap_or(self.identical_max_distance),
+            approximation_tolerance: check
+                .approximation_tolerance
+                .unwrap_or(self.approximation_tolerance),
+            checks: BTreeMap::new(),
+        }
+    }
 }
 
 impl Default for ValidationConfig {
@@ -45,6 +104,125 @@ impl Default for ValidationConfig {
             // false positives due to floating-point accuracy issues), we can
             // adjust it.
             identical_max_distance: Scalar::from_f64(5e-14),
+
+            approximation_tolerance: Tolerance::from_scalar(0.001)
+                .expect("Tolerance is positive"),
+
+            checks: BTreeMap::new(),
+        }
+    }
+}
+
+/// Configuration of a single validation check
+///
+/// See [`ValidationConfig::checks`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CheckConfig {
+    /// Whether the validation check runs at all
+    ///
+    /// Defaults to `true`.
+    pub enabled: bool,
+
+    /// How to treat the errors that the validation check produces
+    ///
+    /// Defaults to [`Severity::Error`].
+    pub severity: Severity,
+
+    /// Overrides [`ValidationConfig::distinct_min_distance`] for this check
+    pub distinct_min_distance: Option<Scalar>,
+
+    /// Overrides [`ValidationConfig::identical_max_distance`] for this check
+    pub identical_max_distance: Option<Scalar>,
+
+    /// Overrides [`ValidationConfig::approximation_tolerance`] for this check
+    pub approximation_tolerance: Option<Tolerance>,
+}
+
+impl Default for CheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            severity: Severity::default(),
+            distinct_min_distance: None,
+            identical_max_distance: None,
+            approximation_tolerance: None,
         }
     }
 }
+
+/// How to treat the errors that a validation check produces
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Severity {
+    /// Treat them as errors
+    ///
+    /// Errors are stored in the validation layer, and cause a panic, if they
+    /// are still unhandled when that is dropped.
+    #[default]
+    Error,
+
+    /// Treat them as warnings
+    ///
+    /// Warnings are stored in the validation layer separately from errors. They
+    /// are available for inspection, but are otherwise ignored.
+    Warning,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        operations::{build::BuildFace, insert::Insert},
+        topology::Face,
+        validation::{checks::FaceHasNoBoundary, ValidationCheck},
+        Core,
+    };
+
+    use super::{Severity, ValidationConfig};
+
+    #[test]
+    fn for_check_applies_overrides_of_that_check() {
+        let mut config = ValidationConfig::default();
+
+        let check = config.check_mut("ShellIsSelfIntersecting");
+        check.severity = Severity::Warning;
+        check.distinct_min_distance = Some(Scalar::from(1e-3));
+
+        let overridden = config.for_check("ShellIsSelfIntersecting");
+        assert_eq!(overridden.distinct_min_distance, Scalar::from(1e-3));
+        assert_eq!(
+            overridden.identical_max_distance,
+            config.identical_max_distance,
+        );
+
+        let other = config.for_check("ShellIsNotClosed");
+        assert_eq!(other.distinct_min_distance, config.distinct_min_distance);
+        assert_eq!(config.check("ShellIsNotClosed").severity, Severity::Error);
+    }
+
+    #[test]
+    fn checks_can_be_downgraded_to_warnings() {
+        let mut config = ValidationConfig::default();
+        config.check_mut(FaceHasNoBoundary::NAME).severity = Severity::Warning;
+        let mut core = Core::with_validation_config(config);
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let _face = Face::unbound(surface, &mut core).insert(&mut core);
+
+        assert!(core.layers.validation.take_errors().is_ok());
+        assert_eq!(core.layers.validation.take_warnings().len(), 1);
+    }
+
+    #[test]
+    fn checks_can_be_disabled() {
+        let mut config = ValidationConfig::default();
+        config.check_mut(FaceHasNoBoundary::NAME).enabled = false;
+        let mut core = Core::with_validation_config(config);
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let _face = Face::unbound(surface, &mut core).insert(&mut core);
+
+        assert!(core.layers.validation.take_errors().is_ok());
+        assert!(core.layers.validation.take_warnings().is_empty());
+    }
+}
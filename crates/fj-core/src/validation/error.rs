@@ -1,14 +1,24 @@
 use std::{convert::Infallible, fmt};
 
+use fj_math::{Point, Scalar};
+
 use crate::{
-    topology::{Cycle, Face, HalfEdge, Region, Shell},
-    validate::{SketchValidationError, SolidValidationError},
+    geometry::Geometry,
+    storage::Handle,
+    topology::{
+        AnyObject, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Stored,
+    },
+    validation::ValidationCheck,
 };
 
 use super::checks::{
     AdjacentHalfEdgesNotConnected, CoincidentHalfEdgesAreNotSiblings,
-    CurveGeometryMismatch, FaceHasNoBoundary, HalfEdgeHasNoSibling,
+    CurveGeometryMismatch, DistinctVerticesCoincide,
+    ExteriorCycleHasClockwiseWinding, FaceHasNoBoundary, HalfEdgeHasNoSibling,
+    IdenticalVerticesNotCoincident, InteriorCycleHasCounterClockwiseWinding,
     InteriorCycleHasInvalidWinding, MultipleReferencesToObject,
+    ShellIsNotClosed, ShellIsNotConsistentlyOriented, ShellIsNotManifold,
+    ShellIsSelfIntersecting,
 };
 
 /// An error that can occur during a validation
@@ -28,6 +38,14 @@ pub enum ValidationError {
     #[error(transparent)]
     CurveGeometryMismatch(#[from] CurveGeometryMismatch),
 
+    /// Distinct vertices coincide
+    #[error(transparent)]
+    DistinctVerticesCoincide(#[from] DistinctVerticesCoincide),
+
+    /// Exterior cycle has clockwise winding
+    #[error(transparent)]
+    ExteriorCycleHasClockwiseWinding(#[from] ExteriorCycleHasClockwiseWinding),
+
     /// Face has no boundary
     #[error(transparent)]
     FaceHasNoBoundary(#[from] FaceHasNoBoundary),
@@ -36,6 +54,16 @@ pub enum ValidationError {
     #[error(transparent)]
     HalfEdgeHasNoSibling(#[from] HalfEdgeHasNoSibling),
 
+    /// Identical vertices are not coincident
+    #[error(transparent)]
+    IdenticalVerticesNotCoincident(#[from] IdenticalVerticesNotCoincident),
+
+    /// Interior cycle has counter-clockwise winding
+    #[error(transparent)]
+    InteriorCycleHasCounterClockwiseWinding(
+        #[from] InteriorCycleHasCounterClockwiseWinding,
+    ),
+
     /// Interior cycle has invalid winding
     #[error(transparent)]
     InteriorCycleHasInvalidWinding(#[from] InteriorCycleHasInvalidWinding),
@@ -62,13 +90,320 @@ pub enum ValidationError {
         #[from] MultipleReferencesToObject<Region, Face>,
     ),
 
-    /// `Solid` validation error
-    #[error("`Solid` validation error")]
-    Solid(#[from] SolidValidationError),
+    /// Shell is not closed
+    #[error(transparent)]
+    ShellIsNotClosed(#[from] ShellIsNotClosed),
+
+    /// Shell is not consistently oriented
+    #[error(transparent)]
+    ShellIsNotConsistentlyOriented(#[from] ShellIsNotConsistentlyOriented),
+
+    /// Shell is not 2-manifold
+    #[error(transparent)]
+    ShellIsNotManifold(#[from] ShellIsNotManifold),
+
+    /// Shell intersects itself
+    #[error(transparent)]
+    ShellIsSelfIntersecting(#[from] ShellIsSelfIntersecting),
+}
+
+/// Implement the methods that name the check behind each validation error
+///
+/// Takes one entry per variant of [`ValidationError`], consisting of the
+/// variant, its check, and the type of object that check validates.
+macro_rules! check_names {
+    ($($variant:ident: $check:ty, $object:ty;)*) => {
+        const NUM_CHECKS: usize = [$(stringify!($variant)),*].len();
+
+        impl ValidationError {
+            /// The names of all validation checks
+            ///
+            /// See [`ValidationCheck::NAME`].
+            pub const CHECK_NAMES: [&'static str; NUM_CHECKS] =
+                [$(check_name::<$check, $object>()),*];
+
+            /// The name of the validation check that produced this error
+            ///
+            /// See [`ValidationCheck::NAME`].
+            pub fn check_name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => check_name::<$check, $object>(),)*
+                }
+            }
+        }
+    };
+}
+
+check_names! {
+    AdjacentHalfEdgesNotConnected: AdjacentHalfEdgesNotConnected, Face;
+    CoincidentHalfEdgesAreNotSiblings:
+        CoincidentHalfEdgesAreNotSiblings, Shell;
+    CurveGeometryMismatch: CurveGeometryMismatch, Shell;
+    DistinctVerticesCoincide: DistinctVerticesCoincide, Solid;
+    ExteriorCycleHasClockwiseWinding: ExteriorCycleHasClockwiseWinding, Sketch;
+    FaceHasNoBoundary: FaceHasNoBoundary, Face;
+    HalfEdgeHasNoSibling: HalfEdgeHasNoSibling, Shell;
+    IdenticalVerticesNotCoincident: IdenticalVerticesNotCoincident, Solid;
+    InteriorCycleHasCounterClockwiseWinding:
+        InteriorCycleHasCounterClockwiseWinding, Sketch;
+    InteriorCycleHasInvalidWinding: InteriorCycleHasInvalidWinding, Face;
+    MultipleReferencesToCycle:
+        MultipleReferencesToObject<Cycle, Region>, Solid;
+    MultipleReferencesToFace: MultipleReferencesToObject<Face, Shell>, Solid;
+    MultipleReferencesToHalfEdge:
+        MultipleReferencesToObject<HalfEdge, Cycle>, Solid;
+    MultipleReferencesToRegion:
+        MultipleReferencesToObject<Region, Face>, Solid;
+    ShellIsNotClosed: ShellIsNotClosed, Shell;
+    ShellIsNotConsistentlyOriented: ShellIsNotConsistentlyOriented, Shell;
+    ShellIsNotManifold: ShellIsNotManifold, Shell;
+    ShellIsSelfIntersecting: ShellIsSelfIntersecting, Shell;
+}
+
+const fn check_name<C, O>() -> &'static str
+where
+    C: ValidationCheck<O>,
+{
+    C::NAME
+}
+
+impl ValidationError {
+    /// The objects involved in this error
+    ///
+    /// This doesn't include the object that was validated, unless it is also
+    /// referenced by the error itself.
+    pub fn objects(&self) -> Vec<AnyObject<Stored>> {
+        match self {
+            Self::AdjacentHalfEdgesNotConnected(err) => err
+                .unconnected_half_edges
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+            Self::CoincidentHalfEdgesAreNotSiblings(err) => {
+                let half_edges = [&err.half_edge_a, &err.half_edge_b]
+                    .into_iter()
+                    .cloned()
+                    .map(Into::into);
+                let curves = err.curves.iter().cloned().map(Into::into);
+                let vertices = err
+                    .vertices
+                    .iter()
+                    .flat_map(|vertices| vertices.inner.iter().cloned())
+                    .map(Into::into);
+
+                half_edges.chain(curves).chain(vertices).collect()
+            }
+            Self::CurveGeometryMismatch(err) => vec![err.curve.clone().into()],
+            Self::DistinctVerticesCoincide(err) => {
+                vec![err.vertex_a.clone().into(), err.vertex_b.clone().into()]
+            }
+            Self::ExteriorCycleHasClockwiseWinding(err) => {
+                vec![err.cycle.clone().into()]
+            }
+            Self::FaceHasNoBoundary(_) => Vec::new(),
+            Self::HalfEdgeHasNoSibling(err) => {
+                vec![err.half_edge.clone().into()]
+            }
+            Self::IdenticalVerticesNotCoincident(err) => {
+                vec![err.vertex_a.clone().into(), err.vertex_b.clone().into()]
+            }
+            Self::InteriorCycleHasCounterClockwiseWinding(err) => {
+                vec![err.cycle.clone().into()]
+            }
+            Self::InteriorCycleHasInvalidWinding(err) => {
+                vec![err.interior_cycle.clone().into()]
+            }
+            Self::MultipleReferencesToCycle(err) => {
+                multiple_references_objects(err)
+            }
+            Self::MultipleReferencesToFace(err) => {
+                multiple_references_objects(err)
+            }
+            Self::MultipleReferencesToHalfEdge(err) => {
+                multiple_references_objects(err)
+            }
+            Self::MultipleReferencesToRegion(err) => {
+                multiple_references_objects(err)
+            }
+            Self::ShellIsNotClosed(err) => {
+                vec![err.half_edge.clone().into(), err.face.clone().into()]
+            }
+            Self::ShellIsNotConsistentlyOriented(err) => vec![
+                err.half_edge_a.clone().into(),
+                err.half_edge_b.clone().into(),
+                err.face_a.clone().into(),
+                err.face_b.clone().into(),
+            ],
+            Self::ShellIsNotManifold(err) => {
+                let curve = [err.curve.clone().into()];
+                let half_edges = err.half_edges.iter().cloned().map(Into::into);
+                let faces = err.faces.iter().cloned().map(Into::into);
+
+                curve.into_iter().chain(half_edges).chain(faces).collect()
+            }
+            Self::ShellIsSelfIntersecting(err) => {
+                vec![err.face_a.clone().into(), err.face_b.clone().into()]
+            }
+        }
+    }
+
+    /// The positions in 3D space, that are relevant to this error
+    ///
+    /// Positions that are defined locally, on a curve or surface, are converted
+    /// into 3D space using the provided geometry. Positions whose geometry is
+    /// not defined in 3D space, like those of a sketch, are left out.
+    pub fn positions(&self, geometry: &Geometry) -> Vec<Point<3>> {
+        match self {
+            Self::AdjacentHalfEdgesNotConnected(err) => {
+                let Some(surface) = geometry.try_of_surface(&err.surface)
+                else {
+                    return Vec::new();
+                };
+
+                [
+                    err.end_pos_of_first_half_edge,
+                    err.start_pos_of_second_half_edge,
+                ]
+                .map(|point| surface.point_from_surface_coords(point))
+                .to_vec()
+            }
+            Self::CoincidentHalfEdgesAreNotSiblings(err) => {
+                [&err.half_edge_a, &err.half_edge_b]
+                    .into_iter()
+                    .filter_map(|half_edge| {
+                        start_position(half_edge, None, geometry)
+                    })
+                    .collect()
+            }
+            Self::CurveGeometryMismatch(err) => vec![err.point_a, err.point_b],
+            Self::DistinctVerticesCoincide(err) => {
+                vec![err.position_a, err.position_b]
+            }
+            Self::ExteriorCycleHasClockwiseWinding(err) => {
+                start_positions(&err.cycle, geometry)
+            }
+            Self::HalfEdgeHasNoSibling(err) => {
+                start_position(&err.half_edge, None, geometry)
+                    .into_iter()
+                    .collect()
+            }
+            Self::IdenticalVerticesNotCoincident(err) => {
+                vec![err.position_a, err.position_b]
+            }
+            Self::InteriorCycleHasCounterClockwiseWinding(err) => {
+                start_positions(&err.cycle, geometry)
+            }
+            Self::InteriorCycleHasInvalidWinding(err) => {
+                start_positions(&err.interior_cycle, geometry)
+            }
+            Self::ShellIsNotClosed(err) => {
+                start_position(&err.half_edge, Some(&err.face), geometry)
+                    .into_iter()
+                    .collect()
+            }
+            Self::ShellIsNotConsistentlyOriented(err) => [
+                (&err.half_edge_a, &err.face_a),
+                (&err.half_edge_b, &err.face_b),
+            ]
+            .into_iter()
+            .filter_map(|(half_edge, face)| {
+                start_position(half_edge, Some(face), geometry)
+            })
+            .collect(),
+            Self::ShellIsNotManifold(err) => err
+                .half_edges
+                .iter()
+                .zip(&err.faces)
+                .filter_map(|(half_edge, face)| {
+                    start_position(half_edge, Some(face), geometry)
+                })
+                .collect(),
+            Self::ShellIsSelfIntersecting(err) => [&err.face_a, &err.face_b]
+                .into_iter()
+                .flat_map(|face| {
+                    face.region().exterior().half_edges().iter().filter_map(
+                        |half_edge| {
+                            start_position(half_edge, Some(face), geometry)
+                        },
+                    )
+                })
+                .collect(),
+            Self::FaceHasNoBoundary(_)
+            | Self::MultipleReferencesToCycle(_)
+            | Self::MultipleReferencesToFace(_)
+            | Self::MultipleReferencesToHalfEdge(_)
+            | Self::MultipleReferencesToRegion(_) => Vec::new(),
+        }
+    }
+
+    /// The distances that caused this error
+    pub fn distances(&self) -> Vec<Scalar> {
+        match self {
+            Self::AdjacentHalfEdgesNotConnected(err) => {
+                vec![err.distance_between_positions]
+            }
+            Self::CurveGeometryMismatch(err) => vec![err.distance],
+            Self::DistinctVerticesCoincide(err) => {
+                vec![err.position_a.distance_to(&err.position_b)]
+            }
+            Self::IdenticalVerticesNotCoincident(err) => {
+                vec![err.position_a.distance_to(&err.position_b)]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// The position of a half-edge's start vertex in 3D space
+///
+/// The position is converted through the surface of the provided face, if the
+/// curve of the half-edge is defined there, or through any other surface that
+/// curve is defined on. Returns `None`, if none of those surfaces is located in
+/// 3D space.
+fn start_position(
+    half_edge: &Handle<HalfEdge>,
+    face: Option<&Handle<Face>>,
+    geometry: &Geometry,
+) -> Option<Point<3>> {
+    let position = geometry
+        .of_vertex(half_edge.start_vertex())?
+        .local_on(half_edge.curve())?
+        .position;
+    let curve = geometry.of_curve(half_edge.curve())?;
+
+    face.map(|face| face.surface())
+        .into_iter()
+        .chain(curve.definitions.keys())
+        .find_map(|surface| {
+            let path = &curve.local_on(surface)?.path;
+            let surface = geometry.try_of_surface(surface)?;
+
+            Some(surface.point_from_surface_coords(
+                path.point_from_path_coords(position),
+            ))
+        })
+}
+
+fn start_positions(cycle: &Cycle, geometry: &Geometry) -> Vec<Point<3>> {
+    cycle
+        .half_edges()
+        .iter()
+        .filter_map(|half_edge| start_position(half_edge, None, geometry))
+        .collect()
+}
+
+fn multiple_references_objects<T, U>(
+    err: &MultipleReferencesToObject<T, U>,
+) -> Vec<AnyObject<Stored>>
+where
+    Handle<T>: Into<AnyObject<Stored>>,
+    Handle<U>: Into<AnyObject<Stored>>,
+{
+    let object = [err.object().clone().into()];
+    let referenced_by = err.referenced_by().iter().cloned().map(Into::into);
 
-    /// `Sketch` validation error
-    #[error("`Sketch` validation error")]
-    Sketch(#[from] SketchValidationError),
+    object.into_iter().chain(referenced_by).collect()
 }
 
 impl From<Infallible> for ValidationError {
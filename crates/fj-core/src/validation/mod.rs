@@ -28,7 +28,7 @@ mod validation_check;
 pub mod checks;
 
 pub use self::{
-    config::ValidationConfig,
+    config::{CheckConfig, Severity, ValidationConfig},
     error::{ValidationError, ValidationErrors},
     validation::Validation,
     validation_check::ValidationCheck,
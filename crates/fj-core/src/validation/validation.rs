@@ -10,6 +10,11 @@ pub struct Validation {
     /// All unhandled validation errors
     pub errors: HashMap<ObjectId, ValidationError>,
 
+    /// All validation errors of checks that are configured as warnings
+    ///
+    /// See [`Severity::Warning`](super::Severity::Warning).
+    pub warnings: HashMap<ObjectId, ValidationError>,
+
     /// Validation configuration for the validation service
     pub config: ValidationConfig,
 }
@@ -18,7 +23,12 @@ impl Validation {
     /// Construct an instance of `Validation`, using the provided configuration
     pub fn with_validation_config(config: ValidationConfig) -> Self {
         let errors = HashMap::new();
-        Self { errors, config }
+        let warnings = HashMap::new();
+        Self {
+            errors,
+            warnings,
+            config,
+        }
     }
 }
 
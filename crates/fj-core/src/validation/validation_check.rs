@@ -9,6 +9,15 @@ use super::ValidationConfig;
 /// This trait is implemented once per validation check and object it applies
 /// to. `Self` is the object, while `T` identifies the validation check.
 pub trait ValidationCheck<T>: Sized {
+    /// The name of the validation check
+    ///
+    /// This is the same as the name of the respective variant of
+    /// [`ValidationError`], and is used to refer to the check in
+    /// [`ValidationConfig::checks`].
+    ///
+    /// [`ValidationError`]: super::ValidationError
+    const NAME: &'static str;
+
     /// Run the validation check on the implementing object
     fn check<'r>(
         object: &'r T,
@@ -0,0 +1,201 @@
+//! Solid bodies of a model
+//!
+//! See [`Bodies`].
+
+use fj_core::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        bounding_volume::BoundingVolume,
+        classify::{ClassifyPoint, PointClassification},
+    },
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Shell, Solid},
+};
+use fj_math::Scalar;
+
+/// A solid body, bounded by an outer shell and the shells of any voids in it
+///
+/// This is what file formats usually consider a solid. A [`Solid`] is more
+/// general, and can be made up of multiple bodies.
+#[derive(Clone, Debug)]
+pub struct Body {
+    /// The shell that bounds the body from the outside
+    pub shell: Handle<Shell>,
+
+    /// The shells that bound the voids within the body
+    ///
+    /// Like the faces of the outer shell, the faces of these shells point away
+    /// from the body, into the voids.
+    pub voids: Vec<Handle<Shell>>,
+}
+
+impl Body {
+    /// Iterate over all shells of the body, starting with the outer one
+    pub fn shells(&self) -> impl Iterator<Item = &Handle<Shell>> {
+        [&self.shell].into_iter().chain(&self.voids)
+    }
+}
+
+/// Split a model into the solid bodies that it is made up of
+pub trait Bodies {
+    /// Compute the bodies of the model
+    fn bodies(&self, geometry: &Geometry) -> Vec<Body>;
+}
+
+impl Bodies for Solid {
+    /// Compute the bodies of the solid
+    ///
+    /// A shell that is contained in an odd number of other shells bounds a
+    /// void within the innermost of those. All other shells bound bodies.
+    fn bodies(&self, geometry: &Geometry) -> Vec<Body> {
+        let shells = self.shells().iter().collect::<Vec<_>>();
+
+        // Whether shells are nested is only a question of which side of a
+        // shell the other shells are on. Shells don't intersect, so this
+        // doesn't need to be precise, as long as it's not too coarse to tell.
+        let tolerance = self.aabb(geometry).and_then(|aabb| {
+            let min_extent = aabb
+                .size()
+                .components
+                .into_iter()
+                .filter(|&extent| extent > Scalar::ZERO)
+                .reduce(Scalar::min)?;
+            Tolerance::from_scalar(min_extent / 1000.).ok()
+        });
+
+        // The indices of the shells that contain each shell.
+        let containers = shells
+            .iter()
+            .map(|&shell| {
+                let Some(tolerance) = tolerance else {
+                    return Vec::new();
+                };
+
+                // Any point on the shell will do, as it can't be on another
+                // shell.
+                let Some(point) = shell
+                    .approx(tolerance, geometry)
+                    .into_iter()
+                    .flat_map(|face| face.points())
+                    .map(|point| point.global_form)
+                    .next()
+                else {
+                    return Vec::new();
+                };
+
+                shells
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &other)| {
+                        other != shell
+                            && other
+                                .aabb(geometry)
+                                .is_some_and(|aabb| aabb.contains(point))
+                            && other.classify_point(point, tolerance, geometry)
+                                == PointClassification::Inside
+                    })
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut bodies = shells
+            .iter()
+            .zip(&containers)
+            .map(|(&shell, outer)| {
+                let is_body = outer.len() % 2 == 0;
+                is_body.then(|| Body {
+                    shell: shell.clone(),
+                    voids: Vec::new(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for (&shell, outer) in shells.iter().zip(&containers) {
+            let depth = outer.len();
+            if depth % 2 == 0 {
+                continue;
+            }
+
+            let innermost =
+                outer.iter().find(|&&i| containers[i].len() == depth - 1);
+            if let Some(Some(body)) = innermost.map(|&i| &mut bodies[i]) {
+                body.voids.push(shell.clone());
+            }
+        }
+
+        bodies.into_iter().flatten().collect()
+    }
+}
+
+impl Bodies for Handle<Shell> {
+    fn bodies(&self, _: &Geometry) -> Vec<Body> {
+        vec![Body {
+            shell: self.clone(),
+            voids: Vec::new(),
+        }]
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use fj_core::{
+        operations::{build::BuildShell, insert::Insert},
+        storage::Handle,
+        topology::{Shell, Solid},
+        Core,
+    };
+    use fj_math::{Point, Vector};
+
+    use super::Bodies;
+
+    #[test]
+    fn nested_shells() {
+        let mut core = Core::new();
+
+        let outer = tetrahedron([0., 0., 0.], 16., false, &mut core);
+        let void = tetrahedron([1., 1., 1.], 8., true, &mut core);
+        let island = tetrahedron([2., 2., 2.], 1., false, &mut core);
+        let separate = tetrahedron([20., 0., 0.], 1., false, &mut core);
+
+        let solid = Solid::new([
+            outer.clone(),
+            void.clone(),
+            island.clone(),
+            separate.clone(),
+        ]);
+        let bodies = solid.bodies(&core.layers.geometry);
+
+        let voids_of = |shell: &Handle<Shell>| {
+            bodies
+                .iter()
+                .find(|body| body.shell == *shell)
+                .map(|body| body.voids.clone())
+        };
+
+        assert_eq!(bodies.len(), 3);
+        assert_eq!(voids_of(&outer), Some(vec![void]));
+        assert_eq!(voids_of(&island), Some(vec![]));
+        assert_eq!(voids_of(&separate), Some(vec![]));
+    }
+
+    /// Build a tetrahedron, inverted to bound a void, if requested
+    pub(crate) fn tetrahedron(
+        origin: impl Into<Point<3>>,
+        size: f64,
+        inverted: bool,
+        core: &mut Core,
+    ) -> Handle<Shell> {
+        let origin = origin.into();
+        let [a, mut b, mut c, d] =
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]]
+                .map(|point| origin + Vector::from(point) * size);
+
+        if inverted {
+            std::mem::swap(&mut b, &mut c);
+        }
+
+        Shell::tetrahedron([a, b, c, d], core).shell.insert(core)
+    }
+}
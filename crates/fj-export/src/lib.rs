@@ -8,17 +8,68 @@
 //!
 //! [Fornjot]: https://www.fornjot.app/
 
-use std::{
-    fs::File,
-    io::{Seek, Write},
-    path::Path,
-};
+mod bodies;
+mod step;
+mod three_mf;
+
+use std::{ffi::OsStr, fs::File, io::Write, path::Path, slice};
 
 use thiserror::Error;
 
+use fj_core::{
+    algorithms::{approx::Tolerance, triangulate::Triangulate},
+    storage::Handle,
+    topology::Shell,
+    Core,
+};
 use fj_interop::Mesh;
 use fj_math::{Point, Triangle};
 
+pub use self::{
+    bodies::{Bodies, Body},
+    step::export_step,
+    three_mf::{export_3mf, export_3mf_objects},
+};
+
+/// Export the provided model to the file at the given path.
+///
+/// This function will create a file if it does not exist, and will truncate it if it does.
+///
+/// In addition to the file types that [`export`] supports, this supports STEP
+/// files, which describe the exact geometry of the model. For all other file
+/// types, the model is triangulated, as defined by `tolerance`. 3MF files
+/// contain a separate object for every body of the model (see [`Bodies`]).
+pub fn export_model(
+    model: &impl Bodies,
+    tolerance: Tolerance,
+    core: &mut Core,
+    path: &Path,
+) -> Result<(), Error> {
+    match path.extension() {
+        Some(extension) if is_step(extension) => {
+            let mut file = File::create(path)?;
+            export_step(model, &core.layers.geometry, &mut file)
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
+            let meshes = model
+                .bodies(&core.layers.geometry)
+                .iter()
+                .map(|body| triangulate(body.shells(), tolerance, core))
+                .collect::<Vec<_>>();
+            three_mf::check_meshes(&meshes)?;
+
+            let mut file = File::create(path)?;
+            export_3mf_objects(&meshes, &mut file)
+        }
+        _ => {
+            let bodies = model.bodies(&core.layers.geometry);
+            let shells = bodies.iter().flat_map(Body::shells);
+
+            export(&triangulate(shells, tolerance, core), path)
+        }
+    }
+}
+
 /// Export the provided mesh to the file at the given path.
 ///
 /// This function will create a file if it does not exist, and will truncate it if it does.
@@ -28,6 +79,8 @@ use fj_math::{Point, Triangle};
 pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     match path.extension() {
         Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
+            three_mf::check_meshes(slice::from_ref(mesh))?;
+
             let mut file = File::create(path)?;
             export_3mf(mesh, &mut file)
         }
@@ -46,40 +99,21 @@ pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
     }
 }
 
-/// Export the provided mesh to the provided writer in the 3MF format.
-pub fn export_3mf(
-    mesh: &Mesh<Point<3>>,
-    write: impl Write + Seek,
-) -> Result<(), Error> {
-    let vertices = mesh
-        .vertices()
-        .map(|point| threemf::model::Vertex {
-            x: point.x.into_f64(),
-            y: point.y.into_f64(),
-            z: point.z.into_f64(),
-        })
-        .collect();
-
-    let indices: Vec<_> = mesh.indices().collect();
-    let triangles = indices
-        .chunks(3)
-        .map(|triangle| threemf::model::Triangle {
-            v1: triangle[0] as usize,
-            v2: triangle[1] as usize,
-            v3: triangle[2] as usize,
-        })
-        .collect();
-
-    let mesh = threemf::Mesh {
-        vertices: threemf::model::Vertices { vertex: vertices },
-        triangles: threemf::model::Triangles {
-            triangle: triangles,
-        },
-    };
-
-    threemf::write(write, mesh)?;
+fn triangulate<'r>(
+    shells: impl IntoIterator<Item = &'r Handle<Shell>>,
+    tolerance: Tolerance,
+    core: &mut Core,
+) -> Mesh<Point<3>> {
+    let mut mesh = Mesh::new();
+    for shell in shells {
+        (&**shell, tolerance).triangulate_into_mesh(&mut mesh, core);
+    }
+    mesh
+}
 
-    Ok(())
+fn is_step(extension: &OsStr) -> bool {
+    let extension = extension.to_ascii_uppercase();
+    extension == "STEP" || extension == "STP"
 }
 
 /// Export the provided mesh to the provided writer in the STL format.
@@ -199,11 +233,57 @@ pub enum Error {
     #[error("maximum triangle count exceeded")]
     InvalidTriangleCount,
 
-    /// Threemf error whilst exporting to 3MF file
-    #[error("threemf error whilst exporting to 3MF file")]
-    ThreeMF(#[from] threemf::Error),
+    /// ZIP error whilst exporting to 3MF file (3MF files are ZIP files)
+    #[error("zip error whilst exporting to 3MF file")]
+    ThreeMF(#[from] zip::result::ZipError),
 
     /// OBJ exporter error whilst exporting to OBJ file
     #[error("obj error whilst exporting to OBJ file")]
     OBJ,
+
+    /// Mesh without triangles, which can't be exported
+    #[error("can't export mesh without triangles")]
+    EmptyMesh,
+
+    /// Geometry that can't be represented in the STEP file
+    #[error("geometry can't be exported to STEP: {0}")]
+    UnsupportedGeometry(&'static str),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use fj_core::{
+        algorithms::approx::Tolerance,
+        operations::insert::Insert,
+        topology::{Shell, Solid},
+        Core,
+    };
+    use fj_interop::Mesh;
+
+    use crate::{export, export_model, Error};
+
+    #[test]
+    fn failed_3mf_export_leaves_no_file() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let path = env::temp_dir()
+            .join(format!("fj-export-{}-empty.3mf", std::process::id()));
+
+        assert!(matches!(export(&Mesh::new(), &path), Err(Error::EmptyMesh)));
+        assert!(!path.exists());
+
+        // A shell without faces becomes a body with an empty mesh.
+        let shell = Shell::new([]).insert(&mut core);
+        let solid = Solid::new([shell]);
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        assert!(matches!(
+            export_model(&solid, tolerance, &mut core, &path),
+            Err(Error::EmptyMesh)
+        ));
+        assert!(!path.exists());
+
+        let _ = fs::remove_file(path);
+        Ok(())
+    }
 }
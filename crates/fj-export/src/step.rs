@@ -0,0 +1,1129 @@
+//! STEP export
+//!
+//! See [`export_step`].
+
+use std::{collections::BTreeMap, io::Write};
+
+use fj_core::{
+    geometry::{Geometry, GlobalPath, SurfaceGeom, SurfacePath, SweptCurve},
+    storage::Handle,
+    topology::{Curve, Cycle, Face, Handedness, Shell, Vertex},
+};
+use fj_math::{Circle, Line, NurbsCurve, NurbsSurface, Point, Scalar, Vector};
+
+use crate::{Bodies, Error};
+
+/// Export the provided model to the provided writer in the STEP format
+///
+/// Writes an AP214 file that describes the model as a boundary representation.
+/// Every body of the model (see [`Bodies`]) becomes a separate manifold solid,
+/// with any voids within it. Planes, cylinders, cones, spheres, tori, and NURBS
+/// surfaces, as well as lines, circles, and NURBS curves, are written as exact
+/// geometry.
+///
+/// Returns [`Error::UnsupportedGeometry`], if the model contains geometry that
+/// has no exact STEP representation yet, like a circle on a curved surface.
+pub fn export_step(
+    model: &impl Bodies,
+    geometry: &Geometry,
+    write: impl Write,
+) -> Result<(), Error> {
+    let mut step = StepWriter::default();
+
+    let mut breps = Vec::new();
+    for body in model.bodies(geometry) {
+        let shell = step.shell(&body.shell, false, geometry)?;
+
+        if body.voids.is_empty() {
+            breps.push(step.add(format!("MANIFOLD_SOLID_BREP('',#{shell})")));
+            continue;
+        }
+
+        let mut voids = Vec::new();
+        for void in &body.voids {
+            // STEP expects the shell of a void to face outwards, like that of
+            // a body, and reverses it. Ours faces into the void already.
+            let shell = step.shell(void, true, geometry)?;
+            voids.push(
+                step.add(format!("ORIENTED_CLOSED_SHELL('',*,#{shell},.F.)")),
+            );
+        }
+
+        breps.push(
+            step.add(format!("BREP_WITH_VOIDS('',#{shell},{})", list(&voids))),
+        );
+    }
+
+    step.product(breps);
+    step.write(write)?;
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct StepWriter {
+    entities: Vec<String>,
+    vertices: BTreeMap<Handle<Vertex>, usize>,
+    edges: BTreeMap<(Handle<Curve>, [Handle<Vertex>; 2]), Edge>,
+}
+
+impl StepWriter {
+    /// Add an entity and return its instance id
+    fn add(&mut self, entity: String) -> usize {
+        self.entities.push(entity);
+        self.entities.len()
+    }
+
+    /// Add a closed shell and return its id
+    ///
+    /// Reverses the orientation of all faces, if `reversed` is `true`.
+    fn shell(
+        &mut self,
+        shell: &Shell,
+        reversed: bool,
+        geometry: &Geometry,
+    ) -> Result<usize, Error> {
+        let mut faces = Vec::new();
+        for face in shell.faces() {
+            faces.push(self.face(face, reversed, geometry)?);
+        }
+
+        Ok(self.add(format!("CLOSED_SHELL('',{})", list(&faces))))
+    }
+
+    fn face(
+        &mut self,
+        face: &Face,
+        reversed: bool,
+        geometry: &Geometry,
+    ) -> Result<usize, Error> {
+        let surface = geometry.of_surface(face.surface());
+        let (surface_id, normal_is_natural) = self.surface(surface);
+
+        let mut bounds = Vec::new();
+        for (i, cycle) in face.region().all_cycles().enumerate() {
+            let edge_loop = self.edge_loop(cycle, face, geometry)?;
+            let bound = if i == 0 {
+                "FACE_OUTER_BOUND"
+            } else {
+                "FACE_BOUND"
+            };
+            bounds.push(self.add(format!(
+                "{bound}('',#{edge_loop},{})",
+                boolean(!reversed),
+            )));
+        }
+
+        // The front side of the face is in the direction of the surface's
+        // natural normal, if the face's coordinate system is right-handed.
+        let is_right_handed =
+            face.coord_handedness(geometry) == Handedness::RightHanded;
+        let same_sense = (is_right_handed == normal_is_natural) != reversed;
+
+        Ok(self.add(format!(
+            "ADVANCED_FACE('',{},#{surface_id},{})",
+            list(&bounds),
+            boolean(same_sense),
+        )))
+    }
+
+    fn edge_loop(
+        &mut self,
+        cycle: &Cycle,
+        face: &Face,
+        geometry: &Geometry,
+    ) -> Result<usize, Error> {
+        let surface = geometry.of_surface(face.surface());
+
+        let mut oriented_edges = Vec::new();
+        for (half_edge, next_half_edge) in cycle.half_edges().pairs() {
+            let path = &geometry
+                .of_curve(half_edge.curve())
+                .unwrap()
+                .local_on(face.surface())
+                .unwrap()
+                .path;
+
+            let vertices =
+                [half_edge.start_vertex(), next_half_edge.start_vertex()];
+            let boundary = vertices.map(|vertex| {
+                geometry
+                    .of_vertex(vertex)
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position
+            });
+
+            let point_along = |fraction: f64| {
+                let [start, end] = boundary;
+                let point_curve = start + (end - start) * fraction;
+                surface.point_from_surface_coords(
+                    path.point_from_path_coords(point_curve),
+                )
+            };
+
+            // Points at a quarter and three quarters of the half-edge. They
+            // are used to tell, whether another half-edge that refers to the
+            // same edge runs in the same direction. Comparing vertices is not
+            // enough for that, as an edge can start and end at the same vertex.
+            let probes = [point_along(0.25), point_along(0.75)];
+
+            let mut key_vertices = vertices.map(Clone::clone);
+            key_vertices.sort();
+            let key = (half_edge.curve().clone(), key_vertices);
+
+            let (edge, orientation) = match self.edges.get(&key) {
+                Some(edge) => {
+                    let orientation = probes[0].distance_to(&edge.probes[0])
+                        < probes[0].distance_to(&edge.probes[1]);
+                    (edge.id, orientation)
+                }
+                None => {
+                    let [start, end] = [(vertices[0], 0.), (vertices[1], 1.)]
+                        .map(|(vertex, fraction)| {
+                            self.vertex(vertex, point_along(fraction))
+                        });
+
+                    let (curve, sense) = global_curve(surface, path)?;
+                    let curve = self.curve(&curve);
+                    let same_sense = sense == (boundary[1] > boundary[0]);
+
+                    let id = self.add(format!(
+                        "EDGE_CURVE('',#{start},#{end},#{curve},{})",
+                        boolean(same_sense),
+                    ));
+                    self.edges.insert(key, Edge { id, probes });
+
+                    (id, true)
+                }
+            };
+
+            oriented_edges.push(self.add(format!(
+                "ORIENTED_EDGE('',*,*,#{edge},{})",
+                boolean(orientation),
+            )));
+        }
+
+        Ok(self.add(format!("EDGE_LOOP('',{})", list(&oriented_edges))))
+    }
+
+    fn vertex(&mut self, vertex: &Handle<Vertex>, position: Point<3>) -> usize {
+        if let Some(&id) = self.vertices.get(vertex) {
+            return id;
+        }
+
+        let point = self.point(position);
+        let id = self.add(format!("VERTEX_POINT('',#{point})"));
+        self.vertices.insert(vertex.clone(), id);
+
+        id
+    }
+
+    /// Add a surface and return its id
+    ///
+    /// Also returns whether the natural normal of the STEP surface points in
+    /// the same direction as the cross product of the surface's u- and v-axes.
+    fn surface(&mut self, surface: &SurfaceGeom) -> (usize, bool) {
+        match surface {
+            SurfaceGeom::SweptCurve(SweptCurve {
+                u: GlobalPath::Line(line),
+                v,
+            }) => {
+                let position = self.placement(
+                    line.origin(),
+                    line.direction().cross(v),
+                    line.direction(),
+                );
+                (self.add(format!("PLANE('',#{position})")), true)
+            }
+            SurfaceGeom::SweptCurve(SweptCurve {
+                u: GlobalPath::Circle(circle),
+                v,
+            }) if circle
+                .a()
+                .cross(&circle.b())
+                .normalize()
+                .cross(&v.normalize())
+                .magnitude()
+                < Scalar::from(EPSILON) =>
+            {
+                let position = self.placement(circle.center(), *v, circle.a());
+                let id = self.add(format!(
+                    "CYLINDRICAL_SURFACE('',#{position},{})",
+                    real(circle.radius()),
+                ));
+
+                let outward = v.dot(&circle.a().cross(&circle.b()));
+                (id, outward.is_positive())
+            }
+            SurfaceGeom::SweptCurve(SweptCurve { u, v }) => {
+                let curve = self.curve(u);
+                let direction = self.vector(*v);
+                let id = self.add(format!(
+                    "SURFACE_OF_LINEAR_EXTRUSION('',#{curve},#{direction})"
+                ));
+                (id, true)
+            }
+            SurfaceGeom::Cylinder(cylinder) => {
+                let position = self.placement(
+                    cylinder.origin(),
+                    cylinder.axis(),
+                    cylinder.a(),
+                );
+                let id = self.add(format!(
+                    "CYLINDRICAL_SURFACE('',#{position},{})",
+                    real(cylinder.radius()),
+                ));
+
+                let outward =
+                    cylinder.a().cross(&cylinder.b()).dot(&cylinder.axis());
+                (id, outward.is_positive())
+            }
+            SurfaceGeom::Cone(cone) => {
+                // STEP defines a cone by the radius at its placement, which
+                // we put where the cone's radius equals the length of `a`.
+                let position = self.placement(
+                    cone.apex() + cone.axis(),
+                    cone.axis(),
+                    cone.a(),
+                );
+                let radius = cone.a().magnitude();
+                let semi_angle = radius.atan2(cone.axis().magnitude());
+                let id = self.add(format!(
+                    "CONICAL_SURFACE('',#{position},{},{})",
+                    real(radius),
+                    real(semi_angle),
+                ));
+
+                let outward = cone.a().cross(&cone.b()).dot(&cone.axis());
+                (id, outward.is_positive())
+            }
+            SurfaceGeom::Sphere(sphere) => {
+                let position =
+                    self.placement(sphere.center(), sphere.c(), sphere.a());
+                let id = self.add(format!(
+                    "SPHERICAL_SURFACE('',#{position},{})",
+                    real(sphere.radius()),
+                ));
+
+                let outward = sphere.a().dot(&sphere.b().cross(&sphere.c()));
+                (id, outward.is_positive())
+            }
+            SurfaceGeom::Torus(torus) => {
+                let position =
+                    self.placement(torus.center(), torus.c(), torus.a());
+                let id = self.add(format!(
+                    "TOROIDAL_SURFACE('',#{position},{},{})",
+                    real(torus.major_radius()),
+                    real(torus.minor_radius()),
+                ));
+
+                let outward = torus.a().dot(&torus.b().cross(&torus.c()));
+                (id, outward.is_positive())
+            }
+            SurfaceGeom::Nurbs(surface) => (self.nurbs_surface(surface), true),
+        }
+    }
+
+    fn curve(&mut self, curve: &GlobalPath) -> usize {
+        match curve {
+            GlobalPath::Line(line) => {
+                let point = self.point(line.origin());
+                let direction = self.vector(line.direction());
+                self.add(format!("LINE('',#{point},#{direction})"))
+            }
+            GlobalPath::Circle(circle) => {
+                let position = self.placement(
+                    circle.center(),
+                    circle.a().cross(&circle.b()),
+                    circle.a(),
+                );
+                self.add(format!(
+                    "CIRCLE('',#{position},{})",
+                    real(circle.radius())
+                ))
+            }
+            GlobalPath::Nurbs(curve) => self.nurbs_curve(curve),
+        }
+    }
+
+    fn nurbs_curve(&mut self, curve: &NurbsCurve<3>) -> usize {
+        let control_points = curve
+            .control_points()
+            .iter()
+            .map(|&point| self.point(point))
+            .collect::<Vec<_>>();
+        let (multiplicities, knots) = knots(curve.knots());
+
+        let degree = curve.degree();
+        let control_points = list(&control_points);
+
+        if is_polynomial(curve.weights()) {
+            return self.add(format!(
+                "B_SPLINE_CURVE_WITH_KNOTS('',{degree},{control_points},\
+                .UNSPECIFIED.,.F.,.F.,{multiplicities},{knots},.UNSPECIFIED.)"
+            ));
+        }
+
+        let weights = reals(curve.weights());
+        self.add(format!(
+            "(BOUNDED_CURVE()\
+            B_SPLINE_CURVE({degree},{control_points},.UNSPECIFIED.,.F.,.F.)\
+            B_SPLINE_CURVE_WITH_KNOTS({multiplicities},{knots},.UNSPECIFIED.)\
+            CURVE()GEOMETRIC_REPRESENTATION_ITEM()\
+            RATIONAL_B_SPLINE_CURVE({weights})REPRESENTATION_ITEM(''))"
+        ))
+    }
+
+    fn nurbs_surface(&mut self, surface: &NurbsSurface) -> usize {
+        let control_points = surface
+            .control_points()
+            .iter()
+            .map(|row| {
+                let row = row
+                    .iter()
+                    .map(|&point| self.point(point))
+                    .collect::<Vec<_>>();
+                list(&row)
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let [(multiplicities_u, knots_u), (multiplicities_v, knots_v)] =
+            surface.knots().each_ref().map(|knots_| knots(knots_));
+
+        let [degree_u, degree_v] = surface.degree();
+        let surface_data = format!(
+            "{degree_u},{degree_v},({control_points}),.UNSPECIFIED.,.F.,.F.,.F."
+        );
+        let knot_data = format!(
+            "{multiplicities_u},{multiplicities_v},{knots_u},{knots_v},\
+            .UNSPECIFIED."
+        );
+
+        if surface.weights().iter().all(|row| is_polynomial(row)) {
+            return self.add(format!(
+                "B_SPLINE_SURFACE_WITH_KNOTS('',{surface_data},{knot_data})"
+            ));
+        }
+
+        let weights = surface
+            .weights()
+            .iter()
+            .map(|row| reals(row))
+            .collect::<Vec<_>>()
+            .join(",");
+        self.add(format!(
+            "(BOUNDED_SURFACE()B_SPLINE_SURFACE({surface_data})\
+            B_SPLINE_SURFACE_WITH_KNOTS({knot_data})\
+            GEOMETRIC_REPRESENTATION_ITEM()\
+            RATIONAL_B_SPLINE_SURFACE(({weights}))\
+            REPRESENTATION_ITEM('')SURFACE())"
+        ))
+    }
+
+    fn placement(
+        &mut self,
+        origin: Point<3>,
+        axis: Vector<3>,
+        reference: Vector<3>,
+    ) -> usize {
+        let origin = self.point(origin);
+        let axis = self.direction(axis);
+        let reference = self.direction(reference);
+        self.add(format!(
+            "AXIS2_PLACEMENT_3D('',#{origin},#{axis},#{reference})"
+        ))
+    }
+
+    fn vector(&mut self, vector: Vector<3>) -> usize {
+        let direction = self.direction(vector);
+        self.add(format!(
+            "VECTOR('',#{direction},{})",
+            real(vector.magnitude())
+        ))
+    }
+
+    fn direction(&mut self, direction: Vector<3>) -> usize {
+        let direction = direction.normalize();
+        self.add(format!("DIRECTION('',{})", reals(&direction.components)))
+    }
+
+    fn point(&mut self, point: Point<3>) -> usize {
+        self.add(format!(
+            "CARTESIAN_POINT('',{})",
+            reals(&point.coords.components)
+        ))
+    }
+
+    /// Add the product structure and the representation context
+    fn product(&mut self, items: Vec<usize>) {
+        let length = self.add(
+            "(LENGTH_UNIT()NAMED_UNIT(*)SI_UNIT(.MILLI.,.METRE.))".to_string(),
+        );
+        let angle = self.add(
+            "(NAMED_UNIT(*)PLANE_ANGLE_UNIT()SI_UNIT($,.RADIAN.))".to_string(),
+        );
+        let solid_angle = self.add(
+            "(NAMED_UNIT(*)SI_UNIT($,.STERADIAN.)SOLID_ANGLE_UNIT())"
+                .to_string(),
+        );
+        let uncertainty = self.add(format!(
+            "UNCERTAINTY_MEASURE_WITH_UNIT(LENGTH_MEASURE({}),#{length},\
+            'distance_accuracy_value','confusion accuracy')",
+            real(Scalar::from(EPSILON)),
+        ));
+        let context = self.add(format!(
+            "(GEOMETRIC_REPRESENTATION_CONTEXT(3)\
+            GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#{uncertainty}))\
+            GLOBAL_UNIT_ASSIGNED_CONTEXT((#{length},#{angle},#{solid_angle}))\
+            REPRESENTATION_CONTEXT('',''))"
+        ));
+
+        let origin =
+            self.placement(Point::origin(), Vector::unit_z(), Vector::unit_x());
+        let items = items.into_iter().chain([origin]).collect::<Vec<_>>();
+        let representation = self.add(format!(
+            "ADVANCED_BREP_SHAPE_REPRESENTATION('',{},#{context})",
+            list(&items),
+        ));
+
+        let application =
+            self.add("APPLICATION_CONTEXT('automotive design')".to_string());
+        self.add(format!(
+            "APPLICATION_PROTOCOL_DEFINITION('international standard',\
+            'automotive_design',2000,#{application})"
+        ));
+        let product_context = self
+            .add(format!("PRODUCT_CONTEXT('',#{application},'mechanical')"));
+        let product = self.add(format!(
+            "PRODUCT('Fornjot model','Fornjot model','',(#{product_context}))"
+        ));
+        let formation =
+            self.add(format!("PRODUCT_DEFINITION_FORMATION('','',#{product})"));
+        let definition_context = self.add(format!(
+            "PRODUCT_DEFINITION_CONTEXT('part definition',#{application},\
+            'design')"
+        ));
+        let definition = self.add(format!(
+            "PRODUCT_DEFINITION('design','',#{formation},\
+            #{definition_context})"
+        ));
+        let shape =
+            self.add(format!("PRODUCT_DEFINITION_SHAPE('','',#{definition})"));
+        self.add(format!(
+            "SHAPE_DEFINITION_REPRESENTATION(#{shape},#{representation})"
+        ));
+    }
+
+    fn write(&self, mut write: impl Write) -> std::io::Result<()> {
+        writeln!(write, "ISO-10303-21;")?;
+        writeln!(write, "HEADER;")?;
+        writeln!(write, "FILE_DESCRIPTION(('Fornjot model'),'2;1');")?;
+        writeln!(write, "FILE_NAME('','',(''),(''),'Fornjot','Fornjot','');")?;
+        writeln!(
+            write,
+            "FILE_SCHEMA(('AUTOMOTIVE_DESIGN {{ 1 0 10303 214 1 1 1 1 }}'));"
+        )?;
+        writeln!(write, "ENDSEC;")?;
+        writeln!(write, "DATA;")?;
+        for (i, entity) in self.entities.iter().enumerate() {
+            writeln!(write, "#{}={entity};", i + 1)?;
+        }
+        writeln!(write, "ENDSEC;")?;
+        writeln!(write, "END-ISO-10303-21;")?;
+
+        Ok(())
+    }
+}
+
+struct Edge {
+    id: usize,
+    probes: [Point<3>; 2],
+}
+
+/// Compute the global form of a path on a surface
+///
+/// Also returns whether the global curve runs in the same direction as the
+/// path, with increasing path coordinates.
+fn global_curve(
+    surface: &SurfaceGeom,
+    path: &SurfacePath,
+) -> Result<(GlobalPath, bool), Error> {
+    if let SurfaceGeom::SweptCurve(SweptCurve {
+        u: GlobalPath::Line(_),
+        ..
+    }) = surface
+    {
+        // The surface is a plane, so any path maps to a curve of the same
+        // kind.
+        let curve = match path {
+            SurfacePath::Circle(circle) => GlobalPath::Circle(Circle::new(
+                surface.point_from_surface_coords(circle.center()),
+                surface.vector_from_surface_coords(circle.center(), circle.a()),
+                surface.vector_from_surface_coords(circle.center(), circle.b()),
+            )),
+            SurfacePath::Line(line) => {
+                GlobalPath::Line(Line::from_origin_and_direction(
+                    surface.point_from_surface_coords(line.origin()),
+                    surface.vector_from_surface_coords(
+                        line.origin(),
+                        line.direction(),
+                    ),
+                ))
+            }
+            SurfacePath::Nurbs(curve) => {
+                GlobalPath::Nurbs(curve.map_control_points(|&point| {
+                    surface.point_from_surface_coords(point)
+                }))
+            }
+        };
+
+        return Ok((curve, true));
+    }
+
+    let SurfacePath::Line(line) = path else {
+        return Err(Error::UnsupportedGeometry(
+            "curved path on curved surface",
+        ));
+    };
+    let origin = line.origin();
+    let direction = line.direction();
+
+    let is_ruled = matches!(
+        surface,
+        SurfaceGeom::SweptCurve(_)
+            | SurfaceGeom::Cylinder(_)
+            | SurfaceGeom::Cone(_)
+    );
+
+    if direction.u.abs() < Scalar::from(EPSILON) && is_ruled {
+        // The path runs along one of the surface's straight rulings.
+        let line = Line::from_origin_and_direction(
+            surface.point_from_surface_coords(origin),
+            surface.vector_from_surface_coords(origin, direction),
+        );
+        return Ok((GlobalPath::Line(line), true));
+    }
+    if direction.v.abs() < Scalar::from(EPSILON) {
+        if let Some(curve) = iso_v_curve(surface, origin.v) {
+            return Ok((curve, direction.u.is_positive()));
+        }
+    }
+    if direction.u.abs() < Scalar::from(EPSILON) {
+        if let Some(curve) = iso_u_curve(surface, origin.u) {
+            return Ok((curve, direction.v.is_positive()));
+        }
+    }
+
+    Err(Error::UnsupportedGeometry("line on curved surface"))
+}
+
+/// Compute the curve of constant v on a curved surface
+///
+/// The returned curve is parametrized by the surface's u-coordinate.
+fn iso_v_curve(surface: &SurfaceGeom, v: Scalar) -> Option<GlobalPath> {
+    let circle = match surface {
+        SurfaceGeom::SweptCurve(swept) => {
+            let offset = swept.v * v;
+            let curve = match &swept.u {
+                GlobalPath::Circle(circle) => GlobalPath::Circle(Circle::new(
+                    circle.center() + offset,
+                    circle.a(),
+                    circle.b(),
+                )),
+                GlobalPath::Line(line) => {
+                    GlobalPath::Line(Line::from_origin_and_direction(
+                        line.origin() + offset,
+                        line.direction(),
+                    ))
+                }
+                GlobalPath::Nurbs(curve) => GlobalPath::Nurbs(
+                    curve.map_control_points(|&point| point + offset),
+                ),
+            };
+            return Some(curve);
+        }
+        SurfaceGeom::Cylinder(cylinder) => Circle::new(
+            cylinder.origin() + cylinder.axis() * v,
+            cylinder.a(),
+            cylinder.b(),
+        ),
+        SurfaceGeom::Cone(cone) => {
+            if v.abs() < Scalar::from(EPSILON) {
+                return None;
+            }
+            Circle::new(
+                cone.apex() + cone.axis() * v,
+                cone.a() * v,
+                cone.b() * v,
+            )
+        }
+        SurfaceGeom::Sphere(sphere) => {
+            let (sin, cos) = v.sin_cos();
+            if cos.abs() < Scalar::from(EPSILON) {
+                return None;
+            }
+            Circle::new(
+                sphere.center() + sphere.c() * sin,
+                sphere.a() * cos,
+                sphere.b() * cos,
+            )
+        }
+        SurfaceGeom::Torus(torus) => {
+            let ratio = torus.minor_radius() / torus.major_radius();
+            let (sin, cos) = v.sin_cos();
+            let scale = Scalar::ONE + ratio * cos;
+            Circle::new(
+                torus.center() + torus.c() * ratio * sin,
+                torus.a() * scale,
+                torus.b() * scale,
+            )
+        }
+        SurfaceGeom::Nurbs(_) => return None,
+    };
+
+    Some(GlobalPath::Circle(circle))
+}
+
+/// Compute the curve of constant u on a curved surface
+///
+/// The returned curve is parametrized by the surface's v-coordinate. Only
+/// covers surfaces whose curves of constant u aren't straight.
+fn iso_u_curve(surface: &SurfaceGeom, u: Scalar) -> Option<GlobalPath> {
+    let (sin, cos) = u.sin_cos();
+
+    let circle = match surface {
+        SurfaceGeom::Sphere(sphere) => {
+            let radial = sphere.a() * cos + sphere.b() * sin;
+            Circle::new(sphere.center(), radial, sphere.c())
+        }
+        SurfaceGeom::Torus(torus) => {
+            let ratio = torus.minor_radius() / torus.major_radius();
+            let radial = torus.a() * cos + torus.b() * sin;
+            Circle::new(
+                torus.center() + radial,
+                radial * ratio,
+                torus.c() * ratio,
+            )
+        }
+        _ => return None,
+    };
+
+    Some(GlobalPath::Circle(circle))
+}
+
+/// Split a knot vector into distinct knots and their multiplicities
+fn knots(knots: &[Scalar]) -> (String, String) {
+    let mut distinct: Vec<Scalar> = Vec::new();
+    let mut multiplicities: Vec<usize> = Vec::new();
+
+    for &knot in knots {
+        match distinct.last() {
+            Some(&last) if last == knot => {
+                if let Some(multiplicity) = multiplicities.last_mut() {
+                    *multiplicity += 1;
+                }
+            }
+            _ => {
+                distinct.push(knot);
+                multiplicities.push(1);
+            }
+        }
+    }
+
+    let multiplicities = multiplicities
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+
+    (format!("({multiplicities})"), reals(&distinct))
+}
+
+fn is_polynomial(weights: &[Scalar]) -> bool {
+    weights.iter().all(|&weight| weight == Scalar::ONE)
+}
+
+fn list(ids: &[usize]) -> String {
+    let ids = ids
+        .iter()
+        .map(|id| format!("#{id}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("({ids})")
+}
+
+fn reals(values: &[Scalar]) -> String {
+    let values = values
+        .iter()
+        .map(|&value| real(value))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("({values})")
+}
+
+/// Format a real number, as required by STEP
+///
+/// STEP requires real numbers to contain a decimal point, even if they are
+/// written in exponent notation.
+fn real(value: Scalar) -> String {
+    let value = format!("{:?}", value.into_f64());
+
+    match value.split_once('e') {
+        Some((mantissa, exponent)) if mantissa.contains('.') => {
+            format!("{mantissa}E{exponent}")
+        }
+        Some((mantissa, exponent)) => format!("{mantissa}.E{exponent}"),
+        None => value,
+    }
+}
+
+fn boolean(value: bool) -> &'static str {
+    if value {
+        ".T."
+    } else {
+        ".F."
+    }
+}
+
+const EPSILON: f64 = 1e-7;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use fj_core::{
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        topology::{Region, Sketch, Solid},
+        Core,
+    };
+    use fj_math::{Scalar, Vector};
+
+    use crate::bodies::tests::tetrahedron;
+
+    use super::{export_step, real};
+
+    #[test]
+    fn cuboid() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let region = Region::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            core.layers.topology.surfaces.space_2d(),
+            &mut core,
+        );
+        let solid = sweep(region, &mut core);
+
+        let step = Step::export(&solid, &core)?;
+
+        assert_eq!(step.count("ADVANCED_FACE"), 6);
+        assert_eq!(step.count("PLANE"), 6);
+        assert_eq!(step.count("EDGE_CURVE"), 12);
+        assert_eq!(step.count("ORIENTED_EDGE"), 24);
+        assert_eq!(step.count("VERTEX_POINT"), 8);
+        assert_eq!(step.count("CLOSED_SHELL"), 1);
+        assert_eq!(step.count("MANIFOLD_SOLID_BREP"), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let region = Region::circle(
+            [0., 0.],
+            1.,
+            core.layers.topology.surfaces.space_2d(),
+            &mut core,
+        );
+        let solid = sweep(region, &mut core);
+
+        let step = Step::export(&solid, &core)?;
+
+        assert_eq!(step.count("ADVANCED_FACE"), 6);
+        assert_eq!(step.count("CYLINDRICAL_SURFACE"), 4);
+        assert_eq!(step.count("CIRCLE"), 8);
+        assert_eq!(step.count("LINE"), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn void() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let solid = Solid::new([
+            tetrahedron([0., 0., 0.], 8., false, &mut core),
+            tetrahedron([1., 1., 1.], 1., true, &mut core),
+        ]);
+
+        let step = Step::export(&solid, &core)?;
+
+        assert_eq!(step.count("MANIFOLD_SOLID_BREP"), 0);
+        assert_eq!(step.count("BREP_WITH_VOIDS"), 1);
+        assert_eq!(step.count("ORIENTED_CLOSED_SHELL"), 1);
+        assert_eq!(step.count("CLOSED_SHELL"), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn separate_bodies() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let solid = Solid::new([
+            tetrahedron([0., 0., 0.], 1., false, &mut core),
+            tetrahedron([2., 0., 0.], 1., false, &mut core),
+        ]);
+
+        let step = Step::export(&solid, &core)?;
+
+        assert_eq!(step.count("MANIFOLD_SOLID_BREP"), 2);
+        assert_eq!(step.count("BREP_WITH_VOIDS"), 0);
+        assert_eq!(step.count("CLOSED_SHELL"), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn real_numbers_have_decimal_point() {
+        assert_eq!(real(Scalar::ONE), "1.0");
+        assert_eq!(real(Scalar::from(-0.5)), "-0.5");
+        assert_eq!(real(Scalar::from(1e-7)), "1.E-7");
+        assert_eq!(real(Scalar::from(1.5e20)), "1.5E20");
+    }
+
+    fn sweep(region: Region, core: &mut Core) -> Solid {
+        let bottom_surface = core.layers.topology.surfaces.xy_plane();
+        Sketch::empty(&core.layers.topology)
+            .add_regions([region], core)
+            .sweep_sketch(bottom_surface, Vector::from([0., 0., 1.]), core)
+    }
+
+    /// The entities of an exported STEP file
+    struct Step {
+        entities: BTreeMap<usize, Entity>,
+    }
+
+    impl Step {
+        /// Export the solid and check the structure of the resulting file
+        fn export(solid: &Solid, core: &Core) -> anyhow::Result<Self> {
+            let mut buffer = Vec::new();
+            export_step(solid, &core.layers.geometry, &mut buffer)?;
+
+            let step = Self::parse(&String::from_utf8(buffer)?);
+            step.validate();
+
+            Ok(step)
+        }
+
+        fn parse(step: &str) -> Self {
+            let data = step
+                .split_once("DATA;\n")
+                .and_then(|(_, data)| data.split_once("ENDSEC;"))
+                .map(|(data, _)| data)
+                .expect("STEP file must have data section");
+
+            let entities = data
+                .lines()
+                .map(|line| {
+                    let (id, entity) = line
+                        .strip_prefix('#')
+                        .and_then(|line| line.strip_suffix(';'))
+                        .and_then(|line| line.split_once('='))
+                        .expect("Expected entity instance");
+                    (id.parse().unwrap(), Entity::parse(entity))
+                })
+                .collect();
+
+            Self { entities }
+        }
+
+        fn count(&self, name: &str) -> usize {
+            self.entities
+                .values()
+                .filter(|entity| entity.name == name)
+                .count()
+        }
+
+        fn get(&self, id: usize) -> &Entity {
+            self.entities
+                .get(&id)
+                .unwrap_or_else(|| panic!("Reference to missing entity #{id}"))
+        }
+
+        /// Check the references and orientations of the topological entities
+        fn validate(&self) {
+            let curves = ["LINE", "CIRCLE", "B_SPLINE_CURVE_WITH_KNOTS"];
+            let surfaces = [
+                "PLANE",
+                "CYLINDRICAL_SURFACE",
+                "CONICAL_SURFACE",
+                "SPHERICAL_SURFACE",
+                "TOROIDAL_SURFACE",
+                "SURFACE_OF_LINEAR_EXTRUSION",
+                "B_SPLINE_SURFACE_WITH_KNOTS",
+            ];
+
+            // Complex entities, like rational B-splines, are not checked.
+            for entity in self.entities.values() {
+                let references = entity.references.as_slice();
+
+                match entity.name.as_str() {
+                    "MANIFOLD_SOLID_BREP" => {
+                        self.expect(entity, references, &["CLOSED_SHELL"]);
+                    }
+                    "BREP_WITH_VOIDS" => {
+                        let (shell, voids) = references.split_first().unwrap();
+                        self.expect(entity, &[*shell], &["CLOSED_SHELL"]);
+                        self.expect(entity, voids, &["ORIENTED_CLOSED_SHELL"]);
+                        assert!(!voids.is_empty());
+                    }
+                    "ORIENTED_CLOSED_SHELL" => {
+                        self.expect(entity, references, &["CLOSED_SHELL"]);
+                        assert!(!entity.flag(), "Voids must be reversed");
+                    }
+                    "CLOSED_SHELL" => {
+                        self.expect(entity, references, &["ADVANCED_FACE"]);
+                        self.validate_shell(entity);
+                    }
+                    "ADVANCED_FACE" => {
+                        let (surface, bounds) =
+                            references.split_last().unwrap();
+                        self.expect(entity, &[*surface], &surfaces);
+                        self.expect(
+                            entity,
+                            bounds,
+                            &["FACE_OUTER_BOUND", "FACE_BOUND"],
+                        );
+                    }
+                    "FACE_OUTER_BOUND" | "FACE_BOUND" => {
+                        self.expect(entity, references, &["EDGE_LOOP"]);
+                    }
+                    "EDGE_LOOP" => {
+                        self.expect(entity, references, &["ORIENTED_EDGE"]);
+                    }
+                    "ORIENTED_EDGE" => {
+                        self.expect(entity, references, &["EDGE_CURVE"]);
+                    }
+                    "EDGE_CURVE" => {
+                        let (curve, vertices) =
+                            references.split_last().unwrap();
+                        self.expect(entity, &[*curve], &curves);
+                        self.expect(entity, vertices, &["VERTEX_POINT"]);
+                        assert_eq!(vertices.len(), 2);
+                    }
+                    "VERTEX_POINT" => {
+                        self.expect(entity, references, &["CARTESIAN_POINT"]);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        fn expect(
+            &self,
+            entity: &Entity,
+            references: &[usize],
+            types: &[&str],
+        ) {
+            assert!(
+                !references.is_empty(),
+                "{} is missing references",
+                entity.name
+            );
+
+            for &id in references {
+                let name = &self.get(id).name;
+                assert!(
+                    types.contains(&name.as_str()),
+                    "{} must not refer to {name}",
+                    entity.name,
+                );
+            }
+        }
+
+        /// Check that every edge of a closed shell is used twice, once in each
+        /// direction
+        fn validate_shell(&self, shell: &Entity) {
+            let mut uses = BTreeMap::new();
+
+            for &face in &shell.references {
+                let face = self.get(face);
+                let bounds = &face.references[..face.references.len() - 1];
+
+                for &bound in bounds {
+                    let bound = self.get(bound);
+                    let edge_loop = self.get(bound.references[0]);
+
+                    for &oriented_edge in &edge_loop.references {
+                        let oriented_edge = self.get(oriented_edge);
+                        let edge = oriented_edge.references[0];
+                        let direction = bound.flag() == oriented_edge.flag();
+
+                        uses.entry(edge)
+                            .or_insert_with(Vec::new)
+                            .push(direction);
+                    }
+                }
+            }
+
+            for (edge, mut directions) in uses {
+                directions.sort();
+                assert_eq!(
+                    directions,
+                    [false, true],
+                    "Edge #{edge} must be used once in each direction",
+                );
+            }
+        }
+    }
+
+    struct Entity {
+        name: String,
+
+        /// The referenced entities, in order
+        references: Vec<usize>,
+
+        /// The text of the entity's parameters
+        parameters: String,
+    }
+
+    impl Entity {
+        fn parse(entity: &str) -> Self {
+            let (name, parameters) =
+                entity.split_once('(').unwrap_or((entity, ""));
+
+            let references = parameters
+                .split('#')
+                .skip(1)
+                .map(|reference| {
+                    let digits = reference
+                        .chars()
+                        .take_while(char::is_ascii_digit)
+                        .collect::<String>();
+                    digits.parse().unwrap()
+                })
+                .collect();
+
+            Self {
+                name: name.to_string(),
+                references,
+                parameters: parameters.to_string(),
+            }
+        }
+
+        /// The value of the boolean parameter that the entity ends with
+        fn flag(&self) -> bool {
+            if self.parameters.ends_with(".T.)") {
+                true
+            } else if self.parameters.ends_with(".F.)") {
+                false
+            } else {
+                panic!("Expected {} to end with flag", self.name);
+            }
+        }
+    }
+}
@@ -0,0 +1,326 @@
+//! 3MF export
+//!
+//! See [`export_3mf`] and [`export_3mf_objects`].
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    io::{Seek, Write},
+    slice,
+};
+
+use fj_interop::{Color, Mesh};
+use fj_math::Point;
+use zip::{write::FileOptions, ZipWriter};
+
+use crate::Error;
+
+/// Export the provided mesh to the provided writer in the 3MF format
+///
+/// The mesh is written as a single object. See [`export_3mf_objects`].
+pub fn export_3mf(
+    mesh: &Mesh<Point<3>>,
+    write: impl Write + Seek,
+) -> Result<(), Error> {
+    export_3mf_objects(slice::from_ref(mesh), write)
+}
+
+/// Export the provided meshes to the provided writer in the 3MF format
+///
+/// Every mesh is written as a separate object, each with its own item in the
+/// build, so slicers can treat them as separate parts. Typically, each mesh is
+/// the triangulation of one solid or shell.
+///
+/// The colors of the triangles are preserved, by writing them as base
+/// materials that the triangles refer to.
+///
+/// Returns [`Error::EmptyMesh`], if there are no meshes, or if any of them has
+/// no triangles. 3MF doesn't allow objects with empty meshes.
+pub fn export_3mf_objects(
+    meshes: &[Mesh<Point<3>>],
+    write: impl Write + Seek,
+) -> Result<(), Error> {
+    check_meshes(meshes)?;
+
+    let model = model(meshes);
+
+    let mut archive = ZipWriter::new(write);
+
+    archive.start_file("[Content_Types].xml", FileOptions::default())?;
+    archive.write_all(CONTENT_TYPES.as_bytes())?;
+
+    archive.start_file("_rels/.rels", FileOptions::default())?;
+    archive.write_all(RELATIONSHIPS.as_bytes())?;
+
+    archive.start_file("3D/3dmodel.model", FileOptions::default())?;
+    archive.write_all(model.as_bytes())?;
+
+    archive.finish()?;
+
+    Ok(())
+}
+
+/// Generate the XML of the 3MF model part
+/// Check that the meshes can be exported as 3MF objects
+///
+/// This allows callers to reject meshes before creating the file they would be
+/// written to.
+pub(crate) fn check_meshes(meshes: &[Mesh<Point<3>>]) -> Result<(), Error> {
+    if meshes.is_empty()
+        || meshes.iter().any(|mesh| mesh.triangles().next().is_none())
+    {
+        return Err(Error::EmptyMesh);
+    }
+
+    Ok(())
+}
+
+fn model(meshes: &[Mesh<Point<3>>]) -> String {
+    // All objects share one group of base materials, with one material per
+    // distinct color. It is the first resource, so objects start at id 2.
+    const MATERIALS_ID: usize = 1;
+
+    let mut materials = BTreeMap::new();
+    for triangle in meshes.iter().flat_map(|mesh| mesh.triangles()) {
+        let next_index = materials.len();
+        materials.entry(triangle.color).or_insert(next_index);
+    }
+
+    // Writing into a `String` can't fail, so the results of `write!` are
+    // ignored throughout.
+    let mut xml = String::new();
+    let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        xml,
+        r#"<model unit="millimeter" xml:lang="en-US" xmlns="{CORE_NAMESPACE}">"#
+    );
+    let _ = writeln!(xml, "  <resources>");
+
+    let mut materials_by_index = materials.iter().collect::<Vec<_>>();
+    materials_by_index.sort_by_key(|(_, &index)| index);
+
+    // An empty group of base materials is not allowed.
+    if !materials_by_index.is_empty() {
+        let _ = writeln!(xml, r#"    <basematerials id="{MATERIALS_ID}">"#);
+        for (color, index) in materials_by_index {
+            let _ = writeln!(
+                xml,
+                r#"      <base name="Color {index}" displaycolor="{}"/>"#,
+                display_color(*color),
+            );
+        }
+        let _ = writeln!(xml, "    </basematerials>");
+    }
+
+    for (i, mesh) in meshes.iter().enumerate() {
+        let id = MATERIALS_ID + 1 + i;
+
+        let default_material = mesh
+            .triangles()
+            .next()
+            .map(|triangle| {
+                let index = materials[&triangle.color];
+                format!(r#" pid="{MATERIALS_ID}" pindex="{index}""#)
+            })
+            .unwrap_or_default();
+
+        let _ = writeln!(
+            xml,
+            r#"    <object id="{id}" type="model"{default_material}>"#
+        );
+        let _ = writeln!(xml, "      <mesh>");
+
+        let _ = writeln!(xml, "        <vertices>");
+        for vertex in mesh.vertices() {
+            let [x, y, z] = vertex.coords.components.map(|s| s.into_f64());
+            let _ =
+                writeln!(xml, r#"          <vertex x="{x}" y="{y}" z="{z}"/>"#);
+        }
+        let _ = writeln!(xml, "        </vertices>");
+
+        let _ = writeln!(xml, "        <triangles>");
+        let indices = mesh.indices().collect::<Vec<_>>();
+        for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+            let material = materials[&triangle.color];
+            let _ = writeln!(
+                xml,
+                r#"          <triangle v1="{}" v2="{}" v3="{}" pid="{MATERIALS_ID}" p1="{material}"/>"#,
+                indices[0], indices[1], indices[2],
+            );
+        }
+        let _ = writeln!(xml, "        </triangles>");
+
+        let _ = writeln!(xml, "      </mesh>");
+        let _ = writeln!(xml, "    </object>");
+    }
+
+    let _ = writeln!(xml, "  </resources>");
+    let _ = writeln!(xml, "  <build>");
+    for i in 0..meshes.len() {
+        let id = MATERIALS_ID + 1 + i;
+        let _ = writeln!(xml, r#"    <item objectid="{id}"/>"#);
+    }
+    let _ = writeln!(xml, "  </build>");
+    let _ = writeln!(xml, "</model>");
+
+    xml
+}
+
+fn display_color(Color([r, g, b, a]): Color) -> String {
+    format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+}
+
+const CORE_NAMESPACE: &str =
+    "http://schemas.microsoft.com/3dmanufacturing/core/2015/02";
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+
+const RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{Cursor, Read, Seek},
+    };
+
+    use fj_core::{algorithms::approx::Tolerance, topology::Solid, Core};
+    use fj_interop::{Color, Mesh};
+    use fj_math::Point;
+
+    use crate::{bodies::tests::tetrahedron, export_model, Error};
+
+    use super::{export_3mf, export_3mf_objects};
+
+    #[test]
+    fn colors_and_objects() -> anyhow::Result<()> {
+        let red = Color([255, 0, 0, 255]);
+        let blue = Color([0, 0, 255, 255]);
+
+        let mut a = Mesh::new();
+        a.push_triangle([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]], red);
+        a.push_triangle([[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]], blue);
+
+        let mut b = Mesh::<Point<3>>::new();
+        b.push_triangle([[0., 0., 1.], [1., 0., 1.], [0., 1., 1.]], blue);
+
+        let mut buffer = Cursor::new(Vec::new());
+        export_3mf_objects(&[a, b], &mut buffer)?;
+
+        let model = read_model(buffer)?;
+
+        assert_eq!(model.matches("<base ").count(), 2);
+        assert!(model.contains(r##"displaycolor="#FF0000FF""##));
+        assert!(model.contains(r##"displaycolor="#0000FFFF""##));
+
+        assert_eq!(model.matches("<object ").count(), 2);
+        assert_eq!(model.matches("<item ").count(), 2);
+
+        assert_eq!(model.matches(r#"p1="0""#).count(), 1);
+        assert_eq!(model.matches(r#"p1="1""#).count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn vertices_are_shared_between_triangles() -> anyhow::Result<()> {
+        let mut mesh = Mesh::new();
+        mesh.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+        mesh.push_triangle(
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+
+        let mut buffer = Cursor::new(Vec::new());
+        export_3mf(&mesh, &mut buffer)?;
+
+        let mut archive = zip::ZipArchive::new(&mut buffer)?;
+        archive.by_name("[Content_Types].xml")?;
+        archive.by_name("_rels/.rels")?;
+
+        let model = read_model(buffer)?;
+        assert_eq!(model.matches("<vertex ").count(), 4);
+        assert!(model.contains(r#"<triangle v1="0" v2="1" v3="2""#));
+        assert!(model.contains(r#"<triangle v1="1" v2="3" v3="2""#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_meshes_are_rejected() {
+        let mut triangle = Mesh::new();
+        triangle.push_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+        );
+
+        let mut buffer = Cursor::new(Vec::new());
+
+        assert!(matches!(
+            export_3mf(&Mesh::new(), &mut buffer),
+            Err(Error::EmptyMesh),
+        ));
+        assert!(matches!(
+            export_3mf_objects(&[], &mut buffer),
+            Err(Error::EmptyMesh),
+        ));
+        assert!(matches!(
+            export_3mf_objects(&[triangle, Mesh::new()], &mut buffer),
+            Err(Error::EmptyMesh),
+        ));
+    }
+
+    #[test]
+    fn bodies_become_objects() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // One body with a void, and a separate one.
+        let solid = Solid::new([
+            tetrahedron([0., 0., 0.], 8., false, &mut core),
+            tetrahedron([1., 1., 1.], 1., true, &mut core),
+            tetrahedron([10., 0., 0.], 1., false, &mut core),
+        ]);
+
+        let path = env::temp_dir().join("fj-export-bodies-become-objects.3mf");
+        export_model(&solid, Tolerance::from_scalar(0.1)?, &mut core, &path)?;
+        let file = fs::File::open(&path)?;
+        let model = read_model(file);
+        fs::remove_file(&path)?;
+        let model = model?;
+
+        let mut triangles_per_object = model
+            .split("<object ")
+            .skip(1)
+            .map(|object| object.matches("<triangle ").count())
+            .collect::<Vec<_>>();
+        triangles_per_object.sort();
+
+        assert_eq!(triangles_per_object, [4, 8]);
+        assert_eq!(model.matches("<item ").count(), 2);
+
+        Ok(())
+    }
+
+    fn read_model(read: impl Read + Seek) -> anyhow::Result<String> {
+        let mut archive = zip::ZipArchive::new(read)?;
+
+        let mut model = String::new();
+        archive
+            .by_name("3D/3dmodel.model")?
+            .read_to_string(&mut model)?;
+
+        Ok(model)
+    }
+}
@@ -0,0 +1,120 @@
+//! # Fornjot Importer
+//!
+//! [Fornjot] is an early-stage b-rep CAD kernel written in Rust. The kernel is
+//! split into multiple libraries that can be used semi-independently, and this
+//! is one of those.
+//!
+//! This library imports triangle meshes from external file formats, turning
+//! them into shells that can be combined with other Fornjot models.
+//!
+//! [Fornjot]: https://www.fornjot.app/
+
+mod mesh;
+mod obj;
+mod stl;
+
+use std::{fs::File, io::Read, path::Path};
+
+use fj_core::{topology::Shell, Core};
+use fj_math::Point;
+use thiserror::Error;
+
+pub use self::{
+    mesh::{MeshDefect, TriangleMesh},
+    obj::read_obj,
+    stl::read_stl,
+};
+
+/// Import a shell from the mesh file at the given path
+///
+/// Currently STL & OBJ file types are supported. The case insensitive file
+/// extension of the provided path is used to switch between supported types.
+pub fn import(path: &Path, core: &mut Core) -> Result<Shell, Error> {
+    match path.extension() {
+        Some(extension) if extension.to_ascii_uppercase() == "STL" => {
+            let file = File::open(path)?;
+            import_stl(file, core)
+        }
+        Some(extension) if extension.to_ascii_uppercase() == "OBJ" => {
+            let file = File::open(path)?;
+            import_obj(file, core)
+        }
+        Some(extension) => Err(Error::InvalidExtension(
+            extension.to_string_lossy().into_owned(),
+        )),
+        None => Err(Error::NoExtension),
+    }
+}
+
+/// Import a shell from the provided reader in the STL format
+///
+/// Both ASCII and binary STL files are supported.
+pub fn import_stl(read: impl Read, core: &mut Core) -> Result<Shell, Error> {
+    read_stl(read)?.into_shell(core)
+}
+
+/// Import a shell from the provided reader in the OBJ format
+pub fn import_obj(read: impl Read, core: &mut Core) -> Result<Shell, Error> {
+    read_obj(read)?.into_shell(core)
+}
+
+/// An error that can occur while importing
+#[derive(Debug, Error)]
+pub enum Error {
+    /// No extension specified
+    #[error("no extension specified")]
+    NoExtension,
+
+    /// Unrecognized extension found
+    #[error("unrecognized extension found `{0:?}`")]
+    InvalidExtension(String),
+
+    /// I/O error whilst importing from file
+    #[error("I/O error whilst importing from file")]
+    Io(#[from] std::io::Error),
+
+    /// The file could not be parsed
+    #[error("syntax error in line {line}: {message}")]
+    Syntax {
+        /// The line the error occurred in, starting at 1
+        line: usize,
+
+        /// A description of the error
+        message: String,
+    },
+
+    /// The binary file is shorter than its header claims
+    #[error("binary STL file is truncated")]
+    Truncated,
+
+    /// The mesh does not bound a closed, consistently oriented volume
+    #[error("mesh is not a closed manifold ({} defects)", .0.len())]
+    NonManifold(Vec<MeshDefect>),
+}
+
+impl Error {
+    fn syntax(line: usize, message: impl Into<String>) -> Self {
+        Self::Syntax {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+fn parse_point<'r>(
+    mut coords: impl Iterator<Item = &'r str>,
+    line: usize,
+) -> Result<Point<3>, Error> {
+    let mut components = [0.; 3];
+
+    for component in &mut components {
+        let coord = coords
+            .next()
+            .ok_or_else(|| Error::syntax(line, "expected 3 coordinates"))?;
+        *component = coord.parse().map_err(|_| {
+            Error::syntax(line, format!("invalid coordinate `{coord}`"))
+        })?;
+    }
+
+    Ok(Point::from(components))
+}
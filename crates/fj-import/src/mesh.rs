@@ -0,0 +1,326 @@
+//! Triangle meshes read from external files
+//!
+//! See [`TriangleMesh`].
+
+use std::collections::{BTreeMap, HashMap};
+
+use fj_core::{operations::build::BuildShell, topology::Shell, Core};
+use fj_math::{Point, Scalar};
+
+use crate::Error;
+
+/// A triangle mesh, as read from an external file
+///
+/// Coincident vertices are merged while building the mesh, so triangles that
+/// share a position in the file share a vertex index here.
+#[derive(Clone, Debug, Default)]
+pub struct TriangleMesh {
+    vertices: Vec<Point<3>>,
+    triangles: Vec<[usize; 3]>,
+    lookup: HashMap<[i64; 3], Vec<usize>>,
+}
+
+impl TriangleMesh {
+    /// The distance below which two vertices are considered coincident
+    pub const MERGE_DISTANCE: f64 = 1e-6;
+
+    /// Create an empty mesh
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Access the vertices of the mesh
+    pub fn vertices(&self) -> &[Point<3>] {
+        &self.vertices
+    }
+
+    /// Access the triangles of the mesh, as indices into its vertices
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Add a vertex, merging it with a coincident one, if there is one
+    ///
+    /// Returns the index of the vertex.
+    pub fn push_vertex(&mut self, position: impl Into<Point<3>>) -> usize {
+        let position = position.into();
+        let cell = Self::cell(position);
+
+        // A vertex within the merge distance might have ended up in one of
+        // the neighboring cells, so all of those need to be checked.
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let neighbor = [cell[0] + dx, cell[1] + dy, cell[2] + dz];
+                    let Some(candidates) = self.lookup.get(&neighbor) else {
+                        continue;
+                    };
+
+                    for &index in candidates {
+                        let distance =
+                            self.vertices[index].distance_to(&position);
+                        if distance <= Scalar::from(Self::MERGE_DISTANCE) {
+                            return index;
+                        }
+                    }
+                }
+            }
+        }
+
+        let index = self.vertices.len();
+        self.vertices.push(position);
+        self.lookup.entry(cell).or_default().push(index);
+
+        index
+    }
+
+    /// Add a triangle, whose vertices have already been added
+    ///
+    /// The indices are not checked here. Any that don't refer to a vertex of
+    /// the mesh are reported by [`TriangleMesh::defects`].
+    pub fn push_triangle(&mut self, indices: [usize; 3]) {
+        self.triangles.push(indices);
+    }
+
+    /// Add a triangle by the positions of its vertices
+    pub fn push_triangle_points(&mut self, points: [impl Into<Point<3>>; 3]) {
+        let indices = points.map(|point| self.push_vertex(point));
+        self.push_triangle(indices);
+    }
+
+    /// Check that the mesh bounds a closed, consistently oriented volume
+    ///
+    /// Returns all defects that were found. An empty list means that the
+    /// mesh can be converted into a valid [`Shell`].
+    pub fn defects(&self) -> Vec<MeshDefect> {
+        let mut defects = Vec::new();
+
+        // Every edge, as it is traversed by the triangles that use it.
+        let mut edges: BTreeMap<[usize; 2], Vec<(usize, bool)>> =
+            BTreeMap::new();
+
+        for (triangle, &[a, b, c]) in self.triangles.iter().enumerate() {
+            if let Some(&index) = [a, b, c]
+                .iter()
+                .find(|&&index| index >= self.vertices.len())
+            {
+                defects.push(MeshDefect::IndexOutOfRange { triangle, index });
+                continue;
+            }
+
+            let [pa, pb, pc] = [a, b, c].map(|index| self.vertices[index]);
+            let area = (pb - pa).cross(&(pc - pa)).magnitude();
+            let min_area =
+                Scalar::from(Self::MERGE_DISTANCE * Self::MERGE_DISTANCE);
+            if a == b || b == c || c == a || area <= min_area {
+                defects.push(MeshDefect::DegenerateTriangle { triangle });
+                continue;
+            }
+
+            for [start, end] in [[a, b], [b, c], [c, a]] {
+                let forward = start < end;
+                let key = if forward { [start, end] } else { [end, start] };
+                edges.entry(key).or_default().push((triangle, forward));
+            }
+        }
+
+        for (key, uses) in edges {
+            let vertices = key.map(|index| self.vertices[index]);
+
+            match uses.as_slice() {
+                [(triangle, _)] => {
+                    defects.push(MeshDefect::OpenEdge {
+                        vertices,
+                        triangle: *triangle,
+                    });
+                }
+                [(a, a_forward), (b, b_forward)] => {
+                    if a_forward == b_forward {
+                        defects.push(MeshDefect::InconsistentOrientation {
+                            vertices,
+                            triangles: [*a, *b],
+                        });
+                    }
+                }
+                _ => {
+                    defects.push(MeshDefect::NonManifoldEdge {
+                        vertices,
+                        triangles: uses
+                            .iter()
+                            .map(|&(triangle, _)| triangle)
+                            .collect(),
+                    });
+                }
+            }
+        }
+
+        defects
+    }
+
+    /// Convert the mesh into a shell
+    ///
+    /// Returns [`Error::NonManifold`], if the mesh has any defects. See
+    /// [`TriangleMesh::defects`].
+    pub fn into_shell(self, core: &mut Core) -> Result<Shell, Error> {
+        let defects = self.defects();
+        if !defects.is_empty() {
+            return Err(Error::NonManifold(defects));
+        }
+
+        Ok(Shell::from_vertices_and_indices(
+            self.vertices,
+            self.triangles,
+            core,
+        ))
+    }
+
+    fn cell(position: Point<3>) -> [i64; 3] {
+        position.coords.components.map(|coord| {
+            (coord.into_f64() / Self::MERGE_DISTANCE).floor() as i64
+        })
+    }
+}
+
+/// A defect that prevents a mesh from being converted into a shell
+///
+/// Triangles are identified by their index in the order they were read from
+/// the file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MeshDefect {
+    /// A triangle refers to a vertex that doesn't exist
+    IndexOutOfRange {
+        /// The index of the triangle
+        triangle: usize,
+
+        /// The vertex index that is out of range
+        index: usize,
+    },
+
+    /// A triangle has no area
+    DegenerateTriangle {
+        /// The index of the triangle
+        triangle: usize,
+    },
+
+    /// An edge is only used by a single triangle, leaving a hole in the mesh
+    OpenEdge {
+        /// The positions of the edge's vertices
+        vertices: [Point<3>; 2],
+
+        /// The index of the triangle that uses the edge
+        triangle: usize,
+    },
+
+    /// An edge is used by more than two triangles
+    NonManifoldEdge {
+        /// The positions of the edge's vertices
+        vertices: [Point<3>; 2],
+
+        /// The indices of the triangles that use the edge
+        triangles: Vec<usize>,
+    },
+
+    /// Two triangles traverse their shared edge in the same direction
+    ///
+    /// This means that one of them is flipped relative to the other.
+    InconsistentOrientation {
+        /// The positions of the edge's vertices
+        vertices: [Point<3>; 2],
+
+        /// The indices of the triangles that share the edge
+        triangles: [usize; 2],
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::Core;
+
+    use super::{MeshDefect, TriangleMesh};
+
+    #[test]
+    fn merge_coincident_vertices() {
+        let mut mesh = TriangleMesh::new();
+
+        let a = mesh.push_vertex([0., 0., 0.]);
+        let b = mesh.push_vertex([1., 0., 0.]);
+        let c = mesh.push_vertex([0., 0., 5e-7]);
+
+        assert_ne!(a, b);
+        assert_eq!(a, c);
+        assert_eq!(mesh.vertices().len(), 2);
+    }
+
+    #[test]
+    fn tetrahedron_has_no_defects() {
+        let mesh = tetrahedron();
+
+        assert_eq!(mesh.defects(), []);
+        assert!(mesh.into_shell(&mut Core::new()).is_ok());
+    }
+
+    #[test]
+    fn report_open_edges() {
+        let mut mesh = tetrahedron();
+        mesh.triangles.pop();
+
+        let defects = mesh.defects();
+        assert_eq!(defects.len(), 3);
+        assert!(defects
+            .iter()
+            .all(|defect| matches!(defect, MeshDefect::OpenEdge { .. })));
+    }
+
+    #[test]
+    fn report_inconsistent_orientation() {
+        let mut mesh = tetrahedron();
+        mesh.triangles[0].reverse();
+
+        let defects = mesh.defects();
+        assert_eq!(defects.len(), 3);
+        assert!(defects.iter().all(|defect| matches!(
+            defect,
+            MeshDefect::InconsistentOrientation { .. }
+        )));
+    }
+
+    #[test]
+    fn report_non_manifold_edges() {
+        let mut mesh = tetrahedron();
+        mesh.push_triangle_points([[0., 0., 0.], [1., 0., 0.], [0., 0., -1.]]);
+
+        let defects = mesh.defects();
+        assert!(defects.contains(&MeshDefect::NonManifoldEdge {
+            vertices: [[0., 0., 0.].into(), [1., 0., 0.].into()],
+            triangles: vec![0, 1, 4],
+        }));
+    }
+
+    #[test]
+    fn report_indices_out_of_range() {
+        let mut mesh = tetrahedron();
+        mesh.push_triangle([0, 1, 4]);
+
+        let defects = mesh.defects();
+        assert!(defects.contains(&MeshDefect::IndexOutOfRange {
+            triangle: 4,
+            index: 4,
+        }));
+        assert!(mesh.into_shell(&mut Core::new()).is_err());
+    }
+
+    fn tetrahedron() -> TriangleMesh {
+        let mut mesh = TriangleMesh::new();
+
+        let a = [0., 0., 0.];
+        let b = [1., 0., 0.];
+        let c = [0., 1., 0.];
+        let d = [0., 0., 1.];
+
+        for triangle in [[a, c, b], [a, b, d], [b, c, d], [c, a, d]] {
+            mesh.push_triangle_points(triangle);
+        }
+
+        mesh
+    }
+}
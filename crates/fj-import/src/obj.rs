@@ -0,0 +1,135 @@
+//! OBJ import
+//!
+//! See [`read_obj`].
+
+use std::io::{BufRead, BufReader, Read};
+
+use crate::{parse_point, Error, TriangleMesh};
+
+/// Read a triangle mesh from the provided reader in the OBJ format
+///
+/// Only vertex positions and faces are read. Everything else, like normals,
+/// texture coordinates, groups, or materials, is ignored. Faces with more than
+/// 3 vertices are split into a fan of triangles, which assumes that they are
+/// convex.
+pub fn read_obj(read: impl Read) -> Result<TriangleMesh, Error> {
+    let mut mesh = TriangleMesh::new();
+
+    // Indices in the file refer to the vertices in the order they are defined,
+    // which no longer match the mesh, once coincident vertices are merged.
+    let mut vertices = Vec::new();
+
+    for (i, line) in BufReader::new(read).lines().enumerate() {
+        let line = line?;
+        let line_number = i + 1;
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => {
+                let position = parse_point(tokens, line_number)?;
+                vertices.push(mesh.push_vertex(position));
+            }
+            Some("f") => {
+                let indices = tokens
+                    .map(|token| {
+                        parse_index(token, vertices.len(), line_number)
+                            .map(|index| vertices[index])
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let [first, rest @ ..] = indices.as_slice() else {
+                    return Err(Error::syntax(line_number, "empty face"));
+                };
+                if rest.len() < 2 {
+                    return Err(Error::syntax(
+                        line_number,
+                        "face must have at least 3 vertices",
+                    ));
+                }
+
+                for pair in rest.windows(2) {
+                    mesh.push_triangle([*first, pair[0], pair[1]]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(mesh)
+}
+
+/// Parse a face element into a zero-based vertex index
+///
+/// Face elements can also refer to texture coordinates and normals (as in
+/// `1/2/3` or `1//3`), which are ignored. Negative indices are relative to the
+/// most recently defined vertex.
+fn parse_index(
+    token: &str,
+    num_vertices: usize,
+    line: usize,
+) -> Result<usize, Error> {
+    let index = token.split('/').next().unwrap_or(token);
+    let invalid = || Error::syntax(line, format!("invalid vertex `{token}`"));
+
+    let index: i64 = index.parse().map_err(|_| invalid())?;
+    let index = if index < 0 {
+        num_vertices as i64 + index
+    } else {
+        index - 1
+    };
+
+    usize::try_from(index)
+        .ok()
+        .filter(|&index| index < num_vertices)
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::Core;
+
+    use crate::{import_obj, Error};
+
+    use super::read_obj;
+
+    const CUBE: &str = "\
+# A unit cube, made of quads
+o cube
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+v 0 0 1
+v 1 0 1
+v 1 1 1
+v 0 1 1
+vn 0 0 -1
+f 1//1 4//1 3//1 2//1
+f 5 6 7 8
+f 1 2 6 5
+f 2 3 7 6
+f 3 4 8 7
+f -8 -4 -1 -5
+";
+
+    #[test]
+    fn quads_are_triangulated() -> anyhow::Result<()> {
+        let mesh = read_obj(CUBE.as_bytes())?;
+
+        assert_eq!(mesh.vertices().len(), 8);
+        assert_eq!(mesh.triangles().len(), 12);
+        assert_eq!(mesh.defects(), []);
+
+        import_obj(CUBE.as_bytes(), &mut Core::new())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_invalid_index() {
+        let obj = CUBE.replace("f 5 6 7 8", "f 5 6 7 9");
+
+        let result = read_obj(obj.as_bytes());
+        assert!(matches!(result, Err(Error::Syntax { line: 13, .. })));
+    }
+}
@@ -0,0 +1,214 @@
+//! STL import
+//!
+//! See [`read_stl`].
+
+use std::io::{Cursor, Read};
+
+use crate::{parse_point, Error, TriangleMesh};
+
+/// Read a triangle mesh from the provided reader in the STL format
+///
+/// Both ASCII and binary STL files are supported. Binary files are recognized
+/// by their size matching the triangle count in their header, as binary files
+/// are allowed to start with `solid`, just like ASCII files do.
+pub fn read_stl(mut read: impl Read) -> Result<TriangleMesh, Error> {
+    let mut bytes = Vec::new();
+    read.read_to_end(&mut bytes)?;
+
+    if is_binary(&bytes) || !bytes.starts_with(b"solid") {
+        read_binary(&bytes)
+    } else {
+        let text = String::from_utf8_lossy(&bytes);
+        read_ascii(&text)
+    }
+}
+
+fn is_binary(bytes: &[u8]) -> bool {
+    let Some(count) = bytes.get(80..84) else {
+        return false;
+    };
+    let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]);
+
+    bytes.len() as u64 == 84 + 50 * u64::from(count)
+}
+
+fn read_binary(bytes: &[u8]) -> Result<TriangleMesh, Error> {
+    let file =
+        stl::read_stl(&mut Cursor::new(bytes)).map_err(|err| {
+            match err.kind() {
+                std::io::ErrorKind::UnexpectedEof
+                | std::io::ErrorKind::Other => Error::Truncated,
+                _ => Error::Io(err),
+            }
+        })?;
+
+    let mut mesh = TriangleMesh::new();
+    for triangle in file.triangles {
+        let points = [triangle.v1, triangle.v2, triangle.v3]
+            .map(|point| point.map(f64::from));
+        mesh.push_triangle_points(points);
+    }
+
+    Ok(mesh)
+}
+
+fn read_ascii(text: &str) -> Result<TriangleMesh, Error> {
+    let mut mesh = TriangleMesh::new();
+    let mut facet = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("facet") => {
+                if facet.is_some() {
+                    return Err(Error::syntax(line_number, "unclosed facet"));
+                }
+                facet = Some(Vec::new());
+            }
+            Some("vertex") => {
+                let Some(points) = &mut facet else {
+                    return Err(Error::syntax(
+                        line_number,
+                        "vertex outside of facet",
+                    ));
+                };
+                points.push(parse_point(tokens, line_number)?);
+            }
+            Some("endfacet") => {
+                let points = facet.take().ok_or_else(|| {
+                    Error::syntax(line_number, "unexpected `endfacet`")
+                })?;
+                let points: [_; 3] = points.try_into().map_err(|_| {
+                    Error::syntax(line_number, "facet must have 3 vertices")
+                })?;
+                mesh.push_triangle_points(points);
+            }
+            // The remaining keywords don't carry any information that we
+            // need. Normals are derived from the vertex order instead.
+            Some("solid" | "endsolid" | "outer" | "endloop") | None => {}
+            Some(token) => {
+                return Err(Error::syntax(
+                    line_number,
+                    format!("unexpected `{token}`"),
+                ));
+            }
+        }
+    }
+
+    if facet.is_some() {
+        return Err(Error::syntax(text.lines().count(), "unclosed facet"));
+    }
+
+    Ok(mesh)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::Core;
+
+    use crate::{import_stl, Error};
+
+    use super::read_stl;
+
+    const TETRAHEDRON: &str = "\
+solid tetrahedron
+  facet normal 0 0 -1
+    outer loop
+      vertex 0 0 0
+      vertex 0 1 0
+      vertex 1 0 0
+    endloop
+  endfacet
+  facet normal 0 -1 0
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 0 0 1
+    endloop
+  endfacet
+  facet normal 1 1 1
+    outer loop
+      vertex 1 0 0
+      vertex 0 1 0
+      vertex 0 0 1
+    endloop
+  endfacet
+  facet normal -1 0 0
+    outer loop
+      vertex 0 1 0
+      vertex 0 0 0
+      vertex 0 0 1
+    endloop
+  endfacet
+endsolid tetrahedron
+";
+
+    #[test]
+    fn ascii() -> anyhow::Result<()> {
+        let mesh = read_stl(TETRAHEDRON.as_bytes())?;
+
+        assert_eq!(mesh.vertices().len(), 4);
+        assert_eq!(mesh.triangles().len(), 4);
+
+        import_stl(TETRAHEDRON.as_bytes(), &mut Core::new())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary() -> anyhow::Result<()> {
+        let ascii = read_stl(TETRAHEDRON.as_bytes())?;
+
+        let triangles = ascii
+            .triangles()
+            .iter()
+            .map(|triangle| {
+                let [v1, v2, v3] = triangle.map(|index| {
+                    ascii.vertices()[index]
+                        .coords
+                        .components
+                        .map(|s| s.into_f32())
+                });
+                stl::Triangle {
+                    normal: [0.; 3],
+                    v1,
+                    v2,
+                    v3,
+                    attr_byte_count: 0,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Binary STL files may start with `solid`, just like ASCII ones.
+        let mut header = [0u8; 80];
+        header[..5].copy_from_slice(b"solid");
+
+        let mut bytes = Vec::new();
+        stl::write_stl(
+            &mut bytes,
+            &stl::BinaryStlFile {
+                header: stl::BinaryStlHeader {
+                    header,
+                    num_triangles: triangles.len() as u32,
+                },
+                triangles,
+            },
+        )?;
+
+        let binary = read_stl(bytes.as_slice())?;
+        assert_eq!(binary.vertices(), ascii.vertices());
+        assert_eq!(binary.triangles(), ascii.triangles());
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_missing_vertex() {
+        let stl = TETRAHEDRON.replacen("      vertex 0 0 0\n", "", 1);
+
+        let result = read_stl(stl.as_bytes());
+        assert!(matches!(result, Err(Error::Syntax { line: 7, .. })));
+    }
+}
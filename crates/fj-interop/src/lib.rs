@@ -17,6 +17,6 @@ pub mod ext;
 
 pub use self::{
     color::Color,
-    mesh::{Index, Mesh, Triangle},
+    mesh::{Edge, Index, Mesh, SourceId, Triangle},
     model::Model,
 };
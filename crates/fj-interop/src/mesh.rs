@@ -12,6 +12,7 @@ pub struct Mesh<V> {
 
     indices_by_vertex: HashMap<V, Index>,
     triangles: Vec<Triangle>,
+    edges: Vec<Edge>,
 }
 
 impl<V> Mesh<V>
@@ -69,6 +70,11 @@ where
     pub fn triangles(&self) -> impl Iterator<Item = Triangle> + '_ {
         self.triangles.iter().copied()
     }
+
+    /// Access the edges of the mesh
+    pub fn edges(&self) -> impl Iterator<Item = &Edge> + '_ {
+        self.edges.iter()
+    }
 }
 
 impl Mesh<Point<3>> {
@@ -77,6 +83,46 @@ impl Mesh<Point<3>> {
         &mut self,
         triangle: impl Into<fj_math::Triangle<3>>,
         color: Color,
+    ) {
+        self.push_triangle_with_source(triangle, color, None);
+    }
+
+    /// Add a triangle to the mesh, that approximates the given face
+    pub fn push_face_triangle(
+        &mut self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+        color: Color,
+        face: SourceId,
+    ) {
+        self.push_triangle_with_source(triangle, color, Some(face));
+    }
+
+    /// Add an edge to the mesh
+    ///
+    /// Edges are not part of the triangle mesh. They are kept alongside it, to
+    /// make the edges of the original shape available for display and
+    /// selection.
+    ///
+    /// An edge of a shape can be created from two objects, one for each of the
+    /// adjacent faces. The second one can be provided as the `sibling`.
+    pub fn push_edge(
+        &mut self,
+        points: impl IntoIterator<Item = Point<3>>,
+        source: SourceId,
+        sibling: Option<SourceId>,
+    ) {
+        self.edges.push(Edge {
+            points: points.into_iter().collect(),
+            source,
+            sibling,
+        });
+    }
+
+    fn push_triangle_with_source(
+        &mut self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+        color: Color,
+        face: Option<SourceId>,
     ) {
         let triangle = triangle.into();
 
@@ -84,10 +130,7 @@ impl Mesh<Point<3>> {
             self.push_vertex(point);
         }
 
-        self.triangles.push(Triangle {
-            inner: triangle,
-            color,
-        });
+        self.triangles.push(Triangle::new(triangle, color, face));
     }
 }
 
@@ -100,6 +143,7 @@ impl<V> Default for Mesh<V> {
             indices: Vec::default(),
             indices_by_vertex: HashMap::default(),
             triangles: Vec::default(),
+            edges: Vec::default(),
         }
     }
 }
@@ -110,11 +154,67 @@ pub type Index = u32;
 /// A triangle
 ///
 /// Extension of [`fj_math::Triangle`] that also includes a color.
+///
+/// More fields might be added to this struct in the future. Use
+/// [`Triangle::new`] to construct it.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[non_exhaustive]
 pub struct Triangle {
     /// The points of the triangle
     pub inner: fj_math::Triangle<3>,
 
     /// The color of the triangle
     pub color: Color,
+
+    /// The face that the triangle approximates, if known
+    pub face: Option<SourceId>,
 }
+
+impl Triangle {
+    /// Construct a triangle
+    pub fn new(
+        inner: impl Into<fj_math::Triangle<3>>,
+        color: Color,
+        face: Option<SourceId>,
+    ) -> Self {
+        Self {
+            inner: inner.into(),
+            color,
+            face,
+        }
+    }
+}
+
+/// An edge of the shape that a mesh approximates
+///
+/// See [`Mesh::push_edge`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Edge {
+    /// The points of the polyline that approximates the edge
+    pub points: Vec<Point<3>>,
+
+    /// The object that the edge was created from
+    pub source: SourceId,
+
+    /// The other object that the edge was created from, if any
+    ///
+    /// An edge that bounds two faces of a shape, is usually made up of two
+    /// objects, one for each face.
+    pub sibling: Option<SourceId>,
+}
+
+impl Edge {
+    /// Iterate over all objects that the edge was created from
+    pub fn sources(&self) -> impl Iterator<Item = SourceId> + '_ {
+        [self.source].into_iter().chain(self.sibling)
+    }
+}
+
+/// Identifies the object that a part of a mesh was created from
+///
+/// This allows mapping triangles and edges back to the objects of the shape
+/// they approximate, for example to select those objects in a viewer. The
+/// value is opaque. It is only meaningful to the code that created the mesh.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SourceId(pub u64);
@@ -45,6 +45,17 @@ impl<const D: usize> Aabb<D> {
 
         true
     }
+
+    /// Determine whether the AABB intersects another AABB
+    ///
+    /// AABBs that merely touch are considered intersecting.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let [min, max, other_min, other_max] =
+            [self.min, self.max, other.min, other.max]
+                .map(|point| point.coords.components);
+
+        (0..D).all(|i| min[i] <= other_max[i] && other_min[i] <= max[i])
+    }
 }
 
 impl Aabb<2> {
@@ -174,4 +185,13 @@ mod tests {
         assert!(!aabb.contains([0., 2.]));
         assert!(!aabb.contains([4., 2.]));
     }
+
+    #[test]
+    fn intersects() {
+        let aabb = Aabb::<2>::from_points([[1., 1.], [3., 3.]]);
+
+        assert!(aabb.intersects(&Aabb::<2>::from_points([[2., 2.], [4., 4.]])));
+        assert!(aabb.intersects(&Aabb::<2>::from_points([[3., 0.], [4., 1.]])));
+        assert!(!aabb.intersects(&Aabb::<2>::from_points([[2., 4.], [4., 5.]])));
+    }
 }
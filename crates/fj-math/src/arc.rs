@@ -65,16 +65,9 @@ impl Arc {
             let from_center = p0 - center;
             from_center.v.atan2(from_center.u)
         };
-        let end_angle = {
-            let from_center = p1 - center;
-            let offset = if more_than_half_turn {
-                Scalar::TAU
-            } else {
-                Scalar::ZERO
-            };
-
-            from_center.v.atan2(from_center.u) + offset
-        };
+        // The end angle follows from the start angle, as the arc could cross
+        // the point where the angle computed from its position wraps around.
+        let end_angle = start_angle + angle_rad;
         Self {
             center,
             radius,
@@ -124,6 +117,12 @@ mod tests {
             0_f64.to_radians(),
             270_f64.to_radians(),
         );
+        check_arc_calculation(
+            [2., 1.],
+            0.5,
+            135_f64.to_radians(),
+            225_f64.to_radians(),
+        );
     }
 
     fn check_arc_calculation(
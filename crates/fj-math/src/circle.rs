@@ -33,9 +33,8 @@ impl<const D: usize> Circle<D> {
         let a = a.into();
         let b = b.into();
 
-        assert_eq!(
-            a.magnitude(),
-            b.magnitude(),
+        assert!(
+            (a.magnitude() - b.magnitude()).abs() < Scalar::default_epsilon(),
             "`a` and `b` must be of equal length"
         );
         assert_ne!(
@@ -125,12 +124,7 @@ impl<const D: usize> Circle<D> {
     ) -> Point<1> {
         let vector = (point.into() - self.center).to_uv();
         let atan = Scalar::atan2(vector.v, vector.u);
-        let coord = if atan >= Scalar::ZERO {
-            atan
-        } else {
-            atan + Scalar::TAU
-        };
-        Point::from([coord])
+        Point::from([normalize_angle(atan)])
     }
 
     /// Convert a point in circle coordinates into a `D`-dimensional point
@@ -163,6 +157,17 @@ impl<const D: usize> Circle<D> {
     }
 }
 
+/// Normalize an angle to be between `0.` (inclusive) and `PI * 2.` (exclusive)
+///
+/// Expects an angle as returned by [`Scalar::atan2`].
+pub(crate) fn normalize_angle(angle: Scalar) -> Scalar {
+    if angle >= Scalar::ZERO {
+        angle
+    } else {
+        angle + Scalar::TAU
+    }
+}
+
 impl<const D: usize> approx::AbsDiffEq for Circle<D> {
     type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
 
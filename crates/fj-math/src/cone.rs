@@ -0,0 +1,194 @@
+use approx::AbsDiffEq;
+
+use crate::{circle::normalize_angle, Point, Scalar, Vector};
+
+/// A circular cone
+///
+/// The first coordinate of the cone's coordinate system is the angle around its
+/// axis. The second coordinate is the position along the axis, with zero being
+/// the apex of the cone.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Cone {
+    apex: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    axis: Vector<3>,
+}
+
+impl Cone {
+    /// Construct a cone
+    ///
+    /// At the second coordinate `1`, the cone has the cross-section of a circle
+    /// around `apex + axis`, which is defined by `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The length of `a` and `b` must not be zero.
+    /// - `a` and `b` must be of equal length.
+    /// - `a`, `b`, and `axis` must be perpendicular to each other.
+    /// - `axis` must not be of length zero.
+    pub fn new(
+        apex: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        axis: impl Into<Vector<3>>,
+    ) -> Self {
+        let apex = apex.into();
+        let a = a.into();
+        let b = b.into();
+        let axis = axis.into();
+
+        assert!(
+            (a.magnitude() - b.magnitude()).abs() < Scalar::default_epsilon(),
+            "`a` and `b` must be of equal length"
+        );
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "cone cross-section must not be zero"
+        );
+        assert_ne!(
+            axis.magnitude(),
+            Scalar::ZERO,
+            "cone axis must not be zero"
+        );
+        for [v, w] in [[a, b], [a, axis], [b, axis]] {
+            assert!(
+                v.dot(&w).abs() < Scalar::default_epsilon(),
+                "`a`, `b`, and `axis` must be perpendicular to each other"
+            );
+        }
+
+        Self { apex, a, b, axis }
+    }
+
+    /// Construct a cone that opens along the z-axis
+    ///
+    /// The half-angle is the angle between the axis and the cone's surface, and
+    /// must be between `0.` and `PI / 2.` (both exclusive).
+    pub fn from_apex_and_half_angle(
+        apex: impl Into<Point<3>>,
+        half_angle_rad: impl Into<Scalar>,
+    ) -> Self {
+        let half_angle_rad = half_angle_rad.into();
+        assert!(
+            half_angle_rad > Scalar::ZERO && half_angle_rad < Scalar::PI / 2.,
+            "cone half-angle must be between 0 and 90 degrees"
+        );
+
+        let (sin, cos) = half_angle_rad.sin_cos();
+        let radius = sin / cos;
+
+        Self::new(
+            apex,
+            Vector::unit_x() * radius,
+            Vector::unit_y() * radius,
+            Vector::unit_z(),
+        )
+    }
+
+    /// Access the apex of the cone
+    pub fn apex(&self) -> Point<3> {
+        self.apex
+    }
+
+    /// Access the vector that defines the zero angle of the cone
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the angle
+    ///
+    /// This vector is perpendicular to [`Self::a`], and of equal length.
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the axis of the cone
+    pub fn axis(&self) -> Vector<3> {
+        self.axis
+    }
+
+    /// Access the angle between the cone's axis and its surface
+    pub fn half_angle(&self) -> Scalar {
+        self.a.magnitude().atan2(self.axis.magnitude())
+    }
+
+    /// Convert a point in cone coordinates into a 3-dimensional point
+    pub fn point_from_cone_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point = point.into();
+        let (sin, cos) = point.u.sin_cos();
+
+        self.apex + (self.axis + self.a * cos + self.b * sin) * point.v
+    }
+
+    /// Convert a vector at a point in cone coordinates into a 3D vector
+    pub fn vector_from_cone_coords(
+        &self,
+        point: impl Into<Point<2>>,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let point = point.into();
+        let vector = vector.into();
+        let (sin, cos) = point.u.sin_cos();
+
+        (self.b * cos - self.a * sin) * point.v * vector.u
+            + (self.axis + self.a * cos + self.b * sin) * vector.v
+    }
+
+    /// Convert a 3-dimensional point into cone coordinates
+    ///
+    /// Returns an angle between `0.` (inclusive) and `PI * 2.` (exclusive) as
+    /// the first coordinate.
+    ///
+    /// Points that are not on the cone are projected onto it, towards its
+    /// axis. Callers are advised to be careful about the points they pass, as
+    /// this will not result in an error.
+    pub fn point_to_cone_coords(&self, point: impl Into<Point<3>>) -> Point<2> {
+        let vector = point.into() - self.apex;
+        let [x, y, z] = [self.a, self.b, self.axis]
+            .map(|axis| vector.dot(&axis) / axis.dot(&axis));
+
+        Point::from([normalize_angle(y.atan2(x)), z])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_4, PI};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::Cone;
+
+    #[test]
+    fn cone_coords() {
+        let cone = Cone::from_apex_and_half_angle([0., 0., 1.], FRAC_PI_4);
+        assert_abs_diff_eq!(cone.half_angle(), Scalar::from(FRAC_PI_4));
+
+        let point = cone.point_from_cone_coords([PI, 2.]);
+        assert_abs_diff_eq!(
+            point,
+            Point::from([-2., 0., 3.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            cone.point_to_cone_coords(point),
+            Point::from([PI, 2.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        assert_abs_diff_eq!(
+            cone.vector_from_cone_coords([PI, 2.], [1., 1.]),
+            Vector::from([-1., -2., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+}
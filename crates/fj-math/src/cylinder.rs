@@ -0,0 +1,188 @@
+use approx::AbsDiffEq;
+
+use crate::{circle::normalize_angle, Point, Scalar, Vector};
+
+/// A circular cylinder
+///
+/// The cylinder's coordinate system is defined by a circle around its axis. The
+/// first coordinate is the angle on that circle, the second coordinate is the
+/// position along the axis.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Cylinder {
+    origin: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    axis: Vector<3>,
+}
+
+impl Cylinder {
+    /// Construct a cylinder
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The cylinder radius (defined by the length of `a` and `b`) must not be
+    ///   zero.
+    /// - `a` and `b` must be of equal length.
+    /// - `a`, `b`, and `axis` must be perpendicular to each other.
+    /// - `axis` must not be of length zero.
+    pub fn new(
+        origin: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        axis: impl Into<Vector<3>>,
+    ) -> Self {
+        let origin = origin.into();
+        let a = a.into();
+        let b = b.into();
+        let axis = axis.into();
+
+        assert!(
+            (a.magnitude() - b.magnitude()).abs() < Scalar::default_epsilon(),
+            "`a` and `b` must be of equal length"
+        );
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "cylinder radius must not be zero"
+        );
+        assert_ne!(
+            axis.magnitude(),
+            Scalar::ZERO,
+            "cylinder axis must not be zero"
+        );
+        for [v, w] in [[a, b], [a, axis], [b, axis]] {
+            assert!(
+                v.dot(&w).abs() < Scalar::default_epsilon(),
+                "`a`, `b`, and `axis` must be perpendicular to each other"
+            );
+        }
+
+        Self { origin, a, b, axis }
+    }
+
+    /// Construct a cylinder around the z-axis through the provided point
+    pub fn from_origin_and_radius(
+        origin: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        let radius = radius.into();
+
+        Self::new(
+            origin,
+            Vector::unit_x() * radius,
+            Vector::unit_y() * radius,
+            Vector::unit_z(),
+        )
+    }
+
+    /// Access the origin of the cylinder
+    ///
+    /// This is the point on the axis, where the second coordinate is zero.
+    pub fn origin(&self) -> Point<3> {
+        self.origin
+    }
+
+    /// Access the radius of the cylinder
+    pub fn radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the vector that defines the zero angle of the cylinder
+    ///
+    /// The length of this vector defines the radius of the cylinder.
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the angle
+    ///
+    /// This vector is perpendicular to [`Self::a`], and of equal length.
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the axis of the cylinder
+    ///
+    /// The length of this vector defines the scale of the second coordinate.
+    pub fn axis(&self) -> Vector<3> {
+        self.axis
+    }
+
+    /// Convert a point in cylinder coordinates into a 3-dimensional point
+    pub fn point_from_cylinder_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point = point.into();
+        let (sin, cos) = point.u.sin_cos();
+
+        self.origin + self.a * cos + self.b * sin + self.axis * point.v
+    }
+
+    /// Convert a vector at a point in cylinder coordinates into a 3D vector
+    pub fn vector_from_cylinder_coords(
+        &self,
+        point: impl Into<Point<2>>,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let point = point.into();
+        let vector = vector.into();
+        let (sin, cos) = point.u.sin_cos();
+
+        (self.b * cos - self.a * sin) * vector.u + self.axis * vector.v
+    }
+
+    /// Convert a 3-dimensional point into cylinder coordinates
+    ///
+    /// Returns an angle between `0.` (inclusive) and `PI * 2.` (exclusive) as
+    /// the first coordinate.
+    ///
+    /// Points that are not on the cylinder are projected onto it, towards its
+    /// axis. Callers are advised to be careful about the points they pass, as
+    /// this will not result in an error.
+    pub fn point_to_cylinder_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = point.into() - self.origin;
+        let [x, y, z] = [self.a, self.b, self.axis]
+            .map(|axis| vector.dot(&axis) / axis.dot(&axis));
+
+        Point::from([normalize_angle(y.atan2(x)), z])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Vector};
+
+    use super::Cylinder;
+
+    #[test]
+    fn cylinder_coords() {
+        let cylinder = Cylinder::new(
+            [1., 2., 3.],
+            [0., 2., 0.],
+            [0., 0., 2.],
+            [3., 0., 0.],
+        );
+
+        let point = cylinder.point_from_cylinder_coords([FRAC_PI_2, 2.]);
+        assert_abs_diff_eq!(point, Point::from([7., 2., 5.]));
+        assert_abs_diff_eq!(
+            cylinder.point_to_cylinder_coords(point),
+            Point::from([FRAC_PI_2, 2.]),
+        );
+
+        assert_abs_diff_eq!(
+            cylinder.vector_from_cylinder_coords([FRAC_PI_2, 2.], [1., 1.]),
+            Vector::from([3., -2., 0.]),
+        );
+    }
+}
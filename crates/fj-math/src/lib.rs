@@ -34,13 +34,18 @@
 mod aabb;
 mod arc;
 mod circle;
+mod cone;
 mod coordinates;
+mod cylinder;
 mod line;
+mod nurbs;
 mod plane;
 mod point;
 mod poly_chain;
 mod scalar;
 mod segment;
+mod sphere;
+mod torus;
 mod transform;
 mod triangle;
 mod vector;
@@ -49,13 +54,18 @@ pub use self::{
     aabb::Aabb,
     arc::Arc,
     circle::Circle,
+    cone::Cone,
     coordinates::{Uv, Xyz, T},
+    cylinder::Cylinder,
     line::Line,
+    nurbs::{NurbsCurve, NurbsSurface},
     plane::Plane,
     point::Point,
     poly_chain::PolyChain,
     scalar::{Scalar, Sign},
     segment::Segment,
+    sphere::Sphere,
+    torus::Torus,
     transform::Transform,
     triangle::{Triangle, Winding},
     vector::Vector,
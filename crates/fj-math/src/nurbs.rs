@@ -0,0 +1,1367 @@
+use crate::{Aabb, Circle, Point, Scalar, Vector};
+
+/// A non-uniform rational B-spline (NURBS) curve
+///
+/// The curve coordinate is the parameter of the curve, which is defined over
+/// the domain returned by [`NurbsCurve::domain`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NurbsCurve<const D: usize> {
+    degree: usize,
+    control_points: Vec<Point<D>>,
+    weights: Vec<Scalar>,
+    knots: Vec<Scalar>,
+}
+
+impl<const D: usize> NurbsCurve<D> {
+    /// Construct a NURBS curve
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The degree must be at least `1`.
+    /// - There must be more control points than the degree.
+    /// - There must be one weight per control point, and all weights must be
+    ///   positive.
+    /// - There must be as many knots as control points, plus the degree, plus
+    ///   one.
+    /// - The knots must not be decreasing, and the domain of the curve must not
+    ///   be empty.
+    pub fn new(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+        weights: impl IntoIterator<Item = impl Into<Scalar>>,
+        knots: impl IntoIterator<Item = impl Into<Scalar>>,
+    ) -> Self {
+        let control_points = control_points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+        let weights = weights.into_iter().map(Into::into).collect::<Vec<_>>();
+        let knots = knots.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        validate(degree, control_points.len(), &weights, &knots);
+
+        Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        }
+    }
+
+    /// Construct a non-rational B-spline curve from its control points
+    ///
+    /// The curve is clamped to its first and last control point, with its knots
+    /// spaced uniformly over the domain `0..=1`.
+    pub fn from_control_points(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Self {
+        let control_points = control_points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+        let num_control_points = control_points.len();
+
+        Self::new(
+            degree,
+            control_points,
+            vec![Scalar::ONE; num_control_points],
+            clamped_uniform_knots(degree, num_control_points),
+        )
+    }
+
+    /// Construct a NURBS curve that follows a circle between two angles
+    ///
+    /// The curve is rational and represents the arc exactly. It consists of
+    /// one span per quarter circle, or part thereof, and its domain is `0..=1`.
+    /// Please note that, unlike the angle, the curve coordinate is not
+    /// proportional to the length of the arc.
+    ///
+    /// The arc follows the circle in negative direction, if the end angle is
+    /// smaller than the start angle.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if both angles are equal.
+    pub fn circle_arc(
+        circle: &Circle<D>,
+        angles_rad: [impl Into<Scalar>; 2],
+    ) -> Self {
+        let [start, end] = angles_rad.map(Into::into);
+        assert_ne!(start, end, "arc must not be empty");
+
+        // Angles often come out of computations that introduce rounding
+        // errors. Don't let those add a span to an arc that covers a quarter
+        // circle.
+        let num_spans = ((end - start).abs() / (Scalar::PI / 2.) - 1e-9)
+            .ceil()
+            .into_f64()
+            .max(1.) as usize;
+        let span = (end - start) / num_spans as f64;
+        let (_, half_span_cos) = (span / 2.).sin_cos();
+
+        let point_at = |angle: Scalar| {
+            let (sin, cos) = angle.sin_cos();
+            circle.center() + circle.a() * cos + circle.b() * sin
+        };
+
+        let mut control_points = vec![point_at(start)];
+        let mut weights = vec![Scalar::ONE];
+        let mut knots = vec![Scalar::ZERO; 3];
+
+        for i in 0..num_spans {
+            let angle = start + span * i as f64;
+
+            // The middle control point of each span is where the tangents at
+            // its ends intersect.
+            let middle = point_at(angle + span / 2.);
+            control_points.push(
+                circle.center() + (middle - circle.center()) / half_span_cos,
+            );
+            weights.push(half_span_cos);
+
+            control_points.push(point_at(angle + span));
+            weights.push(Scalar::ONE);
+
+            let knot = Scalar::from((i + 1) as f64 / num_spans as f64);
+            knots.extend([knot, knot]);
+        }
+        knots.push(Scalar::ONE);
+
+        Self::new(2, control_points, weights, knots)
+    }
+
+    /// Access the degree of the curve
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Access the control points of the curve
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// Access the weights of the control points
+    pub fn weights(&self) -> &[Scalar] {
+        &self.weights
+    }
+
+    /// Access the knot vector of the curve
+    pub fn knots(&self) -> &[Scalar] {
+        &self.knots
+    }
+
+    /// Access the domain of the curve
+    ///
+    /// Returns the first and last curve coordinate for which the curve is
+    /// defined.
+    pub fn domain(&self) -> [Scalar; 2] {
+        domain(self.degree, &self.knots)
+    }
+
+    /// Create a new instance of the curve with the same control points, but
+    /// mapped through the provided function
+    ///
+    /// This can be used to transform the curve, as NURBS curves are invariant
+    /// under affine transformations. This is not true for projective
+    /// transformations, as those would also have to change the weights.
+    pub fn map_control_points<const E: usize>(
+        &self,
+        f: impl FnMut(&Point<D>) -> Point<E>,
+    ) -> NurbsCurve<E> {
+        NurbsCurve {
+            degree: self.degree,
+            control_points: self.control_points.iter().map(f).collect(),
+            weights: self.weights.clone(),
+            knots: self.knots.clone(),
+        }
+    }
+
+    /// Create a new instance that is the reverse of this one
+    ///
+    /// The curve coordinate of a point on the reversed curve is the negation
+    /// of the curve coordinate of the same point on the original curve.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.control_points.reverse();
+        self.weights.reverse();
+        self.knots = self.knots.iter().rev().map(|&knot| -knot).collect();
+
+        self
+    }
+
+    /// Create a new instance that only covers part of this one
+    ///
+    /// The part is defined by the curve coordinates of its start and end. The
+    /// new curve has the same shape and curve coordinates as this one, within
+    /// that part. If the end comes before the start, the new curve is reversed
+    /// (see [`NurbsCurve::reverse`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics, if start and end are equal.
+    #[must_use]
+    pub fn segment(&self, boundary: [impl Into<Scalar>; 2]) -> Self {
+        let [start, end] = boundary.map(Into::into);
+        assert_ne!(start, end, "segment must not be empty");
+
+        if start > end {
+            return self.segment([end, start]).reverse();
+        }
+
+        let [domain_start, domain_end] = self.domain();
+        let degree = self.degree;
+        let mut curve = self.clone();
+
+        if start > domain_start {
+            curve.insert_knot_up_to_degree(start);
+
+            // Only the basis functions that end after the start contribute to
+            // the new curve.
+            let first =
+                curve.knots.partition_point(|&knot| knot <= start) - degree - 1;
+
+            curve.control_points.drain(..first);
+            curve.weights.drain(..first);
+            curve.knots.drain(..=first + degree);
+            curve.knots.splice(0..0, vec![start; degree + 1]);
+        }
+
+        if end < domain_end {
+            curve.insert_knot_up_to_degree(end);
+
+            // Only the basis functions that start before the end contribute to
+            // the new curve.
+            let last = curve.knots.partition_point(|&knot| knot < end);
+
+            curve.control_points.truncate(last);
+            curve.weights.truncate(last);
+            curve.knots.truncate(last);
+            curve.knots.extend(vec![end; degree + 1]);
+        }
+
+        curve
+    }
+
+    /// Create a new instance that has the same shape, but a different domain
+    ///
+    /// The curve coordinates are mapped linearly, so that the start of the
+    /// domain of this curve becomes the start of the provided domain, and its
+    /// end becomes the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the end of the provided domain doesn't come after its start.
+    #[must_use]
+    pub fn with_domain(&self, domain: [impl Into<Scalar>; 2]) -> Self {
+        let [start, end] = domain.map(Into::into);
+        let [old_start, old_end] = self.domain();
+
+        let scale = (end - start) / (old_end - old_start);
+        let knots = self
+            .knots
+            .iter()
+            .map(|&knot| start + (knot - old_start) * scale);
+
+        Self::new(
+            self.degree,
+            self.control_points.clone(),
+            self.weights.clone(),
+            knots,
+        )
+    }
+
+    /// Create a new instance that has the same shape, but a higher degree
+    ///
+    /// The degree of the new curve is higher by one. Its curve coordinates are
+    /// the same as those of this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the curve is not clamped, meaning the knots at the start and
+    /// end of the domain don't have a multiplicity of the degree plus one.
+    #[must_use]
+    pub fn elevate_degree(&self) -> Self {
+        let degree = self.degree;
+        let [start, end] = self.domain();
+
+        assert!(
+            self.knots[..=degree].iter().all(|&knot| knot == start)
+                && self.knots[self.knots.len() - degree - 1..]
+                    .iter()
+                    .all(|&knot| knot == end),
+            "curve must be clamped"
+        );
+
+        // Split the curve into Bézier segments, by inserting each interior
+        // knot until its multiplicity equals the degree. Each segment can then
+        // be elevated on its own.
+        let mut curve = self.clone();
+        for knot in distinct(&self.knots) {
+            if knot > start && knot < end {
+                curve.insert_knot_up_to_degree(knot);
+            }
+        }
+
+        let homogeneous = curve
+            .control_points
+            .iter()
+            .zip(&curve.weights)
+            .map(|(point, &weight)| (point.coords * weight, weight))
+            .collect::<Vec<_>>();
+
+        let mut control_points = Vec::new();
+        let mut weights = Vec::new();
+        let mut push = |(coords, weight): (Vector<D>, Scalar)| {
+            control_points.push(Point {
+                coords: coords / weight,
+            });
+            weights.push(weight);
+        };
+
+        push(homogeneous[0]);
+        for segment in homogeneous.windows(degree + 1).step_by(degree) {
+            for i in 1..=degree {
+                let alpha = Scalar::from(i as f64 / (degree + 1) as f64);
+                let [a, b] = [segment[i - 1], segment[i]];
+
+                push((
+                    a.0 * alpha + b.0 * (Scalar::ONE - alpha),
+                    a.1 * alpha + b.1 * (Scalar::ONE - alpha),
+                ));
+            }
+            push(segment[degree]);
+        }
+
+        // Every distinct knot gains an additional multiplicity.
+        let mut knots = curve.knots.clone();
+        for knot in distinct(&curve.knots) {
+            let index = knots.partition_point(|&k| k <= knot);
+            knots.insert(index, knot);
+        }
+
+        Self::new(degree + 1, control_points, weights, knots)
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_curve_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let (point, _) = self.evaluate(point.into().t);
+        point
+    }
+
+    /// Convert a vector at a point in curve coordinates into a `D`-dimensional
+    /// vector
+    ///
+    /// Unlike lines and circles, the direction of a NURBS curve depends on the
+    /// location on the curve. The point that the vector is located at is
+    /// required to compute it.
+    pub fn vector_from_curve_coords(
+        &self,
+        point: impl Into<Point<1>>,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let (_, derivative) = self.evaluate(point.into().t);
+        derivative * vector.into().t
+    }
+
+    /// Convert a `D`-dimensional point into curve coordinates
+    ///
+    /// Returns the curve coordinate of the point on the curve that is closest
+    /// to the provided point. The closest point is found numerically, starting
+    /// from the closest of a number of samples of the curve. Callers are
+    /// advised to be careful about the points they pass, as points that are
+    /// far away from the curve might not result in the globally closest point.
+    pub fn point_to_curve_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        const SAMPLES_PER_CONTROL_POINT: usize = 4;
+        const MAX_ITERATIONS: usize = 32;
+
+        let point = point.into();
+        let [start, end] = self.domain();
+
+        let num_samples = self.control_points.len() * SAMPLES_PER_CONTROL_POINT;
+
+        let mut closest = start;
+        let mut closest_distance = None;
+        for i in 0..=num_samples {
+            let candidate = start
+                + (end - start) * Scalar::from(i as f64 / num_samples as f64);
+            let distance =
+                (self.point_from_curve_coords([candidate]) - point).magnitude();
+
+            if closest_distance.map_or(true, |closest| distance < closest) {
+                closest = candidate;
+                closest_distance = Some(distance);
+            }
+        }
+
+        // Refine the closest sample using Newton's method, by finding the
+        // point where the vector to the provided point is perpendicular to the
+        // derivative.
+        for _ in 0..MAX_ITERATIONS {
+            let (curve_point, derivative) = self.evaluate(closest);
+            let r = curve_point - point;
+
+            let d = derivative.dot(&derivative);
+            if d == Scalar::ZERO {
+                break;
+            }
+
+            let next = (closest - r.dot(&derivative) / d).max(start).min(end);
+
+            let step = (next - closest).abs();
+            closest = next;
+
+            if step < Scalar::from(1e-12) {
+                break;
+            }
+        }
+
+        Point::from([closest])
+    }
+
+    /// Calculate an AABB that contains the curve
+    ///
+    /// A NURBS curve is contained within the convex hull of its control points,
+    /// which means the returned AABB is conservative.
+    pub fn aabb(&self) -> Aabb<D> {
+        let mut min = self.control_points[0];
+        let mut max = self.control_points[0];
+
+        for point in &self.control_points {
+            for i in 0..D {
+                min.coords.components[i] =
+                    min.coords.components[i].min(point.coords.components[i]);
+                max.coords.components[i] =
+                    max.coords.components[i].max(point.coords.components[i]);
+            }
+        }
+
+        Aabb { min, max }
+    }
+
+    /// Insert a knot, until its multiplicity equals the degree of the curve
+    ///
+    /// This doesn't change the shape of the curve. It only makes sure that the
+    /// curve can be split at the knot.
+    fn insert_knot_up_to_degree(&mut self, t: Scalar) {
+        let multiplicity = self.knots.iter().filter(|&&knot| knot == t).count();
+
+        for _ in multiplicity..self.degree {
+            self.insert_knot(t);
+        }
+    }
+
+    /// Insert the knots of another curve, that this curve doesn't have yet
+    ///
+    /// Afterwards, each knot of the other curve has at least the same
+    /// multiplicity in this curve.
+    fn insert_knots_of(&mut self, other: &Self) {
+        for knot in distinct(&other.knots) {
+            let [ours, theirs] = [&self.knots, &other.knots]
+                .map(|knots| knots.iter().filter(|&&k| k == knot).count());
+
+            for _ in ours..theirs {
+                self.insert_knot(knot);
+            }
+        }
+    }
+
+    /// Insert a single knot, without changing the shape of the curve
+    fn insert_knot(&mut self, t: Scalar) {
+        let degree = self.degree;
+        let span = find_span(degree, &self.knots, t);
+
+        let mut control_points = Vec::new();
+        let mut weights = Vec::new();
+
+        for i in 0..=self.control_points.len() {
+            if i + degree <= span {
+                control_points.push(self.control_points[i]);
+                weights.push(self.weights[i]);
+            } else if i <= span {
+                // The new control points are computed in homogeneous
+                // coordinates, to take the weights into account.
+                let alpha = (t - self.knots[i])
+                    / (self.knots[i + degree] - self.knots[i]);
+                let [a, b] = [i - 1, i].map(|i| {
+                    (
+                        self.control_points[i].coords * self.weights[i],
+                        self.weights[i],
+                    )
+                });
+
+                let weight = a.1 * (Scalar::ONE - alpha) + b.1 * alpha;
+                let coords =
+                    (a.0 * (Scalar::ONE - alpha) + b.0 * alpha) / weight;
+
+                control_points.push(Point { coords });
+                weights.push(weight);
+            } else {
+                control_points.push(self.control_points[i - 1]);
+                weights.push(self.weights[i - 1]);
+            }
+        }
+
+        self.control_points = control_points;
+        self.weights = weights;
+        self.knots.insert(span + 1, t);
+    }
+
+    /// Evaluate the point and first derivative at the provided parameter
+    fn evaluate(&self, t: Scalar) -> (Point<D>, Vector<D>) {
+        let [start, end] = self.domain();
+        let t = t.max(start).min(end);
+
+        let span = find_span(self.degree, &self.knots, t);
+        let [basis, derivatives] =
+            basis_functions_and_derivatives(self.degree, &self.knots, span, t);
+
+        let mut a = Vector::<D>::default();
+        let mut a_derivative = Vector::<D>::default();
+        let mut w = Scalar::ZERO;
+        let mut w_derivative = Scalar::ZERO;
+
+        for j in 0..=self.degree {
+            let i = span - self.degree + j;
+            let weight = self.weights[i];
+            let coords = self.control_points[i].coords;
+
+            a = a + coords * basis[j] * weight;
+            a_derivative = a_derivative + coords * derivatives[j] * weight;
+            w += basis[j] * weight;
+            w_derivative += derivatives[j] * weight;
+        }
+
+        let point = a / w;
+        let derivative = (a_derivative - point * w_derivative) / w;
+
+        (Point { coords: point }, derivative)
+    }
+}
+
+/// A non-uniform rational B-spline (NURBS) surface
+///
+/// The control points are arranged in a grid. The outer dimension of the grid
+/// corresponds to the u-direction of the surface, the inner dimension to its
+/// v-direction.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NurbsSurface {
+    degree: [usize; 2],
+    control_points: Vec<Vec<Point<3>>>,
+    weights: Vec<Vec<Scalar>>,
+    knots: [Vec<Scalar>; 2],
+}
+
+impl NurbsSurface {
+    /// Construct a NURBS surface
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the requirements documented on [`NurbsCurve::new`] are not
+    /// met in either direction, or if the grid of control points or weights is
+    /// not rectangular.
+    pub fn new(
+        degree: [usize; 2],
+        control_points: Vec<Vec<Point<3>>>,
+        weights: Vec<Vec<Scalar>>,
+        knots: [Vec<Scalar>; 2],
+    ) -> Self {
+        let num_u = control_points.len();
+        let num_v = control_points.first().map(Vec::len).unwrap_or(0);
+
+        assert_eq!(
+            weights.len(),
+            num_u,
+            "must have one weight per control point"
+        );
+        for (points, weights) in control_points.iter().zip(&weights) {
+            assert_eq!(
+                points.len(),
+                num_v,
+                "grid of control points must be rectangular"
+            );
+            assert_eq!(
+                weights.len(),
+                num_v,
+                "must have one weight per control point"
+            );
+        }
+
+        let [knots_u, knots_v] = &knots;
+        validate(degree[0], num_u, &vec![Scalar::ONE; num_u], knots_u);
+        validate(degree[1], num_v, &weights.concat(), knots_v);
+
+        Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        }
+    }
+
+    /// Construct a non-rational B-spline surface from its control points
+    ///
+    /// The surface is clamped to the corners of the grid of control points,
+    /// with its knots spaced uniformly over the domain `0..=1`, in both
+    /// directions.
+    pub fn from_control_points(
+        degree: [usize; 2],
+        control_points: Vec<Vec<Point<3>>>,
+    ) -> Self {
+        let num_u = control_points.len();
+        let num_v = control_points.first().map(Vec::len).unwrap_or(0);
+
+        Self::new(
+            degree,
+            control_points,
+            vec![vec![Scalar::ONE; num_v]; num_u],
+            [
+                clamped_uniform_knots(degree[0], num_u),
+                clamped_uniform_knots(degree[1], num_v),
+            ],
+        )
+    }
+
+    /// Construct a ruled surface between two curves
+    ///
+    /// The u-direction of the surface follows both curves, with the same
+    /// curve coordinates. In v-direction, the surface connects the points of
+    /// both curves with straight lines, from `0` at the first curve to `1` at
+    /// the second.
+    ///
+    /// The curves may have different degrees and knots. Where they do, the
+    /// surface is built from equivalent curves of the higher degree, that have
+    /// the knots of both. Where their weights differ, the points along each
+    /// line are not spaced linearly in v-direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the curves don't have the same domain, or if a curve of a
+    /// lower degree is not clamped (see [`NurbsCurve::elevate_degree`]).
+    pub fn ruled(curves: [&NurbsCurve<3>; 2]) -> Self {
+        let [mut a, mut b] = curves.map(Clone::clone);
+
+        assert_eq!(a.domain(), b.domain(), "curves must have the same domain");
+
+        while a.degree < b.degree {
+            a = a.elevate_degree();
+        }
+        while b.degree < a.degree {
+            b = b.elevate_degree();
+        }
+
+        a.insert_knots_of(&b);
+        b.insert_knots_of(&a);
+
+        let control_points = a
+            .control_points
+            .iter()
+            .zip(&b.control_points)
+            .map(|(&a, &b)| vec![a, b])
+            .collect();
+        let weights = a
+            .weights
+            .iter()
+            .zip(&b.weights)
+            .map(|(&a, &b)| vec![a, b])
+            .collect();
+
+        Self::new(
+            [a.degree, 1],
+            control_points,
+            weights,
+            [a.knots.clone(), clamped_uniform_knots(1, 2)],
+        )
+    }
+
+    /// Access the degree of the surface, in u- and v-direction
+    pub fn degree(&self) -> [usize; 2] {
+        self.degree
+    }
+
+    /// Access the grid of control points
+    pub fn control_points(&self) -> &[Vec<Point<3>>] {
+        &self.control_points
+    }
+
+    /// Access the weights of the control points
+    pub fn weights(&self) -> &[Vec<Scalar>] {
+        &self.weights
+    }
+
+    /// Access the knot vectors, in u- and v-direction
+    pub fn knots(&self) -> &[Vec<Scalar>; 2] {
+        &self.knots
+    }
+
+    /// Access the domain of the surface
+    ///
+    /// Returns the minimum and maximum surface coordinates for which the
+    /// surface is defined.
+    pub fn domain(&self) -> [Point<2>; 2] {
+        let [u, v] = [0, 1].map(|i| domain(self.degree[i], &self.knots[i]));
+        [Point::from([u[0], v[0]]), Point::from([u[1], v[1]])]
+    }
+
+    /// Create a new instance of the surface with its control points mapped
+    /// through the provided function
+    ///
+    /// This can be used to transform the surface, as NURBS surfaces are
+    /// invariant under affine transformations. This is not true for projective
+    /// transformations, as those would also have to change the weights.
+    #[must_use]
+    pub fn map_control_points(
+        &self,
+        mut f: impl FnMut(&Point<3>) -> Point<3>,
+    ) -> Self {
+        Self {
+            degree: self.degree,
+            control_points: self
+                .control_points
+                .iter()
+                .map(|points| points.iter().map(&mut f).collect())
+                .collect(),
+            weights: self.weights.clone(),
+            knots: self.knots.clone(),
+        }
+    }
+
+    /// Calculate an AABB that contains the surface
+    ///
+    /// A NURBS surface is contained within the convex hull of its control
+    /// points, which means the returned AABB is conservative.
+    pub fn aabb(&self) -> Aabb<3> {
+        Aabb::<3>::from_points(self.control_points.concat())
+    }
+
+    /// Convert a point in surface coordinates into a 3-dimensional point
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let (point, _) = self.evaluate(point.into());
+        point
+    }
+
+    /// Convert a vector at a point in surface coordinates into a 3D vector
+    pub fn vector_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let (_, [d_u, d_v]) = self.evaluate(point.into());
+        let vector = vector.into();
+
+        d_u * vector.u + d_v * vector.v
+    }
+
+    /// Convert a 3-dimensional point into surface coordinates
+    ///
+    /// Returns the surface coordinates of the point on the surface that is
+    /// closest to the provided point. The closest point is found numerically,
+    /// starting from the closest of a number of samples of the surface.
+    /// Callers are advised to be careful about the points they pass, as points
+    /// that are far away from the surface might not result in the globally
+    /// closest point.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        const SAMPLES_PER_CONTROL_POINT: usize = 4;
+        const MAX_ITERATIONS: usize = 32;
+
+        let point = point.into();
+        let [min, max] = self.domain();
+
+        let num_samples =
+            [self.control_points.len(), self.control_points[0].len()]
+                .map(|n| n * SAMPLES_PER_CONTROL_POINT);
+
+        let mut closest = min;
+        let mut closest_distance = None;
+        for i in 0..=num_samples[0] {
+            for j in 0..=num_samples[1] {
+                let candidate = Point::from([
+                    min.u
+                        + (max.u - min.u)
+                            * Scalar::from(i as f64 / num_samples[0] as f64),
+                    min.v
+                        + (max.v - min.v)
+                            * Scalar::from(j as f64 / num_samples[1] as f64),
+                ]);
+                let distance = (self.point_from_surface_coords(candidate)
+                    - point)
+                    .magnitude();
+
+                if closest_distance.map_or(true, |closest| distance < closest) {
+                    closest = candidate;
+                    closest_distance = Some(distance);
+                }
+            }
+        }
+
+        // Refine the closest sample using Newton's method, by finding the
+        // point where the vector to the provided point is perpendicular to
+        // both partial derivatives.
+        for _ in 0..MAX_ITERATIONS {
+            let (surface_point, [d_u, d_v]) = self.evaluate(closest);
+            let r = surface_point - point;
+
+            let [a, b, c] = [d_u.dot(&d_u), d_u.dot(&d_v), d_v.dot(&d_v)];
+            let [f, g] = [r.dot(&d_u), r.dot(&d_v)];
+
+            let determinant = a * c - b * b;
+            if determinant == Scalar::ZERO {
+                break;
+            }
+
+            let delta_u = (b * g - c * f) / determinant;
+            let delta_v = (b * f - a * g) / determinant;
+
+            let next = Point::from([
+                (closest.u + delta_u).max(min.u).min(max.u),
+                (closest.v + delta_v).max(min.v).min(max.v),
+            ]);
+
+            let step = (next - closest).magnitude();
+            closest = next;
+
+            if step < Scalar::from(1e-12) {
+                break;
+            }
+        }
+
+        closest
+    }
+
+    /// Evaluate the point and partial derivatives at the provided parameters
+    fn evaluate(&self, point: Point<2>) -> (Point<3>, [Vector<3>; 2]) {
+        let [min, max] = self.domain();
+        let [u, v] = [0, 1].map(|i| {
+            point.coords.components[i]
+                .max(min.coords.components[i])
+                .min(max.coords.components[i])
+        });
+
+        let [degree_u, degree_v] = self.degree;
+        let [knots_u, knots_v] = &self.knots;
+
+        let span_u = find_span(degree_u, knots_u, u);
+        let span_v = find_span(degree_v, knots_v, v);
+        let [basis_u, derivatives_u] =
+            basis_functions_and_derivatives(degree_u, knots_u, span_u, u);
+        let [basis_v, derivatives_v] =
+            basis_functions_and_derivatives(degree_v, knots_v, span_v, v);
+
+        let mut a = Vector::<3>::default();
+        let mut a_u = Vector::<3>::default();
+        let mut a_v = Vector::<3>::default();
+        let mut w = Scalar::ZERO;
+        let mut w_u = Scalar::ZERO;
+        let mut w_v = Scalar::ZERO;
+
+        for k in 0..=degree_u {
+            for l in 0..=degree_v {
+                let i = span_u - degree_u + k;
+                let j = span_v - degree_v + l;
+
+                let weight = self.weights[i][j];
+                let coords = self.control_points[i][j].coords;
+
+                let n = basis_u[k] * basis_v[l] * weight;
+                let n_u = derivatives_u[k] * basis_v[l] * weight;
+                let n_v = basis_u[k] * derivatives_v[l] * weight;
+
+                a = a + coords * n;
+                a_u = a_u + coords * n_u;
+                a_v = a_v + coords * n_v;
+                w += n;
+                w_u += n_u;
+                w_v += n_v;
+            }
+        }
+
+        let point = a / w;
+        let d_u = (a_u - point * w_u) / w;
+        let d_v = (a_v - point * w_v) / w;
+
+        (Point { coords: point }, [d_u, d_v])
+    }
+}
+
+fn validate(
+    degree: usize,
+    num_control_points: usize,
+    weights: &[Scalar],
+    knots: &[Scalar],
+) {
+    assert!(degree >= 1, "degree must be at least 1");
+    assert!(
+        num_control_points > degree,
+        "must have more control points than the degree"
+    );
+    assert!(
+        weights.len() % num_control_points == 0,
+        "must have one weight per control point"
+    );
+    assert!(
+        weights.iter().all(|&weight| weight > Scalar::ZERO),
+        "weights must be positive"
+    );
+    assert_eq!(
+        knots.len(),
+        num_control_points + degree + 1,
+        "number of knots must equal number of control points plus degree plus \
+        one"
+    );
+    assert!(
+        knots.windows(2).all(|knots| knots[0] <= knots[1]),
+        "knots must not be decreasing"
+    );
+
+    let [start, end] = domain(degree, knots);
+    assert!(start < end, "domain must not be empty");
+}
+
+fn clamped_uniform_knots(
+    degree: usize,
+    num_control_points: usize,
+) -> Vec<Scalar> {
+    let num_spans = num_control_points.saturating_sub(degree).max(1);
+
+    let mut knots = vec![Scalar::ZERO; degree + 1];
+    knots.extend(
+        (1..num_spans).map(|i| Scalar::from(i as f64 / num_spans as f64)),
+    );
+    knots.extend(vec![Scalar::ONE; degree + 1]);
+
+    knots
+}
+
+fn domain(degree: usize, knots: &[Scalar]) -> [Scalar; 2] {
+    [knots[degree], knots[knots.len() - degree - 1]]
+}
+
+/// Return the distinct values of a knot vector, in order
+fn distinct(knots: &[Scalar]) -> Vec<Scalar> {
+    let mut knots = knots.to_vec();
+    knots.dedup();
+    knots
+}
+
+/// Find the knot span that contains the provided parameter
+///
+/// Returns the index `i`, for which `knots[i] <= t < knots[i + 1]` holds. The
+/// end of the domain is assigned to the last non-empty span.
+fn find_span(degree: usize, knots: &[Scalar], t: Scalar) -> usize {
+    let n = knots.len() - degree - 2;
+
+    if t >= knots[n + 1] {
+        // The last span of the domain might be followed by empty spans, if the
+        // knot vector is clamped. Make sure to find the last non-empty one.
+        let mut span = n;
+        while span > degree && knots[span] >= knots[n + 1] {
+            span -= 1;
+        }
+        return span;
+    }
+    if t <= knots[degree] {
+        let mut span = degree;
+        while knots[span + 1] <= knots[degree] {
+            span += 1;
+        }
+        return span;
+    }
+
+    let mut low = degree;
+    let mut high = n + 1;
+    let mut mid = (low + high) / 2;
+
+    while t < knots[mid] || t >= knots[mid + 1] {
+        if t < knots[mid] {
+            high = mid;
+        } else {
+            low = mid;
+        }
+        mid = (low + high) / 2;
+    }
+
+    mid
+}
+
+/// Compute the non-zero basis functions at the provided parameter
+///
+/// Returns the values of the `degree + 1` basis functions that are non-zero
+/// within the provided knot span.
+fn basis_functions(
+    degree: usize,
+    knots: &[Scalar],
+    span: usize,
+    t: Scalar,
+) -> Vec<Scalar> {
+    let mut basis = vec![Scalar::ZERO; degree + 1];
+    let mut left = vec![Scalar::ZERO; degree + 1];
+    let mut right = vec![Scalar::ZERO; degree + 1];
+
+    basis[0] = Scalar::ONE;
+
+    for j in 1..=degree {
+        left[j] = t - knots[span + 1 - j];
+        right[j] = knots[span + j] - t;
+
+        let mut saved = Scalar::ZERO;
+        for r in 0..j {
+            let temp = basis[r] / (right[r + 1] + left[j - r]);
+            basis[r] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        basis[j] = saved;
+    }
+
+    basis
+}
+
+/// Compute the non-zero basis functions and their first derivatives
+fn basis_functions_and_derivatives(
+    degree: usize,
+    knots: &[Scalar],
+    span: usize,
+    t: Scalar,
+) -> [Vec<Scalar>; 2] {
+    let basis = basis_functions(degree, knots, span, t);
+    let lower = basis_functions(degree - 1, knots, span, t);
+
+    let p = Scalar::from(degree as f64);
+    let derivatives = (0..=degree)
+        .map(|j| {
+            let i = span - degree + j;
+            let mut derivative = Scalar::ZERO;
+
+            if j > 0 {
+                let denominator = knots[i + degree] - knots[i];
+                if denominator != Scalar::ZERO {
+                    derivative += p * lower[j - 1] / denominator;
+                }
+            }
+            if j < degree {
+                let denominator = knots[i + degree + 1] - knots[i + 1];
+                if denominator != Scalar::ZERO {
+                    derivative -= p * lower[j] / denominator;
+                }
+            }
+
+            derivative
+        })
+        .collect();
+
+    [basis, derivatives]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Circle, Point, Scalar, Vector};
+
+    use super::{NurbsCurve, NurbsSurface};
+
+    #[test]
+    fn curve_quarter_circle() {
+        let curve = NurbsCurve::new(
+            2,
+            [[1., 0.], [1., 1.], [0., 1.]],
+            [1., FRAC_1_SQRT_2, 1.],
+            [0., 0., 0., 1., 1., 1.],
+        );
+
+        for i in 0..=10 {
+            let point = curve.point_from_curve_coords([i as f64 / 10.]);
+            assert_abs_diff_eq!(
+                point.coords.magnitude(),
+                Scalar::ONE,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+
+        let tangent = curve.vector_from_curve_coords([0.], [1.]);
+        assert_abs_diff_eq!(
+            tangent.normalize(),
+            Vector::from([0., 1.]),
+            epsilon = Scalar::from(1e-12)
+        );
+
+        let reversed = curve.clone().reverse();
+        assert_abs_diff_eq!(
+            reversed.point_from_curve_coords([-0.25]),
+            curve.point_from_curve_coords([0.25]),
+            epsilon = Scalar::from(1e-12)
+        );
+    }
+
+    #[test]
+    fn curve_circle_arc() {
+        let circle = Circle::new([1., 2.], [2., 0.], [0., 2.]);
+
+        for angles in [[0., 5.], [1., -2.], [0., std::f64::consts::TAU]] {
+            let curve = NurbsCurve::circle_arc(&circle, angles);
+            let [start, end] =
+                angles.map(|angle| circle.point_from_circle_coords([angle]));
+
+            assert_abs_diff_eq!(
+                curve.point_from_curve_coords([0.]),
+                start,
+                epsilon = Scalar::from(1e-12)
+            );
+            assert_abs_diff_eq!(
+                curve.point_from_curve_coords([1.]),
+                end,
+                epsilon = Scalar::from(1e-12)
+            );
+
+            for i in 0..=20 {
+                let point = curve.point_from_curve_coords([i as f64 / 20.]);
+                assert_abs_diff_eq!(
+                    point.distance_to(&circle.center()),
+                    Scalar::TWO,
+                    epsilon = Scalar::from(1e-12)
+                );
+            }
+
+            // The curve follows the circle in the direction from the start
+            // angle to the end angle.
+            let tangent = curve.vector_from_curve_coords([0.], [1.]);
+            let expected = circle.vector_from_circle_coords([1.])
+                * (angles[1] - angles[0]).signum();
+            assert!(tangent.dot(&expected) > Scalar::ZERO);
+        }
+    }
+
+    #[test]
+    fn curve_segment() {
+        let curve = NurbsCurve::new(
+            3,
+            [
+                [0., 0., 0.],
+                [1., 2., 0.],
+                [2., -1., 1.],
+                [3., 0., 0.],
+                [4., 1., 1.],
+            ],
+            [1., 0.5, 2., 1., 1.5],
+            [0., 0., 0., 0., 0.4, 1., 1., 1., 1.],
+        );
+
+        for boundary in [[0.1, 0.7], [0.4, 1.], [0.9, 0.2], [0., 1.]] {
+            let segment = curve.segment(boundary);
+            let [start, end] = boundary;
+
+            for i in 0..=10 {
+                let t = start + (end - start) * (i as f64 / 10.);
+                let t_segment = if start < end { t } else { -t };
+
+                assert_abs_diff_eq!(
+                    segment.point_from_curve_coords([t_segment]),
+                    curve.point_from_curve_coords([t]),
+                    epsilon = Scalar::from(1e-12)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn curve_with_domain() {
+        let curve =
+            NurbsCurve::from_control_points(2, [[0., 0.], [1., 2.], [2., 0.]]);
+        let mapped = curve.with_domain([2., 6.]);
+
+        assert_eq!(mapped.domain(), [Scalar::from(2.), Scalar::from(6.)]);
+        for t in [0., 0.25, 0.6, 1.] {
+            assert_abs_diff_eq!(
+                mapped.point_from_curve_coords([2. + 4. * t]),
+                curve.point_from_curve_coords([t]),
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn curve_elevate_degree() {
+        let curve = NurbsCurve::new(
+            3,
+            [
+                [0., 0., 0.],
+                [1., 2., 0.],
+                [2., -1., 1.],
+                [3., 0., 0.],
+                [4., 1., 1.],
+            ],
+            [1., 0.5, 2., 1., 1.5],
+            [0., 0., 0., 0., 0.4, 1., 1., 1., 1.],
+        );
+        let elevated = curve.elevate_degree();
+
+        assert_eq!(elevated.degree(), 4);
+        assert_eq!(elevated.domain(), curve.domain());
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+            assert_abs_diff_eq!(
+                elevated.point_from_curve_coords([t]),
+                curve.point_from_curve_coords([t]),
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn curve_derivative() {
+        let curve = NurbsCurve::from_control_points(
+            3,
+            [
+                [0., 0., 0.],
+                [1., 2., 0.],
+                [2., -1., 1.],
+                [3., 0., 0.],
+                [4., 1., 1.],
+            ],
+        );
+
+        let t = 0.3;
+        let h = 1e-6;
+        let expected = (curve.point_from_curve_coords([t + h])
+            - curve.point_from_curve_coords([t - h]))
+            / (2. * h);
+
+        assert_abs_diff_eq!(
+            curve.vector_from_curve_coords([t], [1.]),
+            expected,
+            epsilon = Scalar::from(1e-6)
+        );
+        assert_eq!(
+            curve.point_from_curve_coords([1.]),
+            Point::from([4., 1., 1.])
+        );
+    }
+
+    #[test]
+    fn curve_coords() {
+        let curve =
+            NurbsCurve::from_control_points(2, [[0., 0.], [1., 2.], [2., 0.]]);
+
+        for t in [0., 0.25, 0.5, 0.9, 1.] {
+            let point = curve.point_from_curve_coords([t]);
+            let projected = curve.point_to_curve_coords(point);
+            assert!((projected.t - Scalar::from(t)).abs() < Scalar::from(1e-9));
+        }
+    }
+
+    #[test]
+    fn surface_ruled() {
+        let circle = Circle::new([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let bottom = NurbsCurve::circle_arc(&circle, [0., 3.]);
+        let top = bottom
+            .map_control_points(|point| *point + Vector::from([1., 0., 2.]));
+
+        let surface = NurbsSurface::ruled([&bottom, &top]);
+
+        for t in [0., 0.3, 1.] {
+            let [a, b] =
+                [&bottom, &top].map(|curve| curve.point_from_curve_coords([t]));
+
+            for v in [0., 0.25, 1.] {
+                assert_abs_diff_eq!(
+                    surface.point_from_surface_coords([t, v]),
+                    a + (b - a) * v,
+                    epsilon = Scalar::from(1e-12)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn surface_ruled_between_different_curves() {
+        let circle = Circle::new([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let arc = NurbsCurve::circle_arc(&circle, [0., 3.]);
+        let line =
+            NurbsCurve::from_control_points(1, [[1., 0., 1.], [-1., 0., 1.]]);
+
+        let surface = NurbsSurface::ruled([&arc, &line]);
+
+        for t in [0., 0.3, 0.5, 1.] {
+            let [a, b] =
+                [&arc, &line].map(|curve| curve.point_from_curve_coords([t]));
+
+            // The weights of the curves differ, so the points are not spaced
+            // linearly along the lines between the curves.
+            for (v, expected) in [(0., a), (1., b)] {
+                assert_abs_diff_eq!(
+                    surface.point_from_surface_coords([t, v]),
+                    expected,
+                    epsilon = Scalar::from(1e-12)
+                );
+            }
+
+            let point = surface.point_from_surface_coords([t, 0.25]);
+            let s = (point - a).dot(&(b - a)) / (b - a).dot(&(b - a));
+            assert!(s > Scalar::ZERO && s < Scalar::ONE);
+            assert_abs_diff_eq!(
+                point,
+                a + (b - a) * s,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn surface_coords() {
+        let surface = NurbsSurface::from_control_points(
+            [2, 2],
+            vec![
+                vec![
+                    [0., 0., 0.].into(),
+                    [0., 1., 1.].into(),
+                    [0., 2., 0.].into(),
+                ],
+                vec![
+                    [1., 0., 1.].into(),
+                    [1., 1., 2.].into(),
+                    [1., 2., 1.].into(),
+                ],
+                vec![
+                    [2., 0., 0.].into(),
+                    [2., 1., 1.].into(),
+                    [2., 2., 0.].into(),
+                ],
+            ],
+        );
+
+        assert_eq!(
+            surface.point_from_surface_coords([1., 0.]),
+            Point::from([2., 0., 0.])
+        );
+
+        let point = Point::from([0.3, 0.6]);
+        let projected = surface
+            .point_to_surface_coords(surface.point_from_surface_coords(point));
+        assert_abs_diff_eq!(projected, point, epsilon = Scalar::from(1e-9));
+
+        let h = 1e-6;
+        let expected = (surface.point_from_surface_coords([0.3, 0.6 + h])
+            - surface.point_from_surface_coords([0.3, 0.6 - h]))
+            / (2. * h);
+        assert_abs_diff_eq!(
+            surface.vector_from_surface_coords(point, [0., 1.]),
+            expected,
+            epsilon = Scalar::from(1e-6)
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use approx::AbsDiffEq;
+
+use crate::{circle::normalize_angle, Point, Scalar, Vector};
+
+/// A sphere
+///
+/// The first coordinate of the sphere's coordinate system is the longitude,
+/// the angle around the axis defined by `c`. The second coordinate is the
+/// latitude, between `-PI / 2.` at one pole and `PI / 2.` at the other.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Sphere {
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    c: Vector<3>,
+}
+
+impl Sphere {
+    /// Construct a sphere
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The sphere radius (defined by the length of `a`, `b`, and `c`) must
+    ///   not be zero.
+    /// - `a`, `b`, and `c` must be of equal length.
+    /// - `a`, `b`, and `c` must be perpendicular to each other.
+    pub fn new(
+        center: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        c: impl Into<Vector<3>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+        let c = c.into();
+
+        for v in [b, c] {
+            assert!(
+                (a.magnitude() - v.magnitude()).abs()
+                    < Scalar::default_epsilon(),
+                "`a`, `b`, and `c` must be of equal length"
+            );
+        }
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "sphere radius must not be zero"
+        );
+        for [v, w] in [[a, b], [a, c], [b, c]] {
+            assert!(
+                v.dot(&w).abs() < Scalar::default_epsilon(),
+                "`a`, `b`, and `c` must be perpendicular to each other"
+            );
+        }
+
+        Self { center, a, b, c }
+    }
+
+    /// Construct a sphere from a center point and a radius
+    pub fn from_center_and_radius(
+        center: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        let radius = radius.into();
+
+        Self::new(
+            center,
+            Vector::unit_x() * radius,
+            Vector::unit_y() * radius,
+            Vector::unit_z() * radius,
+        )
+    }
+
+    /// Access the center point of the sphere
+    pub fn center(&self) -> Point<3> {
+        self.center
+    }
+
+    /// Access the radius of the sphere
+    pub fn radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the vector that points to zero longitude on the equator
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the longitude
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the vector that points to the pole at positive latitude
+    pub fn c(&self) -> Vector<3> {
+        self.c
+    }
+
+    /// Convert a point in sphere coordinates into a 3-dimensional point
+    pub fn point_from_sphere_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point = point.into();
+        let (sin_u, cos_u) = point.u.sin_cos();
+        let (sin_v, cos_v) = point.v.sin_cos();
+
+        self.center + (self.a * cos_u + self.b * sin_u) * cos_v + self.c * sin_v
+    }
+
+    /// Convert a vector at a point in sphere coordinates into a 3D vector
+    pub fn vector_from_sphere_coords(
+        &self,
+        point: impl Into<Point<2>>,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let point = point.into();
+        let vector = vector.into();
+        let (sin_u, cos_u) = point.u.sin_cos();
+        let (sin_v, cos_v) = point.v.sin_cos();
+
+        (self.b * cos_u - self.a * sin_u) * cos_v * vector.u
+            + (self.c * cos_v - (self.a * cos_u + self.b * sin_u) * sin_v)
+                * vector.v
+    }
+
+    /// Convert a 3-dimensional point into sphere coordinates
+    ///
+    /// Returns a longitude between `0.` (inclusive) and `PI * 2.` (exclusive)
+    /// as the first coordinate.
+    ///
+    /// Points that are not on the sphere are projected onto it, towards its
+    /// center. Callers are advised to be careful about the points they pass,
+    /// as this will not result in an error.
+    pub fn point_to_sphere_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = point.into() - self.center;
+        let [x, y, z] = [self.a, self.b, self.c]
+            .map(|axis| vector.dot(&axis) / axis.dot(&axis));
+
+        let distance_from_axis = x.into_f64().hypot(y.into_f64());
+
+        Point::from([
+            normalize_angle(y.atan2(x)),
+            z.atan2(Scalar::from(distance_from_axis)),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::Sphere;
+
+    #[test]
+    fn sphere_coords() {
+        let sphere = Sphere::from_center_and_radius([1., 1., 1.], 2.);
+
+        let point = sphere.point_from_sphere_coords([FRAC_PI_2, FRAC_PI_4]);
+        let expected = Point::from([1., 1. + 2_f64.sqrt(), 1. + 2_f64.sqrt()]);
+        assert_abs_diff_eq!(point, expected, epsilon = Scalar::from(1e-12));
+        assert_abs_diff_eq!(
+            sphere.point_to_sphere_coords(point),
+            Point::from([FRAC_PI_2, FRAC_PI_4]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        assert_abs_diff_eq!(
+            sphere.vector_from_sphere_coords([0., 0.], [1., 1.]),
+            Vector::from([0., 2., 2.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use approx::AbsDiffEq;
+
+use crate::{circle::normalize_angle, Point, Scalar, Vector};
+
+/// A torus
+///
+/// The torus is defined by a major circle, the center of the tube, and a minor
+/// radius, the radius of the tube. The first coordinate of the torus'
+/// coordinate system is the angle on the major circle. The second coordinate
+/// is the angle around the tube, with zero being on the outside of the torus.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Torus {
+    center: Point<3>,
+    a: Vector<3>,
+    b: Vector<3>,
+    c: Vector<3>,
+    minor_radius: Scalar,
+}
+
+impl Torus {
+    /// Construct a torus
+    ///
+    /// `a` and `b` define the major circle. `c` defines the direction of the
+    /// torus' axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The major radius (defined by the length of `a`, `b`, and `c`) must
+    ///   not be zero.
+    /// - `a`, `b`, and `c` must be of equal length.
+    /// - `a`, `b`, and `c` must be perpendicular to each other.
+    /// - The minor radius must be larger than zero, but smaller than the major
+    ///   radius.
+    pub fn new(
+        center: impl Into<Point<3>>,
+        a: impl Into<Vector<3>>,
+        b: impl Into<Vector<3>>,
+        c: impl Into<Vector<3>>,
+        minor_radius: impl Into<Scalar>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+        let c = c.into();
+        let minor_radius = minor_radius.into();
+
+        for v in [b, c] {
+            assert!(
+                (a.magnitude() - v.magnitude()).abs()
+                    < Scalar::default_epsilon(),
+                "`a`, `b`, and `c` must be of equal length"
+            );
+        }
+        assert_ne!(
+            a.magnitude(),
+            Scalar::ZERO,
+            "major radius must not be zero"
+        );
+        for [v, w] in [[a, b], [a, c], [b, c]] {
+            assert!(
+                v.dot(&w).abs() < Scalar::default_epsilon(),
+                "`a`, `b`, and `c` must be perpendicular to each other"
+            );
+        }
+        assert!(
+            minor_radius > Scalar::ZERO && minor_radius < a.magnitude(),
+            "minor radius must be between zero and the major radius"
+        );
+
+        Self {
+            center,
+            a,
+            b,
+            c,
+            minor_radius,
+        }
+    }
+
+    /// Construct a torus around the z-axis
+    pub fn from_center_and_radii(
+        center: impl Into<Point<3>>,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+    ) -> Self {
+        let major_radius = major_radius.into();
+
+        Self::new(
+            center,
+            Vector::unit_x() * major_radius,
+            Vector::unit_y() * major_radius,
+            Vector::unit_z() * major_radius,
+            minor_radius,
+        )
+    }
+
+    /// Access the center point of the torus
+    pub fn center(&self) -> Point<3> {
+        self.center
+    }
+
+    /// Access the major radius of the torus
+    pub fn major_radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the minor radius of the torus
+    pub fn minor_radius(&self) -> Scalar {
+        self.minor_radius
+    }
+
+    /// Access the vector that defines the zero angle of the major circle
+    pub fn a(&self) -> Vector<3> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the major circle
+    pub fn b(&self) -> Vector<3> {
+        self.b
+    }
+
+    /// Access the vector that defines the direction of the torus' axis
+    pub fn c(&self) -> Vector<3> {
+        self.c
+    }
+
+    /// Convert a point in torus coordinates into a 3-dimensional point
+    pub fn point_from_torus_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point = point.into();
+        let ratio = self.ratio();
+        let (sin_u, cos_u) = point.u.sin_cos();
+        let (sin_v, cos_v) = point.v.sin_cos();
+
+        self.center
+            + (self.a * cos_u + self.b * sin_u) * (Scalar::ONE + ratio * cos_v)
+            + self.c * ratio * sin_v
+    }
+
+    /// Convert a vector at a point in torus coordinates into a 3D vector
+    pub fn vector_from_torus_coords(
+        &self,
+        point: impl Into<Point<2>>,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let point = point.into();
+        let vector = vector.into();
+        let ratio = self.ratio();
+        let (sin_u, cos_u) = point.u.sin_cos();
+        let (sin_v, cos_v) = point.v.sin_cos();
+
+        (self.b * cos_u - self.a * sin_u)
+            * (Scalar::ONE + ratio * cos_v)
+            * vector.u
+            + (self.c * cos_v - (self.a * cos_u + self.b * sin_u) * sin_v)
+                * ratio
+                * vector.v
+    }
+
+    /// Convert a 3-dimensional point into torus coordinates
+    ///
+    /// Returns angles between `0.` (inclusive) and `PI * 2.` (exclusive).
+    ///
+    /// Points that are not on the torus are projected onto it, towards the
+    /// major circle. Callers are advised to be careful about the points they
+    /// pass, as this will not result in an error.
+    pub fn point_to_torus_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = point.into() - self.center;
+        let [x, y, z] = [self.a, self.b, self.c]
+            .map(|axis| vector.dot(&axis) / axis.dot(&axis));
+
+        let distance_from_major_circle =
+            Scalar::from(x.into_f64().hypot(y.into_f64())) - Scalar::ONE;
+
+        Point::from([
+            normalize_angle(y.atan2(x)),
+            normalize_angle(z.atan2(distance_from_major_circle)),
+        ])
+    }
+
+    fn ratio(&self) -> Scalar {
+        self.minor_radius / self.major_radius()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::Torus;
+
+    #[test]
+    fn torus_coords() {
+        let torus = Torus::from_center_and_radii([0., 0., 1.], 3., 1.);
+
+        let point = torus.point_from_torus_coords([FRAC_PI_2, PI]);
+        assert_abs_diff_eq!(
+            point,
+            Point::from([0., 2., 1.]),
+            epsilon = Scalar::from(1e-12)
+        );
+        assert_abs_diff_eq!(
+            torus.point_to_torus_coords(point),
+            Point::from([FRAC_PI_2, PI]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        assert_abs_diff_eq!(
+            torus.vector_from_torus_coords([0., FRAC_PI_2], [1., 1.]),
+            Vector::from([-1., 3., 0.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+}
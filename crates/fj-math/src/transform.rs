@@ -1,8 +1,11 @@
 use std::ops;
 
-use nalgebra::Perspective3;
+use nalgebra::{Orthographic3, Perspective3};
 
-use crate::{Circle, Line, Scalar};
+use crate::{
+    Circle, Cone, Cylinder, Line, NurbsCurve, NurbsSurface, Scalar, Sphere,
+    Torus,
+};
 
 use super::{Aabb, Point, Segment, Triangle, Vector};
 
@@ -95,6 +98,69 @@ impl Transform {
         )
     }
 
+    /// Transform the given NURBS curve
+    pub fn transform_nurbs_curve(
+        &self,
+        curve: &NurbsCurve<3>,
+    ) -> NurbsCurve<3> {
+        curve.map_control_points(|point| self.transform_point(point))
+    }
+
+    /// Transform the given NURBS surface
+    pub fn transform_nurbs_surface(
+        &self,
+        surface: &NurbsSurface,
+    ) -> NurbsSurface {
+        surface.map_control_points(|point| self.transform_point(point))
+    }
+
+    /// Transform the given cylinder
+    pub fn transform_cylinder(&self, cylinder: &Cylinder) -> Cylinder {
+        Cylinder::new(
+            self.transform_point(&cylinder.origin()),
+            self.transform_vector(&cylinder.a()),
+            self.transform_vector(&cylinder.b()),
+            self.transform_vector(&cylinder.axis()),
+        )
+    }
+
+    /// Transform the given cone
+    pub fn transform_cone(&self, cone: &Cone) -> Cone {
+        Cone::new(
+            self.transform_point(&cone.apex()),
+            self.transform_vector(&cone.a()),
+            self.transform_vector(&cone.b()),
+            self.transform_vector(&cone.axis()),
+        )
+    }
+
+    /// Transform the given sphere
+    pub fn transform_sphere(&self, sphere: &Sphere) -> Sphere {
+        Sphere::new(
+            self.transform_point(&sphere.center()),
+            self.transform_vector(&sphere.a()),
+            self.transform_vector(&sphere.b()),
+            self.transform_vector(&sphere.c()),
+        )
+    }
+
+    /// Transform the given torus
+    ///
+    /// Only supports transforms that scale uniformly, as a torus that is scaled
+    /// non-uniformly is no longer a torus.
+    pub fn transform_torus(&self, torus: &Torus) -> Torus {
+        let a = self.transform_vector(&torus.a());
+        let scale = a.magnitude() / torus.major_radius();
+
+        Torus::new(
+            self.transform_point(&torus.center()),
+            a,
+            self.transform_vector(&torus.b()),
+            self.transform_vector(&torus.c()),
+            torus.minor_radius() * scale,
+        )
+    }
+
     /// Inverse transform
     pub fn inverse(&self) -> Self {
         Self(self.0.inverse())
@@ -126,6 +192,37 @@ impl Transform {
         array.map(Scalar::from)
     }
 
+    /// Project transform using an orthographic projection, return data as an
+    /// array
+    ///
+    /// `half_width` is half the width of the visible area. The height is
+    /// derived from it using the aspect ratio. Used primarily for graphics
+    /// code.
+    pub fn project_orthographic_to_array(
+        &self,
+        aspect_ratio: f64,
+        half_width: f64,
+        znear: f64,
+        zfar: f64,
+    ) -> [Scalar; 16] {
+        let half_height = half_width / aspect_ratio;
+        let projection = Orthographic3::new(
+            -half_width,
+            half_width,
+            -half_height,
+            half_height,
+            znear,
+            zfar,
+        );
+
+        let mut array = [0.; 16];
+        array.copy_from_slice(
+            (projection.to_projective() * self.0).matrix().as_slice(),
+        );
+
+        array.map(Scalar::from)
+    }
+
     /// Return a copy of the inner nalgebra transform
     pub fn get_inner(&self) -> nalgebra::Transform<f64, nalgebra::TAffine, 3> {
         self.0
@@ -224,4 +321,19 @@ mod tests {
             epsilon = 1e-8,
         );
     }
+
+    #[test]
+    fn project_orthographic() {
+        let projection = Transform::identity()
+            .project_orthographic_to_array(2., 4., 1., 11.)
+            .map(Scalar::into_f64);
+        let projection = nalgebra::Matrix4::from_column_slice(&projection);
+
+        // The size of the projection doesn't depend on the distance.
+        for z in [-1., -6., -11.] {
+            let point = projection * nalgebra::Vector4::new(4., 2., z, 1.);
+            assert_abs_diff_eq!(point.x / point.w, 1., epsilon = 1e-8);
+            assert_abs_diff_eq!(point.y / point.w, 1., epsilon = 1e-8);
+        }
+    }
 }
@@ -1,6 +1,9 @@
-use parry3d_f64::query::{Ray, RayCast as _};
+use parry3d_f64::{
+    math::Isometry,
+    query::{PointQuery as _, Ray, RayCast as _},
+};
 
-use crate::Vector;
+use crate::{Segment, Vector};
 
 use super::{Point, Scalar};
 
@@ -102,6 +105,44 @@ impl Triangle<3> {
             .map(Into::into)
     }
 
+    /// Find the point on the triangle that is closest to the provided point
+    pub fn closest_point(&self, point: impl Into<Point<3>>) -> Point<3> {
+        let projection = self
+            .to_parry()
+            .project_local_point(&point.into().to_na(), true);
+        Point::from_na(projection.point)
+    }
+
+    /// Determine whether the triangle intersects another triangle
+    ///
+    /// Triangles that merely touch are considered intersecting.
+    pub fn intersects(&self, other: &Self) -> bool {
+        let identity = Isometry::identity();
+
+        parry3d_f64::query::intersection_test(
+            &identity,
+            &self.to_parry(),
+            &identity,
+            &other.to_parry(),
+        )
+        .expect("Intersection between triangles is supported")
+    }
+
+    /// Determine whether the triangle intersects a line segment
+    ///
+    /// A segment that merely touches the triangle is considered intersecting.
+    pub fn intersects_segment(&self, segment: &Segment<3>) -> bool {
+        let identity = Isometry::identity();
+
+        parry3d_f64::query::intersection_test(
+            &identity,
+            &self.to_parry(),
+            &identity,
+            &segment.to_parry(),
+        )
+        .expect("Intersection between triangle and segment is supported")
+    }
+
     /// Compute the triangle's normal
     pub fn normal(&self) -> Vector<3> {
         self.to_parry()
@@ -151,7 +192,7 @@ impl Winding {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Point, Vector};
+    use crate::{Point, Segment, Vector};
 
     use super::Triangle;
 
@@ -195,4 +236,71 @@ mod tests {
             Triangle::from([[0.0, 0.0, 0.0], [2.0, 1.0, 0.0], [2.0, 0.0, 0.0]]);
         assert_eq!(triangle.normal(), Vector::from([0.0, 0.0, -1.0]));
     }
+
+    #[test]
+    fn closest_point() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+
+        // Above the triangle
+        assert_eq!(
+            triangle.closest_point([0.5, 0.5, 1.0]),
+            Point::from([0.5, 0.5, 0.0]),
+        );
+
+        // Next to an edge
+        assert_eq!(
+            triangle.closest_point([1.0, -1.0, 0.0]),
+            Point::from([1.0, 0.0, 0.0]),
+        );
+
+        // Next to a vertex
+        assert_eq!(
+            triangle.closest_point([-1.0, -1.0, -1.0]),
+            Point::from([0.0, 0.0, 0.0]),
+        );
+    }
+
+    #[test]
+    fn intersects() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+
+        let crossing = Triangle::from([
+            [0.5, 0.5, -1.0],
+            [0.5, 0.5, 1.0],
+            [3.0, 3.0, 0.0],
+        ]);
+        assert!(triangle.intersects(&crossing));
+
+        let above =
+            Triangle::from([[0.0, 0.0, 1.0], [2.0, 0.0, 1.0], [0.0, 2.0, 1.0]]);
+        assert!(!triangle.intersects(&above));
+
+        let beside = Triangle::from([
+            [2.0, 2.0, -1.0],
+            [2.0, 2.0, 1.0],
+            [3.0, 3.0, 0.0],
+        ]);
+        assert!(!triangle.intersects(&beside));
+    }
+
+    #[test]
+    fn intersects_segment() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+
+        let crossing =
+            Segment::from_points([[0.5, 0.5, -1.0], [0.5, 0.5, 1.0]]);
+        assert!(triangle.intersects_segment(&crossing));
+
+        let touching = Segment::from_points([[1.0, 1.0, 0.0], [2.0, 2.0, 0.0]]);
+        assert!(triangle.intersects_segment(&touching));
+
+        let beside = Segment::from_points([[2.0, 2.0, -1.0], [2.0, 2.0, 1.0]]);
+        assert!(!triangle.intersects_segment(&beside));
+
+        let above = Segment::from_points([[0.5, 0.5, 1.0], [1.0, 0.5, 1.0]]);
+        assert!(!triangle.intersects_segment(&above));
+    }
 }
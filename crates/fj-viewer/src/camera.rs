@@ -1,8 +1,13 @@
 //! Viewer camera module
-use std::f64::consts::FRAC_PI_2;
+use std::{
+    f64::consts::{FRAC_PI_2, FRAC_PI_4},
+    time::Duration,
+};
 
 use fj_interop::{Mesh, Model};
 use fj_math::{Aabb, Point, Scalar, Transform, Vector};
+use nalgebra::{Matrix3, UnitQuaternion};
+use web_time::Instant;
 
 use crate::screen::NormalizedScreenPosition;
 
@@ -12,7 +17,7 @@ use crate::screen::NormalizedScreenPosition;
 /// is handled, for example) is not that of a camera freely flying through a
 /// static scene. Instead, the camera is static, and the model is freely
 /// translated and rotated.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Camera {
     /// The distance to the near plane
     near_plane: f64,
@@ -25,6 +30,18 @@ pub struct Camera {
 
     /// The locational part of the transform
     pub translation: Transform,
+
+    /// The projection that is used to render the model
+    pub projection: Projection,
+
+    /// The distance from the camera to the center of the model
+    ///
+    /// Determines the size of the visible area, when using an orthographic
+    /// projection.
+    view_distance: f64,
+
+    /// The transition to a new view, if one is in progress
+    transition: Option<Transition>,
 }
 
 impl Camera {
@@ -41,6 +58,10 @@ impl Camera {
 
             rotation: Transform::identity(),
             translation: Transform::identity(),
+
+            projection: Projection::Perspective,
+            view_distance: 1.,
+            transition: None,
         }
     }
 
@@ -65,19 +86,57 @@ impl Camera {
             .inverse_transform_point(&Point::<3>::origin())
     }
 
+    /// Returns half the width of the visible area at the given distance
+    ///
+    /// When using an orthographic projection, this doesn't depend on the
+    /// distance.
+    pub fn half_width_at(&self, distance: f64) -> f64 {
+        let distance = match self.projection {
+            Projection::Perspective => distance,
+            Projection::Orthographic => self.view_distance,
+        };
+
+        (self.field_of_view_in_x() / 2.).tan() * distance
+    }
+
     /// Transform a normalized cursor position on the near plane to model space.
     pub fn cursor_to_model_space(
         &self,
         cursor: NormalizedScreenPosition,
     ) -> Point<3> {
         // Cursor position in camera space.
-        let f = (self.field_of_view_in_x() / 2.).tan() * self.near_plane();
+        let f = self.half_width_at(self.near_plane());
         let cursor = Point::origin()
             + Vector::from([cursor.x * f, cursor.y * f, -self.near_plane()]);
 
         self.camera_to_model().inverse_transform_point(&cursor)
     }
 
+    /// Compute the ray from the camera through the cursor, in model space
+    ///
+    /// Returns the origin of the ray, and its normalized direction.
+    pub fn cursor_ray(
+        &self,
+        cursor: NormalizedScreenPosition,
+    ) -> (Point<3>, Vector<3>) {
+        let cursor = self.cursor_to_model_space(cursor);
+
+        match self.projection {
+            Projection::Perspective => {
+                let origin = self.position();
+                (origin, (cursor - origin).normalize())
+            }
+            Projection::Orthographic => {
+                // All rays are parallel to the view direction.
+                let dir = self
+                    .camera_to_model()
+                    .inverse()
+                    .transform_vector(&Vector::from([0., 0., -1.]));
+                (cursor, dir.normalize())
+            }
+        }
+    }
+
     /// Compute the point on the model, that the cursor currently points to.
     pub fn focus_point(
         &self,
@@ -93,10 +152,7 @@ impl Camera {
         cursor: Option<NormalizedScreenPosition>,
         mesh: &Mesh<Point<3>>,
     ) -> Option<FocusPoint> {
-        // Transform camera and cursor positions to model space.
-        let origin = self.position();
-        let cursor = self.cursor_to_model_space(cursor?);
-        let dir = (cursor - origin).normalize();
+        let (origin, dir) = self.cursor_ray(cursor?);
 
         let mut min_t = None;
 
@@ -181,6 +237,36 @@ impl Camera {
         let view_transform = self.camera_to_model();
         let view_direction = Vector::from([0., 0., -1.]);
 
+        // The orthographic projection needs some distance to size the visible
+        // area, that changes when zooming, just like the perspective one does.
+        // The distance to the model is the natural choice.
+        let center = view_transform.transform_point(&aabb.center());
+        self.view_distance = (-center.z.into_f64()).max(MIN_VIEW_DISTANCE);
+
+        if self.projection == Projection::Orthographic {
+            // The camera's position along the view direction doesn't matter
+            // for an orthographic projection. Unlike with a perspective one,
+            // geometry behind the camera can be visible.
+            let distances = aabb.vertices().map(|vertex| {
+                -view_transform.transform_point(&vertex).z.into_f64()
+            });
+            let dist_min = distances.into_iter().reduce(f64::min);
+            let dist_max = distances.into_iter().reduce(f64::max);
+
+            if let (Some(dist_min), Some(dist_max)) = (dist_min, dist_max) {
+                let margin = (dist_max - dist_min).max(MIN_VIEW_DISTANCE);
+                let depth = dist_max - dist_min + margin * 2.;
+
+                // The projection maps depth to the range from -1 to 1, but
+                // only the range from 0 to 1 ends up being drawn. Place the
+                // near plane such, that the model fits into the back half.
+                self.far_plane = dist_max + margin;
+                self.near_plane = self.far_plane - depth * 2.;
+            }
+
+            return;
+        }
+
         let mut dist_min = f64::INFINITY;
         let mut dist_max = f64::NEG_INFINITY;
 
@@ -220,6 +306,102 @@ impl Camera {
             Self::DEFAULT_FAR_PLANE
         };
     }
+
+    /// Start a transition to the provided view of the model
+    ///
+    /// The view is reached over a short time, as the camera is updated by
+    /// [`Camera::update_transition`]. This keeps the viewer oriented, in a way
+    /// that an instant jump would not.
+    pub fn transition_to(&mut self, view: ViewPreset, aabb: &Aabb<3>) {
+        let center = aabb.center();
+
+        // Keep the whole model visible, regardless of the direction it is
+        // viewed from.
+        let radius = aabb.size().magnitude().into_f64() / 2.;
+        let distance =
+            radius * 2. / (Self::INITIAL_FIELD_OF_VIEW_IN_X / 2.).tan();
+
+        let from = self.view_state(center);
+        let to = ViewState {
+            rotation: view.rotation(),
+            center_in_camera: Vector::from([0., 0., -distance]),
+        };
+
+        self.transition = Some(Transition {
+            start: Instant::now(),
+            center,
+            from,
+            to,
+        });
+    }
+
+    /// Update the camera, if a transition to a new view is in progress
+    ///
+    /// Call this once per frame.
+    pub fn update_transition(&mut self) {
+        let Some(transition) = &self.transition else {
+            return;
+        };
+
+        let progress = transition.start.elapsed().as_secs_f64()
+            / TRANSITION_DURATION.as_secs_f64();
+        let progress = progress.min(1.);
+
+        // Ease in and out, so the movement starts and stops smoothly.
+        let t = progress * progress * (3. - 2. * progress);
+
+        let rotation =
+            transition.from.rotation.slerp(&transition.to.rotation, t);
+        let center_in_camera = transition.from.center_in_camera
+            + (transition.to.center_in_camera
+                - transition.from.center_in_camera)
+                * t;
+        let center = transition.center;
+
+        self.set_view_state(
+            ViewState {
+                rotation,
+                center_in_camera,
+            },
+            center,
+        );
+
+        if progress >= 1. {
+            self.transition = None;
+        }
+    }
+
+    /// Stop the transition to a new view, if one is in progress
+    ///
+    /// The camera stays where it currently is. Call this, when the user takes
+    /// control of the camera, to not fight over it.
+    pub fn stop_transition(&mut self) {
+        self.transition = None;
+    }
+
+    fn view_state(&self, center: Point<3>) -> ViewState {
+        let rotation = self.rotation.get_inner();
+        let rotation = Matrix3::from_fn(|i, j| rotation.matrix()[(i, j)]);
+
+        ViewState {
+            rotation: UnitQuaternion::from_matrix(&rotation),
+            center_in_camera: self
+                .camera_to_model()
+                .transform_point(&center)
+                .coords,
+        }
+    }
+
+    fn set_view_state(&mut self, state: ViewState, center: Point<3>) {
+        self.rotation =
+            Transform::rotation(Vector::from(state.rotation.scaled_axis()));
+
+        // The model is rotated first, then translated, so the center ends up
+        // where it's supposed to be.
+        let rotated_center = self.rotation.transform_point(&center).coords;
+        self.translation =
+            Transform::translation(state.center_in_camera - rotated_center);
+    }
 }
 
 impl Default for Camera {
@@ -228,9 +410,177 @@ impl Default for Camera {
     }
 }
 
+/// The projection that the camera uses
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Projection {
+    /// Objects further away appear smaller, like in the real world
+    #[default]
+    Perspective,
+
+    /// Objects appear at the same size, regardless of their distance
+    ///
+    /// Parallel lines stay parallel, which is what technical drawings use.
+    Orthographic,
+}
+
+impl Projection {
+    /// Return the other projection
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Perspective => Self::Orthographic,
+            Self::Orthographic => Self::Perspective,
+        }
+    }
+}
+
+/// A standard view of the model
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ViewPreset {
+    /// View from the front (negative y), with z pointing up
+    Front,
+
+    /// View from the back (positive y), with z pointing up
+    Back,
+
+    /// View from the left (negative x), with z pointing up
+    Left,
+
+    /// View from the right (positive x), with z pointing up
+    Right,
+
+    /// View from the top (positive z), with y pointing up
+    Top,
+
+    /// View from the bottom (negative z), with y pointing down
+    Bottom,
+
+    /// View from the front, right, and top, with z pointing up
+    Isometric,
+}
+
+impl ViewPreset {
+    /// The rotation of the camera, that results in this view
+    fn rotation(self) -> UnitQuaternion<f64> {
+        let x = |angle| UnitQuaternion::from_euler_angles(angle, 0., 0.);
+        let z = |angle| UnitQuaternion::from_euler_angles(0., 0., angle);
+
+        // Without rotation, we're looking at the model from the top. Tilting
+        // it back makes us look at it from the front, with z pointing up.
+        let front = x(-FRAC_PI_2);
+
+        match self {
+            Self::Front => front,
+            Self::Back => front * z(FRAC_PI_2 * 2.),
+            Self::Left => front * z(FRAC_PI_2),
+            Self::Right => front * z(-FRAC_PI_2),
+            Self::Top => UnitQuaternion::identity(),
+            Self::Bottom => x(FRAC_PI_2 * 2.),
+            Self::Isometric => x(-(2f64).sqrt().atan()) * z(-FRAC_PI_4),
+        }
+    }
+}
+
+/// An animated transition of the camera to a new view
+#[derive(Clone, Debug)]
+struct Transition {
+    start: Instant,
+
+    /// The point in model space that the transition is centered on
+    center: Point<3>,
+
+    from: ViewState,
+    to: ViewState,
+}
+
+/// A view, as it can be interpolated during a transition
+#[derive(Clone, Debug)]
+struct ViewState {
+    rotation: UnitQuaternion<f64>,
+
+    /// The position of the transition's center, in camera space
+    center_in_camera: Vector<3>,
+}
+
+/// The duration of a transition to a new view
+const TRANSITION_DURATION: Duration = Duration::from_millis(300);
+
+/// The minimum distance between the camera and the model
+///
+/// Used to size the visible area of the orthographic projection, so it doesn't
+/// collapse, if the camera moves past the model.
+const MIN_VIEW_DISTANCE: f64 = 1e-3;
+
 /// The point around which camera movement happens.
 ///
 /// This will be the point on the model that the cursor is currently pointing at if such a point exists,
 /// falling back to the center point of the model's bounding volume otherwise.
 #[derive(Clone, Copy)]
 pub struct FocusPoint(pub Point<3>);
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point};
+    use nalgebra::Vector3;
+
+    use super::{Camera, Projection, ViewPreset};
+
+    #[test]
+    fn toggle_projection() {
+        let projection = Projection::default();
+        assert_eq!(projection, Projection::Perspective);
+        assert_eq!(projection.toggled(), Projection::Orthographic);
+        assert_eq!(projection.toggled().toggled(), projection);
+    }
+
+    #[test]
+    fn view_presets_look_at_the_model_from_the_right_direction() {
+        // The direction from the model towards the camera, and the direction
+        // that's up on the screen, both in model space.
+        let views = [
+            (ViewPreset::Front, [0., -1., 0.], [0., 0., 1.]),
+            (ViewPreset::Back, [0., 1., 0.], [0., 0., 1.]),
+            (ViewPreset::Left, [-1., 0., 0.], [0., 0., 1.]),
+            (ViewPreset::Right, [1., 0., 0.], [0., 0., 1.]),
+            (ViewPreset::Top, [0., 0., 1.], [0., 1., 0.]),
+            (ViewPreset::Bottom, [0., 0., -1.], [0., -1., 0.]),
+        ];
+
+        for (view, towards_camera, up) in views {
+            let rotation = view.rotation();
+
+            // The camera looks along negative z, with y pointing up.
+            let towards_camera = rotation * Vector3::from(towards_camera);
+            let up = rotation * Vector3::from(up);
+
+            assert!((towards_camera - Vector3::z()).norm() < 1e-12, "{view:?}");
+            assert!((up - Vector3::y()).norm() < 1e-12, "{view:?}");
+        }
+
+        let towards_camera =
+            ViewPreset::Isometric.rotation() * Vector3::new(1., -1., 1.);
+        assert!((towards_camera.normalize() - Vector3::z()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn orthographic_width_does_not_depend_on_distance() {
+        let aabb = Aabb {
+            min: Point::from([-1., -1., -1.]),
+            max: Point::from([1., 1., 1.]),
+        };
+
+        let mut camera = Camera::new();
+        camera.init_planes(&aabb);
+        camera.update_planes(&aabb);
+        assert!(camera.half_width_at(1.) < camera.half_width_at(2.));
+
+        camera.projection = Projection::Orthographic;
+        camera.update_planes(&aabb);
+        assert_eq!(camera.half_width_at(1.), camera.half_width_at(2.));
+        assert!(camera.half_width_at(1.) > 0.);
+
+        // The whole model must be in between the clipping planes.
+        let distance = -camera.translation.transform_point(&aabb.center()).z;
+        assert!(camera.near_plane() < distance.into_f64() - 1.);
+        assert!(camera.far_plane() > distance.into_f64() + 1.);
+    }
+}
@@ -9,13 +9,29 @@ pub struct Device {
 impl Device {
     pub async fn from_preferred_adapter(
         instance: &wgpu::Instance,
-        surface: &wgpu::Surface<'_>,
+        surface: Option<&wgpu::Surface<'_>>,
+    ) -> Result<(Self, wgpu::Adapter, wgpu::Features), DeviceError> {
+        Self::from_requested_adapter(instance, surface, false).await
+    }
+
+    /// Get a device from the fallback adapter, usually a software renderer
+    pub async fn from_fallback_adapter(
+        instance: &wgpu::Instance,
+        surface: Option<&wgpu::Surface<'_>>,
+    ) -> Result<(Self, wgpu::Adapter, wgpu::Features), DeviceError> {
+        Self::from_requested_adapter(instance, surface, true).await
+    }
+
+    async fn from_requested_adapter(
+        instance: &wgpu::Instance,
+        surface: Option<&wgpu::Surface<'_>>,
+        force_fallback_adapter: bool,
     ) -> Result<(Self, wgpu::Adapter, wgpu::Features), DeviceError> {
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
                 power_preference: wgpu::PowerPreference::None,
-                force_fallback_adapter: false,
-                compatible_surface: Some(surface),
+                force_fallback_adapter,
+                compatible_surface: surface,
             })
             .await
             .ok_or(DeviceError::RequestAdapter)?;
@@ -1,3 +1,5 @@
+use fj_math::{Point, Vector};
+
 /// High level configuration for rendering the active model
 #[derive(Debug)]
 pub struct DrawConfig {
@@ -6,6 +8,15 @@ pub struct DrawConfig {
 
     /// Toggle for displaying the wireframe model
     pub draw_mesh: bool,
+
+    /// Toggle for displaying the edges of the model
+    pub draw_edges: bool,
+
+    /// Toggle for cutting the model at the clipping plane
+    pub clip_model: bool,
+
+    /// The plane that the model is cut at, if `clip_model` is enabled
+    pub clipping_plane: ClippingPlane,
 }
 
 impl Default for DrawConfig {
@@ -13,6 +24,115 @@ impl Default for DrawConfig {
         Self {
             draw_model: true,
             draw_mesh: false,
+            draw_edges: true,
+            clip_model: false,
+            clipping_plane: ClippingPlane::default(),
         }
     }
 }
+
+/// A plane that cuts away part of the model, to show its inside
+///
+/// Everything on the side of the plane that its normal points to is removed.
+/// Where the plane cuts through a closed shape, the cut is capped, so the
+/// walls of the shape appear solid.
+#[derive(Clone, Copy, Debug)]
+pub struct ClippingPlane {
+    /// A point on the plane, in model space
+    pub origin: Point<3>,
+
+    /// The normal of the plane, pointing towards the removed side
+    pub normal: Vector<3>,
+}
+
+impl ClippingPlane {
+    /// The orientations that [`ClippingPlane::next_orientation`] cycles through
+    pub const ORIENTATIONS: [[f64; 3]; 6] = [
+        [1., 0., 0.],
+        [0., 1., 0.],
+        [0., 0., 1.],
+        [-1., 0., 0.],
+        [0., -1., 0.],
+        [0., 0., -1.],
+    ];
+
+    /// Switch to the next axis-aligned orientation
+    ///
+    /// Cycles through the positive axes, then the negative ones. The origin of
+    /// the plane stays where it is.
+    pub fn next_orientation(&mut self) {
+        let current = Self::ORIENTATIONS
+            .iter()
+            .position(|&normal| self.normal == Vector::from(normal));
+        let next = current.map_or(0, |i| (i + 1) % Self::ORIENTATIONS.len());
+
+        self.normal = Vector::from(Self::ORIENTATIONS[next]);
+    }
+
+    /// Compute the plane equation, as expected by the shader
+    ///
+    /// The first three components are the normal, the fourth the distance of
+    /// the plane from the origin along the normal.
+    pub(crate) fn to_equation(self) -> [f32; 4] {
+        let normal = self.normal.normalize();
+        let distance = normal.dot(&self.origin.coords);
+
+        let [x, y, z] = normal.components.map(|s| s.into_f32());
+        [x, y, z, distance.into_f32()]
+    }
+}
+
+impl Default for ClippingPlane {
+    fn default() -> Self {
+        Self {
+            origin: Point::origin(),
+            normal: Vector::from(Self::ORIENTATIONS[0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use super::ClippingPlane;
+
+    #[test]
+    fn equation() {
+        let plane = ClippingPlane {
+            origin: Point::from([1., 2., 3.]),
+            normal: Vector::from([0., 0., 2.]),
+        };
+        assert_eq!(plane.to_equation(), [0., 0., 1., 3.]);
+
+        let plane = ClippingPlane {
+            origin: Point::from([1., 2., 3.]),
+            normal: Vector::from([0., -1., 0.]),
+        };
+        assert_eq!(plane.to_equation(), [0., -1., 0., -2.]);
+    }
+
+    #[test]
+    fn next_orientation() {
+        let origin = Point::from([1., 2., 3.]);
+        let mut plane = ClippingPlane {
+            origin,
+            normal: Vector::from(ClippingPlane::ORIENTATIONS[0]),
+        };
+
+        for i in 1..=ClippingPlane::ORIENTATIONS.len() {
+            plane.next_orientation();
+
+            let expected = ClippingPlane::ORIENTATIONS
+                [i % ClippingPlane::ORIENTATIONS.len()];
+            assert_eq!(plane.normal, Vector::from(expected));
+            assert_eq!(plane.origin, origin);
+        }
+
+        // An orientation that isn't in the list starts the cycle from the
+        // beginning.
+        plane.normal = Vector::from([1., 1., 0.]);
+        plane.next_orientation();
+        assert_eq!(plane.normal, Vector::from(ClippingPlane::ORIENTATIONS[0]));
+    }
+}
@@ -6,6 +6,10 @@ use super::{
 pub struct Drawables<'r> {
     pub model: Drawable<'r>,
     pub mesh: Option<Drawable<'r>>,
+    pub edges: Drawable<'r>,
+    pub selected_edges: Drawable<'r>,
+    pub measurement: Drawable<'r>,
+    pub issue_markers: Drawable<'r>,
 }
 
 impl<'r> Drawables<'r> {
@@ -15,8 +19,26 @@ impl<'r> Drawables<'r> {
             .mesh
             .as_ref()
             .map(|pipeline| Drawable::new(&geometries.mesh, pipeline));
+        let edges = Drawable::new(&geometries.edges, &pipelines.edges);
+        let selected_edges = Drawable::new(
+            &geometries.selected_edges,
+            &pipelines.selected_edges,
+        );
+        // Measurement and issue markers are drawn on top of everything, just
+        // like the selected edges.
+        let measurement =
+            Drawable::new(&geometries.measurement, &pipelines.selected_edges);
+        let issue_markers =
+            Drawable::new(&geometries.issue_markers, &pipelines.selected_edges);
 
-        Self { model, mesh }
+        Self {
+            model,
+            mesh,
+            edges,
+            selected_edges,
+            measurement,
+            issue_markers,
+        }
     }
 }
 
@@ -2,18 +2,39 @@ use std::convert::TryInto;
 
 use wgpu::util::DeviceExt;
 
-use super::vertices::{Vertex, Vertices};
+use super::vertices::Vertices;
 
 #[derive(Debug)]
 pub struct Geometries {
     pub mesh: Geometry,
+    pub edges: Geometry,
+    pub selected_edges: Geometry,
+    pub measurement: Geometry,
+    pub issue_markers: Geometry,
 }
 
 impl Geometries {
-    pub fn new(device: &wgpu::Device, mesh: &Vertices) -> Self {
-        let mesh = Geometry::new(device, mesh.vertices(), mesh.indices());
+    pub fn new(
+        device: &wgpu::Device,
+        mesh: &Vertices,
+        edges: &Vertices,
+        selected_edges: &Vertices,
+        measurement: &Vertices,
+        issue_markers: &Vertices,
+    ) -> Self {
+        let mesh = Geometry::new(device, mesh);
+        let edges = Geometry::new(device, edges);
+        let selected_edges = Geometry::new(device, selected_edges);
+        let measurement = Geometry::new(device, measurement);
+        let issue_markers = Geometry::new(device, issue_markers);
 
-        Self { mesh }
+        Self {
+            mesh,
+            edges,
+            selected_edges,
+            measurement,
+            issue_markers,
+        }
     }
 }
 
@@ -25,11 +46,10 @@ pub struct Geometry {
 }
 
 impl Geometry {
-    fn new(
-        device: &wgpu::Device,
-        vertices: &[Vertex],
-        indices: &[u32],
-    ) -> Self {
+    pub fn new(device: &wgpu::Device, vertices: &Vertices) -> Self {
+        let indices = vertices.indices();
+        let vertices = vertices.vertices();
+
         Self {
             vertex_buffer: device.create_buffer_init(
                 &wgpu::util::BufferInitDescriptor {
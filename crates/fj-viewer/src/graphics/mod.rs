@@ -6,6 +6,7 @@ mod drawables;
 mod geometries;
 mod model;
 mod navigation_cube;
+mod overlay;
 mod pipelines;
 mod renderer;
 mod shaders;
@@ -16,8 +17,9 @@ mod vertices;
 
 pub use self::{
     device::DeviceError,
-    draw_config::DrawConfig,
-    renderer::{Renderer, RendererInitError},
+    draw_config::{ClippingPlane, DrawConfig},
+    renderer::{RenderError, Renderer, RendererInitError},
+    vertices::Vertices,
 };
 
 pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
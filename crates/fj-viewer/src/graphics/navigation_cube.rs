@@ -1,6 +1,8 @@
 use fj_math::Transform;
 use wgpu::util::DeviceExt;
 
+use crate::{camera::ViewPreset, screen::NormalizedScreenPosition};
+
 use super::{
     model::{self, load_model, DrawModel, Model},
     transform,
@@ -193,7 +195,75 @@ impl NavigationCubeRenderer {
         render_pass.draw_model(&self.cube_model);
     }
 
+    /// Determine the face of the navigation cube that is under the cursor
+    ///
+    /// Returns the view that is associated with that face.
+    pub fn face_at(
+        cursor: NormalizedScreenPosition,
+        aspect_ratio: f64,
+        rotation: Transform,
+    ) -> Option<ViewPreset> {
+        let inverse = Self::mvp_matrix(rotation, aspect_ratio).try_inverse()?;
+
+        // The y coordinate of the cursor has the aspect ratio factored in,
+        // which normalized device coordinates don't.
+        let [x, y] = [cursor.x, cursor.y * aspect_ratio];
+        let [near, far] = [0., 0.5].map(|z| {
+            let point = inverse * nalgebra::Vector4::new(x, y, z, 1.);
+            point.xyz() / point.w
+        });
+
+        // Intersect the ray with the cube, which spans -1 to 1 on all axes
+        // in its model space.
+        let dir = far - near;
+        let mut t_min = f64::NEG_INFINITY;
+        let mut t_max = f64::INFINITY;
+        for i in 0..3 {
+            if dir[i] == 0. {
+                if near[i].abs() > 1. {
+                    return None;
+                }
+                continue;
+            }
+
+            let a = (-1. - near[i]) / dir[i];
+            let b = (1. - near[i]) / dir[i];
+            t_min = t_min.max(a.min(b));
+            t_max = t_max.min(a.max(b));
+        }
+        if t_min > t_max {
+            return None;
+        }
+
+        // The coordinate with the largest magnitude identifies the face.
+        let hit = near + dir * t_min;
+        let axis = hit.iamax();
+        let positive = hit[axis] > 0.;
+
+        let view = match (axis, positive) {
+            (0, true) => ViewPreset::Right,
+            (0, false) => ViewPreset::Left,
+            (1, true) => ViewPreset::Back,
+            (1, false) => ViewPreset::Front,
+            (_, true) => ViewPreset::Top,
+            (_, false) => ViewPreset::Bottom,
+        };
+
+        Some(view)
+    }
+
     fn get_mvp_matrix(rotation: Transform, aspect_ratio: f64) -> [f32; 16] {
+        let matrix = Self::mvp_matrix(rotation, aspect_ratio);
+
+        let mut mat = [0.; 16];
+        mat.copy_from_slice(matrix.as_slice());
+        mat.map(|x| x as f32)
+    }
+
+    fn mvp_matrix(
+        rotation: Transform,
+        aspect_ratio: f64,
+    ) -> nalgebra::Matrix4<f64> {
         let scale = Transform::scale(SCALE_FACTOR);
         let world_translation = Transform::translation([0.0, 0.0, -1.0]);
 
@@ -213,13 +283,9 @@ impl NavigationCubeRenderer {
 
         let screen_translation = Transform::translation(CUBE_TRANSLATION);
 
-        let matrix = screen_translation.get_inner().matrix()
+        screen_translation.get_inner().matrix()
             * *perspective.to_projective().matrix()
             * view_matrix
-            * model_matrix.get_inner().matrix();
-
-        let mut mat = [0.; 16];
-        mat.copy_from_slice(matrix.as_slice());
-        mat.map(|x| x as f32)
+            * model_matrix.get_inner().matrix()
     }
 }
@@ -0,0 +1,283 @@
+use ab_glyph::{point, Font, FontRef, PxScale, ScaleFont};
+use image::{Rgba, RgbaImage};
+use wgpu::util::DeviceExt;
+
+use crate::screen::ScreenSize;
+
+use super::texture::Texture;
+
+/// Renders lines of text in the top-left corner of the screen
+#[derive(Debug)]
+pub struct OverlayRenderer {
+    font: FontRef<'static>,
+    render_pipeline: wgpu::RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    rect_buffer: wgpu::Buffer,
+
+    /// The rendered text, if there is any, with its size in pixels
+    text: Option<(wgpu::BindGroup, [u32; 2])>,
+}
+
+impl OverlayRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        color_format: wgpu::TextureFormat,
+    ) -> Self {
+        let font = FontRef::try_from_slice(FONT).expect("Font is valid");
+
+        let bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            sample_type: wgpu::TextureSampleType::Float {
+                                filterable: true,
+                            },
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering,
+                        ),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+                label: Some("overlay_bind_group_layout"),
+            });
+
+        let rect_buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Overlay Rect Buffer"),
+                contents: bytemuck::cast_slice(&[0f32; 4]),
+                usage: wgpu::BufferUsages::UNIFORM
+                    | wgpu::BufferUsages::COPY_DST,
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("Overlay Shader"),
+                source: wgpu::ShaderSource::Wgsl(
+                    include_str!("overlay.wgsl").into(),
+                ),
+            });
+
+        let render_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Overlay Pipeline Layout"),
+                bind_group_layouts: &[&bind_group_layout],
+                push_constant_ranges: &[],
+            });
+
+        let render_pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Overlay Renderer"),
+                layout: Some(&render_pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vertex",
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fragment",
+                    compilation_options:
+                        wgpu::PipelineCompilationOptions::default(),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: color_format,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            });
+
+        Self {
+            font,
+            render_pipeline,
+            bind_group_layout,
+            rect_buffer,
+            text: None,
+        }
+    }
+
+    /// Replace the text that is displayed
+    ///
+    /// Nothing is displayed, if `lines` is empty.
+    pub fn update(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        lines: &[String],
+    ) {
+        if lines.is_empty() {
+            self.text = None;
+            return;
+        }
+
+        let image = self.rasterize(lines);
+        let size = [image.width(), image.height()];
+        let texture = Texture::from_image(
+            device,
+            queue,
+            &image.into(),
+            Some("overlay_texture"),
+        );
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&texture.view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&texture.sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: self.rect_buffer.as_entire_binding(),
+                },
+            ],
+            label: Some("overlay_bind_group"),
+        });
+
+        self.text = Some((bind_group, size));
+    }
+
+    pub fn draw(
+        &self,
+        view: &wgpu::TextureView,
+        encoder: &mut wgpu::CommandEncoder,
+        queue: &wgpu::Queue,
+        screen_size: ScreenSize,
+    ) {
+        let Some((bind_group, [width, height])) = &self.text else {
+            return;
+        };
+
+        // Convert from pixels to normalized device coordinates, keeping the
+        // text at its native size, so it stays crisp.
+        let [screen_width, screen_height] =
+            [screen_size.width, screen_size.height].map(|s| s as f32);
+        let left = -1. + 2. * MARGIN / screen_width;
+        let top = 1. - 2. * MARGIN / screen_height;
+        let right = left + 2. * *width as f32 / screen_width;
+        let bottom = top - 2. * *height as f32 / screen_height;
+
+        queue.write_buffer(
+            &self.rect_buffer,
+            0,
+            bytemuck::cast_slice(&[left, top, right, bottom]),
+        );
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                ..Default::default()
+            });
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(0, bind_group, &[]);
+        render_pass.draw(0..6, 0..1);
+    }
+
+    /// Render the lines of text into an image, on a translucent background
+    fn rasterize(&self, lines: &[String]) -> RgbaImage {
+        let font = self.font.as_scaled(PxScale::from(FONT_SIZE));
+        let line_height = font.height() + font.line_gap();
+
+        let line_width = |line: &str| {
+            line.chars()
+                .map(|c| font.h_advance(font.glyph_id(c)))
+                .sum::<f32>()
+        };
+        let text_width =
+            lines.iter().map(|line| line_width(line)).fold(0., f32::max);
+        let text_height = line_height * lines.len() as f32;
+
+        let width = (text_width + 2. * PADDING).ceil() as u32;
+        let height = (text_height + 2. * PADDING).ceil() as u32;
+        let mut image = RgbaImage::from_pixel(width, height, BACKGROUND);
+
+        for (i, line) in lines.iter().enumerate() {
+            let baseline = PADDING + font.ascent() + line_height * i as f32;
+            let mut caret = PADDING;
+
+            for c in line.chars() {
+                let mut glyph = font.scaled_glyph(c);
+                glyph.position = point(caret, baseline);
+                caret += font.h_advance(glyph.id);
+
+                let Some(outlined) = font.outline_glyph(glyph) else {
+                    continue;
+                };
+                let bounds = outlined.px_bounds();
+
+                outlined.draw(|x, y, coverage| {
+                    let x = bounds.min.x as i32 + x as i32;
+                    let y = bounds.min.y as i32 + y as i32;
+                    let (Ok(x), Ok(y)) = (u32::try_from(x), u32::try_from(y))
+                    else {
+                        return;
+                    };
+                    let Some(pixel) = image.get_pixel_mut_checked(x, y) else {
+                        return;
+                    };
+
+                    for (channel, foreground) in
+                        pixel.0.iter_mut().zip(FOREGROUND.0)
+                    {
+                        let blended = f32::from(*channel)
+                            + (f32::from(foreground) - f32::from(*channel))
+                                * coverage.min(1.);
+                        *channel = blended.round() as u8;
+                    }
+                });
+            }
+        }
+
+        image
+    }
+}
+
+const FONT: &[u8] = include_bytes!("fonts/B612Mono-Regular.ttf");
+const FONT_SIZE: f32 = 18.;
+
+/// The distance between the text and the edges of its background, in pixels
+const PADDING: f32 = 8.;
+
+/// The distance between the overlay and the edges of the screen, in pixels
+const MARGIN: f32 = 10.;
+
+const FOREGROUND: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const BACKGROUND: Rgba<u8> = Rgba([0, 0, 0, 160]);
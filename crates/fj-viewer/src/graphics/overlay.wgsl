@@ -0,0 +1,48 @@
+// Vertex shader
+
+// Left, top, right, and bottom edge of the overlay, in normalized device
+// coordinates.
+@group(0) @binding(2)
+var<uniform> rect: vec4<f32>;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+// The overlay is a single quad, made of two triangles. No vertex buffer
+// needed.
+@vertex
+fn vertex(@builtin(vertex_index) index: u32) -> VertexOutput {
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 1.0),
+    );
+    let corner = corners[index];
+
+    var out: VertexOutput;
+    out.tex_coords = corner;
+    out.clip_position = vec4<f32>(
+        mix(rect.x, rect.z, corner.x),
+        mix(rect.y, rect.w, corner.y),
+        0.0,
+        1.0,
+    );
+    return out;
+}
+
+// Fragment shader
+
+@group(0) @binding(0)
+var t_text: texture_2d<f32>;
+@group(0) @binding(1)
+var s_text: sampler;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(t_text, s_text, in.tex_coords);
+}
@@ -10,6 +10,8 @@ use super::{
 pub struct Pipelines {
     pub model: Pipeline,
     pub mesh: Option<Pipeline>,
+    pub edges: Pipeline,
+    pub selected_edges: Pipeline,
 }
 
 impl Pipelines {
@@ -34,6 +36,7 @@ impl Pipelines {
             shaders.model(),
             wgpu::PrimitiveTopology::TriangleList,
             wgpu::PolygonMode::Fill,
+            wgpu::CompareFunction::LessEqual,
             color_format,
         );
 
@@ -47,13 +50,42 @@ impl Pipelines {
                 shaders.mesh(),
                 wgpu::PrimitiveTopology::TriangleList,
                 wgpu::PolygonMode::Line,
+                wgpu::CompareFunction::LessEqual,
                 color_format,
             ))
         } else {
             None
         };
 
-        Self { model, mesh }
+        let edges = Pipeline::new(
+            device,
+            &pipeline_layout,
+            shaders.lines(),
+            wgpu::PrimitiveTopology::LineList,
+            wgpu::PolygonMode::Fill,
+            wgpu::CompareFunction::LessEqual,
+            color_format,
+        );
+
+        // Selected edges are drawn on top of everything else. They would be
+        // fighting with the faces they bound for depth otherwise, and it
+        // doesn't hurt to see selected edges that are hidden.
+        let selected_edges = Pipeline::new(
+            device,
+            &pipeline_layout,
+            shaders.lines(),
+            wgpu::PrimitiveTopology::LineList,
+            wgpu::PolygonMode::Fill,
+            wgpu::CompareFunction::Always,
+            color_format,
+        );
+
+        Self {
+            model,
+            mesh,
+            edges,
+            selected_edges,
+        }
     }
 }
 
@@ -67,6 +99,7 @@ impl Pipeline {
         shader: Shader,
         topology: wgpu::PrimitiveTopology,
         polygon_mode: wgpu::PolygonMode,
+        depth_compare: wgpu::CompareFunction,
         color_format: wgpu::TextureFormat,
     ) -> Self {
         let pipeline =
@@ -75,7 +108,7 @@ impl Pipeline {
                 layout: Some(pipeline_layout),
                 vertex: wgpu::VertexState {
                     module: shader.module,
-                    entry_point: "vertex",
+                    entry_point: shader.vert_entry,
                     compilation_options:
                         wgpu::PipelineCompilationOptions::default(),
                     buffers: &[wgpu::VertexBufferLayout {
@@ -100,7 +133,7 @@ impl Pipeline {
                 depth_stencil: Some(wgpu::DepthStencilState {
                     format: DEPTH_FORMAT,
                     depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    depth_compare,
                     stencil: wgpu::StencilState {
                         front: wgpu::StencilFaceState::IGNORE,
                         back: wgpu::StencilFaceState::IGNORE,
@@ -1,25 +1,37 @@
-use std::{io, mem::size_of, vec};
+use std::{io, mem::size_of, sync::mpsc, vec};
 
 use thiserror::Error;
 use tracing::{error, trace};
 use wgpu::util::DeviceExt as _;
 
 use crate::{
-    camera::Camera,
-    screen::{Screen, ScreenSize},
+    camera::{Camera, ViewPreset},
+    screen::{NormalizedScreenPosition, Screen, ScreenSize},
 };
 
 use super::{
-    device::Device, draw_config::DrawConfig, drawables::Drawables,
-    geometries::Geometries, navigation_cube::NavigationCubeRenderer,
-    pipelines::Pipelines, transform::Transform, uniforms::Uniforms,
-    vertices::Vertices, DeviceError, DEPTH_FORMAT, SAMPLE_COUNT,
+    device::Device,
+    draw_config::DrawConfig,
+    drawables::Drawables,
+    geometries::{Geometries, Geometry},
+    navigation_cube::NavigationCubeRenderer,
+    overlay::OverlayRenderer,
+    pipelines::Pipelines,
+    transform::Transform,
+    uniforms::Uniforms,
+    vertices::Vertices,
+    DeviceError, DEPTH_FORMAT, SAMPLE_COUNT,
 };
 
+/// The color format that headless renderers render images in
+const HEADLESS_COLOR_FORMAT: wgpu::TextureFormat =
+    wgpu::TextureFormat::Rgba8Unorm;
+
 /// Graphics rendering state and target abstraction
 #[derive(Debug)]
 pub struct Renderer {
-    surface: wgpu::Surface<'static>,
+    /// The surface to render to, unless the renderer is headless
+    surface: Option<wgpu::Surface<'static>>,
     device: Device,
 
     surface_config: wgpu::SurfaceConfiguration,
@@ -33,15 +45,13 @@ pub struct Renderer {
     pipelines: Pipelines,
 
     navigation_cube_renderer: NavigationCubeRenderer,
+    overlay_renderer: OverlayRenderer,
 }
 
 impl Renderer {
     /// Returns a new `Renderer`.
     pub async fn new(screen: &impl Screen) -> Result<Self, RendererInitError> {
-        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::all(),
-            ..Default::default()
-        });
+        let instance = Self::create_instance();
 
         // This is sound, as `window` is an object to create a surface upon.
         let surface = instance.create_surface(screen.window())?;
@@ -51,28 +61,8 @@ impl Renderer {
             tracing::debug!("Available adapter: {:?}", adapter.get_info());
         }
 
-        let result = Device::from_preferred_adapter(&instance, &surface).await;
-        let (device, adapter, features) = match result {
-            Ok((device, adapter, features)) => (device, adapter, features),
-            Err(_) => {
-                error!("Failed to acquire device from preferred adapter");
-
-                match Device::try_from_all_adapters(&instance).await {
-                    Ok((device, adapter, features)) => {
-                        (device, adapter, features)
-                    }
-                    Err(err) => {
-                        error!("Prepend `RUST_LOG=fj_viewer=debug` and re-run");
-                        error!("Then open an issue and post your output");
-                        error!(
-                            "https://github.com/hannobraun/fornjot/issues/new"
-                        );
-
-                        return Err(err.into());
-                    }
-                }
-            }
-        };
+        let (device, adapter, features) =
+            Self::acquire_device(&instance, Some(&surface)).await?;
 
         let color_format = 'color_format: {
             let capabilities = surface.get_capabilities(&adapter);
@@ -100,8 +90,75 @@ impl Renderer {
                 .expect("No color formats supported")
         };
 
-        let ScreenSize { width, height } = screen.size();
-        let surface_config = wgpu::SurfaceConfiguration {
+        let surface_config = Self::surface_config(color_format, screen.size());
+        surface.configure(&device.device, &surface_config);
+
+        Ok(Self::with_target(
+            Some(surface),
+            device,
+            surface_config,
+            features,
+        ))
+    }
+
+    /// Returns a new `Renderer` that renders into images instead of a window
+    ///
+    /// Doesn't require a display. If no hardware adapter is available, a
+    /// software adapter is used, if the platform provides one.
+    pub async fn headless(size: ScreenSize) -> Result<Self, RendererInitError> {
+        let instance = Self::create_instance();
+        let (device, _, features) =
+            Self::acquire_device(&instance, None).await?;
+
+        // This is the format that images are written in, which saves us from
+        // having to convert pixels after reading them back.
+        let surface_config = Self::surface_config(HEADLESS_COLOR_FORMAT, size);
+
+        Ok(Self::with_target(None, device, surface_config, features))
+    }
+
+    fn create_instance() -> wgpu::Instance {
+        wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            ..Default::default()
+        })
+    }
+
+    async fn acquire_device(
+        instance: &wgpu::Instance,
+        surface: Option<&wgpu::Surface<'_>>,
+    ) -> Result<(Device, wgpu::Adapter, wgpu::Features), RendererInitError>
+    {
+        let result = Device::from_preferred_adapter(instance, surface).await;
+        if let Ok(result) = result {
+            return Ok(result);
+        }
+        error!("Failed to acquire device from preferred adapter");
+
+        let result = Device::from_fallback_adapter(instance, surface).await;
+        if let Ok(result) = result {
+            return Ok(result);
+        }
+        error!("Failed to acquire device from fallback adapter");
+
+        match Device::try_from_all_adapters(instance).await {
+            Ok(result) => Ok(result),
+            Err(err) => {
+                error!("Prepend `RUST_LOG=fj_viewer=debug` and re-run");
+                error!("Then open an issue and post your output");
+                error!("https://github.com/hannobraun/fornjot/issues/new");
+
+                Err(err.into())
+            }
+        }
+    }
+
+    fn surface_config(
+        color_format: wgpu::TextureFormat,
+        size: ScreenSize,
+    ) -> wgpu::SurfaceConfiguration {
+        let ScreenSize { width, height } = size;
+        wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
             format: color_format,
             width,
@@ -120,9 +177,15 @@ impl Renderer {
             // @hannobraun
             alpha_mode: wgpu::CompositeAlphaMode::Auto,
             view_formats: vec![],
-        };
-        surface.configure(&device.device, &surface_config);
+        }
+    }
 
+    fn with_target(
+        surface: Option<wgpu::Surface<'static>>,
+        device: Device,
+        surface_config: wgpu::SurfaceConfiguration,
+        features: wgpu::Features,
+    ) -> Self {
         let frame_buffer =
             Self::create_frame_buffer(&device.device, &surface_config);
         let depth_view =
@@ -171,11 +234,18 @@ impl Renderer {
                 label: None,
             });
 
-        let geometries = Geometries::new(&device.device, &Vertices::empty());
+        let geometries = Geometries::new(
+            &device.device,
+            &Vertices::empty(),
+            &Vertices::empty(),
+            &Vertices::empty(),
+            &Vertices::empty(),
+            &Vertices::empty(),
+        );
         let pipelines = Pipelines::new(
             &device.device,
             &bind_group_layout,
-            color_format,
+            surface_config.format,
             features,
         );
 
@@ -184,8 +254,10 @@ impl Renderer {
             &device.queue,
             &surface_config,
         );
+        let overlay_renderer =
+            OverlayRenderer::new(&device.device, surface_config.format);
 
-        Ok(Self {
+        Self {
             surface,
             device,
 
@@ -200,12 +272,47 @@ impl Renderer {
             pipelines,
 
             navigation_cube_renderer,
-        })
+            overlay_renderer,
+        }
     }
 
     /// Updates the geometry of the model being rendered.
     pub fn update_geometry(&mut self, mesh: Vertices) {
-        self.geometries = Geometries::new(&self.device.device, &mesh);
+        self.geometries.mesh = Geometry::new(&self.device.device, &mesh);
+    }
+
+    /// Updates the lines that show the edges of the model being rendered.
+    pub fn update_edges(&mut self, edges: Vertices) {
+        self.geometries.edges = Geometry::new(&self.device.device, &edges);
+    }
+
+    /// Updates the lines that highlight the selected edges.
+    pub fn update_selected_edges(&mut self, edges: Vertices) {
+        self.geometries.selected_edges =
+            Geometry::new(&self.device.device, &edges);
+    }
+
+    /// Updates the markers that show what is being measured.
+    pub fn update_measurement(&mut self, markers: Vertices) {
+        self.geometries.measurement =
+            Geometry::new(&self.device.device, &markers);
+    }
+
+    /// Updates the markers that show the points of the current issue.
+    pub fn update_issue_markers(&mut self, markers: Vertices) {
+        self.geometries.issue_markers =
+            Geometry::new(&self.device.device, &markers);
+    }
+
+    /// Updates the lines of text that are displayed on top of the model.
+    ///
+    /// Nothing is displayed, if `lines` is empty.
+    pub fn update_overlay(&mut self, lines: &[String]) {
+        self.overlay_renderer.update(
+            &self.device.device,
+            &self.device.queue,
+            lines,
+        );
     }
 
     /// Resizes the render surface.
@@ -216,8 +323,9 @@ impl Renderer {
         self.surface_config.width = size.width;
         self.surface_config.height = size.height;
 
-        self.surface
-            .configure(&self.device.device, &self.surface_config);
+        if let Some(surface) = &self.surface {
+            surface.configure(&self.device.device, &self.surface_config);
+        }
 
         self.frame_buffer = Self::create_frame_buffer(
             &self.device.device,
@@ -230,25 +338,18 @@ impl Renderer {
     }
 
     /// Draws the renderer, camera, and config state to the window.
+    ///
+    /// Does nothing, if the renderer is headless.
     pub fn draw(
         &mut self,
         camera: &Camera,
         config: &DrawConfig,
     ) -> Result<(), DrawError> {
-        let aspect_ratio = f64::from(self.surface_config.width)
-            / f64::from(self.surface_config.height);
-        let uniforms = Uniforms {
-            transform: Transform::for_vertices(camera, aspect_ratio),
-            transform_normals: Transform::for_normals(camera),
+        let Some(surface) = &self.surface else {
+            return Ok(());
         };
 
-        self.device.queue.write_buffer(
-            &self.uniform_buffer,
-            0,
-            bytemuck::cast_slice(&[uniforms]),
-        );
-
-        let surface_texture = match self.surface.get_current_texture() {
+        let surface_texture = match surface.get_current_texture() {
             Ok(surface_texture) => surface_texture,
             Err(wgpu::SurfaceError::Timeout) => {
                 // I'm seeing this all the time now (as in, multiple times per
@@ -270,56 +371,21 @@ impl Renderer {
             &wgpu::CommandEncoderDescriptor { label: None },
         );
 
-        // Need this block here, as a render pass only takes effect once it's
-        // dropped.
-        {
-            let mut render_pass =
-                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    color_attachments: &[Some(
-                        wgpu::RenderPassColorAttachment {
-                            view: &self.frame_buffer,
-                            resolve_target: Some(&color_view),
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
-                                // Not necessary, due to MSAA being enabled.
-                                store: wgpu::StoreOp::Discard,
-                            },
-                        },
-                    )],
-                    depth_stencil_attachment: Some(
-                        wgpu::RenderPassDepthStencilAttachment {
-                            view: &self.depth_view,
-                            depth_ops: Some(wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(1.0),
-                                store: wgpu::StoreOp::Store,
-                            }),
-                            stencil_ops: None,
-                        },
-                    ),
-                    ..Default::default()
-                });
-            render_pass.set_bind_group(0, &self.bind_group, &[]);
-
-            let drawables = Drawables::new(&self.geometries, &self.pipelines);
-
-            if config.draw_model {
-                drawables.model.draw(&mut render_pass);
-            }
-
-            if let Some(drawable) = drawables.mesh {
-                if config.draw_mesh {
-                    drawable.draw(&mut render_pass);
-                }
-            }
-        }
+        self.draw_model(&mut encoder, &color_view, camera, config);
 
         self.navigation_cube_renderer.draw(
             &color_view,
             &mut encoder,
             &self.device.queue,
-            aspect_ratio,
+            self.aspect_ratio(),
             camera.rotation,
         );
+        self.overlay_renderer.draw(
+            &color_view,
+            &mut encoder,
+            &self.device.queue,
+            self.size(),
+        );
 
         let command_buffer = encoder.finish();
         self.device.queue.submit(Some(command_buffer));
@@ -331,6 +397,204 @@ impl Renderer {
         Ok(())
     }
 
+    /// Draws the renderer, camera, and config state into an image
+    ///
+    /// Unlike [`Renderer::draw`], this doesn't draw the navigation cube, as
+    /// it's only useful for interactive use.
+    pub fn draw_to_image(
+        &mut self,
+        camera: &Camera,
+        config: &DrawConfig,
+    ) -> Result<image::RgbaImage, RenderError> {
+        let ScreenSize { width, height } = self.size();
+
+        let texture =
+            self.device.device.create_texture(&wgpu::TextureDescriptor {
+                label: None,
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: self.surface_config.format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::COPY_SRC,
+                view_formats: &[],
+            });
+        let color_view =
+            texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bytes_per_pixel = 4;
+        let unpadded_bytes_per_row = width * bytes_per_pixel;
+        let bytes_per_row = padded_bytes_per_row(unpadded_bytes_per_row);
+
+        let buffer =
+            self.device.device.create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: u64::from(bytes_per_row) * u64::from(height),
+                usage: wgpu::BufferUsages::COPY_DST
+                    | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            });
+
+        let mut encoder = self.device.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { label: None },
+        );
+
+        self.draw_model(&mut encoder, &color_view, camera, config);
+        self.overlay_renderer.draw(
+            &color_view,
+            &mut encoder,
+            &self.device.queue,
+            self.size(),
+        );
+
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        let command_buffer = encoder.finish();
+        self.device.queue.submit(Some(command_buffer));
+
+        let (sender, receiver) = mpsc::channel();
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            // The receiver is waiting right below, so it can't have been
+            // dropped.
+            let _ = sender.send(result);
+        });
+        self.device.device.poll(wgpu::Maintain::Wait);
+        receiver.recv().map_err(|_| RenderError::ReadBack)??;
+
+        let pixels = remove_row_padding(
+            &slice.get_mapped_range(),
+            unpadded_bytes_per_row,
+            bytes_per_row,
+            height,
+        );
+        buffer.unmap();
+
+        trace!("Finished drawing to image.");
+        image::RgbaImage::from_raw(width, height, pixels)
+            .ok_or(RenderError::ReadBack)
+    }
+
+    fn draw_model(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        color_view: &wgpu::TextureView,
+        camera: &Camera,
+        config: &DrawConfig,
+    ) {
+        let aspect_ratio = self.aspect_ratio();
+        let uniforms = Uniforms {
+            transform: Transform::for_vertices(camera, aspect_ratio),
+            transform_normals: Transform::for_normals(camera),
+            clipping_plane: if config.clip_model {
+                config.clipping_plane.to_equation()
+            } else {
+                [0.; 4]
+            },
+        };
+
+        self.device.queue.write_buffer(
+            &self.uniform_buffer,
+            0,
+            bytemuck::cast_slice(&[uniforms]),
+        );
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.frame_buffer,
+                    resolve_target: Some(color_view),
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                        // Not necessary, due to MSAA being enabled.
+                        store: wgpu::StoreOp::Discard,
+                    },
+                })],
+                depth_stencil_attachment: Some(
+                    wgpu::RenderPassDepthStencilAttachment {
+                        view: &self.depth_view,
+                        depth_ops: Some(wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(1.0),
+                            store: wgpu::StoreOp::Store,
+                        }),
+                        stencil_ops: None,
+                    },
+                ),
+                ..Default::default()
+            });
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+
+        let drawables = Drawables::new(&self.geometries, &self.pipelines);
+
+        if config.draw_model {
+            drawables.model.draw(&mut render_pass);
+        }
+
+        if config.draw_edges {
+            drawables.edges.draw(&mut render_pass);
+        }
+
+        if let Some(drawable) = drawables.mesh {
+            if config.draw_mesh {
+                drawable.draw(&mut render_pass);
+            }
+        }
+
+        drawables.selected_edges.draw(&mut render_pass);
+        drawables.measurement.draw(&mut render_pass);
+        drawables.issue_markers.draw(&mut render_pass);
+    }
+
+    /// Determine the face of the navigation cube that is under the cursor
+    ///
+    /// Returns the view that is associated with that face.
+    pub fn navigation_cube_face(
+        &self,
+        cursor: NormalizedScreenPosition,
+        camera: &Camera,
+    ) -> Option<ViewPreset> {
+        // The navigation cube is only drawn on screen.
+        self.surface.as_ref()?;
+
+        NavigationCubeRenderer::face_at(
+            cursor,
+            self.aspect_ratio(),
+            camera.rotation,
+        )
+    }
+
+    fn size(&self) -> ScreenSize {
+        ScreenSize {
+            width: self.surface_config.width,
+            height: self.surface_config.height,
+        }
+    }
+
+    fn aspect_ratio(&self) -> f64 {
+        let [width, height] = self.size().as_f64();
+        width / height
+    }
+
     fn create_frame_buffer(
         device: &wgpu::Device,
         surface_config: &wgpu::SurfaceConfiguration,
@@ -375,6 +639,31 @@ impl Renderer {
     }
 }
 
+/// Pad the length of a row, as required when copying a texture into a buffer
+fn padded_bytes_per_row(unpadded_bytes_per_row: u32) -> u32 {
+    unpadded_bytes_per_row.div_ceil(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT)
+        * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT
+}
+
+/// Remove the padding that [`padded_bytes_per_row`] added to each row
+fn remove_row_padding(
+    data: &[u8],
+    unpadded_bytes_per_row: u32,
+    padded_bytes_per_row: u32,
+    height: u32,
+) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(
+        (unpadded_bytes_per_row * height).try_into().unwrap_or(0),
+    );
+    for row in data
+        .chunks(padded_bytes_per_row as usize)
+        .take(height as usize)
+    {
+        pixels.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+    }
+    pixels
+}
+
 /// Error describing the set of render surface initialization errors
 #[derive(Error, Debug)]
 pub enum RendererInitError {
@@ -391,9 +680,65 @@ pub enum RendererInitError {
     Device(#[from] DeviceError),
 }
 
+/// Render error
+///
+/// Returned by [`Renderer::draw_to_image`].
+#[derive(Error, Debug)]
+pub enum RenderError {
+    /// Error initializing the renderer
+    #[error(transparent)]
+    Init(#[from] RendererInitError),
+
+    /// Error mapping the buffer that the image is read back from
+    #[error("Error mapping buffer")]
+    MapBuffer(#[from] wgpu::BufferAsyncError),
+
+    /// Error reading back the image
+    #[error("Error reading back rendered image")]
+    ReadBack,
+
+    /// Error encoding or writing the image
+    #[error("Error writing image")]
+    Image(#[from] image::ImageError),
+}
+
 /// Draw error
 ///
 /// Returned by [`Renderer::draw`].
 #[derive(Error, Debug)]
 #[error("Error acquiring output surface: {0}")]
 pub struct DrawError(#[from] wgpu::SurfaceError);
+
+#[cfg(test)]
+mod tests {
+    use super::{padded_bytes_per_row, remove_row_padding};
+
+    #[test]
+    fn rows_are_padded_to_alignment() {
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+        assert_eq!(padded_bytes_per_row(12), align);
+        assert_eq!(padded_bytes_per_row(align), align);
+        assert_eq!(padded_bytes_per_row(align + 4), align * 2);
+    }
+
+    #[test]
+    fn row_padding_is_removed() {
+        let (width, height) = (3, 2);
+        let unpadded = width * 4;
+        let padded = padded_bytes_per_row(unpadded);
+
+        let mut data = vec![0xff; (padded * height) as usize];
+        for row in 0..height {
+            for i in 0..unpadded {
+                data[(row * padded + i) as usize] = (row * unpadded + i) as u8;
+            }
+        }
+
+        let pixels = remove_row_padding(&data, unpadded, padded, height);
+
+        let expected =
+            (0..unpadded * height).map(|i| i as u8).collect::<Vec<_>>();
+        assert_eq!(pixels, expected);
+    }
+}
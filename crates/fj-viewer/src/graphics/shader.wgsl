@@ -1,6 +1,9 @@
 struct Uniforms {
     transform: mat4x4<f32>,
     transform_normals: mat4x4<f32>,
+    // Normal in `xyz`, distance from the origin in `w`. A zero normal disables
+    // clipping.
+    clipping_plane: vec4<f32>,
 };
 
 @group(0) @binding(0)
@@ -16,6 +19,7 @@ struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) normal: vec3<f32>,
     @location(1) color: vec4<f32>,
+    @location(2) model_position: vec3<f32>,
 };
 
 struct FragmentOutput {
@@ -24,22 +28,64 @@ struct FragmentOutput {
 
 @vertex
 fn vertex(in: VertexInput) -> VertexOutput {
+    return transform_vertex(in);
+}
+
+fn transform_vertex(in: VertexInput) -> VertexOutput {
     var out: VertexOutput;
     out.normal = (uniforms.transform_normals * vec4<f32>(in.normal, 0.0)).xyz;
     out.position = uniforms.transform * vec4<f32>(in.position, 1.0);
+    out.model_position = in.position;
     // We use premultiplied alpha blending.
     out.color = vec4<f32>(in.color.rgb * in.color.a, in.color.a);
 
     return out;
 }
 
+// Lines are drawn on top of the faces they bound. Moving them slightly towards
+// the camera prevents them from fighting with those faces over depth.
+const line_depth_offset: f32 = 0.0001;
+
+@vertex
+fn vertex_lines(in: VertexInput) -> VertexOutput {
+    var out = transform_vertex(in);
+    out.position.z -= line_depth_offset * out.position.w;
+
+    return out;
+}
+
 const pi: f32 = 3.14159265359;
 
+fn clipping_enabled() -> bool {
+    return any(uniforms.clipping_plane.xyz != vec3<f32>(0.0));
+}
+
+fn is_clipped(model_position: vec3<f32>) -> bool {
+    let plane = uniforms.clipping_plane;
+    return clipping_enabled() && dot(plane.xyz, model_position) > plane.w;
+}
+
 @fragment
-fn frag_model(in: VertexOutput) -> FragmentOutput {
+fn frag_model(
+    in: VertexOutput,
+    @builtin(front_facing) front_facing: bool,
+) -> FragmentOutput {
+    if is_clipped(in.model_position) {
+        discard;
+    }
+
+    var normal = in.normal;
+    if clipping_enabled() && !front_facing {
+        // Looking at the inside of a closed shape means we're looking through
+        // the cut. Shading those faces as if they were part of the clipping
+        // plane makes the cut look capped.
+        let plane_normal = vec4<f32>(uniforms.clipping_plane.xyz, 0.0);
+        normal = normalize((uniforms.transform_normals * plane_normal).xyz);
+    }
+
     let light = vec3<f32>(0.0, 0.0, -1.0);
 
-    let angle = acos(dot(light, -in.normal));
+    let angle = acos(dot(light, -normal));
     let f_angle = angle / (pi * 0.75);
 
     let f_normal = max(1.0 - f_angle, 0.0);
@@ -52,7 +98,22 @@ fn frag_model(in: VertexOutput) -> FragmentOutput {
 
 @fragment
 fn frag_mesh(in: VertexOutput) -> FragmentOutput {
+    if is_clipped(in.model_position) {
+        discard;
+    }
+
     var out: FragmentOutput;
     out.color = vec4<f32>(1.0 - in.color.rgb, in.color.a);
     return out;
 }
+
+@fragment
+fn frag_lines(in: VertexOutput) -> FragmentOutput {
+    if is_clipped(in.model_position) {
+        discard;
+    }
+
+    var out: FragmentOutput;
+    out.color = in.color;
+    return out;
+}
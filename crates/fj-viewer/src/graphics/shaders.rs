@@ -18,6 +18,7 @@ impl Shaders {
     pub fn model(&self) -> Shader {
         Shader {
             module: &self.0,
+            vert_entry: "vertex",
             frag_entry: "frag_model",
         }
     }
@@ -25,13 +26,23 @@ impl Shaders {
     pub fn mesh(&self) -> Shader {
         Shader {
             module: &self.0,
+            vert_entry: "vertex",
             frag_entry: "frag_mesh",
         }
     }
+
+    pub fn lines(&self) -> Shader {
+        Shader {
+            module: &self.0,
+            vert_entry: "vertex_lines",
+            frag_entry: "frag_lines",
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
 pub struct Shader<'r> {
     pub module: &'r wgpu::ShaderModule,
+    pub vert_entry: &'static str,
     pub frag_entry: &'static str,
 }
@@ -7,6 +7,9 @@ use super::transform::Transform;
 pub struct Uniforms {
     pub transform: Transform,
     pub transform_normals: Transform,
+
+    /// The equation of the clipping plane; a zero normal disables clipping
+    pub clipping_plane: [f32; 4],
 }
 
 impl Default for Uniforms {
@@ -14,6 +17,7 @@ impl Default for Uniforms {
         Self {
             transform: Transform::identity(),
             transform_normals: Transform::identity(),
+            clipping_plane: [0.; 4],
         }
     }
 }
@@ -1,5 +1,10 @@
+use std::collections::BTreeSet;
+
 use bytemuck::{Pod, Zeroable};
-use fj_interop::{Index, Mesh};
+use fj_interop::{Color, Index, Mesh};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::selection::Selectable;
 
 #[derive(Debug)]
 pub struct Vertices {
@@ -22,24 +27,141 @@ impl Vertices {
     pub fn indices(&self) -> &[Index] {
         self.indices.as_slice()
     }
-}
 
-impl From<&Mesh<fj_math::Point<3>>> for Vertices {
-    fn from(mesh: &Mesh<fj_math::Point<3>>) -> Self {
+    /// Create the triangles of the mesh, highlighting the selected faces
+    ///
+    /// Faces that are part of the current issue are highlighted in a different
+    /// color, which takes precedence over that of the selection.
+    pub fn faces(
+        mesh: &Mesh<Point<3>>,
+        selection: &BTreeSet<Selectable>,
+        issue: &BTreeSet<Selectable>,
+    ) -> Self {
         let mut m = Mesh::new();
 
         for triangle in mesh.triangles() {
             let [a, b, c] = triangle.inner.points();
 
             let normal = (b - a).cross(&(c - a)).normalize();
-            let color = triangle.color;
+            let face = triangle.face.map(Selectable::Face);
+            let color = match face {
+                Some(face) if issue.contains(&face) => {
+                    highlight(triangle.color, ISSUE_COLOR)
+                }
+                Some(face) if selection.contains(&face) => {
+                    highlight(triangle.color, HIGHLIGHT_COLOR)
+                }
+                _ => triangle.color,
+            };
 
             m.push_vertex((a, normal, color));
             m.push_vertex((b, normal, color));
             m.push_vertex((c, normal, color));
         }
 
-        let vertices = m
+        Self::from_mesh(m)
+    }
+
+    /// Create lines for the edges of the mesh
+    ///
+    /// The indices of the result describe a line list.
+    pub fn edges(mesh: &Mesh<Point<3>>) -> Self {
+        let mut m = Mesh::new();
+
+        // Lines are not lit, so their normals don't matter.
+        let normal = Vector::from([0., 0., 0.]);
+
+        // Most edges are bounded by two faces, and show up once for each of
+        // their half-edges. No need to draw them twice.
+        let mut segments = BTreeSet::new();
+
+        for edge in mesh.edges() {
+            for segment in edge.points.windows(2) {
+                let mut segment = [segment[0], segment[1]];
+                segment.sort();
+
+                if segments.insert(segment) {
+                    m.push_vertex((segment[0], normal, EDGE_COLOR));
+                    m.push_vertex((segment[1], normal, EDGE_COLOR));
+                }
+            }
+        }
+
+        Self::from_mesh(m)
+    }
+
+    /// Create lines for the selected edges of the mesh
+    ///
+    /// Edges that are part of the current issue are included too, in a
+    /// different color. The indices of the result describe a line list.
+    pub fn selected_edges(
+        mesh: &Mesh<Point<3>>,
+        selection: &BTreeSet<Selectable>,
+        issue: &BTreeSet<Selectable>,
+    ) -> Self {
+        let mut m = Mesh::new();
+
+        // Lines are not lit, so their normals don't matter.
+        let normal = Vector::from([0., 0., 0.]);
+
+        for edge in mesh.edges() {
+            let object = Selectable::Edge(edge.source);
+            let color = if issue.contains(&object) {
+                ISSUE_COLOR
+            } else if selection.contains(&object) {
+                HIGHLIGHT_COLOR
+            } else {
+                continue;
+            };
+
+            for segment in edge.points.windows(2) {
+                m.push_vertex((segment[0], normal, color));
+                m.push_vertex((segment[1], normal, color));
+            }
+        }
+
+        Self::from_mesh(m)
+    }
+
+    /// Create markers for the points that are being measured
+    ///
+    /// Every point is marked with a cross of the provided size, and
+    /// consecutive points are connected. The indices of the result describe a
+    /// line list.
+    pub fn measurement(points: &[Point<3>], marker_size: Scalar) -> Self {
+        let mut m = Mesh::new();
+
+        // Lines are not lit, so their normals don't matter.
+        let normal = Vector::from([0., 0., 0.]);
+
+        for &point in points {
+            push_marker(&mut m, point, marker_size, MEASUREMENT_COLOR);
+        }
+
+        for segment in points.windows(2) {
+            m.push_vertex((segment[0], normal, MEASUREMENT_COLOR));
+            m.push_vertex((segment[1], normal, MEASUREMENT_COLOR));
+        }
+
+        Self::from_mesh(m)
+    }
+
+    /// Create markers for the points that the current issue refers to
+    ///
+    /// Every point is marked with a cross of the provided size. The indices of
+    /// the result describe a line list.
+    pub fn issue_markers(points: &[Point<3>], marker_size: Scalar) -> Self {
+        let mut m = Mesh::new();
+
+        for &point in points {
+            push_marker(&mut m, point, marker_size, ISSUE_COLOR);
+        }
+
+        Self::from_mesh(m)
+    }
+
+    fn from_mesh(mesh: Mesh<(Point<3>, Vector<3>, Color)>) -> Self {
+        let vertices = mesh
             .vertices()
             .map(|(vertex, normal, color)| Vertex {
                 position: vertex.into(),
@@ -48,12 +170,59 @@ impl From<&Mesh<fj_math::Point<3>>> for Vertices {
             })
             .collect();
 
-        let indices = m.indices().collect();
+        let indices = mesh.indices().collect();
 
         Self { vertices, indices }
     }
 }
 
+impl From<&Mesh<Point<3>>> for Vertices {
+    fn from(mesh: &Mesh<Point<3>>) -> Self {
+        Self::faces(mesh, &BTreeSet::new(), &BTreeSet::new())
+    }
+}
+
+/// Push a cross that marks a point into the provided line list
+fn push_marker(
+    mesh: &mut Mesh<(Point<3>, Vector<3>, Color)>,
+    point: Point<3>,
+    marker_size: Scalar,
+    color: Color,
+) {
+    // Lines are not lit, so their normals don't matter.
+    let normal = Vector::from([0., 0., 0.]);
+
+    for axis in [Vector::unit_x(), Vector::unit_y(), Vector::unit_z()] {
+        let offset = axis * marker_size / 2.;
+        mesh.push_vertex((point - offset, normal, color));
+        mesh.push_vertex((point + offset, normal, color));
+    }
+}
+
+/// Blend the provided color with a highlight color
+fn highlight(Color(color): Color, Color(highlight): Color) -> Color {
+    let mut blended = color;
+    for ((blended, color), highlight) in
+        blended.iter_mut().zip(color).zip(highlight).take(3)
+    {
+        *blended = ((u16::from(color) + u16::from(highlight)) / 2) as u8;
+    }
+
+    Color(blended)
+}
+
+/// The color that edges are drawn with
+const EDGE_COLOR: Color = Color([0, 0, 0, 255]);
+
+/// The color of the markers that show what is being measured
+const MEASUREMENT_COLOR: Color = Color([0, 100, 255, 255]);
+
+/// The color that selected objects are highlighted with
+const HIGHLIGHT_COLOR: Color = Color([255, 140, 0, 255]);
+
+/// The color that the objects of the current issue are highlighted with
+const ISSUE_COLOR: Color = Color([230, 0, 0, 255]);
+
 #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
 #[repr(C)]
 pub struct Vertex {
@@ -61,3 +230,83 @@ pub struct Vertex {
     pub normal: [f32; 3],
     pub color: [f32; 4],
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_interop::{Color, Mesh, SourceId};
+    use fj_math::Point;
+
+    use crate::selection::Selectable;
+
+    use super::{Vertices, EDGE_COLOR, ISSUE_COLOR};
+
+    #[test]
+    fn edges_are_drawn_once_per_segment() {
+        let [a, b, c] =
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]].map(Point::from);
+
+        let mut mesh = Mesh::new();
+        mesh.push_edge(vec![a, b, c], SourceId(0), None);
+
+        // Overlaps the first edge, in the opposite direction.
+        mesh.push_edge(vec![b, a], SourceId(1), None);
+
+        let vertices = Vertices::edges(&mesh);
+        let lines = line_list(&vertices);
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines.contains(&[a, b]));
+        assert!(lines.contains(&[b, c]));
+        assert!(vertices
+            .vertices()
+            .iter()
+            .all(|vertex| vertex.color == color(EDGE_COLOR)));
+    }
+
+    #[test]
+    fn selected_edges_include_only_selected_and_issue_edges() {
+        let [a, b, c] =
+            [[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]].map(Point::from);
+
+        let mut mesh = Mesh::new();
+        mesh.push_edge(vec![a, b], SourceId(0), None);
+        mesh.push_edge(vec![b, c], SourceId(1), None);
+        mesh.push_edge(vec![c, a], SourceId(2), None);
+
+        let selection = BTreeSet::from([Selectable::Edge(SourceId(0))]);
+        let issue = BTreeSet::from([
+            Selectable::Edge(SourceId(0)),
+            Selectable::Edge(SourceId(1)),
+        ]);
+
+        let vertices = Vertices::selected_edges(&mesh, &selection, &issue);
+        let lines = line_list(&vertices);
+
+        assert_eq!(lines, [[a, b], [b, c]]);
+
+        // The issue takes precedence over the selection.
+        assert!(vertices
+            .vertices()
+            .iter()
+            .all(|vertex| vertex.color == color(ISSUE_COLOR)));
+    }
+
+    fn line_list(vertices: &Vertices) -> Vec<[Point<3>; 2]> {
+        let point = |index: u32| {
+            let [x, y, z] = vertices.vertices()[index as usize].position;
+            Point::from([x, y, z].map(f64::from))
+        };
+
+        vertices
+            .indices()
+            .chunks(2)
+            .map(|line| [point(line[0]), point(line[1])])
+            .collect()
+    }
+
+    fn color(color: Color) -> [f32; 4] {
+        color.0.map(|v| f32::from(v) / 255.0)
+    }
+}
@@ -0,0 +1,41 @@
+//! Rendering without a window
+//!
+//! See [`render_to_png`].
+
+use std::path::Path;
+
+use fj_interop::Model;
+
+use crate::{
+    graphics::{DrawConfig, RenderError, Renderer, Vertices},
+    Camera, ScreenSize,
+};
+
+/// Render the provided model into a PNG file, without requiring a window
+///
+/// This is intended for generating previews and images for visual regression
+/// tests, for example in CI, where no display is available. If no hardware
+/// adapter is available, a software adapter is used, if the platform provides
+/// one.
+///
+/// The clipping planes of the camera are adjusted to the model. Everything else
+/// about the camera is used as provided.
+pub async fn render_to_png(
+    model: &Model,
+    camera: &Camera,
+    config: &DrawConfig,
+    size: ScreenSize,
+    path: &Path,
+) -> Result<(), RenderError> {
+    let mut renderer = Renderer::headless(size).await?;
+    renderer.update_geometry((&model.mesh).into());
+    renderer.update_edges(Vertices::edges(&model.mesh));
+
+    let mut camera = camera.clone();
+    camera.update_planes(&model.aabb);
+
+    let image = renderer.draw_to_image(&camera, config)?;
+    image.save_with_format(path, image::ImageFormat::Png)?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::screen::NormalizedScreenPosition;
+use crate::{camera::ViewPreset, screen::NormalizedScreenPosition};
 
 /// An input event
 pub enum InputEvent {
@@ -20,4 +20,21 @@ pub enum InputEvent {
 
     /// Move the view forwards and backwards
     Zoom(f64),
+
+    /// Switch between perspective and orthographic projection
+    ToggleProjection,
+
+    /// Move the camera to a standard view of the model
+    View(ViewPreset),
+
+    /// Enable or disable cutting the model at the clipping plane
+    ToggleClippingPlane,
+
+    /// Switch the clipping plane to the next axis-aligned orientation
+    RotateClippingPlane,
+
+    /// Move the clipping plane along its normal
+    ///
+    /// The distance is relative to the size of the model.
+    MoveClippingPlane(f64),
 }
@@ -24,6 +24,14 @@ impl InputHandler {
             InputEvent::Zoom(zoom_delta) => {
                 Zoom::apply(zoom_delta, focus_point, camera);
             }
+            InputEvent::ToggleProjection
+            | InputEvent::View(_)
+            | InputEvent::ToggleClippingPlane
+            | InputEvent::RotateClippingPlane
+            | InputEvent::MoveClippingPlane(_) => {
+                // These don't depend on the focus point. They are handled by
+                // the viewer.
+            }
         }
     }
 }
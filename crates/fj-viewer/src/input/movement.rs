@@ -1,7 +1,7 @@
 use fj_math::{Point, Scalar, Transform, Vector};
 
 use crate::{
-    camera::{Camera, FocusPoint},
+    camera::{Camera, FocusPoint, Projection},
     screen::NormalizedScreenPosition,
 };
 
@@ -17,10 +17,19 @@ impl Movement {
         let previous = camera.cursor_to_model_space(previous);
         let cursor = camera.cursor_to_model_space(current);
 
-        let d1 = Point::distance_to(&camera.position(), &cursor);
-        let d2 = Point::distance_to(&camera.position(), &focus_point.0);
+        let diff = match camera.projection {
+            Projection::Perspective => {
+                // Move the model, so the focus point follows the cursor.
+                let d1 = Point::distance_to(&camera.position(), &cursor);
+                let d2 = Point::distance_to(&camera.position(), &focus_point.0);
 
-        let diff = (cursor - previous) * d2 / d1;
+                (cursor - previous) * d2 / d1
+            }
+            Projection::Orthographic => {
+                // The cursor moves the same distance at any depth.
+                cursor - previous
+            }
+        };
         let offset = camera.camera_to_model().transform_vector(&diff);
 
         camera.translation = camera.translation
@@ -0,0 +1,25 @@
+//! Issues with the model that are shown in the viewer
+//!
+//! See [`Issue`].
+
+use fj_math::Point;
+
+use crate::selection::Selectable;
+
+/// An issue with the model, for example a validation error
+///
+/// The viewer lists all issues, and highlights the objects and points of the
+/// current one. See [`Viewer::set_issues`].
+///
+/// [`Viewer::set_issues`]: crate::Viewer::set_issues
+#[derive(Clone, Debug, PartialEq)]
+pub struct Issue {
+    /// A single-line description of the issue, shown in the list of issues
+    pub description: String,
+
+    /// The objects of the model that the issue refers to
+    pub objects: Vec<Selectable>,
+
+    /// The points that the issue refers to
+    pub points: Vec<Point<3>>,
+}
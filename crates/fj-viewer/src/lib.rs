@@ -11,13 +11,24 @@
 mod assets;
 mod camera;
 mod graphics;
+mod headless;
 mod input;
+mod issue;
+mod measurement;
 mod screen;
+mod selection;
 mod viewer;
 
 pub use self::{
-    graphics::{DeviceError, RendererInitError},
+    camera::{Camera, Projection, ViewPreset},
+    graphics::{
+        ClippingPlane, DeviceError, DrawConfig, RenderError, RendererInitError,
+    },
+    headless::render_to_png,
     input::InputEvent,
+    issue::Issue,
+    measurement::{Measurement, Snap, SnapTarget},
     screen::{NormalizedScreenPosition, Screen, ScreenSize},
+    selection::Selectable,
     viewer::Viewer,
 };
@@ -0,0 +1,391 @@
+//! Measurements of the model in the viewer
+//!
+//! See [`Snap`] and [`Measurement`].
+
+use std::fmt;
+
+use fj_interop::{Mesh, SourceId};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    camera::Camera,
+    screen::NormalizedScreenPosition,
+    selection::{nearest_face, pick_radius, ray_to_segment},
+};
+
+/// A point on the model that a measurement refers to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Snap {
+    /// The position of the point
+    pub point: Point<3>,
+
+    /// The object that the point was snapped to
+    pub target: SnapTarget,
+}
+
+/// An object that a [`Snap`] can refer to
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapTarget {
+    /// A vertex of the model
+    Vertex,
+
+    /// An edge of the model
+    Edge(SourceId),
+
+    /// A face of the model
+    Face(SourceId),
+}
+
+impl fmt::Display for SnapTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Vertex => write!(f, "Vertex"),
+            Self::Edge(_) => write!(f, "Edge"),
+            Self::Face(_) => write!(f, "Face"),
+        }
+    }
+}
+
+/// Find the point on the model that the cursor points to
+///
+/// Vertices take priority over the edges they bound, which in turn take
+/// priority over faces. A point on an edge or face is the point on that object
+/// that is closest to the cursor.
+pub fn snap(
+    camera: &Camera,
+    cursor: NormalizedScreenPosition,
+    mesh: &Mesh<Point<3>>,
+) -> Option<Snap> {
+    let (origin, dir) = camera.cursor_ray(cursor);
+    let face = nearest_face(origin, dir, mesh);
+
+    // See `selection::pick` for why this needs some leeway.
+    let max_t = face.map(|(t, _)| t + pick_radius(camera, t));
+    let is_visible = |t: Scalar| max_t.map_or(true, |max_t| t <= max_t);
+
+    let mut vertex = None;
+    for point in mesh.edges().flat_map(|edge| {
+        edge.points.first().into_iter().chain(edge.points.last())
+    }) {
+        let to_point = *point - origin;
+        let t = to_point.dot(&dir);
+        let distance = (to_point - dir * t).magnitude();
+
+        if t < Scalar::ZERO
+            || distance > pick_radius(camera, t) * VERTEX_SNAP_FACTOR
+            || !is_visible(t)
+        {
+            continue;
+        }
+
+        if vertex.map_or(true, |(min_distance, _)| distance < min_distance) {
+            vertex = Some((distance, *point));
+        }
+    }
+    if let Some((_, point)) = vertex {
+        return Some(Snap {
+            point,
+            target: SnapTarget::Vertex,
+        });
+    }
+
+    let mut edge = None;
+    for e in mesh.edges() {
+        for segment in e.points.windows(2) {
+            let Some((t, distance, point)) =
+                ray_to_segment(origin, dir, segment[0], segment[1])
+            else {
+                continue;
+            };
+
+            if distance > pick_radius(camera, t) || !is_visible(t) {
+                continue;
+            }
+
+            if edge.map_or(true, |(min_distance, _)| distance < min_distance) {
+                edge = Some((
+                    distance,
+                    Snap {
+                        point,
+                        target: SnapTarget::Edge(e.source),
+                    },
+                ));
+            }
+        }
+    }
+    if let Some((_, snap)) = edge {
+        return Some(snap);
+    }
+
+    let (t, face) = face?;
+    Some(Snap {
+        point: origin + dir * t,
+        target: SnapTarget::Face(face?),
+    })
+}
+
+/// A quantity measured on the model
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Measurement {
+    /// The distance between two points
+    Distance(Scalar),
+
+    /// The length of an edge
+    EdgeLength(Scalar),
+
+    /// The radius of a circular edge
+    Radius(Scalar),
+
+    /// The angle between the normals of two planar faces, in degrees
+    Angle(Scalar),
+}
+
+impl Measurement {
+    /// Measure what the provided snaps refer to
+    ///
+    /// A single snap on an edge measures the length of that edge and, if it is
+    /// circular, its radius. Two snaps measure the distance between their
+    /// points and, if both are on planar faces, the angle between those.
+    pub fn from_snaps(snaps: &[Snap], mesh: &Mesh<Point<3>>) -> Vec<Self> {
+        let mut measurements = Vec::new();
+
+        match snaps {
+            [snap] => {
+                if let SnapTarget::Edge(source) = snap.target {
+                    let points = mesh
+                        .edges()
+                        .find(|edge| edge.source == source)
+                        .map(|edge| edge.points.as_slice())
+                        .unwrap_or_default();
+
+                    let length = points
+                        .windows(2)
+                        .map(|segment| segment[0].distance_to(&segment[1]))
+                        .fold(Scalar::ZERO, |sum, length| sum + length);
+                    measurements.push(Self::EdgeLength(length));
+
+                    if let Some(radius) = circle_radius(points) {
+                        measurements.push(Self::Radius(radius));
+                    }
+                }
+            }
+            [a, b] => {
+                measurements
+                    .push(Self::Distance(a.point.distance_to(&b.point)));
+
+                if let (SnapTarget::Face(a), SnapTarget::Face(b)) =
+                    (a.target, b.target)
+                {
+                    if let (Some(a), Some(b)) =
+                        (plane_normal(a, mesh), plane_normal(b, mesh))
+                    {
+                        let cos = a.dot(&b).clamp(-Scalar::ONE, Scalar::ONE);
+                        let angle = cos.acos().into_f64().to_degrees();
+                        measurements.push(Self::Angle(angle.into()));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        measurements
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Distance(distance) => write!(f, "Distance: {distance:.3}"),
+            Self::EdgeLength(length) => write!(f, "Edge length: {length:.3}"),
+            Self::Radius(radius) => write!(f, "Radius: {radius:.3}"),
+            Self::Angle(angle) => write!(f, "Angle: {angle:.2}°"),
+        }
+    }
+}
+
+/// Compute the radius of the circle that the points lie on
+///
+/// Returns `None`, if there aren't enough points to tell, or if they don't lie
+/// on a circle.
+fn circle_radius(points: &[Point<3>]) -> Option<Scalar> {
+    let n = points.len();
+    if n < 3 {
+        return None;
+    }
+
+    // Closed edges end where they start, so the first and last point can't
+    // both be used.
+    let [a, b, c] = [0, n / 3, 2 * n / 3].map(|i| points[i]);
+
+    let ab = b - a;
+    let ac = c - a;
+    let normal = ab.cross(&ac);
+    let normal_squared = normal.dot(&normal);
+    if normal_squared <= ab.dot(&ab) * ac.dot(&ac) * CIRCLE_TOLERANCE {
+        return None;
+    }
+
+    let center = a
+        + (normal.cross(&ab) * ac.dot(&ac) + ac.cross(&normal) * ab.dot(&ab))
+            / (normal_squared * 2.);
+    let radius = center.distance_to(&a);
+
+    let normal = normal.normalize();
+    let tolerance = radius * CIRCLE_TOLERANCE;
+    let on_circle = points.iter().all(|point| {
+        let to_point = *point - center;
+        (to_point.magnitude() - radius).abs() <= tolerance
+            && to_point.dot(&normal).abs() <= tolerance
+    });
+
+    on_circle.then_some(radius)
+}
+
+/// Compute the normal of a face, if it is planar
+fn plane_normal(face: SourceId, mesh: &Mesh<Point<3>>) -> Option<Vector<3>> {
+    let normals = mesh
+        .triangles()
+        .filter(|triangle| triangle.face == Some(face))
+        .map(|triangle| triangle.inner.normal())
+        .collect::<Vec<_>>();
+
+    let first = *normals.first()?;
+    let planar = normals
+        .iter()
+        .all(|normal| normal.dot(&first) >= Scalar::ONE - CIRCLE_TOLERANCE);
+
+    planar.then_some(first)
+}
+
+/// How much further from the cursor than edges vertices can be to snap to them
+///
+/// Snapping to vertices would be hard otherwise, as they are surrounded by the
+/// edges they bound.
+const VERTEX_SNAP_FACTOR: f64 = 2.;
+
+/// The relative deviation allowed when recognizing circles and planes
+const CIRCLE_TOLERANCE: f64 = 1e-6;
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::{Color, Mesh, SourceId};
+    use fj_math::{Point, Scalar, Vector};
+
+    use super::{circle_radius, Measurement, Snap, SnapTarget};
+
+    #[test]
+    fn radius_of_circle() {
+        let center = Point::from([1., 2., 3.]);
+        let radius = 2.;
+
+        // A closed edge, tilted out of the coordinate planes.
+        let a = Vector::from([1., 0., 0.]) * radius;
+        let b = Vector::from([0., 1., 1.]).normalize() * radius;
+        let n = 12;
+        let points = (0..=n)
+            .map(|i| {
+                let angle = Scalar::TAU * i as f64 / n as f64;
+                let (sin, cos) = angle.sin_cos();
+                center + a * cos + b * sin
+            })
+            .collect::<Vec<_>>();
+
+        let measured = circle_radius(&points).unwrap();
+        assert!((measured - radius).abs() < Scalar::from(1e-9));
+    }
+
+    #[test]
+    fn radius_of_non_circles() {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [2., 0., 0.], [1., 1., 0.]]
+                .map(Point::from);
+
+        // Too few points.
+        assert_eq!(circle_radius(&[a, b]), None);
+
+        // Collinear points.
+        assert_eq!(circle_radius(&[a, b, c]), None);
+
+        // Points that aren't all on the circle through the first three.
+        assert_eq!(circle_radius(&[a, d, c, b, a]), None);
+    }
+
+    #[test]
+    fn edge_length_and_radius() {
+        let points = [[1., 0., 0.], [0., 1., 0.], [-1., 0., 0.], [0., -1., 0.]]
+            .map(Point::from);
+
+        let mut mesh = Mesh::new();
+        mesh.push_edge(
+            points.iter().chain(&points[..1]).copied(),
+            SourceId(0),
+            None,
+        );
+
+        let snap = Snap {
+            point: points[0],
+            target: SnapTarget::Edge(SourceId(0)),
+        };
+
+        let measurements = Measurement::from_snaps(&[snap], &mesh);
+        let [Measurement::EdgeLength(length), Measurement::Radius(radius)] =
+            measurements.as_slice()
+        else {
+            panic!("Unexpected measurements: {measurements:?}");
+        };
+
+        assert!((*length - 2f64.sqrt() * 4.).abs() < Scalar::from(1e-9));
+        assert!((*radius - 1.).abs() < Scalar::from(1e-9));
+    }
+
+    #[test]
+    fn distance_and_angle_between_faces() {
+        let [a, b] = [SourceId(0), SourceId(1)];
+
+        let mut mesh = Mesh::new();
+        mesh.push_face_triangle(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+            Color::default(),
+            a,
+        );
+        mesh.push_face_triangle(
+            [[0., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            Color::default(),
+            b,
+        );
+
+        let snaps = [
+            Snap {
+                point: Point::from([0.5, 0.25, 0.]),
+                target: SnapTarget::Face(a),
+            },
+            Snap {
+                point: Point::from([0., 0.25, 0.5]),
+                target: SnapTarget::Face(b),
+            },
+        ];
+
+        let measurements = Measurement::from_snaps(&snaps, &mesh);
+        let [Measurement::Distance(distance), Measurement::Angle(angle)] =
+            measurements.as_slice()
+        else {
+            panic!("Unexpected measurements: {measurements:?}");
+        };
+
+        assert_eq!(*distance, Scalar::from(0.5f64.sqrt()));
+        assert!((*angle - Scalar::from(90.)).abs() < Scalar::from(1e-9));
+
+        // Snaps that aren't both on faces only measure the distance.
+        let snaps = [
+            snaps[0],
+            Snap {
+                target: SnapTarget::Vertex,
+                ..snaps[1]
+            },
+        ];
+        assert_eq!(
+            Measurement::from_snaps(&snaps, &mesh),
+            [Measurement::Distance(Scalar::from(0.5f64.sqrt()))]
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Selection of objects in the viewer
+//!
+//! See [`Selectable`].
+
+use fj_interop::{Mesh, SourceId};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{camera::Camera, screen::NormalizedScreenPosition};
+
+/// An object in the model that can be selected
+///
+/// The IDs are those that the mesh of the model carries. See
+/// [`fj_interop::Triangle::face`] and [`fj_interop::Edge::source`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Selectable {
+    /// A face of the model
+    Face(SourceId),
+
+    /// An edge of the model
+    Edge(SourceId),
+}
+
+/// Find the object that the cursor points to
+///
+/// Edges take priority over the faces they bound, as they would be very hard
+/// to hit otherwise.
+pub fn pick(
+    camera: &Camera,
+    cursor: NormalizedScreenPosition,
+    mesh: &Mesh<Point<3>>,
+) -> Option<Selectable> {
+    let (origin, dir) = camera.cursor_ray(cursor);
+    let face = nearest_face(origin, dir, mesh);
+
+    // Edges that are further away than the nearest face are hidden behind it.
+    // The edges that bound that face are at the same distance, so there needs
+    // to be some leeway.
+    let max_t = face.map(|(t, _)| t + pick_radius(camera, t));
+
+    let mut edge = None;
+    for e in mesh.edges() {
+        for segment in e.points.windows(2) {
+            let Some((t, distance, _)) =
+                ray_to_segment(origin, dir, segment[0], segment[1])
+            else {
+                continue;
+            };
+
+            if distance > pick_radius(camera, t)
+                || max_t.is_some_and(|max_t| t > max_t)
+            {
+                continue;
+            }
+
+            if edge.map_or(true, |(min_distance, _)| distance < min_distance) {
+                edge = Some((distance, e.source));
+            }
+        }
+    }
+
+    if let Some((_, source)) = edge {
+        return Some(Selectable::Edge(source));
+    }
+
+    face.and_then(|(_, face)| face).map(Selectable::Face)
+}
+
+/// Find the triangle of the mesh that a ray hits first
+///
+/// Returns the distance along the ray, and the face that the triangle belongs
+/// to, if it is known.
+pub(crate) fn nearest_face(
+    origin: Point<3>,
+    dir: Vector<3>,
+    mesh: &Mesh<Point<3>>,
+) -> Option<(Scalar, Option<SourceId>)> {
+    let mut face = None;
+    for triangle in mesh.triangles() {
+        let Some(t) =
+            triangle
+                .inner
+                .cast_local_ray(origin, dir, f64::INFINITY, true)
+        else {
+            continue;
+        };
+
+        if face.map_or(true, |(min_t, _)| t < min_t) {
+            face = Some((t, triangle.face));
+        }
+    }
+
+    face
+}
+
+/// How close the cursor must be to an object at the provided distance
+pub(crate) fn pick_radius(camera: &Camera, t: Scalar) -> Scalar {
+    Scalar::from(camera.half_width_at(t.into_f64()) * EDGE_PICK_RADIUS)
+}
+
+/// Compute the closest approach between a ray and a line segment
+///
+/// Returns the distance along the ray, the distance between ray and segment at
+/// that point, and the closest point on the segment. Returns `None`, if the
+/// closest approach is behind the ray's origin.
+pub(crate) fn ray_to_segment(
+    origin: Point<3>,
+    dir: Vector<3>,
+    a: Point<3>,
+    b: Point<3>,
+) -> Option<(Scalar, Scalar, Point<3>)> {
+    let segment = b - a;
+    let w = origin - a;
+
+    let d_dot_s = dir.dot(&segment);
+    let s_dot_s = segment.dot(&segment);
+    let d_dot_w = dir.dot(&w);
+    let s_dot_w = segment.dot(&w);
+
+    // `dir` is normalized, so the first term is just `s_dot_s`.
+    let denominator = s_dot_s - d_dot_s * d_dot_s;
+    let u = if denominator > Scalar::ZERO {
+        ((s_dot_w - d_dot_s * d_dot_w) / denominator)
+            .clamp(Scalar::ZERO, Scalar::ONE)
+    } else {
+        // Ray and segment are parallel. Every point is as close as any other.
+        Scalar::ZERO
+    };
+
+    let point_on_segment = a + segment * u;
+    let t = (point_on_segment - origin).dot(&dir);
+    if t < Scalar::ZERO {
+        return None;
+    }
+
+    let point_on_ray = origin + dir * t;
+    Some((
+        t,
+        (point_on_ray - point_on_segment).magnitude(),
+        point_on_segment,
+    ))
+}
+
+/// How close the cursor must be to an edge to pick it
+///
+/// This is relative to the width of the visible area, which makes it constant
+/// on screen.
+const EDGE_PICK_RADIUS: f64 = 0.01;
@@ -1,10 +1,16 @@
+use std::collections::BTreeSet;
+
 use fj_interop::Model;
+use fj_math::{Scalar, Vector};
 use tracing::warn;
 
 use crate::{
-    camera::{Camera, FocusPoint},
-    graphics::{DrawConfig, Renderer},
+    camera::{Camera, FocusPoint, ViewPreset},
+    graphics::{DrawConfig, Renderer, Vertices},
     input::InputHandler,
+    issue::Issue,
+    measurement::{self, Measurement, Snap},
+    selection::{self, Selectable},
     InputEvent, NormalizedScreenPosition, RendererInitError, Screen,
     ScreenSize,
 };
@@ -17,6 +23,11 @@ pub struct Viewer {
     focus_point: Option<FocusPoint>,
     renderer: Renderer,
     model: Option<Model>,
+    selection: BTreeSet<Selectable>,
+    measuring: bool,
+    snaps: Vec<Snap>,
+    issues: Vec<Issue>,
+    current_issue: usize,
 }
 
 impl Viewer {
@@ -31,6 +42,11 @@ impl Viewer {
             focus_point: None,
             renderer,
             model: None,
+            selection: BTreeSet::new(),
+            measuring: false,
+            snaps: Vec::new(),
+            issues: Vec::new(),
+            current_issue: 0,
         })
     }
 
@@ -49,23 +65,273 @@ impl Viewer {
         self.draw_config.draw_mesh = !self.draw_config.draw_mesh;
     }
 
+    /// Toggle the "draw edges" setting
+    pub fn toggle_draw_edges(&mut self) {
+        self.draw_config.draw_edges = !self.draw_config.draw_edges;
+    }
+
+    /// Toggle measurement mode
+    ///
+    /// While measurement mode is active, clicking on the model picks the
+    /// points to measure, instead of selecting objects. See
+    /// [`Viewer::measure_at_cursor`].
+    pub fn toggle_measurement_mode(&mut self) {
+        self.measuring = !self.measuring;
+        self.snaps.clear();
+        self.update_measurement();
+    }
+
+    /// Replace the issues with the model
+    ///
+    /// All issues are listed on screen, unless measurement mode is active. The
+    /// objects and points of the current issue are highlighted. The first issue
+    /// is the current one, initially.
+    pub fn set_issues(&mut self, issues: Vec<Issue>) {
+        self.issues = issues;
+        self.current_issue = 0;
+        self.update_issue();
+    }
+
+    /// Access the issues with the model
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Access the current issue, if there are any
+    pub fn current_issue(&self) -> Option<&Issue> {
+        self.issues.get(self.current_issue)
+    }
+
+    /// Make the next issue the current one
+    ///
+    /// Wraps around to the first issue, after the last one.
+    pub fn next_issue(&mut self) {
+        if self.issues.is_empty() {
+            return;
+        }
+
+        self.current_issue = (self.current_issue + 1) % self.issues.len();
+        self.update_issue();
+    }
+
+    /// Make the previous issue the current one
+    ///
+    /// Wraps around to the last issue, before the first one.
+    pub fn previous_issue(&mut self) {
+        if self.issues.is_empty() {
+            return;
+        }
+
+        self.current_issue = self
+            .current_issue
+            .checked_sub(1)
+            .unwrap_or(self.issues.len() - 1);
+        self.update_issue();
+    }
+
     /// Handle the model being updated
+    ///
+    /// The camera is only positioned for the first model. Later updates keep
+    /// the current view, so the model can be replaced while looking at it.
+    ///
+    /// Everything else that depends on the model is reset. The clipping plane
+    /// is moved to the center of the new model, and the selection and issues
+    /// are cleared, as they refer to objects of the previous one.
     pub fn handle_model_update(&mut self, model: Model) {
-        self.renderer.update_geometry((&model.mesh).into());
-
         let aabb = model.aabb;
         if self.model.replace(model).is_none() {
             self.camera.init_planes(&aabb);
         }
+        self.camera.update_planes(&aabb);
+        self.draw_config.clipping_plane.origin = aabb.center();
+
+        if let Some(model) = &self.model {
+            self.renderer.update_edges(Vertices::edges(&model.mesh));
+        }
+
+        self.selection.clear();
+        self.issues.clear();
+        self.current_issue = 0;
+        self.update_issue();
+
+        // The points that were picked might no longer be on the model.
+        self.snaps.clear();
+        self.update_measurement();
+    }
+
+    /// Access the selected objects
+    pub fn selection(&self) -> &BTreeSet<Selectable> {
+        &self.selection
+    }
+
+    /// Select the object under the cursor
+    ///
+    /// If `add` is `false`, the object replaces the current selection, and
+    /// clicking on nothing clears it. Otherwise, the object is added to the
+    /// selection, or removed, if it was already selected.
+    ///
+    /// Returns the object under the cursor, if there is one.
+    pub fn select_at_cursor(&mut self, add: bool) -> Option<Selectable> {
+        let picked = match (&self.model, self.cursor) {
+            (Some(model), Some(cursor)) => {
+                selection::pick(&self.camera, cursor, &model.mesh)
+            }
+            _ => None,
+        };
+
+        if !add {
+            self.selection.clear();
+        }
+        if let Some(object) = picked {
+            if !self.selection.insert(object) {
+                self.selection.remove(&object);
+            }
+        }
+
+        self.update_geometry();
+
+        picked
+    }
+
+    /// Replace the selection
+    pub fn set_selection(
+        &mut self,
+        selection: impl IntoIterator<Item = Selectable>,
+    ) {
+        self.selection = selection.into_iter().collect();
+        self.update_geometry();
+    }
+
+    /// Clear the selection
+    pub fn clear_selection(&mut self) {
+        self.set_selection([]);
+    }
+
+    /// Access the points that are being measured
+    pub fn snaps(&self) -> &[Snap] {
+        &self.snaps
+    }
+
+    /// Measure what the picked points refer to
+    ///
+    /// See [`Measurement::from_snaps`].
+    pub fn measurements(&self) -> Vec<Measurement> {
+        let Some(model) = &self.model else {
+            return Vec::new();
+        };
+
+        Measurement::from_snaps(&self.snaps, &model.mesh)
+    }
+
+    /// Pick the point under the cursor for measuring
+    ///
+    /// The point snaps to the vertex, edge, or face under the cursor. Up to two
+    /// points are picked. Picking another one starts a new measurement.
+    ///
+    /// Returns the picked point, if there is one under the cursor.
+    pub fn measure_at_cursor(&mut self) -> Option<Snap> {
+        let snap = match (&self.model, self.cursor) {
+            (Some(model), Some(cursor)) => {
+                measurement::snap(&self.camera, cursor, &model.mesh)
+            }
+            _ => None,
+        };
+
+        if let Some(snap) = snap {
+            if self.snaps.len() >= 2 {
+                self.snaps.clear();
+            }
+            self.snaps.push(snap);
+        }
+
+        self.update_measurement();
+
+        snap
     }
 
     /// Handle an input event
     pub fn handle_input_event(&mut self, event: InputEvent) {
-        if let Some(focus_point) = self.focus_point {
-            InputHandler::handle_event(event, focus_point, &mut self.camera);
+        match event {
+            InputEvent::ToggleProjection => {
+                self.camera.projection = self.camera.projection.toggled();
+            }
+            InputEvent::View(view) => {
+                self.transition_to(view);
+            }
+            InputEvent::ToggleClippingPlane => {
+                self.draw_config.clip_model = !self.draw_config.clip_model;
+            }
+            InputEvent::RotateClippingPlane => {
+                self.draw_config.clipping_plane.next_orientation();
+            }
+            InputEvent::MoveClippingPlane(distance) => {
+                self.move_clipping_plane(distance);
+            }
+            event => {
+                if let Some(focus_point) = self.focus_point {
+                    // The user is moving the camera. Don't fight them over
+                    // it.
+                    self.camera.stop_transition();
+
+                    InputHandler::handle_event(
+                        event,
+                        focus_point,
+                        &mut self.camera,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Handle a click at the current cursor position
+    ///
+    /// Clicking a face of the navigation cube moves the camera to the
+    /// corresponding view. Otherwise, the point under the cursor is picked
+    /// for measuring in measurement mode (see [`Viewer::measure_at_cursor`]),
+    /// or the object under the cursor is selected (see
+    /// [`Viewer::select_at_cursor`]).
+    ///
+    /// `add` is only used for selecting.
+    pub fn handle_click(&mut self, add: bool) {
+        let view = self.cursor.and_then(|cursor| {
+            self.renderer.navigation_cube_face(cursor, &self.camera)
+        });
+
+        match view {
+            Some(view) => self.transition_to(view),
+            None if self.measuring => {
+                self.measure_at_cursor();
+            }
+            None => {
+                self.select_at_cursor(add);
+            }
         }
     }
 
+    fn transition_to(&mut self, view: ViewPreset) {
+        if let Some(model) = &self.model {
+            self.camera.transition_to(view, &model.aabb);
+        }
+    }
+
+    fn move_clipping_plane(&mut self, distance: f64) {
+        let Some(model) = &self.model else { return };
+        let aabb = model.aabb;
+        let plane = &mut self.draw_config.clipping_plane;
+
+        let normal = plane.normal.normalize();
+        let size = aabb.size();
+        let extent = Vector::from(normal.components.map(|s| s.abs()));
+        let half_extent = size.dot(&extent) / 2.;
+
+        // Keep the plane within the model, or it could get lost.
+        let offset = normal.dot(&(plane.origin - aabb.center()))
+            + half_extent * 2. * distance;
+        let offset = offset.clamp(-half_extent, half_extent);
+
+        plane.origin = aabb.center() + normal * offset;
+    }
+
     /// Handle the screen being resized
     pub fn handle_screen_resize(&mut self, screen_size: ScreenSize) {
         self.renderer.handle_resize(screen_size);
@@ -86,6 +352,120 @@ impl Viewer {
         self.focus_point = None;
     }
 
+    fn update_geometry(&mut self) {
+        let Some(model) = &self.model else { return };
+
+        let issue = self
+            .current_issue()
+            .map(|issue| issue.objects.iter().copied().collect())
+            .unwrap_or_default();
+
+        self.renderer.update_geometry(Vertices::faces(
+            &model.mesh,
+            &self.selection,
+            &issue,
+        ));
+        self.renderer
+            .update_selected_edges(Vertices::selected_edges(
+                &model.mesh,
+                &self.selection,
+                &issue,
+            ));
+    }
+
+    fn update_issue(&mut self) {
+        let points = self
+            .current_issue()
+            .map(|issue| issue.points.as_slice())
+            .unwrap_or_default();
+        self.renderer.update_issue_markers(Vertices::issue_markers(
+            points,
+            self.marker_size(),
+        ));
+
+        self.update_geometry();
+        self.update_overlay();
+    }
+
+    fn update_measurement(&mut self) {
+        let points =
+            self.snaps.iter().map(|snap| snap.point).collect::<Vec<_>>();
+        self.renderer.update_measurement(Vertices::measurement(
+            &points,
+            self.marker_size(),
+        ));
+
+        self.update_overlay();
+    }
+
+    fn update_overlay(&mut self) {
+        let lines = if self.measuring {
+            self.measurement_lines()
+        } else {
+            self.issue_lines()
+        };
+
+        self.renderer.update_overlay(&lines);
+    }
+
+    fn measurement_lines(&self) -> Vec<String> {
+        let mut lines = vec![String::from("Measurement mode")];
+        if self.snaps.is_empty() {
+            lines.push(String::from("Click on a vertex, edge, or face"));
+        }
+        for (i, snap) in self.snaps.iter().enumerate() {
+            let [x, y, z] = snap.point.coords.components;
+            lines.push(format!(
+                "{}: {} at ({x:.3}, {y:.3}, {z:.3})",
+                i + 1,
+                snap.target,
+            ));
+        }
+        lines.extend(
+            self.measurements()
+                .iter()
+                .map(|measurement| measurement.to_string()),
+        );
+
+        lines
+    }
+
+    fn issue_lines(&self) -> Vec<String> {
+        if self.issues.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lines = vec![format!(
+            "{} issue(s) (Tab: next, Shift+Tab: previous)",
+            self.issues.len(),
+        )];
+
+        // Only show the issues around the current one, so the list doesn't
+        // cover the whole screen.
+        let first = self
+            .current_issue
+            .saturating_sub(MAX_LISTED_ISSUES / 2)
+            .min(self.issues.len().saturating_sub(MAX_LISTED_ISSUES));
+        for (i, issue) in self
+            .issues
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(MAX_LISTED_ISSUES)
+        {
+            let marker = if i == self.current_issue { '>' } else { ' ' };
+            lines.push(format!("{marker} {}: {}", i + 1, issue.description));
+        }
+
+        lines
+    }
+
+    fn marker_size(&self) -> Scalar {
+        self.model.as_ref().map_or(Scalar::ZERO, |model| {
+            model.aabb.size().magnitude() * MARKER_SIZE
+        })
+    }
+
     /// Draw the graphics
     pub fn draw(&mut self) {
         let aabb = self
@@ -94,6 +474,7 @@ impl Viewer {
             .map(|shape| shape.aabb)
             .unwrap_or_default();
 
+        self.camera.update_transition();
         self.camera.update_planes(&aabb);
 
         if let Err(err) = self.renderer.draw(&self.camera, &self.draw_config) {
@@ -101,3 +482,11 @@ impl Viewer {
         }
     }
 }
+
+/// The size of the markers that show the measured points and issues
+///
+/// This is relative to the size of the model.
+const MARKER_SIZE: f64 = 0.02;
+
+/// The maximum number of issues that are listed on screen at once
+const MAX_LISTED_ISSUES: usize = 10;
@@ -0,0 +1,63 @@
+//! Display a model that changes while the window is open
+//!
+//! A new model is sent to the window every second, until the window is closed.
+//! In a real application, the models would come from re-running a model
+//! function, for example whenever a file watcher notices a change.
+//!
+//! Run with `cargo run -p fj-window --example display_updates`.
+
+use std::{sync::mpsc, thread, time::Duration};
+
+use fj_interop::{Color, Mesh, Model};
+use fj_math::{Aabb, Point};
+
+fn main() -> Result<(), fj_window::Error> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for i in 0.. {
+            let size = 1. + f64::from(i % 10) * 0.5;
+            if sender.send(cuboid([1., 1., size])).is_err() {
+                // The window has been closed.
+                break;
+            }
+
+            thread::sleep(Duration::from_secs(1));
+        }
+    });
+
+    fj_window::display_updates(receiver, false)
+}
+
+fn cuboid(size: [f64; 3]) -> Model {
+    let [x, y, z] = size;
+    let vertex = |i: usize| {
+        Point::from([
+            if i & 1 == 0 { 0. } else { x },
+            if i & 2 == 0 { 0. } else { y },
+            if i & 4 == 0 { 0. } else { z },
+        ])
+    };
+
+    let mut mesh = Mesh::new();
+    for [a, b, c, d] in [
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+    ] {
+        let [a, b, c, d] = [a, b, c, d].map(vertex);
+        mesh.push_triangle([a, b, c], Color::default());
+        mesh.push_triangle([a, c, d], Color::default());
+    }
+
+    Model {
+        mesh,
+        aabb: Aabb {
+            min: Point::origin(),
+            max: Point::from(size),
+        },
+    }
+}
@@ -1,7 +1,9 @@
+use std::{sync::mpsc::Receiver, thread};
+
 use fj_interop::Model;
 use fj_viewer::{
-    InputEvent, NormalizedScreenPosition, RendererInitError, Screen,
-    ScreenSize, Viewer,
+    InputEvent, Issue, NormalizedScreenPosition, RendererInitError, Screen,
+    ScreenSize, ViewPreset, Viewer,
 };
 use futures::executor::block_on;
 use winit::{
@@ -12,7 +14,7 @@ use winit::{
         ElementState, KeyEvent, MouseButton, MouseScrollDelta, WindowEvent,
     },
     event_loop::{ActiveEventLoop, EventLoop},
-    keyboard::{Key, NamedKey},
+    keyboard::{Key, ModifiersState, NamedKey},
     window::WindowId,
 };
 
@@ -20,14 +22,74 @@ use crate::window::{self, Window};
 
 /// Display the provided mesh in a window that processes input
 pub fn display(model: Model, invert_zoom: bool) -> Result<(), Error> {
-    let event_loop = EventLoop::new()?;
+    run(Some(model), Vec::new(), None, invert_zoom)
+}
+
+/// Display the provided mesh, along with issues that were found with it
+///
+/// The issues are listed in the window, and the objects and points of the
+/// current one are highlighted. Tab and Shift+Tab cycle through the issues.
+pub fn display_with_issues(
+    model: Model,
+    issues: Vec<Issue>,
+    invert_zoom: bool,
+) -> Result<(), Error> {
+    run(Some(model), issues, None, invert_zoom)
+}
+
+/// Display models in a window, replacing them as updates are received
+///
+/// Every model received from `updates` replaces the one currently displayed,
+/// while the camera stays where it is. This makes it possible to re-run a
+/// model function whenever its source changes, for example, and see the
+/// result without restarting the window.
+///
+/// The window stays open after the sender is dropped, showing the last model
+/// received.
+///
+/// Neither this crate nor `fj::Instance` watch for changes, so nothing sends
+/// models through `updates` on its own. The caller has to drive the channel
+/// from another thread, by re-running the model function whenever it should be
+/// reloaded, and sending the [`Model`] that results from triangulating it. This
+/// function blocks until the window is closed.
+pub fn display_updates(
+    updates: Receiver<Model>,
+    invert_zoom: bool,
+) -> Result<(), Error> {
+    run(None, Vec::new(), Some(updates), invert_zoom)
+}
+
+fn run(
+    model: Option<Model>,
+    issues: Vec<Issue>,
+    updates: Option<Receiver<Model>>,
+    invert_zoom: bool,
+) -> Result<(), Error> {
+    let event_loop = EventLoop::<Model>::with_user_event().build()?;
+
+    if let Some(updates) = updates {
+        // The receiver can only be waited on by blocking, so it needs its own
+        // thread. Sending a model through the proxy wakes up the event loop.
+        let proxy = event_loop.create_proxy();
+        thread::spawn(move || {
+            for model in updates {
+                if proxy.send_event(model).is_err() {
+                    // The event loop has been closed.
+                    break;
+                }
+            }
+        });
+    }
 
     let mut display_state = DisplayState {
-        model: Some(model),
+        model,
+        issues: Some(issues),
         invert_zoom,
         window: None,
         viewer: None,
         held_mouse_button: None,
+        click_start: None,
+        modifiers: ModifiersState::default(),
         new_size: None,
         stop_drawing: false,
     };
@@ -55,15 +117,18 @@ pub enum Error {
 
 struct DisplayState {
     model: Option<Model>,
+    issues: Option<Vec<Issue>>,
     invert_zoom: bool,
     window: Option<Window>,
     viewer: Option<Viewer>,
     held_mouse_button: Option<MouseButton>,
+    click_start: Option<NormalizedScreenPosition>,
+    modifiers: ModifiersState,
     new_size: Option<ScreenSize>,
     stop_drawing: bool,
 }
 
-impl ApplicationHandler for DisplayState {
+impl ApplicationHandler<Model> for DisplayState {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         let window = self
             .window
@@ -76,6 +141,20 @@ impl ApplicationHandler for DisplayState {
         if let Some(model) = self.model.take() {
             viewer.handle_model_update(model);
         }
+        if let Some(issues) = self.issues.take() {
+            viewer.set_issues(issues);
+        }
+    }
+
+    fn user_event(&mut self, _: &ActiveEventLoop, model: Model) {
+        match &mut self.viewer {
+            Some(viewer) => viewer.handle_model_update(model),
+            None => {
+                // Not initialized yet. The latest model will be picked up
+                // in `resumed`.
+                self.model = Some(model);
+            }
+        }
     }
 
     fn window_event(
@@ -122,6 +201,57 @@ impl ApplicationHandler for DisplayState {
                 Key::Character("2") => {
                     viewer.toggle_draw_mesh();
                 }
+                Key::Character("e") => {
+                    viewer.toggle_draw_edges();
+                }
+                Key::Character("m") => {
+                    viewer.toggle_measurement_mode();
+                }
+                Key::Named(NamedKey::Tab) => {
+                    if self.modifiers.shift_key() {
+                        viewer.previous_issue();
+                    } else {
+                        viewer.next_issue();
+                    }
+                }
+                Key::Character("3") => {
+                    viewer.handle_input_event(InputEvent::ToggleClippingPlane);
+                }
+                Key::Character("4") => {
+                    viewer.handle_input_event(InputEvent::RotateClippingPlane);
+                }
+                Key::Character("5") => {
+                    viewer.handle_input_event(InputEvent::ToggleProjection);
+                }
+                Key::Character("f") => {
+                    viewer.handle_input_event(InputEvent::View(
+                        ViewPreset::Front,
+                    ));
+                }
+                Key::Character("t") => {
+                    viewer
+                        .handle_input_event(InputEvent::View(ViewPreset::Top));
+                }
+                Key::Character("r") => {
+                    viewer.handle_input_event(InputEvent::View(
+                        ViewPreset::Right,
+                    ));
+                }
+                Key::Character("i") => {
+                    viewer.handle_input_event(InputEvent::View(
+                        ViewPreset::Isometric,
+                    ));
+                }
+                Key::Named(NamedKey::PageUp) => {
+                    viewer.handle_input_event(InputEvent::MoveClippingPlane(
+                        CLIPPING_PLANE_STEP,
+                    ));
+                }
+                Key::Named(NamedKey::PageDown) => {
+                    viewer.handle_input_event(InputEvent::MoveClippingPlane(
+                        -CLIPPING_PLANE_STEP,
+                    ));
+                }
                 _ => {}
             },
             WindowEvent::Resized(size) => {
@@ -130,14 +260,35 @@ impl ApplicationHandler for DisplayState {
                     height: size.height,
                 });
             }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers.state();
+            }
             WindowEvent::MouseInput { state, button, .. } => match state {
                 ElementState::Pressed => {
                     self.held_mouse_button = Some(button);
                     viewer.add_focus_point();
+
+                    if button == MouseButton::Left {
+                        self.click_start = *viewer.cursor();
+                    }
                 }
                 ElementState::Released => {
                     self.held_mouse_button = None;
                     viewer.remove_focus_point();
+
+                    // Dragging with the left mouse button rotates the model.
+                    // Only select something, if the mouse didn't move.
+                    let click_start = self.click_start.take();
+                    if let (Some(start), Some(end)) =
+                        (click_start, *viewer.cursor())
+                    {
+                        let distance = (end.x - start.x).hypot(end.y - start.y);
+                        if button == MouseButton::Left
+                            && distance < MAX_CLICK_DISTANCE
+                        {
+                            viewer.handle_click(self.modifiers.shift_key());
+                        }
+                    }
                 }
             },
             WindowEvent::MouseWheel { .. } => viewer.add_focus_point(),
@@ -233,6 +384,14 @@ const ZOOM_FACTOR_LINE: f64 = 0.075;
 /// Larger values will move the camera more with the same input.
 const ZOOM_FACTOR_PIXEL: f64 = 0.005;
 
+/// How far the clipping plane moves per key press, relative to the model size
+const CLIPPING_PLANE_STEP: f64 = 0.02;
+
+/// How far the cursor can move between press and release, to count as a click
+///
+/// This is in normalized screen coordinates [-1, 1].
+const MAX_CLICK_DISTANCE: f64 = 0.005;
+
 /// Affects the speed of rotation given a change in normalized screen position [-1, 1]
 ///
 /// Smaller values will move the camera less with the same input.
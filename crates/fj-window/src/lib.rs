@@ -12,6 +12,6 @@ mod display;
 mod window;
 
 pub use self::{
-    display::{display, Error},
+    display::{display, display_updates, display_with_issues, Error},
     window::WindowError,
 };
@@ -1,7 +1,15 @@
-use std::{num::ParseFloatError, path::PathBuf, str::FromStr};
+use std::{
+    num::{ParseFloatError, ParseIntError},
+    path::PathBuf,
+    str::FromStr,
+};
 
-use fj_core::algorithms::approx::{InvalidTolerance, Tolerance};
+use fj_core::{
+    algorithms::approx::{InvalidTolerance, Tolerance},
+    validation::{Severity, ValidationConfig, ValidationError},
+};
 use fj_math::Scalar;
+use fj_viewer::ScreenSize;
 
 /// Standardized CLI for Fornjot models
 ///
@@ -17,9 +25,25 @@ use fj_math::Scalar;
 #[derive(clap::Parser)]
 pub struct Args {
     /// Export model to this path
+    ///
+    /// The file type is determined by the extension. Supported are 3MF, STL,
+    /// OBJ, and STEP (`.step` or `.stp`).
     #[arg(short, long, value_name = "PATH")]
     pub export: Option<PathBuf>,
 
+    /// Render model to a PNG image at this path, without opening a window
+    #[arg(short, long, value_name = "PATH")]
+    pub render: Option<PathBuf>,
+
+    /// The size of the image rendered through `--render`, in pixels
+    #[arg(
+        long,
+        value_name = "WIDTHxHEIGHT",
+        value_parser = parse_render_size,
+        default_value = "1024x768"
+    )]
+    pub render_size: ScreenSize,
+
     /// How much the export can deviate from the original model
     #[arg(short, long, value_parser = parse_tolerance)]
     pub tolerance: Option<Tolerance>,
@@ -27,6 +51,51 @@ pub struct Args {
     /// Ignore validation errors
     #[arg(short, long)]
     pub ignore_validation: bool,
+
+    /// Open the viewer despite validation errors, highlighting them
+    ///
+    /// The model is still triangulated for display, which can fail for some
+    /// validation errors. The errors are returned once the window is closed.
+    /// Has no effect, if `--export` or `--render` are passed.
+    #[arg(long)]
+    pub show_validation_errors: bool,
+
+    /// Write a JSON report of all validation errors to this path
+    ///
+    /// The report includes all validation errors and warnings, regardless of
+    /// other arguments. It is written, even if `--ignore-validation` is
+    /// passed.
+    #[arg(long, value_name = "PATH")]
+    pub validation_report: Option<PathBuf>,
+
+    /// Disable a validation check (can be repeated)
+    ///
+    /// Requires the instance to be constructed using `Instance::from_args`.
+    /// Processing the model fails otherwise.
+    #[arg(long, value_name = "CHECK", value_parser = parse_check_name)]
+    pub disable_check: Vec<&'static str>,
+
+    /// Treat errors of a validation check as warnings (can be repeated)
+    ///
+    /// Requires the instance to be constructed using `Instance::from_args`.
+    /// Processing the model fails otherwise.
+    #[arg(long, value_name = "CHECK", value_parser = parse_check_name)]
+    pub warn_check: Vec<&'static str>,
+
+    /// Override a threshold of a validation check (can be repeated)
+    ///
+    /// Expects `CHECK:THRESHOLD=VALUE`, where `THRESHOLD` is one of
+    /// `distinct_min_distance`, `identical_max_distance`, or
+    /// `approximation_tolerance`.
+    ///
+    /// Requires the instance to be constructed using `Instance::from_args`.
+    /// Processing the model fails otherwise.
+    #[arg(
+        long,
+        value_name = "CHECK:THRESHOLD=VALUE",
+        value_parser = parse_check_threshold
+    )]
+    pub check_threshold: Vec<CheckThreshold>,
 }
 
 impl Args {
@@ -37,6 +106,58 @@ impl Args {
     pub fn parse() -> Self {
         <Self as clap::Parser>::parse()
     }
+
+    /// Apply the validation-related arguments to a validation configuration
+    pub fn configure_validation(&self, config: &mut ValidationConfig) {
+        for check in &self.disable_check {
+            config.check_mut(check).enabled = false;
+        }
+        for check in &self.warn_check {
+            config.check_mut(check).severity = Severity::Warning;
+        }
+        for CheckThreshold { check, threshold } in &self.check_threshold {
+            let check = config.check_mut(check);
+
+            match *threshold {
+                Threshold::DistinctMinDistance(distance) => {
+                    check.distinct_min_distance = Some(distance);
+                }
+                Threshold::IdenticalMaxDistance(distance) => {
+                    check.identical_max_distance = Some(distance);
+                }
+                Threshold::ApproximationTolerance(tolerance) => {
+                    check.approximation_tolerance = Some(tolerance);
+                }
+            }
+        }
+    }
+}
+
+/// A threshold of a specific validation check, as overridden via [`Args`]
+#[derive(Clone, Copy, Debug)]
+pub struct CheckThreshold {
+    /// The name of the validation check
+    pub check: &'static str,
+
+    /// The overridden threshold
+    pub threshold: Threshold,
+}
+
+/// A threshold of a validation check
+///
+/// See [`CheckConfig`] for more information on the individual thresholds.
+///
+/// [`CheckConfig`]: fj_core::validation::CheckConfig
+#[derive(Clone, Copy, Debug)]
+pub enum Threshold {
+    /// Overrides the minimum distance between distinct objects
+    DistinctMinDistance(Scalar),
+
+    /// Overrides the maximum distance between identical objects
+    IdenticalMaxDistance(Scalar),
+
+    /// Overrides the tolerance used to approximate curved geometry
+    ApproximationTolerance(Tolerance),
 }
 
 fn parse_tolerance(input: &str) -> Result<Tolerance, ArgsError> {
@@ -47,6 +168,50 @@ fn parse_tolerance(input: &str) -> Result<Tolerance, ArgsError> {
     Ok(tolerance)
 }
 
+fn parse_render_size(input: &str) -> Result<ScreenSize, ArgsError> {
+    let malformed = || ArgsError::MalformedRenderSize(input.to_string());
+
+    let (width, height) = input.split_once('x').ok_or_else(malformed)?;
+    let [width, height] = [width, height].map(u32::from_str);
+    let (width, height) = (width?, height?);
+
+    if width == 0 || height == 0 {
+        return Err(malformed());
+    }
+
+    Ok(ScreenSize { width, height })
+}
+
+fn parse_check_name(input: &str) -> Result<&'static str, ArgsError> {
+    ValidationError::CHECK_NAMES
+        .into_iter()
+        .find(|name| *name == input)
+        .ok_or_else(|| ArgsError::UnknownCheck(input.to_string()))
+}
+
+fn parse_check_threshold(input: &str) -> Result<CheckThreshold, ArgsError> {
+    let malformed = || ArgsError::MalformedCheckThreshold(input.to_string());
+
+    let (check, threshold) = input.split_once(':').ok_or_else(malformed)?;
+    let (threshold, value) = threshold.split_once('=').ok_or_else(malformed)?;
+
+    let check = parse_check_name(check)?;
+    let value = Scalar::from_f64(f64::from_str(value)?);
+
+    let threshold = match threshold {
+        "distinct_min_distance" => Threshold::DistinctMinDistance(value),
+        "identical_max_distance" => Threshold::IdenticalMaxDistance(value),
+        "approximation_tolerance" => {
+            Threshold::ApproximationTolerance(Tolerance::from_scalar(value)?)
+        }
+        threshold => {
+            return Err(ArgsError::UnknownThreshold(threshold.to_string()))
+        }
+    };
+
+    Ok(CheckThreshold { check, threshold })
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ArgsError {
     #[error("Error parsing tolerance")]
@@ -54,4 +219,40 @@ pub enum ArgsError {
 
     #[error(transparent)]
     InvalidTolerance(#[from] InvalidTolerance),
+
+    #[error("Error parsing render size")]
+    ParseRenderSize(#[from] ParseIntError),
+
+    #[error("Expected `WIDTHxHEIGHT`, with both larger than zero, got `{0}`")]
+    MalformedRenderSize(String),
+
+    #[error(
+        "Unknown validation check `{0}`; expected one of: {}",
+        ValidationError::CHECK_NAMES.join(", ")
+    )]
+    UnknownCheck(String),
+
+    #[error("Expected `CHECK:THRESHOLD=VALUE`, got `{0}`")]
+    MalformedCheckThreshold(String),
+
+    #[error(
+        "Unknown threshold `{0}`; expected one of: distinct_min_distance, \
+        identical_max_distance, approximation_tolerance"
+    )]
+    UnknownThreshold(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_render_size;
+
+    #[test]
+    fn render_size() {
+        let size = parse_render_size("640x480").unwrap();
+        assert_eq!((size.width, size.height), (640, 480));
+
+        for input in ["640", "640x", "x480", "0x480", "640x-1", "640x480x2"] {
+            assert!(parse_render_size(input).is_err(), "{input}");
+        }
+    }
 }
@@ -11,9 +11,15 @@ use fj_core::{
 };
 use fj_interop::Model;
 use fj_math::{Aabb, Point, Scalar};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use fj_viewer::{Camera, DrawConfig};
+use futures::executor::block_on;
+use tracing_subscriber::{
+    layer::SubscriberExt, util::SubscriberInitExt, EnvFilter,
+};
 
-use crate::Args;
+use crate::{
+    export::Bodies, issues::validation_issues, report::ValidationReport, Args,
+};
 
 /// An instance of Fornjot
 ///
@@ -36,6 +42,18 @@ impl Instance {
         Self { core }
     }
 
+    /// Construct an instance of `Instance`, configured by CLI arguments
+    ///
+    /// Validation checks run as objects are constructed. This means that the
+    /// validation-related arguments (see [`Args`]) can only take effect, if
+    /// the instance is constructed using this method.
+    pub fn from_args() -> Self {
+        let mut config = ValidationConfig::default();
+        Args::parse().configure_validation(&mut config);
+
+        Self::with_validation_config(config)
+    }
+
     /// Export or display a model, according to CLI arguments
     ///
     /// This function is intended to be called by applications that define a
@@ -48,16 +66,59 @@ impl Instance {
     where
         for<'r> (&'r M, Tolerance): Triangulate,
         for<'r> &'r M: BoundingVolume<3>,
+        M: Bodies,
     {
+        // Without explicit configuration, show errors from all crates, and
+        // the warnings of this one. Those include validation warnings.
+        let filter = EnvFilter::try_from_default_env()
+            .unwrap_or_else(|_| EnvFilter::new("error,fj=warn"));
+
         tracing_subscriber::registry()
             .with(tracing_subscriber::fmt::layer())
-            .with(tracing_subscriber::EnvFilter::from_default_env())
+            .with(filter)
             .init();
 
         let args = Args::parse();
 
+        // Validation checks run as objects are constructed, which happened
+        // before this method was called. It's too late to apply the
+        // validation-related arguments now, so make sure they were applied
+        // already, instead of silently ignoring them.
+        let config = &self.core.layers.validation.config;
+        let mut configured = config.clone();
+        args.configure_validation(&mut configured);
+        if configured.checks != config.checks {
+            return Err(Error::ValidationArgsNotApplied);
+        }
+
+        // This needs to happen before the errors are taken out of the
+        // validation layer below. The report is written, even if validation
+        // errors are ignored, as it exists to inspect them.
+        if let Some(path) = &args.validation_report {
+            ValidationReport::from_validation(
+                &self.core.layers.validation,
+                &self.core.layers.geometry,
+            )
+            .write_json(path)?;
+        }
+
+        let mut validation_errors = None;
         if !args.ignore_validation {
-            self.core.layers.validation.take_errors()?;
+            let show_errors = args.show_validation_errors
+                && args.export.is_none()
+                && args.render.is_none();
+
+            match self.core.layers.validation.take_errors() {
+                Err(errors) if show_errors => validation_errors = Some(errors),
+                result => result?,
+            }
+
+            for warning in self.core.layers.validation.take_warnings() {
+                tracing::warn!(
+                    "Validation warning ({}):\n{warning}",
+                    warning.check_name()
+                );
+            }
         }
 
         let aabb = model.aabb(&self.core.layers.geometry).unwrap_or(Aabb {
@@ -84,14 +145,45 @@ impl Instance {
             Some(user_defined_tolerance) => user_defined_tolerance,
         };
 
+        if let Some(path) = &args.export {
+            crate::export::export_model(
+                model,
+                tolerance,
+                &mut self.core,
+                path,
+            )?;
+        }
+
         let mesh = (model, tolerance).triangulate(&mut self.core);
 
-        if let Some(path) = args.export {
-            crate::export::export(&mesh, &path)?;
+        let model = Model { mesh, aabb };
+
+        if let Some(path) = &args.render {
+            let mut camera = Camera::new();
+            camera.init_planes(&model.aabb);
+
+            block_on(crate::viewer::render_to_png(
+                &model,
+                &camera,
+                &DrawConfig::default(),
+                args.render_size,
+                path,
+            ))?;
+        }
+
+        if args.export.is_some() || args.render.is_some() {
             return Ok(());
         }
 
-        let model = Model { mesh, aabb };
+        if let Some(errors) = validation_errors {
+            let issues = validation_issues(
+                &errors.0,
+                &model.mesh,
+                &self.core.layers.geometry,
+            );
+            crate::window::display_with_issues(model, issues, false)?;
+            return Err(errors.into());
+        }
 
         crate::window::display(model, false)?;
 
@@ -117,6 +209,10 @@ pub enum Error {
     #[error("Error exporting model")]
     Export(#[from] crate::export::Error),
 
+    /// Error rendering model
+    #[error("Error rendering model")]
+    Render(#[from] crate::viewer::RenderError),
+
     /// Invalid tolerance
     #[error(transparent)]
     Tolerance(#[from] InvalidTolerance),
@@ -124,6 +220,19 @@ pub enum Error {
     /// Unhandled validation errors
     #[error(transparent)]
     Validation(#[from] ValidationErrors),
+
+    /// Validation-related arguments were passed, but not applied
+    ///
+    /// See [`Instance::from_args`].
+    #[error(
+        "Validation-related arguments were passed, but the instance was not \
+        constructed using `Instance::from_args`"
+    )]
+    ValidationArgsNotApplied,
+
+    /// Error writing validation report
+    #[error(transparent)]
+    ValidationReport(#[from] crate::report::Error),
 }
 
 impl fmt::Debug for Error {
@@ -0,0 +1,153 @@
+use fj_core::{
+    geometry::Geometry,
+    topology::{AnyObject, Stored},
+    validation::ValidationError,
+};
+use fj_interop::Mesh;
+use fj_math::Point;
+use fj_viewer::{Issue, Selectable};
+
+/// Convert validation errors into issues that the viewer can highlight
+///
+/// Only faces and half-edges are part of the mesh, so only those can be
+/// highlighted directly. Cycles are highlighted via their half-edges. The ends
+/// of highlighted half-edges are marked, to show where they fail to connect.
+pub fn validation_issues(
+    errors: &[ValidationError],
+    mesh: &Mesh<Point<3>>,
+    geometry: &Geometry,
+) -> Vec<Issue> {
+    errors
+        .iter()
+        .map(|err| {
+            let mut objects = Vec::new();
+            for object in err.objects() {
+                objects.extend(selectables(object));
+            }
+
+            // Sibling half-edges are merged into a single edge of the mesh,
+            // which is identified by only one of them.
+            let mut points = err.positions(geometry);
+            for edge in mesh.edges() {
+                let is_highlighted = |object: &Selectable| {
+                    edge.sources()
+                        .any(|source| *object == Selectable::Edge(source))
+                };
+
+                if objects.iter().any(is_highlighted) {
+                    objects.retain(|object| !is_highlighted(object));
+                    objects.push(Selectable::Edge(edge.source));
+
+                    points.extend(edge.points.first());
+                    points.extend(edge.points.last());
+                }
+            }
+
+            let message = err.to_string();
+            let summary = message.lines().next().unwrap_or_default();
+            let description = format!("{}: {summary}", err.check_name());
+
+            Issue {
+                description,
+                objects,
+                points,
+            }
+        })
+        .collect()
+}
+
+fn selectables(object: AnyObject<Stored>) -> Vec<Selectable> {
+    match object {
+        AnyObject::Face(face) => vec![Selectable::Face(face.id().into())],
+        AnyObject::HalfEdge(half_edge) => {
+            vec![Selectable::Edge(half_edge.id().into())]
+        }
+        AnyObject::Cycle(cycle) => cycle
+            .half_edges()
+            .iter()
+            .map(|half_edge| Selectable::Edge(half_edge.id().into()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        operations::build::BuildShell,
+        topology::Shell,
+        validation::{checks::ShellIsNotClosed, ValidationError},
+        Core,
+    };
+    use fj_interop::SourceId;
+    use fj_viewer::Selectable;
+
+    use super::validation_issues;
+
+    #[test]
+    fn issues_refer_to_mesh_triangles_and_edges() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let shell = Shell::tetrahedron(
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut core,
+        )
+        .shell;
+        let mesh = (&shell, Tolerance::from_scalar(1.)?).triangulate(&mut core);
+
+        // The half-edges of the last face are siblings of half-edges in other
+        // faces. Those were triangulated first, so they identify the edges of
+        // the mesh.
+        let face = shell.faces().iter().last().unwrap().clone();
+        let half_edges = face.region().exterior().half_edges();
+
+        let errors = half_edges
+            .iter()
+            .map(|half_edge| {
+                ValidationError::from(ShellIsNotClosed {
+                    half_edge: half_edge.clone(),
+                    face: face.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let issues = validation_issues(&errors, &mesh, &core.layers.geometry);
+        assert_eq!(issues.len(), half_edges.len());
+
+        let face_id = SourceId::from(face.id());
+        assert!(mesh
+            .triangles()
+            .any(|triangle| triangle.face == Some(face_id)));
+
+        for (issue, half_edge) in issues.iter().zip(half_edges) {
+            assert!(issue.description.starts_with("ShellIsNotClosed: "));
+            assert!(issue.objects.contains(&Selectable::Face(face_id)));
+
+            let edges = issue
+                .objects
+                .iter()
+                .filter_map(|object| match object {
+                    Selectable::Edge(source) => Some(*source),
+                    Selectable::Face(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let [source] = edges.as_slice() else {
+                panic!("Expected exactly one edge, got {edges:?}");
+            };
+
+            let edge = mesh
+                .edges()
+                .find(|edge| edge.source == *source)
+                .expect("Issue must refer to edge of the mesh");
+            assert_eq!(edge.sibling, Some(SourceId::from(half_edge.id())));
+
+            // The edge is identified by the sibling of the half-edge, so it
+            // ends where the half-edge starts.
+            let [start, end] =
+                [edge.points[0], edge.points[edge.points.len() - 1]];
+            assert_eq!(issue.points, [end, start, end]);
+        }
+
+        Ok(())
+    }
+}
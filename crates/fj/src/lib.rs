@@ -11,14 +11,18 @@
 
 mod args;
 mod instance;
+mod issues;
+
+pub mod report;
 
 pub use self::{
-    args::Args,
+    args::{Args, CheckThreshold, Threshold},
     instance::{Error, Instance, Result},
 };
 
 pub use fj_core as core;
 pub use fj_export as export;
+pub use fj_import as import;
 pub use fj_interop as interop;
 pub use fj_math as math;
 pub use fj_viewer as viewer;
@@ -0,0 +1,225 @@
+//! Machine-readable validation reports
+//!
+//! See [`ValidationReport`].
+
+use std::{collections::HashMap, fs, io, path::Path};
+
+use fj_core::{
+    geometry::Geometry,
+    storage::ObjectId,
+    validation::{Validation, ValidationError},
+};
+use fj_interop::SourceId;
+
+/// A machine-readable report of the validation errors of a model
+///
+/// Can be written as JSON, using [`ValidationReport::write_json`]. This is done
+/// by [`Instance::process_model`], if requested via [`Args::validation_report`].
+///
+/// [`Instance::process_model`]: crate::Instance::process_model
+/// [`Args::validation_report`]: crate::Args::validation_report
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ValidationReport {
+    /// The validation errors
+    pub errors: Vec<ValidationReportEntry>,
+
+    /// The validation errors of checks that are configured as warnings
+    pub warnings: Vec<ValidationReportEntry>,
+}
+
+impl ValidationReport {
+    /// Create a report from the errors stored in the validation layer
+    ///
+    /// The entries are sorted by the name of the check that produced them. The
+    /// geometry is used to convert positions into 3D space.
+    pub fn from_validation(
+        validation: &Validation,
+        geometry: &Geometry,
+    ) -> Self {
+        Self {
+            errors: entries(&validation.errors, geometry),
+            warnings: entries(&validation.warnings, geometry),
+        }
+    }
+
+    /// Write the report as JSON to the provided path
+    pub fn write_json(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// A single validation error within a [`ValidationReport`]
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ValidationReportEntry {
+    /// The name of the validation check that produced the error
+    pub check: String,
+
+    /// The ID of the object that was validated
+    pub object: u64,
+
+    /// The human-readable error message
+    pub message: String,
+
+    /// The objects involved in the error
+    pub objects: Vec<ValidationReportObject>,
+
+    /// The positions in 3D space that are relevant to the error
+    pub positions: Vec<[f64; 3]>,
+
+    /// The distances that caused the error
+    pub distances: Vec<f64>,
+}
+
+impl ValidationReportEntry {
+    fn new(
+        object: ObjectId,
+        err: &ValidationError,
+        geometry: &Geometry,
+    ) -> Self {
+        Self {
+            check: err.check_name().to_string(),
+            object: object_id(object),
+            message: err.to_string(),
+            objects: err
+                .objects()
+                .into_iter()
+                .map(|object| ValidationReportObject {
+                    kind: object.type_name().to_string(),
+                    id: object_id(object.id()),
+                })
+                .collect(),
+            positions: err
+                .positions(geometry)
+                .into_iter()
+                .map(|point| point.coords.components.map(|s| s.into_f64()))
+                .collect(),
+            distances: err
+                .distances()
+                .into_iter()
+                .map(|distance| distance.into_f64())
+                .collect(),
+        }
+    }
+}
+
+/// An object involved in a validation error
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ValidationReportObject {
+    /// The type of the object, for example `HalfEdge`
+    pub kind: String,
+
+    /// The ID of the object
+    ///
+    /// This is the same ID that identifies the object in the triangle mesh of
+    /// the model.
+    pub id: u64,
+}
+
+fn entries(
+    errors: &HashMap<ObjectId, ValidationError>,
+    geometry: &Geometry,
+) -> Vec<ValidationReportEntry> {
+    let mut entries = errors
+        .iter()
+        .map(|(object, err)| ValidationReportEntry::new(*object, err, geometry))
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| (&a.check, a.object).cmp(&(&b.check, b.object)));
+    entries
+}
+
+fn object_id(id: ObjectId) -> u64 {
+    SourceId::from(id).0
+}
+
+/// Error writing a [`ValidationReport`]
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Error serializing the report
+    #[error("Error serializing validation report")]
+    Serialize(#[from] serde_json::Error),
+
+    /// Error writing the report to a file
+    #[error("Error writing validation report")]
+    Io(#[from] io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use fj_core::{
+        operations::{
+            build::{BuildFace, BuildShell},
+            insert::Insert,
+            update::UpdateShell,
+        },
+        topology::{Face, Shell},
+        Core,
+    };
+    use fj_interop::SourceId;
+
+    use super::ValidationReport;
+
+    #[test]
+    fn json_round_trip() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::unbound(surface, &mut core).insert(&mut core);
+
+        let report = ValidationReport::from_validation(
+            &core.layers.validation,
+            &core.layers.geometry,
+        );
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.warnings.is_empty());
+
+        let entry = &report.errors[0];
+        assert_eq!(entry.check, "FaceHasNoBoundary");
+        assert_eq!(entry.object, SourceId::from(face.id()).0);
+
+        let path = env::temp_dir().join("fj-validation-report-round-trip.json");
+        report.write_json(&path)?;
+        let json = fs::read_to_string(&path)?;
+        fs::remove_file(&path)?;
+
+        let read: ValidationReport = serde_json::from_str(&json)?;
+        assert_eq!(read, report);
+
+        assert!(core.layers.validation.take_errors().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn positions_are_reported_in_3d() {
+        let mut core = Core::new();
+
+        let [a, b, c, d] =
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]];
+        let tetrahedron = Shell::tetrahedron([a, b, c, d], &mut core);
+        let shell = tetrahedron
+            .shell
+            .remove_face(&tetrahedron.abc.face)
+            .insert(&mut core);
+
+        let report = ValidationReport::from_validation(
+            &core.layers.validation,
+            &core.layers.geometry,
+        );
+        assert!(!report.errors.is_empty());
+
+        for entry in &report.errors {
+            assert_eq!(entry.object, SourceId::from(shell.id()).0);
+            assert!(!entry.positions.is_empty(), "{entry:#?}");
+
+            for position in &entry.positions {
+                assert!([a, b, c].contains(position), "{entry:#?}");
+            }
+        }
+
+        assert!(core.layers.validation.take_errors().is_err());
+    }
+}
@@ -1,5 +1,5 @@
 fn main() -> fj::Result {
-    let mut fj = fj::Instance::new();
+    let mut fj = fj::Instance::from_args();
     let model = vertices_indices::model(&mut fj.core);
     fj.process_model(&model)?;
     Ok(())